@@ -3,6 +3,7 @@
 use parking_lot::RwLock;
 use rustc_hash::FxHashMap;
 use smol_str::SmolStr;
+use std::cmp::Ordering;
 use std::fmt;
 
 /// An interned identifier name.
@@ -29,6 +30,19 @@ impl Name {
     pub const fn index(self) -> u32 {
         self.0
     }
+
+    /// Get a value that displays the name's string, looked up lazily in
+    /// `interner`.
+    ///
+    /// Falls back to the `Debug` form (`Name(12)`) if the interner does not
+    /// contain this name.
+    #[inline]
+    pub fn display(self, interner: &Interner) -> NameDisplay<'_> {
+        NameDisplay {
+            name: self,
+            interner,
+        }
+    }
 }
 
 impl fmt::Debug for Name {
@@ -37,6 +51,30 @@ impl fmt::Debug for Name {
     }
 }
 
+/// Helper returned by [`Name::display`] that formats a name without
+/// allocating.
+#[derive(Copy, Clone)]
+pub struct NameDisplay<'a> {
+    name: Name,
+    interner: &'a Interner,
+}
+
+impl fmt::Display for NameDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.interner.inner.read();
+        match inner.strings.get(self.name.0 as usize) {
+            Some(s) => f.write_str(s),
+            None => fmt::Debug::fmt(&self.name, f),
+        }
+    }
+}
+
+impl fmt::Debug for NameDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
 /// String interner for deduplicating identifier strings.
 ///
 /// Thread-safe via internal locking.
@@ -53,6 +91,27 @@ struct InternerInner {
     strings: Vec<SmolStr>,
 }
 
+impl InternerInner {
+    fn cmp_names(&self, a: Name, b: Name) -> Ordering {
+        let a_str = self.strings.get(a.0 as usize);
+        let b_str = self.strings.get(b.0 as usize);
+        a_str.cmp(&b_str).then(a.cmp(&b))
+    }
+
+    /// Get the index of `s`, inserting it if it is new.
+    fn insert(&mut self, s: &str) -> u32 {
+        if let Some(&index) = self.map.get(s) {
+            return index;
+        }
+
+        let smol = SmolStr::new(s);
+        let index = self.strings.len() as u32;
+        self.strings.push(smol.clone());
+        self.map.insert(smol, index);
+        index
+    }
+}
+
 impl Interner {
     /// Create a new empty interner.
     pub fn new() -> Self {
@@ -75,16 +134,34 @@ impl Interner {
         let mut inner = self.inner.write();
 
         // Double-check after acquiring write lock
-        if let Some(&index) = inner.map.get(s) {
-            return Name::from_raw(index);
-        }
+        Name::from_raw(inner.insert(s))
+    }
 
-        let smol = SmolStr::new(s);
-        let index = inner.strings.len() as u32;
-        inner.strings.push(smol.clone());
-        inner.map.insert(smol, index);
+    /// Intern a batch of strings, returning their `Name`s in the same order.
+    ///
+    /// Equivalent to calling [`Interner::intern`] on each string, but takes the
+    /// write lock at most once for the whole batch. Duplicates within the batch
+    /// get the same `Name`. If every string is already interned, only the read
+    /// lock is taken.
+    pub fn intern_many(&self, strings: &[&str]) -> Vec<Name> {
+        // Fast path: all already interned (read lock)
+        {
+            let inner = self.inner.read();
+            let existing: Option<Vec<Name>> = strings
+                .iter()
+                .map(|s| inner.map.get(*s).map(|&index| Name::from_raw(index)))
+                .collect();
+            if let Some(names) = existing {
+                return names;
+            }
+        }
 
-        Name::from_raw(index)
+        // Slow path: insert the missing strings under one write lock
+        let mut inner = self.inner.write();
+        strings
+            .iter()
+            .map(|s| Name::from_raw(inner.insert(s)))
+            .collect()
     }
 
     /// Look up the string for a `Name`.
@@ -103,6 +180,71 @@ impl Interner {
         self.lookup(name).expect("Name not found in interner")
     }
 
+    /// Look up the `Name` for a string without interning it.
+    ///
+    /// Returns `None` if the string has not been interned. Only takes a read
+    /// lock and never grows the table.
+    pub fn get_existing(&self, s: &str) -> Option<Name> {
+        let inner = self.inner.read();
+        inner.map.get(s).map(|&index| Name::from_raw(index))
+    }
+
+    /// Compare two names by their strings rather than their indices.
+    ///
+    /// `Name`'s own `Ord` follows interning order, which is fast but not
+    /// alphabetical. Names not created by this interner sort first, by index.
+    pub fn cmp_names(&self, a: Name, b: Name) -> Ordering {
+        let inner = self.inner.read();
+        inner.cmp_names(a, b)
+    }
+
+    /// Sort names alphabetically by their strings.
+    ///
+    /// Takes the read lock once for the whole sort.
+    pub fn sort_by_name(&self, names: &mut [Name]) {
+        let inner = self.inner.read();
+        names.sort_by(|&a, &b| inner.cmp_names(a, b));
+    }
+
+    /// Get every interned entry, in index order.
+    ///
+    /// This is a snapshot taken under the read lock; strings interned
+    /// afterwards are not included.
+    pub fn iter(&self) -> Vec<(Name, SmolStr)> {
+        let inner = self.inner.read();
+        inner
+            .strings
+            .iter()
+            .enumerate()
+            .map(|(index, s)| (Name::from_raw(index as u32), s.clone()))
+            .collect()
+    }
+
+    /// Get all interned strings in index order, for persisting to a cache.
+    ///
+    /// The position of each string is its `Name` index, so restoring with
+    /// [`Interner::from_snapshot`] keeps existing `Name` handles valid.
+    pub fn to_snapshot(&self) -> Vec<SmolStr> {
+        self.inner.read().strings.clone()
+    }
+
+    /// Rebuild an interner from a snapshot taken with [`Interner::to_snapshot`].
+    ///
+    /// Snapshots must come from the same crate version: the layout of `Name`
+    /// indices is not a stable format. Duplicate strings keep the index of
+    /// their first occurrence.
+    pub fn from_snapshot(strings: Vec<SmolStr>) -> Self {
+        let mut map = FxHashMap::default();
+        map.reserve(strings.len());
+        for (index, s) in strings.iter().enumerate() {
+            map.entry(s.clone()).or_insert(index as u32);
+        }
+
+        Self {
+            inner: RwLock::new(InternerInner { map, strings }),
+        }
+    }
+
     /// Get the number of interned strings.
     pub fn len(&self) -> usize {
         self.inner.read().strings.len()
@@ -149,6 +291,60 @@ mod tests {
         assert_eq!(interner.len(), 2);
     }
 
+    #[test]
+    fn test_intern_many_matches_individual_interning() {
+        let strings = ["Part", "Real", "Part", "Engine", "Real", "Wheel"];
+
+        let batched = Interner::new();
+        let names = batched.intern_many(&strings);
+
+        let individual = Interner::new();
+        let expected: Vec<Name> = strings.iter().map(|s| individual.intern(s)).collect();
+
+        assert_eq!(names, expected);
+        assert_eq!(names[0], names[2], "duplicates in a batch share a Name");
+        assert_eq!(batched.len(), 4);
+        for (name, s) in names.iter().zip(strings) {
+            assert_eq!(batched.get(*name).as_str(), s);
+        }
+    }
+
+    #[test]
+    fn test_intern_many_overlapping_batches() {
+        let interner = Interner::new();
+        let first = interner.intern_many(&["a", "b", "c"]);
+        let second = interner.intern_many(&["c", "d", "a", "e"]);
+
+        assert_eq!(second[0], first[2]);
+        assert_eq!(second[2], first[0]);
+        assert_eq!(interner.len(), 5);
+
+        // Fully interned batch takes the read-only path and adds nothing
+        let third = interner.intern_many(&["e", "b"]);
+        assert_eq!(third, vec![second[3], first[1]]);
+        assert_eq!(interner.len(), 5);
+
+        assert!(interner.intern_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_cmp_names_is_alphabetical() {
+        let interner = Interner::new();
+        let b = interner.intern("b");
+        let a = interner.intern("a");
+
+        // Index order puts "b" first; string order puts "a" first
+        assert_eq!(b.cmp(&a), Ordering::Less);
+        assert_eq!(interner.cmp_names(a, b), Ordering::Less);
+        assert_eq!(interner.cmp_names(b, a), Ordering::Greater);
+        assert_eq!(interner.cmp_names(a, a), Ordering::Equal);
+
+        let c = interner.intern("c");
+        let mut names = vec![c, b, a];
+        interner.sort_by_name(&mut names);
+        assert_eq!(names, vec![a, b, c]);
+    }
+
     #[test]
     fn test_lookup() {
         let interner = Interner::new();
@@ -159,6 +355,59 @@ mod tests {
         assert_eq!(s.as_str(), "test");
     }
 
+    #[test]
+    fn test_get_existing() {
+        let interner = Interner::new();
+        let name = interner.intern("Real");
+
+        assert_eq!(interner.get_existing("Real"), Some(name));
+        assert_eq!(interner.get_existing("Integer"), None);
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn test_iter() {
+        let interner = Interner::new();
+        let a = interner.intern("a");
+        let b = interner.intern("b");
+
+        let entries = interner.iter();
+        assert_eq!(
+            entries,
+            vec![(a, SmolStr::new("a")), (b, SmolStr::new("b"))]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let interner = Interner::new();
+        let part = interner.intern("Part");
+        let real = interner.intern("Real");
+
+        let restored = Interner::from_snapshot(interner.to_snapshot());
+
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.intern("Real"), real);
+        assert_eq!(restored.intern("Part"), part);
+        assert_eq!(restored.get(real).as_str(), "Real");
+        assert_eq!(restored.len(), 2);
+
+        let new = restored.intern("Integer");
+        assert_eq!(new.index(), 2);
+    }
+
+    #[test]
+    fn test_name_display() {
+        let interner = Interner::new();
+        let name = interner.intern("Engine");
+
+        assert_eq!(format!("{}", name.display(&interner)), "Engine");
+        assert_eq!(
+            format!("{}", Name::from_raw(12).display(&interner)),
+            "Name(12)"
+        );
+    }
+
     #[test]
     fn test_name_size() {
         assert_eq!(std::mem::size_of::<Name>(), 4);
@@ -4,6 +4,8 @@
 //! - [`FileId`] - Interned file identifiers
 //! - [`TextRange`], [`TextSize`] - Source positions (byte offsets)
 //! - [`LineCol`], [`LineIndex`] - Line/column conversion
+//! - [`LineColSpan`](crate::base::LineColSpan) - Line/column range containment and intersection
+//! - [`Edit`](crate::base::Edit), [`remap_range`](crate::base::remap_range) - Shifting ranges across text edits
 //! - [`Position`], [`Span`] - Line/column positions for AST nodes
 //! - [`Name`], [`Interner`] - String interning
 //! - Domain constants (file extensions, relationship types)
@@ -17,9 +19,9 @@ mod position;
 mod span;
 
 pub use file_id::FileId;
-pub use intern::{Interner, Name};
+pub use intern::{Interner, Name, NameDisplay};
 pub use position::{Position, Span};
-pub use span::{LineCol, LineIndex, TextRange, TextSize};
+pub use span::{Edit, LineCol, LineColSpan, LineIndex, TextRange, TextSize, remap_range};
 
 // Re-export text-size types for convenience
 pub use text_size;
@@ -9,11 +9,13 @@ pub use text_size::TextSize;
 /// A line and column position in source text.
 ///
 /// Both line and column are 0-indexed internally, but displayed as 1-indexed.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+/// Positions order by line, then by column.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
 pub struct LineCol {
     /// 0-indexed line number
     pub line: u32,
-    /// 0-indexed column (in UTF-8 bytes, not characters)
+    /// 0-indexed column (in UTF-8 bytes, not characters, unless produced by
+    /// [`LineIndex::line_col_utf16`])
     pub col: u32,
 }
 
@@ -58,33 +60,280 @@ impl fmt::Display for LineCol {
     }
 }
 
+/// A range of source text between two [`LineCol`] positions.
+///
+/// Both ends are inclusive, matching how symbol and reference spans are
+/// stored in the HIR. Named `LineColSpan` to keep it distinct from the
+/// offset-based [`crate::base::Span`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct LineColSpan {
+    pub start: LineCol,
+    pub end: LineCol,
+}
+
+impl LineColSpan {
+    /// Create a span from its start and end positions.
+    #[inline]
+    pub const fn new(start: LineCol, end: LineCol) -> Self {
+        Self { start, end }
+    }
+
+    /// Create a span from 0-indexed line and column coordinates.
+    #[inline]
+    pub const fn from_coords(start_line: u32, start_col: u32, end_line: u32, end_col: u32) -> Self {
+        Self {
+            start: LineCol::new(start_line, start_col),
+            end: LineCol::new(end_line, end_col),
+        }
+    }
+
+    /// Check if a position lies within this span (both ends inclusive).
+    #[inline]
+    pub fn contains(&self, pos: LineCol) -> bool {
+        self.start <= pos && pos <= self.end
+    }
+
+    /// Check if `other` lies entirely within this span.
+    #[inline]
+    pub fn contains_span(&self, other: LineColSpan) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Check if this span and `other` share at least one position.
+    #[inline]
+    pub fn intersects(&self, other: LineColSpan) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+/// A text edit: the bytes in `delete` are replaced by `insert_len` bytes of new text.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Edit {
+    /// Range of the original text that is removed (empty for a pure insertion)
+    pub delete: TextRange,
+    /// Length of the text inserted in its place
+    pub insert_len: TextSize,
+}
+
+impl Edit {
+    /// Create an edit replacing `delete` with `insert_len` bytes.
+    #[inline]
+    pub const fn new(delete: TextRange, insert_len: TextSize) -> Self {
+        Self { delete, insert_len }
+    }
+
+    /// Create an edit replacing `delete` with `text`.
+    pub fn replace(delete: TextRange, text: &str) -> Self {
+        Self::new(delete, TextSize::of(text))
+    }
+}
+
+/// Map a range in the text before `edit` to the same text after it.
+///
+/// Ranges ending at or before the edit are unchanged, and ranges starting at
+/// or after its end are shifted by the change in length. Returns `None` if the
+/// range overlaps the edited region, since its new extent must be recomputed.
+pub fn remap_range(range: TextRange, edit: &Edit) -> Option<TextRange> {
+    if range.end() <= edit.delete.start() {
+        return Some(range);
+    }
+    if range.start() >= edit.delete.end() {
+        let start = range.start() + edit.insert_len - edit.delete.len();
+        return Some(TextRange::at(start, range.len()));
+    }
+    None
+}
+
+/// A non-ASCII character, recorded so columns can be converted to UTF-16.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct WideChar {
+    /// Byte offset of the character in the source text
+    start: TextSize,
+    /// Length in UTF-8 bytes (2..=4)
+    len_utf8: u32,
+    /// Length in UTF-16 code units (1 or 2 for surrogate pairs)
+    len_utf16: u32,
+}
+
+impl WideChar {
+    fn new(start: TextSize, c: char) -> Self {
+        Self {
+            start,
+            len_utf8: c.len_utf8() as u32,
+            len_utf16: c.len_utf16() as u32,
+        }
+    }
+
+    fn end(self) -> TextSize {
+        self.start + TextSize::from(self.len_utf8)
+    }
+}
+
+/// The terminator that ends a line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LineEnding {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    CrLf,
+    /// A lone `\r`
+    Cr,
+}
+
+impl LineEnding {
+    fn len(self) -> TextSize {
+        match self {
+            LineEnding::Lf | LineEnding::Cr => TextSize::from(1),
+            LineEnding::CrLf => TextSize::from(2),
+        }
+    }
+}
+
 /// Index for converting between byte offsets and line/column positions.
-#[derive(Clone, Debug)]
+///
+/// `\n`, `\r\n` and a lone `\r` are all recognized as line terminators.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LineIndex {
     /// Byte offset of the start of each line
     line_starts: Vec<TextSize>,
+    /// Terminator of each line except the last
+    endings: Vec<LineEnding>,
+    /// Non-ASCII characters, sorted by offset
+    wide_chars: Vec<WideChar>,
+    /// Length of the indexed text
+    text_len: TextSize,
 }
 
 impl LineIndex {
     /// Build a line index from source text.
     pub fn new(text: &str) -> Self {
         let mut line_starts = vec![TextSize::from(0)];
+        let mut endings = Vec::new();
+        let mut wide_chars = Vec::new();
 
-        for (offset, c) in text.char_indices() {
-            if c == '\n' {
-                line_starts.push(TextSize::from((offset + 1) as u32));
+        let mut chars = text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            let ending = match c {
+                '\n' => LineEnding::Lf,
+                '\r' if chars.next_if(|&(_, next)| next == '\n').is_some() => LineEnding::CrLf,
+                '\r' => LineEnding::Cr,
+                _ => {
+                    if !c.is_ascii() {
+                        wide_chars.push(WideChar::new(TextSize::from(offset as u32), c));
+                    }
+                    continue;
+                }
+            };
+            line_starts.push(TextSize::from(offset as u32) + ending.len());
+            endings.push(ending);
+        }
+
+        Self {
+            line_starts,
+            endings,
+            wide_chars,
+            text_len: TextSize::of(text),
+        }
+    }
+
+    /// Update the index in place for an edit that replaces `range` with
+    /// `new_text`.
+    ///
+    /// Only the lines around the edit are rescanned; line starts after it are
+    /// shifted by the length delta. The result is identical to rebuilding the
+    /// index from the edited text with [`LineIndex::new`].
+    pub fn apply_edit(&mut self, range: TextRange, new_text: &str) {
+        let inserted = TextSize::of(new_text);
+        let shift = |offset: TextSize| offset - range.len() + inserted;
+
+        // Rescan one extra line on each side so that a `\r` and `\n` brought
+        // together (or split apart) by the edit are re-paired.
+        let first = self.line_of(range.start()).saturating_sub(1);
+        let last = (self.line_of(range.end()) + 1).min(self.line_starts.len() - 1);
+
+        // Terminator bytes of the rescanned lines, as they appear after the edit
+        let mut old_breaks = Vec::new();
+        for line in first..last {
+            let ending = self.endings[line];
+            let start = self.line_starts[line + 1] - ending.len();
+            let bytes: &[u8] = match ending {
+                LineEnding::Lf => b"\n",
+                LineEnding::CrLf => b"\r\n",
+                LineEnding::Cr => b"\r",
+            };
+            for (i, &b) in bytes.iter().enumerate() {
+                old_breaks.push((start + TextSize::from(i as u32), b));
             }
         }
+        let new_breaks = new_text
+            .bytes()
+            .enumerate()
+            .filter(|&(_, b)| b == b'\n' || b == b'\r')
+            .map(|(i, b)| (range.start() + TextSize::from(i as u32), b));
+        let mut breaks = old_breaks
+            .iter()
+            .copied()
+            .filter(|&(offset, _)| offset < range.start())
+            .chain(new_breaks)
+            .chain(
+                old_breaks
+                    .iter()
+                    .copied()
+                    .filter(|&(offset, _)| offset >= range.end())
+                    .map(|(offset, b)| (shift(offset), b)),
+            )
+            .peekable();
+
+        let mut line_starts = Vec::new();
+        let mut endings = Vec::new();
+        while let Some((offset, b)) = breaks.next() {
+            let ending = if b == b'\n' {
+                LineEnding::Lf
+            } else if breaks
+                .next_if(|&(next, b)| b == b'\n' && next == offset + TextSize::from(1))
+                .is_some()
+            {
+                LineEnding::CrLf
+            } else {
+                LineEnding::Cr
+            };
+            line_starts.push(offset + ending.len());
+            endings.push(ending);
+        }
+
+        let tail = first + 1 + line_starts.len();
+        self.line_starts.splice(first + 1..last + 1, line_starts);
+        self.endings.splice(first..last, endings);
+        for start in &mut self.line_starts[tail..] {
+            *start = shift(*start);
+        }
+
+        let lo = self.wide_chars.partition_point(|c| c.start < range.start());
+        let hi = self.wide_chars.partition_point(|c| c.start < range.end());
+        let wide_chars: Vec<_> = new_text
+            .char_indices()
+            .filter(|(_, c)| !c.is_ascii())
+            .map(|(offset, c)| WideChar::new(range.start() + TextSize::from(offset as u32), c))
+            .collect();
+        let tail = lo + wide_chars.len();
+        self.wide_chars.splice(lo..hi, wide_chars);
+        for wide in &mut self.wide_chars[tail..] {
+            wide.start = shift(wide.start);
+        }
+
+        self.text_len = shift(self.text_len);
+    }
 
-        Self { line_starts }
+    /// Get the line containing a byte offset.
+    fn line_of(&self, offset: TextSize) -> usize {
+        self.line_starts
+            .partition_point(|&start| start <= offset)
+            .saturating_sub(1)
     }
 
     /// Convert a byte offset to a line/column position.
     pub fn line_col(&self, offset: TextSize) -> LineCol {
-        let line = self
-            .line_starts
-            .partition_point(|&start| start <= offset)
-            .saturating_sub(1);
+        let line = self.line_of(offset);
 
         let line_start = self.line_starts[line];
         let col = offset - line_start;
@@ -101,6 +350,81 @@ impl LineIndex {
         Some(*line_start + TextSize::from(line_col.col))
     }
 
+    /// Get the range of a line's content, excluding its terminator.
+    ///
+    /// Returns `None` if the line does not exist.
+    pub fn line_text_range(&self, line: u32) -> Option<TextRange> {
+        self.line_range(line, false)
+    }
+
+    /// Get the range of a line, optionally including its terminator.
+    ///
+    /// The last line has no terminator, so both forms are equal for it.
+    /// Returns `None` if the line does not exist.
+    pub fn line_range(&self, line: u32, include_terminator: bool) -> Option<TextRange> {
+        let start = *self.line_starts.get(line as usize)?;
+        let end = match self.endings.get(line as usize) {
+            Some(_) if include_terminator => self.line_starts[line as usize + 1],
+            Some(ending) => self.line_starts[line as usize + 1] - ending.len(),
+            None => self.text_len,
+        };
+        Some(TextRange::new(start, end))
+    }
+
+    /// Iterate over the content range of every line, excluding terminators.
+    pub fn lines(&self) -> impl Iterator<Item = TextRange> + '_ {
+        (0..self.len() as u32).filter_map(|line| self.line_text_range(line))
+    }
+
+    /// Convert a byte offset to a line/column position with the column
+    /// measured in UTF-16 code units, as required by LSP.
+    pub fn line_col_utf16(&self, offset: TextSize) -> LineCol {
+        let LineCol { line, col } = self.line_col(offset);
+
+        let mut utf16_col = col;
+        for wide in self.line_wide_chars(line) {
+            if wide.end() > offset {
+                break;
+            }
+            utf16_col -= wide.len_utf8 - wide.len_utf16;
+        }
+
+        LineCol {
+            line,
+            col: utf16_col,
+        }
+    }
+
+    /// Convert a line and UTF-16 column to a byte offset.
+    ///
+    /// Returns `None` if the line does not exist.
+    pub fn offset_utf16(&self, line: u32, utf16_col: u32) -> Option<TextSize> {
+        let line_start = *self.line_starts.get(line as usize)?;
+
+        let mut col = utf16_col;
+        for wide in self.line_wide_chars(line) {
+            if u32::from(wide.start - line_start) >= col {
+                break;
+            }
+            col += wide.len_utf8 - wide.len_utf16;
+        }
+
+        Some(line_start + TextSize::from(col))
+    }
+
+    /// Non-ASCII characters that start on the given line.
+    fn line_wide_chars(&self, line: u32) -> &[WideChar] {
+        let start = self.line_starts[line as usize];
+        let end = self.line_starts.get(line as usize + 1).copied();
+
+        let lo = self.wide_chars.partition_point(|c| c.start < start);
+        let hi = match end {
+            Some(end) => self.wide_chars.partition_point(|c| c.start < end),
+            None => self.wide_chars.len(),
+        };
+        &self.wide_chars[lo..hi]
+    }
+
     /// Get the number of lines.
     pub fn len(&self) -> usize {
         self.line_starts.len()
@@ -115,6 +439,7 @@ impl LineIndex {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_line_col_display() {
@@ -132,6 +457,81 @@ mod tests {
         assert_eq!(pos.col, 0);
     }
 
+    #[test]
+    fn test_line_col_ordering() {
+        assert!(LineCol::new(0, 9) < LineCol::new(1, 0));
+        assert!(LineCol::new(2, 3) < LineCol::new(2, 4));
+        assert_eq!(
+            LineCol::new(2, 3).cmp(&LineCol::new(2, 3)),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_span_contains_boundaries() {
+        let span = LineColSpan::from_coords(1, 4, 1, 10);
+        assert!(span.contains(LineCol::new(1, 4)));
+        assert!(span.contains(LineCol::new(1, 7)));
+        assert!(span.contains(LineCol::new(1, 10)));
+        assert!(!span.contains(LineCol::new(1, 3)));
+        assert!(!span.contains(LineCol::new(1, 11)));
+        assert!(!span.contains(LineCol::new(0, 5)));
+        assert!(!span.contains(LineCol::new(2, 5)));
+    }
+
+    #[test]
+    fn test_span_contains_multi_line() {
+        let span = LineColSpan::from_coords(2, 8, 5, 1);
+        // Columns on inner lines are unbounded
+        assert!(span.contains(LineCol::new(3, 0)));
+        assert!(span.contains(LineCol::new(4, 200)));
+        // The first line only counts from the start column
+        assert!(span.contains(LineCol::new(2, 8)));
+        assert!(span.contains(LineCol::new(2, 50)));
+        assert!(!span.contains(LineCol::new(2, 7)));
+        // The last line only counts up to the end column
+        assert!(span.contains(LineCol::new(5, 0)));
+        assert!(span.contains(LineCol::new(5, 1)));
+        assert!(!span.contains(LineCol::new(5, 2)));
+    }
+
+    #[test]
+    fn test_span_empty() {
+        let pos = LineCol::new(3, 3);
+        let span = LineColSpan::new(pos, pos);
+        assert!(span.contains(pos));
+        assert!(!span.contains(LineCol::new(3, 4)));
+        assert!(span.contains_span(span));
+        assert!(span.intersects(span));
+    }
+
+    #[test]
+    fn test_span_contains_span() {
+        let outer = LineColSpan::from_coords(1, 0, 10, 0);
+        assert!(outer.contains_span(outer));
+        assert!(outer.contains_span(LineColSpan::from_coords(1, 0, 1, 5)));
+        assert!(outer.contains_span(LineColSpan::from_coords(9, 40, 10, 0)));
+        assert!(outer.contains_span(LineColSpan::from_coords(3, 2, 7, 9)));
+        assert!(!outer.contains_span(LineColSpan::from_coords(0, 5, 2, 0)));
+        assert!(!outer.contains_span(LineColSpan::from_coords(9, 0, 10, 1)));
+        assert!(!LineColSpan::from_coords(3, 2, 7, 9).contains_span(outer));
+    }
+
+    #[test]
+    fn test_span_intersects() {
+        let span = LineColSpan::from_coords(2, 4, 4, 6);
+        // Touching at a single position counts, since both ends are inclusive
+        assert!(span.intersects(LineColSpan::from_coords(0, 0, 2, 4)));
+        assert!(span.intersects(LineColSpan::from_coords(4, 6, 8, 0)));
+        assert!(!span.intersects(LineColSpan::from_coords(0, 0, 2, 3)));
+        assert!(!span.intersects(LineColSpan::from_coords(4, 7, 8, 0)));
+        // Overlap and nesting in both directions
+        assert!(span.intersects(LineColSpan::from_coords(3, 0, 9, 0)));
+        assert!(span.intersects(LineColSpan::from_coords(0, 0, 9, 0)));
+        assert!(LineColSpan::from_coords(0, 0, 9, 0).intersects(span));
+        assert!(span.intersects(LineColSpan::from_coords(3, 1, 3, 2)));
+    }
+
     #[test]
     fn test_line_index_single_line() {
         let index = LineIndex::new("hello world");
@@ -159,4 +559,223 @@ mod tests {
         assert_eq!(index.offset(LineCol::new(1, 0)), Some(TextSize::from(6)));
         assert_eq!(index.offset(LineCol::new(1, 3)), Some(TextSize::from(9)));
     }
+
+    #[test]
+    fn test_line_index_line_endings() {
+        for (text, ending) in [
+            ("part a;\npart b;\n", "\n"),
+            ("part a;\r\npart b;\r\n", "\r\n"),
+            ("part a;\rpart b;\r", "\r"),
+        ] {
+            let index = LineIndex::new(text);
+            let second = TextSize::from(7 + ending.len() as u32);
+
+            assert_eq!(index.len(), 3, "{:?}", ending);
+            assert_eq!(index.line_col(second), LineCol::new(1, 0), "{:?}", ending);
+            assert_eq!(
+                index.offset(LineCol::new(1, 0)),
+                Some(second),
+                "{:?}",
+                ending
+            );
+
+            let first = index.line_text_range(0).unwrap();
+            assert_eq!(&text[first], "part a;", "{:?}", ending);
+            let second = index.line_text_range(1).unwrap();
+            assert_eq!(&text[second], "part b;", "{:?}", ending);
+            let last = index.line_text_range(2).unwrap();
+            assert!(last.is_empty(), "{:?}", ending);
+            assert_eq!(index.line_text_range(3), None);
+        }
+    }
+
+    #[test]
+    fn test_line_index_mixed_line_endings() {
+        let text = "a\r\nb\rc\nd";
+        let index = LineIndex::new(text);
+
+        assert_eq!(index.len(), 4);
+        assert_eq!(index.line_col(TextSize::from(3)), LineCol::new(1, 0));
+        assert_eq!(index.line_col(TextSize::from(5)), LineCol::new(2, 0));
+        assert_eq!(index.line_col(TextSize::from(7)), LineCol::new(3, 0));
+        let lines: Vec<_> = (0..4)
+            .map(|line| &text[index.line_text_range(line).unwrap()])
+            .collect();
+        assert_eq!(lines, ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_line_index_utf16_ascii_matches_bytes() {
+        let index = LineIndex::new("part def A;\npart a : A;");
+
+        let offset = TextSize::from(17);
+        assert_eq!(index.line_col_utf16(offset), index.line_col(offset));
+        assert_eq!(index.offset_utf16(1, 5), Some(offset));
+    }
+
+    #[test]
+    fn test_line_index_utf16_surrogate_pair() {
+        // '😀' is 4 bytes in UTF-8 and 2 code units (a surrogate pair) in UTF-16
+        let text = "doc /* 😀 */ x\n😀y";
+        let index = LineIndex::new(text);
+
+        let x = TextSize::from(text.find('x').unwrap() as u32);
+        assert_eq!(index.line_col(x), LineCol::new(0, 15));
+        assert_eq!(index.line_col_utf16(x), LineCol::new(0, 13));
+        assert_eq!(index.offset_utf16(0, 13), Some(x));
+
+        let y = TextSize::from(text.find('y').unwrap() as u32);
+        assert_eq!(index.line_col(y), LineCol::new(1, 4));
+        assert_eq!(index.line_col_utf16(y), LineCol::new(1, 2));
+        assert_eq!(index.offset_utf16(1, 2), Some(y));
+    }
+
+    #[test]
+    fn test_line_index_utf16_bmp_chars() {
+        // 'é' is 2 bytes, '€' is 3 bytes, both a single UTF-16 code unit
+        let text = "é€a";
+        let index = LineIndex::new(text);
+
+        let a = TextSize::from(5);
+        assert_eq!(index.line_col(a), LineCol::new(0, 5));
+        assert_eq!(index.line_col_utf16(a), LineCol::new(0, 2));
+        assert_eq!(index.offset_utf16(0, 1), Some(TextSize::from(2)));
+        assert_eq!(index.offset_utf16(0, 2), Some(a));
+    }
+
+    #[test]
+    fn test_line_index_apply_edit_multi_line() {
+        let mut text = String::from("package P {\n    part a;\n    part b;\n}\n");
+        let mut index = LineIndex::new(&text);
+
+        // Multi-line paste
+        let range = TextRange::at(TextSize::from(12), TextSize::from(0));
+        let pasted = "    part x;\n    part y;\n";
+        index.apply_edit(range, pasted);
+        text.replace_range(std::ops::Range::<usize>::from(range), pasted);
+        assert_eq!(index, LineIndex::new(&text));
+        assert_eq!(index.len(), 7);
+
+        // Multi-line delete
+        let range = TextRange::new(TextSize::from(16), TextSize::from(40));
+        index.apply_edit(range, "");
+        text.replace_range(std::ops::Range::<usize>::from(range), "");
+        assert_eq!(index, LineIndex::new(&text));
+    }
+
+    #[test]
+    fn test_line_index_apply_edit_joins_cr_lf() {
+        // Inserting `\n` after a lone `\r` turns it into a single CRLF
+        let mut index = LineIndex::new("a\rb");
+        index.apply_edit(TextRange::empty(TextSize::from(2)), "\n");
+        assert_eq!(index, LineIndex::new("a\r\nb"));
+
+        // Inserting between `\r` and `\n` splits a CRLF in two
+        let mut index = LineIndex::new("a\r\nb");
+        index.apply_edit(TextRange::empty(TextSize::from(2)), "x");
+        assert_eq!(index, LineIndex::new("a\rx\nb"));
+    }
+
+    /// Text drawn from multi-byte characters and every kind of line ending.
+    fn edit_text(max_len: usize) -> impl Strategy<Value = String> {
+        let pieces = vec!["a", " ", "\n", "\r", "\r\n", "é", "€", "😀"];
+        prop::collection::vec(prop::sample::select(pieces), 0..max_len)
+            .prop_map(|pieces| pieces.concat())
+    }
+
+    proptest! {
+        /// Compares `apply_edit` against a fresh index across random edits.
+        #[test]
+        fn prop_line_index_apply_edit_matches_rebuild(
+            mut text in edit_text(20),
+            edits in prop::collection::vec(
+                (any::<prop::sample::Index>(), any::<prop::sample::Index>(), edit_text(6)),
+                0..20,
+            ),
+        ) {
+            let mut index = LineIndex::new(&text);
+
+            for (start, end, new_text) in edits {
+                let boundaries: Vec<usize> = text
+                    .char_indices()
+                    .map(|(offset, _)| offset)
+                    .chain([text.len()])
+                    .collect();
+                let mut start = *start.get(&boundaries);
+                let mut end = *end.get(&boundaries);
+                if start > end {
+                    std::mem::swap(&mut start, &mut end);
+                }
+
+                let range =
+                    TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+                index.apply_edit(range, &new_text);
+                text.replace_range(start..end, &new_text);
+
+                prop_assert_eq!(&index, &LineIndex::new(&text), "text: {:?}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_line_index_line_range() {
+        let index = LineIndex::new("ab\r\ncde\nf");
+        let r = |start: u32, end: u32| TextRange::new(start.into(), end.into());
+
+        assert_eq!(index.line_range(0, false), Some(r(0, 2)));
+        assert_eq!(index.line_range(0, true), Some(r(0, 4)));
+        assert_eq!(index.line_range(1, false), Some(r(4, 7)));
+        assert_eq!(index.line_range(1, true), Some(r(4, 8)));
+        // The final line has no terminator
+        assert_eq!(index.line_range(2, false), Some(r(8, 9)));
+        assert_eq!(index.line_range(2, true), Some(r(8, 9)));
+        assert_eq!(index.line_range(3, false), None);
+    }
+
+    #[test]
+    fn test_line_index_lines() {
+        let text = "first\nsecond\nthird";
+        let index = LineIndex::new(text);
+        let lines: Vec<&str> = index.lines().map(|range| &text[range]).collect();
+        assert_eq!(lines, ["first", "second", "third"]);
+
+        // A trailing newline starts an empty final line
+        let text = "first\nsecond\nthird\n";
+        let index = LineIndex::new(text);
+        let lines: Vec<&str> = index.lines().map(|range| &text[range]).collect();
+        assert_eq!(lines, ["first", "second", "third", ""]);
+    }
+
+    #[test]
+    fn test_line_index_utf16_missing_line() {
+        let index = LineIndex::new("a\nb");
+        assert_eq!(index.offset_utf16(2, 0), None);
+    }
+
+    #[test]
+    fn test_remap_range() {
+        let r = |start: u32, end: u32| TextRange::new(start.into(), end.into());
+        // "part def A;" -> "part def Alpha;": replace `A` (9..10) with `Alpha`
+        let edit = Edit::replace(r(9, 10), "Alpha");
+
+        // Before the edit: unchanged, including a range ending where it starts
+        assert_eq!(remap_range(r(0, 4), &edit), Some(r(0, 4)));
+        assert_eq!(remap_range(r(5, 9), &edit), Some(r(5, 9)));
+        // After the edit: shifted by the length change
+        assert_eq!(remap_range(r(10, 11), &edit), Some(r(14, 15)));
+        // Overlapping the edit: must be recomputed
+        assert_eq!(remap_range(r(0, 11), &edit), None);
+        assert_eq!(remap_range(r(9, 10), &edit), None);
+        assert_eq!(remap_range(r(8, 10), &edit), None);
+
+        // Deletions shift later ranges back
+        let delete = Edit::replace(r(2, 6), "");
+        assert_eq!(remap_range(r(8, 12), &delete), Some(r(4, 8)));
+
+        // A pure insertion only invalidates ranges strictly containing it
+        let insert = Edit::replace(r(5, 5), "xyz");
+        assert_eq!(remap_range(r(0, 5), &insert), Some(r(0, 5)));
+        assert_eq!(remap_range(r(5, 7), &insert), Some(r(8, 10)));
+        assert_eq!(remap_range(r(4, 6), &insert), None);
+    }
 }
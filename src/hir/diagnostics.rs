@@ -3,10 +3,15 @@
 //! This module provides diagnostic types for semantic analysis errors
 //! and warnings. It integrates with the symbol index and resolver.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+#[cfg(feature = "serde")]
+use super::input::SourceRoot;
 use super::resolve::{ResolveResult, Resolver, SymbolIndex};
-use super::symbols::{HirSymbol, SymbolKind};
+use super::symbols::{
+    HirSymbol, ROOT_CLASSIFIER, RefKind, RelationshipKind, SymbolKind, TypeRef, TypeRefKind,
+};
 use crate::base::FileId;
 
 // ============================================================================
@@ -32,6 +37,16 @@ impl Severity {
             Severity::Hint => 4,
         }
     }
+
+    /// Lowercase name of the severity (e.g., `"error"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+            Severity::Hint => "hint",
+        }
+    }
 }
 
 /// A diagnostic message with location.
@@ -58,7 +73,7 @@ pub struct Diagnostic {
 }
 
 /// Related information for a diagnostic.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RelatedInfo {
     /// The file containing this info.
     pub file: FileId,
@@ -101,6 +116,36 @@ impl Diagnostic {
         }
     }
 
+    /// Create a new info diagnostic.
+    pub fn info(file: FileId, line: u32, col: u32, message: impl Into<Arc<str>>) -> Self {
+        Self {
+            file,
+            start_line: line,
+            start_col: col,
+            end_line: line,
+            end_col: col,
+            severity: Severity::Info,
+            code: None,
+            message: message.into(),
+            related: Vec::new(),
+        }
+    }
+
+    /// Create a new hint diagnostic.
+    pub fn hint(file: FileId, line: u32, col: u32, message: impl Into<Arc<str>>) -> Self {
+        Self {
+            file,
+            start_line: line,
+            start_col: col,
+            end_line: line,
+            end_col: col,
+            severity: Severity::Hint,
+            code: None,
+            message: message.into(),
+            related: Vec::new(),
+        }
+    }
+
     /// Set the span (range) for this diagnostic.
     pub fn with_span(mut self, end_line: u32, end_col: u32) -> Self {
         self.end_line = end_line;
@@ -121,6 +166,76 @@ impl Diagnostic {
     }
 }
 
+// ============================================================================
+// JSON EXPORT
+// ============================================================================
+
+#[cfg(feature = "serde")]
+impl Diagnostic {
+    /// Render this diagnostic as JSON for CLI and CI consumers.
+    ///
+    /// The file is emitted by numeric id only; use [`Diagnostic::to_json_with_root`]
+    /// to include its path.
+    pub fn to_json(&self) -> serde_json::Value {
+        self.json_value(None)
+    }
+
+    /// Render this diagnostic as JSON, resolving file paths through `root`.
+    pub fn to_json_with_root(&self, root: &SourceRoot) -> serde_json::Value {
+        self.json_value(Some(root))
+    }
+
+    fn json_value(&self, root: Option<&SourceRoot>) -> serde_json::Value {
+        let related: Vec<serde_json::Value> = self
+            .related
+            .iter()
+            .map(|info| {
+                serde_json::json!({
+                    "file": json_file(info.file, root),
+                    "position": { "line": info.line, "character": info.col },
+                    "message": info.message.as_ref(),
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "file": json_file(self.file, root),
+            "range": {
+                "start": { "line": self.start_line, "character": self.start_col },
+                "end": { "line": self.end_line, "character": self.end_col },
+            },
+            "severity": self.severity.as_str(),
+            "code": self.code.as_deref(),
+            "message": self.message.as_ref(),
+            "related": related,
+        })
+    }
+}
+
+/// Render a list of diagnostics as a JSON array.
+///
+/// When `root` is given, each file entry also carries its path.
+#[cfg(feature = "serde")]
+pub fn diagnostics_to_json(
+    diagnostics: &[Diagnostic],
+    root: Option<&SourceRoot>,
+) -> serde_json::Value {
+    serde_json::Value::Array(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.json_value(root))
+            .collect(),
+    )
+}
+
+#[cfg(feature = "serde")]
+fn json_file(file: FileId, root: Option<&SourceRoot>) -> serde_json::Value {
+    let mut value = serde_json::json!({ "id": file.index() });
+    if let Some(path) = root.and_then(|root| root.path(file)) {
+        value["path"] = serde_json::Value::from(path);
+    }
+    value
+}
+
 // ============================================================================
 // DIAGNOSTIC CODES
 // ============================================================================
@@ -176,6 +291,190 @@ pub mod codes {
     pub const DEPRECATED: &str = "W0002";
     /// Naming convention violation.
     pub const NAMING_CONVENTION: &str = "W0003";
+    /// Import whose names are never referenced.
+    pub const UNUSED_IMPORT: &str = "W0004";
+    /// Imported name hidden by a local definition.
+    pub const SHADOWED_IMPORT: &str = "W0005";
+    /// Concrete usage typed directly by an abstract definition.
+    pub const ABSTRACT_TYPED_USAGE: &str = "W0006";
+
+    // ========================================================================
+    // INFORMATION (I0001-I0099)
+    // ========================================================================
+
+    /// Wildcard import cut short by the re-export depth limit.
+    pub const REEXPORT_DEPTH_EXCEEDED: &str = "I0001";
+}
+
+// ============================================================================
+// DIAGNOSTIC CONFIG
+// ============================================================================
+
+/// Checker configuration: per-code overrides of diagnostic severity.
+///
+/// Each diagnostic code (e.g., `W0003`) can be given a different severity or
+/// turned off entirely. Diagnostics with other codes, or without a code, keep
+/// their default severity.
+///
+/// The checks behind [`OPT_IN_CODES`](Self::OPT_IN_CODES) are noisy or a
+/// matter of project style, so [`SemanticChecker::check_file`] only runs
+/// them once their code is given a severity.
+#[derive(Clone, Debug, Default)]
+pub struct DiagnosticConfig {
+    /// Code → overridden severity (`None` turns the code off).
+    overrides: HashMap<Arc<str>, Option<Severity>>,
+    /// Expected name styles for the naming-convention lint.
+    naming: NamingConventions,
+}
+
+impl DiagnosticConfig {
+    /// Codes whose checks are off unless given a severity.
+    pub const OPT_IN_CODES: &'static [&'static str] = &[
+        codes::NAMING_CONVENTION,
+        codes::UNUSED_IMPORT,
+        codes::SHADOWED_IMPORT,
+    ];
+
+    /// Create a config with no overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report diagnostics with `code` at `severity`.
+    pub fn with_severity(mut self, code: impl Into<Arc<str>>, severity: Severity) -> Self {
+        self.overrides.insert(code.into(), Some(severity));
+        self
+    }
+
+    /// Drop diagnostics with `code`.
+    pub fn with_disabled(mut self, code: impl Into<Arc<str>>) -> Self {
+        self.overrides.insert(code.into(), None);
+        self
+    }
+
+    /// Set the name styles expected by the naming-convention lint.
+    pub fn with_naming_conventions(mut self, naming: NamingConventions) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Whether diagnostics with `code` are reported.
+    ///
+    /// Codes in [`OPT_IN_CODES`](Self::OPT_IN_CODES) are reported only when
+    /// given a severity; other codes unless turned off.
+    pub fn is_enabled(&self, code: &str) -> bool {
+        match self.severity_for(code) {
+            Some(severity) => severity.is_some(),
+            None => !Self::OPT_IN_CODES.contains(&code),
+        }
+    }
+
+    /// Get the override for `code`.
+    ///
+    /// Returns `None` if the code isn't overridden, and `Some(None)` if it is
+    /// turned off.
+    pub fn severity_for(&self, code: &str) -> Option<Option<Severity>> {
+        self.overrides.get(code).copied()
+    }
+
+    /// Apply the overrides, dropping diagnostics whose code is turned off.
+    pub fn apply(&self, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        diagnostics
+            .into_iter()
+            .filter_map(|mut diagnostic| {
+                let Some(code) = diagnostic.code.as_deref() else {
+                    return Some(diagnostic);
+                };
+                match self.severity_for(code) {
+                    Some(Some(severity)) => diagnostic.severity = severity,
+                    Some(None) => return None,
+                    None => {}
+                }
+                Some(diagnostic)
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// NAMING CONVENTIONS
+// ============================================================================
+
+/// A casing style for element names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NamingStyle {
+    /// `VehicleMass`
+    UpperCamelCase,
+    /// `vehicleMass`
+    LowerCamelCase,
+    /// `vehicle_mass`
+    SnakeCase,
+}
+
+impl NamingStyle {
+    /// Check if `name` follows this style.
+    pub fn matches(self, name: &str) -> bool {
+        let Some(first) = name.chars().next() else {
+            return true;
+        };
+        match self {
+            NamingStyle::UpperCamelCase => first.is_ascii_uppercase() && !name.contains('_'),
+            NamingStyle::LowerCamelCase => first.is_ascii_lowercase() && !name.contains('_'),
+            NamingStyle::SnakeCase => !name.chars().any(|c| c.is_ascii_uppercase()),
+        }
+    }
+
+    /// Get the display name of this style.
+    pub fn display(self) -> &'static str {
+        match self {
+            NamingStyle::UpperCamelCase => "UpperCamelCase",
+            NamingStyle::LowerCamelCase => "lowerCamelCase",
+            NamingStyle::SnakeCase => "snake_case",
+        }
+    }
+}
+
+/// Expected name styles for the naming-convention lint.
+///
+/// The default follows common SysML style: definitions and packages in
+/// UpperCamelCase, usages in lowerCamelCase. Set a style to `None` to stop
+/// checking that group of elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamingConventions {
+    /// Style for packages and definitions.
+    pub definitions: Option<NamingStyle>,
+    /// Style for usages.
+    pub usages: Option<NamingStyle>,
+}
+
+impl Default for NamingConventions {
+    fn default() -> Self {
+        Self {
+            definitions: Some(NamingStyle::UpperCamelCase),
+            usages: Some(NamingStyle::LowerCamelCase),
+        }
+    }
+}
+
+impl NamingConventions {
+    /// Conventions that check nothing.
+    pub fn disabled() -> Self {
+        Self {
+            definitions: None,
+            usages: None,
+        }
+    }
+
+    /// Get the expected style for a symbol of `kind`, if it is checked.
+    pub fn style_for(&self, kind: SymbolKind) -> Option<NamingStyle> {
+        if kind.is_definition() {
+            self.definitions
+        } else if kind.is_usage() {
+            self.usages
+        } else {
+            None
+        }
+    }
 }
 
 // ============================================================================
@@ -213,6 +512,54 @@ impl DiagnosticCollector {
         );
     }
 
+    /// Add an undefined reference error spanning just the reference text.
+    pub fn undefined_reference_at(&mut self, file: FileId, type_ref: &TypeRef, name: &str) {
+        self.add(
+            Diagnostic::error(
+                file,
+                type_ref.start_line,
+                type_ref.start_col,
+                format!("undefined reference: '{}'", name),
+            )
+            .with_span(type_ref.end_line, type_ref.end_col)
+            .with_code(codes::UNDEFINED_REFERENCE),
+        );
+    }
+
+    /// Add an undefined reference error for an import whose target doesn't exist.
+    ///
+    /// The span covers the import path.
+    pub fn unresolved_import(&mut self, import: &HirSymbol, path: &TypeRef) {
+        self.add(
+            Diagnostic::error(
+                import.file,
+                path.start_line,
+                path.start_col,
+                format!("undefined reference: import of unknown '{}'", path.target),
+            )
+            .with_span(path.end_line, path.end_col)
+            .with_code(codes::UNDEFINED_REFERENCE),
+        );
+    }
+
+    /// Add an undefined reference error for a feature chain member that isn't
+    /// found on the type of the preceding chain part.
+    pub fn undefined_chain_member(&mut self, file: FileId, part: &TypeRef, owner: &str) {
+        self.add(
+            Diagnostic::error(
+                file,
+                part.start_line,
+                part.start_col,
+                format!(
+                    "undefined reference: no member '{}' on '{}' in feature chain",
+                    part.target, owner
+                ),
+            )
+            .with_span(part.end_line, part.end_col)
+            .with_code(codes::UNDEFINED_REFERENCE),
+        );
+    }
+
     /// Add an ambiguous reference error.
     pub fn ambiguous_reference(
         &mut self,
@@ -271,6 +618,28 @@ impl DiagnosticCollector {
         );
     }
 
+    /// Add a circular dependency error for a supertype cycle.
+    ///
+    /// `cycle` lists the qualified names along the cycle, starting and ending
+    /// with `symbol`.
+    pub fn circular_dependency(&mut self, file: FileId, symbol: &HirSymbol, cycle: &[Arc<str>]) {
+        let path: Vec<_> = cycle.iter().map(|q| q.as_ref()).collect();
+        self.add(
+            Diagnostic::error(
+                file,
+                symbol.start_line,
+                symbol.start_col,
+                format!(
+                    "circular dependency: '{}' specializes itself ({})",
+                    symbol.name,
+                    path.join(" :> ")
+                ),
+            )
+            .with_span(symbol.end_line, symbol.end_col)
+            .with_code(codes::CIRCULAR_DEPENDENCY),
+        );
+    }
+
     /// Add a type mismatch error.
     pub fn type_mismatch(&mut self, file: FileId, symbol: &HirSymbol, expected: &str, found: &str) {
         self.add(
@@ -285,6 +654,32 @@ impl DiagnosticCollector {
         );
     }
 
+    /// Add a warning that a concrete usage is typed by an abstract definition.
+    ///
+    /// The span covers the type reference.
+    pub fn abstract_typed_usage(
+        &mut self,
+        usage: &HirSymbol,
+        type_ref: &TypeRef,
+        definition: &str,
+    ) {
+        self.add(
+            Diagnostic::warning(
+                usage.file,
+                type_ref.start_line,
+                type_ref.start_col,
+                format!(
+                    "{} '{}' is typed by abstract definition '{}'",
+                    usage.kind.display(),
+                    usage.name,
+                    definition
+                ),
+            )
+            .with_span(type_ref.end_line, type_ref.end_col)
+            .with_code(codes::ABSTRACT_TYPED_USAGE),
+        );
+    }
+
     /// Add an unused symbol warning.
     pub fn unused_symbol(&mut self, symbol: &HirSymbol) {
         self.add(
@@ -299,6 +694,73 @@ impl DiagnosticCollector {
         );
     }
 
+    /// Add an unused import warning.
+    pub fn unused_import(&mut self, symbol: &HirSymbol) {
+        self.add(
+            Diagnostic::warning(
+                symbol.file,
+                symbol.start_line,
+                symbol.start_col,
+                format!("unused import: '{}'", symbol.name),
+            )
+            .with_span(symbol.end_line, symbol.end_col)
+            .with_code(codes::UNUSED_IMPORT),
+        );
+    }
+
+    /// Add a naming convention warning.
+    pub fn naming_convention(&mut self, symbol: &HirSymbol, style: NamingStyle) {
+        self.add(
+            Diagnostic::warning(
+                symbol.file,
+                symbol.start_line,
+                symbol.start_col,
+                format!(
+                    "{} name '{}' should be {}",
+                    symbol.kind.display(),
+                    symbol.name,
+                    style.display()
+                ),
+            )
+            .with_span(symbol.end_line, symbol.end_col)
+            .with_code(codes::NAMING_CONVENTION),
+        );
+    }
+
+    /// Add a hint that an imported name is shadowed by a local definition.
+    pub fn shadowed_import(&mut self, import: &HirSymbol, name: &str, local: &str) {
+        self.add(
+            Diagnostic::hint(
+                import.file,
+                import.start_line,
+                import.start_col,
+                format!(
+                    "imported '{}' is shadowed by local definition '{}'",
+                    name, local
+                ),
+            )
+            .with_span(import.end_line, import.end_col)
+            .with_code(codes::SHADOWED_IMPORT),
+        );
+    }
+
+    /// Add a note that an import left out names re-exported too deeply.
+    pub fn reexport_depth_exceeded(&mut self, import: &HirSymbol, max_depth: usize) {
+        self.add(
+            Diagnostic::info(
+                import.file,
+                import.start_line,
+                import.start_col,
+                format!(
+                    "import of '{}' skips names re-exported through more than {} scopes",
+                    import.name, max_depth
+                ),
+            )
+            .with_span(import.end_line, import.end_col)
+            .with_code(codes::REEXPORT_DEPTH_EXCEEDED),
+        );
+    }
+
     /// Get all diagnostics.
     pub fn diagnostics(&self) -> &[Diagnostic] {
         &self.diagnostics
@@ -341,6 +803,40 @@ impl DiagnosticCollector {
     pub fn clear(&mut self) {
         self.diagnostics.clear();
     }
+
+    /// Remove diagnostics identical in file, range, code, and message.
+    ///
+    /// The first occurrence is kept in place and gains any related
+    /// information carried only by its duplicates.
+    pub fn dedup(&mut self) {
+        let mut first: HashMap<_, usize> = HashMap::new();
+        let mut kept: Vec<Diagnostic> = Vec::with_capacity(self.diagnostics.len());
+        for diagnostic in self.diagnostics.drain(..) {
+            let key = (
+                diagnostic.file,
+                diagnostic.start_line,
+                diagnostic.start_col,
+                diagnostic.end_line,
+                diagnostic.end_col,
+                diagnostic.code.clone(),
+                diagnostic.message.clone(),
+            );
+            match first.get(&key) {
+                Some(&i) => {
+                    for info in diagnostic.related {
+                        if !kept[i].related.contains(&info) {
+                            kept[i].related.push(info);
+                        }
+                    }
+                }
+                None => {
+                    first.insert(key, kept.len());
+                    kept.push(diagnostic);
+                }
+            }
+        }
+        self.diagnostics = kept;
+    }
 }
 
 // ============================================================================
@@ -353,6 +849,9 @@ pub struct SemanticChecker<'a> {
     collector: DiagnosticCollector,
     /// Track which symbols are referenced (for unused detection).
     referenced: std::collections::HashSet<Arc<str>>,
+    /// Severity overrides applied when finishing, which also enable the
+    /// opt-in checks.
+    config: DiagnosticConfig,
 }
 
 impl<'a> SemanticChecker<'a> {
@@ -362,10 +861,25 @@ impl<'a> SemanticChecker<'a> {
             index,
             collector: DiagnosticCollector::new(),
             referenced: std::collections::HashSet::new(),
+            config: DiagnosticConfig::default(),
         }
     }
 
-    /// Check all symbols in a file.
+    /// Set the severity overrides applied to the collected diagnostics, which
+    /// also enable the opt-in checks [`check_file`](Self::check_file) runs.
+    pub fn with_config(mut self, config: DiagnosticConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Set the name styles expected by the naming-convention lint.
+    pub fn with_naming_conventions(mut self, naming: NamingConventions) -> Self {
+        self.config.naming = naming;
+        self
+    }
+
+    /// Check all symbols in a file, including the opt-in checks enabled in
+    /// the config.
     pub fn check_file(&mut self, file: FileId) {
         let symbols = self.index.symbols_in_file(file);
 
@@ -376,21 +890,80 @@ impl<'a> SemanticChecker<'a> {
 
         // Pass 2: Check for duplicates within this file
         self.check_duplicates(file, &symbols);
+
+        // Pass 3: Check for circular supertype chains
+        self.check_circular_supertypes(&symbols);
+
+        // Pass 4: Note imports cut short by the re-export depth limit
+        self.check_reexport_depth(&symbols);
+
+        // Pass 5: Check import targets exist
+        self.check_imports(&symbols);
+
+        // Pass 6: Opt-in checks
+        if self.config.is_enabled(codes::UNUSED_IMPORT) {
+            self.check_unused_imports(file);
+        }
+        if self.config.is_enabled(codes::SHADOWED_IMPORT) {
+            self.check_shadowed_imports(file);
+        }
+        if self.config.is_enabled(codes::NAMING_CONVENTION) {
+            self.check_naming_conventions(file);
+        }
+    }
+
+    /// Report imports whose target can't be resolved to any element.
+    fn check_imports(&mut self, symbols: &[&HirSymbol]) {
+        for import in symbols.iter().filter(|s| s.kind == SymbolKind::Import) {
+            let Some(TypeRefKind::Simple(path)) = import.type_refs.first() else {
+                continue;
+            };
+            if !self
+                .index
+                .import_target_exists(Self::import_scope(import), &path.target)
+            {
+                self.collector.unresolved_import(import, path);
+            }
+        }
+    }
+
+    /// The scope an import symbol belongs to.
+    fn import_scope(import: &HirSymbol) -> &str {
+        match import.qualified_name.rsplit_once("::import:") {
+            Some((scope, _)) => scope,
+            None => "",
+        }
+    }
+
+    /// Report wildcard imports whose re-exported names were cut off by the
+    /// index's [`max_reexport_depth`](SymbolIndex::max_reexport_depth).
+    fn check_reexport_depth(&mut self, symbols: &[&HirSymbol]) {
+        let Some(max_depth) = self.index.max_reexport_depth() else {
+            return;
+        };
+        for import in symbols.iter().filter(|s| s.kind == SymbolKind::Import) {
+            let truncated = self
+                .index
+                .visibility_for_scope(Self::import_scope(import))
+                .is_some_and(|vis| vis.truncated_imports().contains(&import.qualified_name));
+            if truncated {
+                self.collector.reexport_depth_exceeded(import, max_depth);
+            }
+        }
     }
 
     /// Run all checks across the entire index (for workspace-wide diagnostics).
+    ///
+    /// Runs [`check_file`](Self::check_file) on every file in the index, so
+    /// it reports exactly what checking each file on its own would.
     pub fn check_all(&mut self) {
-        // Collect all symbols first
-        let all_symbols: Vec<_> = self.index.all_symbols().cloned().collect();
+        let mut files: Vec<FileId> = self.index.all_symbols().map(|s| s.file).collect();
+        files.sort_unstable();
+        files.dedup();
 
-        // Check each symbol
-        for symbol in &all_symbols {
-            self.check_symbol(symbol);
+        for file in files {
+            self.check_file(file);
         }
-
-        // Check for unused definitions (only meaningful after checking all references)
-        // Disabled by default as it can be noisy - uncomment to enable
-        // self.check_unused(&all_symbols);
     }
 
     /// Check a single symbol.
@@ -402,6 +975,193 @@ impl<'a> SemanticChecker<'a> {
 
         // Check type_refs based on their RefKind
         self.check_type_refs(symbol);
+
+        // Check that redefinitions keep a conforming type
+        self.check_feature_type_conformance(symbol);
+
+        // Check that concrete usages aren't typed by abstract definitions
+        self.check_abstract_typing(symbol);
+    }
+
+    /// Warn when a usage that isn't itself abstract is typed directly by an
+    /// abstract definition.
+    fn check_abstract_typing(&mut self, symbol: &HirSymbol) {
+        if !symbol.kind.is_usage() || symbol.is_abstract {
+            return;
+        }
+        for type_ref in &symbol.type_refs {
+            let TypeRefKind::Simple(tr) = type_ref else {
+                continue;
+            };
+            if tr.kind != RefKind::TypedBy {
+                continue;
+            }
+            let Some(definition) = tr
+                .resolved_target
+                .as_deref()
+                .and_then(|qname| self.index.lookup_qualified(qname))
+                .filter(|sym| sym.kind.is_definition() && sym.is_abstract)
+            else {
+                continue;
+            };
+            self.collector
+                .abstract_typed_usage(symbol, tr, &definition.name);
+        }
+    }
+
+    /// Check that a usage's declared type conforms to the type of each
+    /// feature it redefines, and can share values with the type of each
+    /// feature it subsets.
+    ///
+    /// Only reported when both types resolve to definitions and the usage's
+    /// type hierarchy resolves completely, so unresolved library types never
+    /// produce false mismatches.
+    fn check_feature_type_conformance(&mut self, symbol: &HirSymbol) {
+        if !symbol.kind.is_usage() {
+            return;
+        }
+        let refs: Vec<&TypeRef> = symbol
+            .type_refs
+            .iter()
+            .filter_map(|tr| match tr {
+                TypeRefKind::Simple(tr) => Some(tr),
+                TypeRefKind::Chain(_) => None,
+            })
+            .collect();
+
+        let Some(own_type) = refs
+            .iter()
+            .find(|tr| tr.kind == RefKind::TypedBy)
+            .and_then(|tr| tr.resolved_target.as_deref())
+            .and_then(|qname| self.index.lookup_qualified(qname))
+            .filter(|sym| sym.kind.is_definition())
+        else {
+            return;
+        };
+
+        // A qualified target also records its qualifier segments (`a::b`
+        // adds `a`) at the same span; only the full target is redefined or
+        // subsetted, and it comes first.
+        let mut seen_spans = std::collections::HashSet::new();
+        for tr in refs
+            .iter()
+            .filter(|tr| matches!(tr.kind, RefKind::Redefines | RefKind::Subsets))
+            .filter(|tr| seen_spans.insert((tr.start_line, tr.start_col)))
+        {
+            let Some(feature) = tr
+                .resolved_target
+                .as_deref()
+                .and_then(|qname| self.index.lookup_qualified(qname))
+                .filter(|sym| sym.kind.is_usage())
+            else {
+                continue;
+            };
+            if feature.qualified_name == symbol.qualified_name {
+                continue;
+            }
+            // The typing chain follows the first supertype; skip features
+            // whose first supertype is an implicit kernel type.
+            let explicitly_typed = feature.supertypes.first().is_some_and(|first| {
+                feature
+                    .type_refs
+                    .iter()
+                    .any(|trk| matches!(trk, TypeRefKind::Simple(tr) if tr.target == *first))
+            });
+            if !explicitly_typed {
+                continue;
+            }
+
+            let scope = Self::extract_scope(&feature.qualified_name);
+            let expected = self.index.follow_typing_chain(feature, &scope);
+            let Some(expected) = self
+                .index
+                .lookup_qualified(&expected)
+                .filter(|sym| sym.kind.is_definition())
+            else {
+                continue;
+            };
+
+            let mismatch = match tr.kind {
+                // A subsetting feature has the subsetted feature's type as
+                // well as its own, so the two only clash when no value can
+                // have both: a data value is never an occurrence.
+                RefKind::Subsets => {
+                    Self::is_data_type(own_type.kind) != Self::is_data_type(expected.kind)
+                        && self.conforms_to(expected, &own_type.qualified_name) == Some(false)
+                        && self.conforms_to(own_type, &expected.qualified_name) == Some(false)
+                }
+                _ => self.conforms_to(own_type, &expected.qualified_name) == Some(false),
+            };
+            if mismatch {
+                self.collector
+                    .type_mismatch(symbol.file, symbol, &expected.name, &own_type.name);
+            }
+        }
+    }
+
+    /// Whether definitions of `kind` classify data values rather than occurrences.
+    fn is_data_type(kind: SymbolKind) -> bool {
+        matches!(
+            kind,
+            SymbolKind::AttributeDefinition
+                | SymbolKind::EnumerationDefinition
+                | SymbolKind::DataType
+        )
+    }
+
+    /// Whether the definition `ty` is `expected` or (transitively) specializes it.
+    ///
+    /// Returns `None` if an explicit supertype along the way doesn't resolve,
+    /// or if the walk reaches a type with no recorded supertypes (KerML types
+    /// implicitly specialize library types that aren't recorded). Implicit
+    /// kernel supertypes that don't resolve (no standard library loaded) are
+    /// skipped.
+    fn conforms_to(&self, ty: &HirSymbol, expected: &str) -> Option<bool> {
+        // Every type implicitly specializes the root classifier, even where
+        // the library doesn't spell it out (e.g., KerML datatypes).
+        if expected == ROOT_CLASSIFIER {
+            return Some(true);
+        }
+        let mut stack = vec![ty.clone()];
+        let mut visited = std::collections::HashSet::new();
+        let mut complete = true;
+
+        while let Some(current) = stack.pop() {
+            if current.qualified_name.as_ref() == expected {
+                return Some(true);
+            }
+            if !visited.insert(current.qualified_name.clone()) {
+                continue;
+            }
+
+            if current.supertypes.is_empty() {
+                complete = false;
+                continue;
+            }
+
+            let scope = Self::extract_scope(&current.qualified_name);
+            let resolver = self.index.resolver_for_scope(&scope);
+            let explicit: Vec<&str> = Self::explicit_supertypes(&current).collect();
+            for supertype in &current.supertypes {
+                let is_explicit = explicit.contains(&supertype.as_ref());
+                let resolved = match resolver.resolve_type(supertype) {
+                    ResolveResult::Found(sym) => Some(sym),
+                    // Implicit supertypes are fully qualified but may not be
+                    // visible from the scope (e.g., in KerML packages)
+                    _ if !is_explicit => self.index.lookup_qualified(supertype).cloned(),
+                    _ => None,
+                };
+                // Library supertypes may be aliases (e.g., `AttributeValue`)
+                match resolved.map(|sym| resolver.resolve_alias_target(&sym)) {
+                    Some(ResolveResult::Found(sym)) => stack.push(sym),
+                    Some(_) => complete = false,
+                    None if is_explicit => complete = false,
+                    None => {}
+                }
+            }
+        }
+
+        complete.then_some(false)
     }
 
     /// Check type references in a symbol's body, filtering by RefKind.
@@ -426,10 +1186,10 @@ impl<'a> SemanticChecker<'a> {
                     // Check based on reference kind
                     if tr.kind.is_type_reference() {
                         // Type references resolve via scope walking
-                        self.check_type_reference(symbol, &tr.target);
+                        self.check_type_reference(symbol, tr);
                     } else if tr.kind.is_feature_reference() {
                         // Feature references (Redefines, Subsets) resolve via inheritance
-                        self.check_feature_reference(symbol, &tr.target);
+                        self.check_feature_reference(symbol, tr);
                     }
                     // Expression and Other refs are not checked
                 }
@@ -441,51 +1201,60 @@ impl<'a> SemanticChecker<'a> {
                         }
                     }
 
-                    // Validate unresolved chain parts (when first part resolves)
-                    // Skip if first part didn't resolve (can't validate without context)
-                    if chain
+                    // Validate the chain up to its first unresolved part. Parts
+                    // after it can't be checked, and a chain whose first part
+                    // didn't resolve has no context to check against
+                    let mut previous = chain
                         .parts
                         .first()
-                        .is_some_and(|p| p.resolved_target.is_some())
-                    {
-                        for part in chain.parts.iter().skip(1) {
-                            if part.resolved_target.is_none() {
-                                let name = part.target.as_ref();
-
-                                // Skip `that` keyword - it's a SysML contextual reference
-                                // meaning "the type of the enclosing feature" and cannot
-                                // be resolved as a regular symbol.
-                                if name == "that" {
-                                    continue;
-                                }
-
-                                self.collector.add(
-                                    Diagnostic::error(
-                                        symbol.file,
-                                        part.start_line,
-                                        part.start_col,
-                                        format!("Undefined member '{}' in feature chain", name),
-                                    )
-                                    .with_span(
-                                        part.end_line,
-                                        part.end_col,
-                                    ),
-                                );
-                            }
+                        .and_then(|first| first.resolved_target.clone());
+                    for part in chain.parts.iter().skip(1) {
+                        let Some(owner) = previous else {
+                            break;
+                        };
+
+                        // Skip `that` keyword - it's a SysML contextual reference
+                        // meaning "the type of the enclosing feature" and cannot
+                        // be resolved as a regular symbol.
+                        if part.target.as_ref() == "that" {
+                            break;
+                        }
+
+                        if part.resolved_target.is_none() {
+                            let owner_type = self.chain_owner_type(&owner);
+                            self.collector
+                                .undefined_chain_member(symbol.file, part, &owner_type);
                         }
+                        previous = part.resolved_target.clone();
                     }
                 }
             }
         }
     }
 
+    /// Name the type whose members a resolved feature chain part exposes: the
+    /// part itself if it's a definition, otherwise its declared type.
+    fn chain_owner_type(&self, qualified_name: &str) -> Arc<str> {
+        match self.index.lookup_qualified(qualified_name) {
+            Some(sym) if sym.kind.is_definition() => sym.name.clone(),
+            Some(sym) => sym
+                .supertypes
+                .first()
+                .cloned()
+                .unwrap_or_else(|| sym.name.clone()),
+            None => Arc::from(qualified_name),
+        }
+    }
+
     /// Check a feature reference resolves via inheritance.
     ///
     /// For `attribute mass redefines Vehicle::mass`, we:
     /// 1. Parse the target - could be qualified (Vehicle::mass) or simple (mass)
     /// 2. For qualified: resolve the prefix, then find member in that scope
     /// 3. For simple: look in containing symbol's supertypes
-    fn check_feature_reference(&mut self, symbol: &HirSymbol, target: &str) {
+    fn check_feature_reference(&mut self, symbol: &HirSymbol, type_ref: &TypeRef) {
+        let target = type_ref.target.as_ref();
+
         // Strip index expressions like [1] from the target (e.g., "cylinders[1]" -> "cylinders")
         let target = if let Some(bracket_pos) = target.find('[') {
             &target[..bracket_pos]
@@ -513,14 +1282,14 @@ impl<'a> SemanticChecker<'a> {
                     } else if !Self::is_builtin_type(target) {
                         // Member not found in the specified scope
                         self.collector
-                            .undefined_reference(symbol.file, symbol, target);
+                            .undefined_reference_at(symbol.file, type_ref, target);
                     }
                 }
                 ResolveResult::NotFound => {
                     // Prefix itself couldn't be resolved
                     if !Self::is_builtin_type(prefix) {
                         self.collector
-                            .undefined_reference(symbol.file, symbol, target);
+                            .undefined_reference_at(symbol.file, type_ref, target);
                     }
                 }
                 ResolveResult::Ambiguous(_) => {
@@ -635,12 +1404,13 @@ impl<'a> SemanticChecker<'a> {
             && !is_contextual_keyword
         {
             self.collector
-                .undefined_reference(symbol.file, symbol, target);
+                .undefined_reference_at(symbol.file, type_ref, target);
         }
     }
 
     /// Check a type reference resolves correctly.
-    fn check_type_reference(&mut self, symbol: &HirSymbol, name: &str) {
+    fn check_type_reference(&mut self, symbol: &HirSymbol, type_ref: &TypeRef) {
+        let name = type_ref.target.as_ref();
         let scope = Self::extract_scope(&symbol.qualified_name);
         let resolver = Resolver::new(self.index).with_scope(scope);
 
@@ -659,50 +1429,325 @@ impl<'a> SemanticChecker<'a> {
                 // These are member access expressions, not type references
                 if !Self::is_builtin_type(name) && !name.contains('.') {
                     self.collector
-                        .undefined_reference(symbol.file, symbol, name);
+                        .undefined_reference_at(symbol.file, type_ref, name);
                 }
             }
         }
     }
 
-    /// Check for duplicate definitions within a file.
+    /// Check for duplicate definitions.
+    ///
+    /// Two definitions clash when they share a simple name and a parent scope;
+    /// the same holds for two usages. A definition and a usage may share a name.
+    /// Definitions other than packages are also checked against those in the
+    /// same scope from other files, with the lower `FileId` treated as the
+    /// original.
     fn check_duplicates(&mut self, file: FileId, symbols: &[&HirSymbol]) {
         use std::collections::HashMap;
 
-        // Group by qualified name
-        let mut by_qname: HashMap<&str, Vec<&HirSymbol>> = HashMap::new();
+        // Group by (parent scope, simple name, is definition), in source order
+        let mut by_scope: HashMap<(String, &str, bool), Vec<&HirSymbol>> = HashMap::new();
         for symbol in symbols {
-            // Skip imports and aliases - they don't count as definitions
-            if symbol.kind == SymbolKind::Import || symbol.kind == SymbolKind::Alias {
+            if !Self::can_clash(symbol) {
                 continue;
             }
-            // Skip anonymous elements - they have synthetic names like <anonymous-dependency>
-            // and multiple anonymous elements with the same synthetic name are allowed
-            if symbol.name.starts_with('<') && symbol.name.ends_with('>') {
+            let scope = Self::extract_scope(&symbol.qualified_name);
+            by_scope
+                .entry((scope, symbol.name.as_ref(), symbol.kind.is_definition()))
+                .or_default()
+                .push(symbol);
+        }
+
+        for ((scope, name, is_definition), mut defs) in by_scope {
+            defs.sort_by_key(|s| (s.start_line, s.start_col));
+
+            // A definition from an earlier file in the same scope comes first
+            let mut first = defs[0];
+            if is_definition {
+                let earlier = self
+                    .index
+                    .lookup_simple(name)
+                    .into_iter()
+                    .filter(|s| {
+                        s.file < file
+                            && s.name.as_ref() == name
+                            && s.kind.is_definition()
+                            && s.kind != SymbolKind::Package
+                            && Self::can_clash(s)
+                            && Self::extract_scope(&s.qualified_name) == scope
+                    })
+                    .min_by_key(|s| (s.file, s.start_line, s.start_col));
+                if let Some(earlier) = earlier {
+                    first = earlier;
+                    self.collector.duplicate_definition(file, defs[0], first);
+                }
+            }
+
+            for dup in &defs[1..] {
+                self.collector.duplicate_definition(file, dup, first);
+            }
+        }
+    }
+
+    /// Check for definitions that specialize themselves through their
+    /// supertype chain (e.g., `A :> B` and `B :> A`).
+    ///
+    /// Each cycle is reported once, on its member with the smallest qualified
+    /// name.
+    fn check_circular_supertypes(&mut self, symbols: &[&HirSymbol]) {
+        for symbol in symbols {
+            if !symbol.kind.is_definition() || Self::explicit_supertypes(symbol).next().is_none() {
                 continue;
             }
-            // Skip elements whose qualified name contains anonymous parent segments
-            // (e.g., parameters inside anonymous transitions like `<:>>foo#1@L26>::s`)
-            // Anonymous segments have format `<...#N@LNN>`
-            if symbol.qualified_name.contains("<") && symbol.qualified_name.contains("#") {
+
+            let mut visited = std::collections::HashSet::new();
+            let mut cycle = vec![symbol.qualified_name.clone()];
+            if !self.find_supertype_cycle(symbol, &symbol.qualified_name, &mut visited, &mut cycle)
+            {
                 continue;
             }
-            by_qname
-                .entry(symbol.qualified_name.as_ref())
-                .or_default()
-                .push(symbol);
+
+            if cycle.iter().min() == Some(&symbol.qualified_name) {
+                self.collector
+                    .circular_dependency(symbol.file, symbol, &cycle);
+            }
         }
+    }
+
+    /// Depth-first search along resolved supertypes for a path back to `target`.
+    ///
+    /// On success, `path` holds the cycle. `visited` guarantees termination on
+    /// cycles that don't pass through `target`.
+    fn find_supertype_cycle(
+        &self,
+        current: &HirSymbol,
+        target: &Arc<str>,
+        visited: &mut std::collections::HashSet<Arc<str>>,
+        path: &mut Vec<Arc<str>>,
+    ) -> bool {
+        let scope = Self::extract_scope(&current.qualified_name);
+        let resolver = Resolver::new(self.index).with_scope(scope);
 
-        // Report duplicates
-        for (_qname, defs) in by_qname {
-            if defs.len() > 1 {
-                // Report error on all but the first definition
-                let first = defs[0];
-                for dup in &defs[1..] {
-                    self.collector.duplicate_definition(file, dup, first);
+        for supertype in Self::explicit_supertypes(current) {
+            let ResolveResult::Found(resolved) = resolver.resolve(supertype) else {
+                continue;
+            };
+            if resolved.qualified_name == *target {
+                path.push(target.clone());
+                return true;
+            }
+            if !visited.insert(resolved.qualified_name.clone()) {
+                continue;
+            }
+            path.push(resolved.qualified_name.clone());
+            if self.find_supertype_cycle(&resolved, target, visited, path) {
+                return true;
+            }
+            path.pop();
+        }
+        false
+    }
+
+    /// Check for imports in a file whose names are never referenced.
+    ///
+    /// A reference uses an import when the first segment of its written target
+    /// resolved to a name that import brings into scope. Only references within
+    /// the import's owning scope (and its nested scopes) are considered.
+    /// Specific imports are flagged when their single name is unused, wildcard
+    /// imports only when none of the names they bring in are used. Public
+    /// imports re-export their names and are never flagged.
+    ///
+    /// Only part of [`check_file`](Self::check_file) when `W0004` is given a
+    /// severity in the config, as imports used only within expressions have
+    /// no resolved references to count.
+    pub fn check_unused_imports(&mut self, file: FileId) {
+        let symbols = self.index.symbols_in_file(file);
+
+        // (owning symbol, qualified name the reference's first segment resolved to)
+        let mut heads: Vec<(&str, Arc<str>)> = Vec::new();
+        for symbol in &symbols {
+            if symbol.kind == SymbolKind::Import {
+                continue;
+            }
+            for type_ref in &symbol.type_refs {
+                let first = match type_ref {
+                    TypeRefKind::Simple(tr) => tr,
+                    TypeRefKind::Chain(chain) => match chain.parts.first() {
+                        Some(tr) => tr,
+                        None => continue,
+                    },
+                };
+                if let Some(head) = Self::resolved_head(first) {
+                    heads.push((&symbol.qualified_name, head));
                 }
             }
         }
+
+        for import in &symbols {
+            if import.kind != SymbolKind::Import || import.is_public {
+                continue;
+            }
+            // Unresolved import targets are reported elsewhere
+            let Some(target) = import.type_refs.iter().find_map(|tr| match tr {
+                TypeRefKind::Simple(tr) => tr.resolved_target.as_deref(),
+                TypeRefKind::Chain(chain) => chain
+                    .parts
+                    .last()
+                    .and_then(|tr| tr.resolved_target.as_deref()),
+            }) else {
+                continue;
+            };
+
+            let scope = match import.qualified_name.rsplit_once("::import:") {
+                Some((scope, _)) => scope,
+                None => "",
+            };
+            let in_scope = |owner: &str| {
+                scope.is_empty()
+                    || owner == scope
+                    || owner
+                        .strip_prefix(scope)
+                        .is_some_and(|rest| rest.starts_with("::"))
+            };
+
+            let used = heads
+                .iter()
+                .filter(|(owner, _)| in_scope(owner))
+                .any(|(_, head)| self.import_brings(&import.name, target, head));
+            if !used {
+                self.collector.unused_import(import);
+            }
+        }
+    }
+
+    /// Qualified name that the first segment of a reference resolved to.
+    ///
+    /// For `Sub::Z` resolved to `A::Sub::Z` this is `A::Sub`.
+    fn resolved_head(type_ref: &TypeRef) -> Option<Arc<str>> {
+        let resolved = type_ref.resolved_target.as_ref()?;
+        let trailing = type_ref.target.matches("::").count();
+        let mut head: &str = resolved;
+        for _ in 0..trailing {
+            head = head.rsplit_once("::")?.0;
+        }
+        Some(Arc::from(head))
+    }
+
+    /// Whether the import named `import_name` (resolving to `target`) brings
+    /// `head` into scope.
+    fn import_brings(&self, import_name: &str, target: &str, head: &str) -> bool {
+        let is_member = |namespace: &str| {
+            Self::extract_scope(head) == namespace
+                || self
+                    .index
+                    .visibility_for_scope(namespace)
+                    .and_then(|vis| vis.lookup(head.rsplit("::").next().unwrap_or(head)))
+                    .is_some_and(|qname| qname.as_ref() == head)
+        };
+
+        if import_name.ends_with("::**") {
+            head.strip_prefix(target)
+                .is_some_and(|rest| rest.starts_with("::"))
+                || is_member(target)
+        } else if import_name.ends_with("::*") {
+            is_member(target)
+        } else {
+            head == target
+        }
+    }
+
+    /// Supertypes written in the source, including subsetted features.
+    ///
+    /// `supertypes` also holds implicit kernel supertypes (e.g., `Parts::Part`),
+    /// which the library itself specializes back into.
+    fn explicit_supertypes(symbol: &HirSymbol) -> impl Iterator<Item = &str> {
+        symbol
+            .relationships
+            .iter()
+            .filter(|r| {
+                matches!(
+                    r.kind,
+                    RelationshipKind::Specializes | RelationshipKind::Subsets
+                )
+            })
+            .map(|r| r.target.as_ref())
+    }
+
+    /// Whether a symbol takes part in duplicate-definition checking.
+    fn can_clash(symbol: &HirSymbol) -> bool {
+        // Imports and aliases don't count as definitions
+        if symbol.kind == SymbolKind::Import || symbol.kind == SymbolKind::Alias {
+            return false;
+        }
+        // Skip anonymous elements - they have synthetic names like <anonymous-dependency>
+        // and multiple anonymous elements with the same synthetic name are allowed
+        if symbol.is_anonymous() {
+            return false;
+        }
+        // Skip elements whose qualified name contains anonymous parent segments
+        // (e.g., parameters inside anonymous transitions like `<:>>foo#1>::s`)
+        // Anonymous segments have format `<...#N>`
+        !(symbol.qualified_name.contains('<') && symbol.qualified_name.contains('#'))
+    }
+
+    /// Hint at imports whose names are hidden by a definition in the
+    /// importing scope, which makes them dead.
+    ///
+    /// Only part of [`check_file`](Self::check_file) when `W0005` is given a
+    /// severity in the config.
+    pub fn check_shadowed_imports(&mut self, file: FileId) {
+        for import in self.index.symbols_in_file(file) {
+            if import.kind != SymbolKind::Import {
+                continue;
+            }
+            let Some(target) = import.type_refs.iter().find_map(|tr| match tr {
+                TypeRefKind::Simple(tr) => tr.resolved_target.as_deref(),
+                TypeRefKind::Chain(chain) => chain
+                    .parts
+                    .last()
+                    .and_then(|tr| tr.resolved_target.as_deref()),
+            }) else {
+                continue;
+            };
+            let scope = match import.qualified_name.rsplit_once("::import:") {
+                Some((scope, _)) => scope,
+                None => "",
+            };
+            let Some(vis) = self.index.visibility_for_scope(scope) else {
+                continue;
+            };
+
+            let mut shadowed: Vec<_> = vis
+                .shadowed_imports()
+                .filter(|(_, imported)| self.import_brings(&import.name, target, imported))
+                .filter_map(|(name, _)| Some((name.clone(), vis.lookup_direct(name)?.clone())))
+                .collect();
+            shadowed.sort();
+            for (name, local) in shadowed {
+                self.collector.shadowed_import(import, &name, &local);
+            }
+        }
+    }
+
+    /// Warn about packages, definitions and usages whose names don't follow
+    /// the configured [`NamingConventions`].
+    ///
+    /// Only plain identifiers are checked; anonymous elements and
+    /// unrestricted (quoted) names are skipped. Only part of
+    /// [`check_file`](Self::check_file) when `W0003` is given a severity in
+    /// the config, as style is a project choice.
+    pub fn check_naming_conventions(&mut self, file: FileId) {
+        for symbol in self.index.symbols_in_file(file) {
+            let Some(style) = self.config.naming.style_for(symbol.kind) else {
+                continue;
+            };
+            if !Self::can_clash(symbol) || !is_basic_name(&symbol.name) {
+                continue;
+            }
+            if !style.matches(&symbol.name) {
+                self.collector.naming_convention(symbol, style);
+            }
+        }
     }
 
     /// Check for unused definitions (optional, can be noisy).
@@ -874,18 +1919,11 @@ impl<'a> SemanticChecker<'a> {
         }
     }
 
-    /// Get the collected diagnostics, deduplicated.
-    pub fn finish(self) -> Vec<Diagnostic> {
-        let mut seen = std::collections::HashSet::new();
-        self.collector
-            .diagnostics
-            .into_iter()
-            .filter(|d| {
-                // Deduplicate by (file, line, col, message)
-                let key = (d.file, d.start_line, d.start_col, d.message.clone());
-                seen.insert(key)
-            })
-            .collect()
+    /// Get the collected diagnostics, deduplicated, with the config's severity
+    /// overrides applied.
+    pub fn finish(mut self) -> Vec<Diagnostic> {
+        self.collector.dedup();
+        self.config.apply(self.collector.diagnostics)
     }
 }
 
@@ -896,49 +1934,56 @@ pub fn check_file(index: &SymbolIndex, file: FileId) -> Vec<Diagnostic> {
     checker.finish()
 }
 
+/// Check a file and return diagnostics, with severities overridden by `config`
+/// and its opt-in checks included.
+pub fn check_file_with_config(
+    index: &SymbolIndex,
+    file: FileId,
+    config: &DiagnosticConfig,
+) -> Vec<Diagnostic> {
+    let mut checker = SemanticChecker::new(index).with_config(config.clone());
+    checker.check_file(file);
+    checker.finish()
+}
+
+/// Check a file's names against the default [`NamingConventions`] and return
+/// the warnings.
+pub fn check_naming_conventions(index: &SymbolIndex, file: FileId) -> Vec<Diagnostic> {
+    let mut checker = SemanticChecker::new(index);
+    checker.check_naming_conventions(file);
+    checker.finish()
+}
+
+/// Check a file for imports shadowed by local definitions and return the hints.
+pub fn check_shadowed_imports(index: &SymbolIndex, file: FileId) -> Vec<Diagnostic> {
+    let mut checker = SemanticChecker::new(index);
+    checker.check_shadowed_imports(file);
+    checker.finish()
+}
+
+/// Whether `name` is a plain identifier rather than an unrestricted name.
+fn is_basic_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Check a file for unused imports and return the warnings.
+pub fn check_unused_imports(index: &SymbolIndex, file: FileId) -> Vec<Diagnostic> {
+    let mut checker = SemanticChecker::new(index);
+    checker.check_unused_imports(file);
+    checker.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::{SymbolKind, new_element_id};
+    use crate::hir::SymbolKind;
 
     fn make_symbol(name: &str, qualified: &str, kind: SymbolKind, file: u32) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qualified),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(file),
-            start_line: 0,
-            start_col: 0,
-            end_line: 0,
-            end_col: 0,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+        HirSymbol::builder(name, qualified, kind, FileId::new(file)).build()
     }
 
     #[test]
@@ -982,6 +2027,42 @@ mod tests {
         assert_eq!(file1_diags.len(), 1);
     }
 
+    #[test]
+    fn test_collector_dedup() {
+        let related = |line| RelatedInfo {
+            file: FileId::new(1),
+            line,
+            col: 0,
+            message: Arc::from("defined here"),
+        };
+        let duplicate = || {
+            Diagnostic::error(FileId::new(0), 2, 4, "undefined reference: 'X'")
+                .with_span(2, 5)
+                .with_code(codes::UNDEFINED_REFERENCE)
+        };
+
+        let mut collector = DiagnosticCollector::new();
+        collector.add(duplicate().with_related(related(1)));
+        collector.add(Diagnostic::error(
+            FileId::new(0),
+            2,
+            4,
+            "undefined reference: 'Y'",
+        ));
+        collector.add(
+            duplicate()
+                .with_related(related(1))
+                .with_related(related(8)),
+        );
+        collector.dedup();
+
+        let diagnostics = collector.diagnostics();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message.as_ref(), "undefined reference: 'X'");
+        assert_eq!(diagnostics[0].related, vec![related(1), related(8)]);
+        assert_eq!(diagnostics[1].message.as_ref(), "undefined reference: 'Y'");
+    }
+
     #[test]
     fn test_severity_to_lsp() {
         assert_eq!(Severity::Error.to_lsp(), 1);
@@ -990,6 +2071,21 @@ mod tests {
         assert_eq!(Severity::Hint.to_lsp(), 4);
     }
 
+    #[test]
+    fn test_naming_style_matches() {
+        assert!(NamingStyle::UpperCamelCase.matches("VehicleMass"));
+        assert!(NamingStyle::UpperCamelCase.matches("SI"));
+        assert!(!NamingStyle::UpperCamelCase.matches("vehicleMass"));
+        assert!(!NamingStyle::UpperCamelCase.matches("Vehicle_Mass"));
+
+        assert!(NamingStyle::LowerCamelCase.matches("vehicleMass"));
+        assert!(!NamingStyle::LowerCamelCase.matches("VehicleMass"));
+        assert!(!NamingStyle::LowerCamelCase.matches("vehicle_mass"));
+
+        assert!(NamingStyle::SnakeCase.matches("vehicle_mass2"));
+        assert!(!NamingStyle::SnakeCase.matches("vehicleMass"));
+    }
+
     #[test]
     fn test_semantic_checker_undefined_reference() {
         use crate::hir::symbols::{RefKind, TypeRef, TypeRefKind};
@@ -1033,4 +2129,58 @@ mod tests {
         // Should have no errors - reference resolves
         assert_eq!(diagnostics.len(), 0);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_diagnostics_to_json() {
+        let mut root = SourceRoot::new();
+        root.insert(FileId::new(0), "models/vehicle.sysml");
+
+        let error = Diagnostic::error(FileId::new(0), 3, 4, "undefined reference: 'Wheel'")
+            .with_span(3, 9)
+            .with_code(codes::UNDEFINED_REFERENCE)
+            .with_related(RelatedInfo {
+                file: FileId::new(1),
+                line: 7,
+                col: 2,
+                message: Arc::from("similar name defined here"),
+            });
+        let warning = Diagnostic::warning(FileId::new(1), 0, 0, "unused import");
+
+        let json = diagnostics_to_json(&[error.clone(), warning], Some(&root));
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "file": { "id": 0, "path": "models/vehicle.sysml" },
+                    "range": {
+                        "start": { "line": 3, "character": 4 },
+                        "end": { "line": 3, "character": 9 },
+                    },
+                    "severity": "error",
+                    "code": "E0001",
+                    "message": "undefined reference: 'Wheel'",
+                    "related": [{
+                        "file": { "id": 1 },
+                        "position": { "line": 7, "character": 2 },
+                        "message": "similar name defined here",
+                    }],
+                },
+                {
+                    "file": { "id": 1 },
+                    "range": {
+                        "start": { "line": 0, "character": 0 },
+                        "end": { "line": 0, "character": 0 },
+                    },
+                    "severity": "warning",
+                    "code": null,
+                    "message": "unused import",
+                    "related": [],
+                },
+            ])
+        );
+
+        // Without a source root only the numeric id is emitted
+        assert_eq!(error.to_json()["file"], serde_json::json!({ "id": 0 }));
+    }
 }
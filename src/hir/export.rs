@@ -0,0 +1,87 @@
+//! Export — rendering the resolved symbol graph for external tools.
+//!
+//! Currently supports Graphviz DOT via [`to_dot`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::sync::Arc;
+
+use super::resolve::SymbolIndex;
+use super::symbols::{HirSymbol, RefKind, TypeRefKind};
+
+/// Render definitions and their resolved relationships as a Graphviz digraph.
+///
+/// Each definition becomes a box node labeled by its qualified name. Resolved
+/// specializations (`:>`) are drawn as solid edges with hollow arrowheads,
+/// feature typings (`:`) as dashed edges. Definitions don't have typings, so
+/// typing edges only appear when `include_usages` adds usages (as ellipses).
+///
+/// Edges whose target isn't a node are left out. Anonymous symbols are skipped.
+pub fn to_dot(index: &SymbolIndex, include_usages: bool) -> String {
+    let is_node = |symbol: &HirSymbol| {
+        !symbol.name.starts_with('<')
+            && (symbol.kind.is_definition() || (include_usages && symbol.kind.is_usage()))
+    };
+
+    // Sorted for stable output
+    let nodes: BTreeMap<&str, &HirSymbol> = index
+        .all_symbols()
+        .filter(|symbol| is_node(symbol))
+        .map(|symbol| (symbol.qualified_name.as_ref(), symbol))
+        .collect();
+
+    // (source, target, is_specialization)
+    let mut edges: BTreeSet<(&str, Arc<str>, bool)> = BTreeSet::new();
+    for (&source, symbol) in &nodes {
+        for type_ref in &symbol.type_refs {
+            let TypeRefKind::Simple(tr) = type_ref else {
+                continue;
+            };
+            if !matches!(tr.kind, RefKind::Specializes | RefKind::TypedBy) {
+                continue;
+            }
+            if let Some(target) = &tr.resolved_target {
+                if target.as_ref() != source && nodes.contains_key(target.as_ref()) {
+                    edges.insert((source, target.clone(), tr.kind == RefKind::Specializes));
+                }
+            }
+        }
+    }
+
+    let mut dot = String::from("digraph symbols {\n    rankdir=BT;\n");
+    for (&name, symbol) in &nodes {
+        let shape = if symbol.kind.is_definition() {
+            "box"
+        } else {
+            "ellipse"
+        };
+        let _ = writeln!(
+            dot,
+            "    {} [label={}, shape={}];",
+            quote(name),
+            quote(name),
+            shape
+        );
+    }
+    for (source, target, is_specialization) in &edges {
+        let attrs = if *is_specialization {
+            "label=\":>\", style=solid, arrowhead=empty"
+        } else {
+            "label=\":\", style=dashed, arrowhead=open"
+        };
+        let _ = writeln!(
+            dot,
+            "    {} -> {} [{}];",
+            quote(source),
+            quote(target),
+            attrs
+        );
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Quote a string as a DOT identifier.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -36,6 +36,7 @@
 
 mod db;
 mod diagnostics;
+mod export;
 mod ids;
 mod input;
 mod resolve;
@@ -47,16 +48,22 @@ pub use db::{
     FileText, ParseResult, RootDatabase, SourceRootInput, file_symbols, file_symbols_from_text,
     parse_file,
 };
+#[cfg(feature = "serde")]
+pub use diagnostics::diagnostics_to_json;
 pub use diagnostics::{
-    Diagnostic, DiagnosticCollector, RelatedInfo, SemanticChecker, Severity, check_file,
+    Diagnostic, DiagnosticCollector, DiagnosticConfig, NamingConventions, NamingStyle, RelatedInfo,
+    SemanticChecker, Severity, check_file, check_file_with_config, check_naming_conventions,
+    check_shadowed_imports, check_unused_imports,
 };
+pub use export::to_dot;
 pub use ids::{DefId, LocalDefId};
 pub use input::SourceRoot;
-pub use resolve::{ResolveResult, Resolver, SymbolIndex};
+pub use resolve::{RefSite, ResolveHit, ResolveResult, ResolveStep, Resolver, SymbolIndex};
 pub use source::FileSet;
 pub use symbols::{
-    ExtractionResult, HirRelationship, HirSymbol, RefKind, RelationshipKind, SymbolKind, TypeRef,
-    TypeRefChain, TypeRefKind, extract_symbols_unified, extract_with_filters, new_element_id,
+    ExtractionResult, HirRelationship, HirSymbol, HirSymbolBuilder, RefKind, RelationshipKind,
+    SymbolKind, TypeRef, TypeRefChain, TypeRefKind, extract_symbols_unified, extract_with_filters,
+    new_element_id,
 };
 pub use views::{
     ExposeRelationship, FilterCondition, ImportPath, MetadataFilter, RenderingDefinition,
@@ -22,11 +22,17 @@ use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
 use super::symbols::{HirSymbol, RefKind, SymbolKind, TypeRefKind};
-use crate::base::FileId;
+use crate::base::{FileId, LineCol, LineColSpan};
 
 /// Type alias for resolution cache: (name, starting_scope) -> resolved_qname
 type ResolutionCache = HashMap<(Arc<str>, Arc<str>), Option<Arc<str>>>;
 
+/// Type alias for a name a namespace import copies: (simple_name, qname, re-export depth)
+type ImportedMember = (Arc<str>, Arc<str>, usize);
+
+/// Type alias for a name listed for an import: (simple_name, qname, re-exported)
+type ImportedName = (Arc<str>, Arc<str>, bool);
+
 // ============================================================================
 // SCOPE VISIBILITY (Pre-computed at index time)
 // ============================================================================
@@ -40,8 +46,9 @@ type ResolutionCache = HashMap<(Arc<str>, Arc<str>), Option<Arc<str>>>;
 /// For a scope like `ISQ` with `public import ISQSpaceTime::*`:
 /// - `direct_defs` contains symbols defined directly in ISQ
 /// - `imports` contains symbols from ISQSpaceTime (via the wildcard import)
+/// - `public_imports` contains the imports re-exported to importers of ISQ
 /// - `public_reexports` tracks that ISQSpaceTime's symbols are re-exported
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ScopeVisibility {
     /// The scope this visibility applies to (e.g., "ISQ", "Automotive::Torque").
     scope: Arc<str>,
@@ -50,13 +57,44 @@ pub struct ScopeVisibility {
     /// SimpleName → QualifiedName
     direct_defs: HashMap<Arc<str>, Arc<str>>,
 
+    /// Simple names defined here for two or more distinct qualified names
+    /// (e.g., definitions sharing a short name).
+    /// SimpleName → all candidate QualifiedNames (in definition order)
+    overloaded_defs: HashMap<Arc<str>, Vec<Arc<str>>>,
+
+    /// Names in `direct_defs` that were inherited from a supertype rather
+    /// than defined here. Imports of this scope do not see them.
+    inherited: HashSet<Arc<str>>,
+
     /// Symbols visible via imports (includes transitive public re-exports).
     /// SimpleName → QualifiedName (the resolved target)
     imports: HashMap<Arc<str>, Arc<str>>,
 
+    /// Simple names imported from two or more distinct qualified names.
+    /// SimpleName → all candidate QualifiedNames (in import order)
+    ambiguous_imports: HashMap<Arc<str>, Vec<Arc<str>>>,
+
+    /// Imports brought in by public imports, re-exported to scopes that
+    /// import this one. Private imports are not transitively visible.
+    /// SimpleName → QualifiedName (the resolved target)
+    public_imports: HashMap<Arc<str>, Arc<str>>,
+
     /// Namespaces that are publicly re-exported from this scope.
     /// Used for transitive import resolution.
     public_reexports: Vec<Arc<str>>,
+
+    /// Imported names hidden by a definition in this scope.
+    /// SimpleName → QualifiedNames (the imported elements, not the local one)
+    shadowed_imports: HashMap<Arc<str>, Vec<Arc<str>>>,
+
+    /// Number of scopes that re-exported each public import, for names that
+    /// arrived through another scope's public imports.
+    /// SimpleName → depth (names not listed have depth 1)
+    reexport_depths: HashMap<Arc<str>, usize>,
+
+    /// Wildcard imports (by qualified name) that left out names because they
+    /// exceeded the index's re-export depth limit.
+    truncated_imports: Vec<Arc<str>>,
 }
 
 impl ScopeVisibility {
@@ -65,8 +103,15 @@ impl ScopeVisibility {
         Self {
             scope: scope.into(),
             direct_defs: HashMap::new(),
+            overloaded_defs: HashMap::new(),
+            inherited: HashSet::new(),
             imports: HashMap::new(),
+            ambiguous_imports: HashMap::new(),
+            public_imports: HashMap::new(),
             public_reexports: Vec::new(),
+            shadowed_imports: HashMap::new(),
+            reexport_depths: HashMap::new(),
+            truncated_imports: Vec::new(),
         }
     }
 
@@ -95,19 +140,131 @@ impl ScopeVisibility {
         self.imports.get(name)
     }
 
+    /// Get all candidates if a name is imported from more than one distinct
+    /// qualified name.
+    ///
+    /// Returns `None` when the import is unambiguous. Direct definitions are
+    /// not considered; they shadow imports regardless.
+    pub fn lookup_ambiguous_import(&self, name: &str) -> Option<&[Arc<str>]> {
+        self.ambiguous_imports.get(name).map(Vec::as_slice)
+    }
+
+    /// Get all candidates if a name is defined directly in this scope for more
+    /// than one distinct qualified name.
+    ///
+    /// Returns `None` when the name has a single definition.
+    pub fn lookup_direct_overloads(&self, name: &str) -> Option<&[Arc<str>]> {
+        self.overloaded_defs.get(name).map(Vec::as_slice)
+    }
+
     /// Add a direct definition to this scope.
+    ///
+    /// Defining a name that is already defined for a different qualified name
+    /// records both as overloads; the latest one is returned by lookups.
     pub fn add_direct(&mut self, simple_name: Arc<str>, qualified_name: Arc<str>) {
+        if let Some(existing) = self.direct_defs.get(&simple_name) {
+            if *existing != qualified_name {
+                let candidates = self
+                    .overloaded_defs
+                    .entry(simple_name.clone())
+                    .or_insert_with(|| vec![existing.clone()]);
+                if !candidates.contains(&qualified_name) {
+                    candidates.push(qualified_name.clone());
+                }
+            }
+        }
+        self.direct_defs.insert(simple_name, qualified_name);
+    }
+
+    /// Add a member inherited from a supertype, unless the name is already
+    /// defined in this scope.
+    ///
+    /// Returns `true` if the member was added.
+    fn add_inherited(&mut self, simple_name: Arc<str>, qualified_name: Arc<str>) -> bool {
+        if self.direct_defs.contains_key(&simple_name) {
+            return false;
+        }
+        self.inherited.insert(simple_name.clone());
         self.direct_defs.insert(simple_name, qualified_name);
+        true
+    }
+
+    /// Check whether a direct definition was inherited from a supertype.
+    fn is_inherited(&self, name: &str) -> bool {
+        self.inherited.contains(name)
     }
 
     /// Add an imported symbol to this scope.
+    ///
+    /// Importing a name that is already imported from a different qualified
+    /// name records both as ambiguous candidates.
     pub fn add_import(&mut self, simple_name: Arc<str>, qualified_name: Arc<str>) {
         // Don't overwrite direct definitions with imports
-        if !self.direct_defs.contains_key(&simple_name) {
-            self.imports.insert(simple_name, qualified_name);
+        if let Some(local) = self.direct_defs.get(&simple_name) {
+            if *local != qualified_name && !self.is_inherited(&simple_name) {
+                let shadowed = self.shadowed_imports.entry(simple_name).or_default();
+                if !shadowed.contains(&qualified_name) {
+                    shadowed.push(qualified_name);
+                }
+            }
+            return;
+        }
+
+        if let Some(existing) = self.imports.get(&simple_name) {
+            if *existing != qualified_name {
+                let candidates = self
+                    .ambiguous_imports
+                    .entry(simple_name.clone())
+                    .or_insert_with(|| vec![existing.clone()]);
+                if !candidates.contains(&qualified_name) {
+                    candidates.push(qualified_name.clone());
+                }
+            }
+        }
+        self.imports.insert(simple_name, qualified_name);
+    }
+
+    /// Add a publicly imported symbol to this scope.
+    ///
+    /// Unlike [`add_import`](Self::add_import), the symbol is re-exported to
+    /// scopes that import this one.
+    pub fn add_public_import(&mut self, simple_name: Arc<str>, qualified_name: Arc<str>) {
+        if self.direct_defs.contains_key(&simple_name) {
+            // Only records the shadowing
+            self.add_import(simple_name, qualified_name);
+            return;
+        }
+        self.reexport_depths.remove(&simple_name);
+        self.public_imports
+            .insert(simple_name.clone(), qualified_name.clone());
+        self.add_import(simple_name, qualified_name);
+    }
+
+    /// Add a public import that was re-exported through `depth` scopes.
+    fn add_public_import_at_depth(
+        &mut self,
+        simple_name: Arc<str>,
+        qualified_name: Arc<str>,
+        depth: usize,
+    ) {
+        self.add_public_import(simple_name.clone(), qualified_name);
+        if depth > 1 && self.public_imports.contains_key(&simple_name) {
+            self.reexport_depths.insert(simple_name, depth);
         }
     }
 
+    /// Get how many scopes re-exported a public import to reach this one.
+    ///
+    /// A name imported publicly from the namespace that defines it has depth 1.
+    pub fn reexport_depth(&self, name: &str) -> usize {
+        self.reexport_depths.get(name).copied().unwrap_or(1)
+    }
+
+    /// Get the wildcard imports cut short by the re-export depth limit.
+    pub fn truncated_imports(&self) -> &[Arc<str>] {
+        &self.truncated_imports
+    }
+
     /// Add a public re-export (for transitive import resolution).
     pub fn add_public_reexport(&mut self, namespace: Arc<str>) {
         if !self.public_reexports.contains(&namespace) {
@@ -130,6 +287,48 @@ impl ScopeVisibility {
         self.imports.iter()
     }
 
+    /// Get iterator over the imports re-exported by public imports.
+    pub fn public_imports(&self) -> impl Iterator<Item = (&Arc<str>, &Arc<str>)> {
+        self.public_imports.iter()
+    }
+
+    /// Get iterator over imported names hidden by a definition in this scope.
+    ///
+    /// Yields the simple name and the qualified name of each imported
+    /// element, once per distinct element hidden under that name.
+    pub fn shadowed_imports(&self) -> impl Iterator<Item = (&Arc<str>, &Arc<str>)> {
+        self.shadowed_imports
+            .iter()
+            .flat_map(|(name, imported)| imported.iter().map(move |qname| (name, qname)))
+    }
+
+    /// Collect the names that resolve differently in `self` and `other`.
+    fn changed_names(&self, other: &ScopeVisibility) -> HashSet<Arc<str>> {
+        let names = self
+            .direct_defs
+            .keys()
+            .chain(self.overloaded_defs.keys())
+            .chain(self.imports.keys())
+            .chain(self.ambiguous_imports.keys())
+            .chain(self.public_imports.keys())
+            .chain(other.direct_defs.keys())
+            .chain(other.overloaded_defs.keys())
+            .chain(other.imports.keys())
+            .chain(other.ambiguous_imports.keys())
+            .chain(other.public_imports.keys());
+
+        names
+            .filter(|name| {
+                self.direct_defs.get(*name) != other.direct_defs.get(*name)
+                    || self.overloaded_defs.get(*name) != other.overloaded_defs.get(*name)
+                    || self.imports.get(*name) != other.imports.get(*name)
+                    || self.ambiguous_imports.get(*name) != other.ambiguous_imports.get(*name)
+                    || self.public_imports.get(*name) != other.public_imports.get(*name)
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Get count of visible symbols (direct + imported).
     pub fn len(&self) -> usize {
         self.direct_defs.len() + self.imports.len()
@@ -170,6 +369,104 @@ impl ScopeVisibility {
     }
 }
 
+/// Reverse dependencies between scope visibility maps.
+///
+/// Recorded while building visibility maps so that a change to some scopes
+/// only rebuilds the scopes whose visibility was computed from them.
+#[derive(Clone, Debug, Default)]
+struct VisibilityDeps {
+    /// Scope → scopes that copy from it (imports and inheritance).
+    copied_by: HashMap<Arc<str>, HashSet<Arc<str>>>,
+    /// Scope → scopes that recursively import it (`::**`), and so depend on
+    /// every scope nested below it.
+    recursively_imported_by: HashMap<Arc<str>, HashSet<Arc<str>>>,
+    /// Name segment → scopes whose import targets or supertypes mention it.
+    named_by: HashMap<Arc<str>, HashSet<Arc<str>>>,
+    /// Imported namespace → (importing scope, file of the import).
+    imported_in_file: HashMap<Arc<str>, HashSet<(Arc<str>, FileId)>>,
+}
+
+impl VisibilityDeps {
+    /// Record that `scope` copies from `target`.
+    fn record_copy(&mut self, target: &str, scope: &Arc<str>) {
+        self.copied_by
+            .entry(Arc::from(target))
+            .or_default()
+            .insert(scope.clone());
+    }
+
+    /// Record that `scope` recursively imports `target`.
+    fn record_recursive(&mut self, target: &str, scope: &Arc<str>) {
+        self.recursively_imported_by
+            .entry(Arc::from(target))
+            .or_default()
+            .insert(scope.clone());
+    }
+
+    /// Record that `scope` resolves the names in a (possibly qualified) path.
+    fn record_names(&mut self, path: &str, scope: &Arc<str>) {
+        for segment in path.split("::") {
+            self.named_by
+                .entry(Arc::from(segment))
+                .or_default()
+                .insert(scope.clone());
+        }
+    }
+
+    /// Record that an import in `file` brings members of `namespace` into `scope`.
+    fn record_import_file(&mut self, namespace: &str, scope: &Arc<str>, file: FileId) {
+        self.imported_in_file
+            .entry(Arc::from(namespace))
+            .or_default()
+            .insert((scope.clone(), file));
+    }
+
+    /// Drop everything recorded for `scopes` (they are about to be rebuilt).
+    fn forget(&mut self, scopes: &HashSet<Arc<str>>) {
+        for map in [
+            &mut self.copied_by,
+            &mut self.recursively_imported_by,
+            &mut self.named_by,
+        ] {
+            map.retain(|_, dependents| {
+                dependents.retain(|scope| !scopes.contains(scope));
+                !dependents.is_empty()
+            });
+        }
+        self.imported_in_file.retain(|_, importers| {
+            importers.retain(|(scope, _)| !scopes.contains(scope));
+            !importers.is_empty()
+        });
+    }
+
+    /// Scopes whose visibility was copied from `scope`.
+    fn dependents_of(&self, scope: &str) -> Vec<Arc<str>> {
+        let mut dependents: Vec<Arc<str>> = self
+            .copied_by
+            .get(scope)
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect();
+
+        // Recursive imports of any enclosing scope see this scope's members
+        let mut current = Some(scope);
+        while let Some(ancestor) = current {
+            if let Some(importers) = self.recursively_imported_by.get(ancestor) {
+                dependents.extend(importers.iter().cloned());
+            }
+            current = SymbolIndex::parent_scope(ancestor);
+        }
+
+        dependents
+    }
+
+    /// Scopes that resolve `name` while building their visibility.
+    fn dependents_of_name(&self, name: &str) -> impl Iterator<Item = &Arc<str>> {
+        self.named_by.get(name).into_iter().flatten()
+    }
+}
+
 // ============================================================================
 // SYMBOL INDEX
 // ============================================================================
@@ -177,18 +474,201 @@ impl ScopeVisibility {
 /// Index into the symbols vector.
 pub type SymbolIdx = usize;
 
+/// A location where a type reference points at a symbol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RefSite {
+    /// The file containing the reference.
+    pub file: FileId,
+    /// The source span of the reference.
+    pub span: LineColSpan,
+    /// The kind of reference (typing, specialization, etc.).
+    pub kind: RefKind,
+}
+
+/// Reverse reference map, keyed by effective target.
+///
+/// Also records which targets each file references, so that a file's sites
+/// can be dropped without scanning the whole map.
+#[derive(Clone, Debug, Default)]
+struct ReferenceMap {
+    /// Effective target qualified name → reference sites.
+    sites: HashMap<Arc<str>, Vec<RefSite>>,
+    /// File → effective targets it has sites under.
+    targets_by_file: HashMap<FileId, HashSet<Arc<str>>>,
+}
+
+impl ReferenceMap {
+    /// Add a symbol's type references.
+    fn record(&mut self, symbol: &HirSymbol) {
+        for type_ref in symbol.type_refs.iter().flat_map(|trk| trk.as_refs()) {
+            let target = type_ref.effective_target();
+            self.sites.entry(target.clone()).or_default().push(RefSite {
+                file: symbol.file,
+                span: type_ref.span(),
+                kind: type_ref.kind,
+            });
+            self.targets_by_file
+                .entry(symbol.file)
+                .or_default()
+                .insert(target.clone());
+        }
+    }
+
+    /// Drop every site in `file`.
+    fn remove_file(&mut self, file: FileId) {
+        let Some(targets) = self.targets_by_file.remove(&file) else {
+            return;
+        };
+        for target in targets {
+            if let Some(sites) = self.sites.get_mut(&target) {
+                sites.retain(|site| site.file != file);
+                if sites.is_empty() {
+                    self.sites.remove(&target);
+                }
+            }
+        }
+    }
+
+    /// Get the sites whose effective target is `target`.
+    fn get(&self, target: &str) -> &[RefSite] {
+        self.sites.get(target).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+/// Storage for the symbols of a [`SymbolIndex`], addressed by [`SymbolIdx`].
+///
+/// Symbols are kept in chunks behind `Arc`s, one per call to
+/// [`SymbolIndex::add_file`], so cloning the store shares every chunk.
+/// Mutating a symbol copies only the chunk holding it: an edit to one file
+/// leaves the other files' symbols shared with outstanding snapshots.
+#[derive(Clone, Debug, Default)]
+struct SymbolStore {
+    /// Non-empty chunks, in index order.
+    chunks: Vec<Arc<Vec<HirSymbol>>>,
+    /// Index of the first symbol in each chunk.
+    starts: Vec<SymbolIdx>,
+    len: usize,
+}
+
+impl SymbolStore {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Find the chunk holding `idx` and the position within it.
+    fn locate(&self, idx: SymbolIdx) -> Option<(usize, usize)> {
+        if idx >= self.len {
+            return None;
+        }
+        let chunk = self.starts.partition_point(|&start| start <= idx) - 1;
+        Some((chunk, idx - self.starts[chunk]))
+    }
+
+    fn get(&self, idx: SymbolIdx) -> Option<&HirSymbol> {
+        let (chunk, offset) = self.locate(idx)?;
+        self.chunks[chunk].get(offset)
+    }
+
+    fn get_mut(&mut self, idx: SymbolIdx) -> Option<&mut HirSymbol> {
+        let (chunk, offset) = self.locate(idx)?;
+        Arc::make_mut(&mut self.chunks[chunk]).get_mut(offset)
+    }
+
+    /// Append symbols as a new chunk.
+    fn push_chunk(&mut self, symbols: Vec<HirSymbol>) {
+        self.push_shared_chunk(Arc::new(symbols));
+    }
+
+    fn push_shared_chunk(&mut self, symbols: Arc<Vec<HirSymbol>>) {
+        if symbols.is_empty() {
+            return;
+        }
+        self.starts.push(self.len);
+        self.len += symbols.len();
+        self.chunks.push(symbols);
+    }
+
+    /// Append a single symbol, to the last chunk if no clone shares it.
+    fn push(&mut self, symbol: HirSymbol) {
+        match self.chunks.last_mut().and_then(Arc::get_mut) {
+            Some(chunk) => {
+                chunk.push(symbol);
+                self.len += 1;
+            }
+            None => self.push_chunk(vec![symbol]),
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &HirSymbol> {
+        self.chunks.iter().flat_map(|chunk| chunk.iter())
+    }
+
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut HirSymbol> {
+        self.chunks
+            .iter_mut()
+            .flat_map(|chunk| Arc::make_mut(chunk).iter_mut())
+    }
+
+    /// Drop the symbols not flagged in `live`, keeping chunks apart.
+    ///
+    /// Returns the new index of each kept symbol, by old index. Fully live
+    /// chunks stay shared.
+    fn compact(&mut self, live: &[bool]) -> Vec<Option<SymbolIdx>> {
+        let mut remap = vec![None; self.len];
+        let mut compacted = SymbolStore::default();
+        for (chunk, start) in std::mem::take(&mut self.chunks)
+            .into_iter()
+            .zip(&self.starts)
+        {
+            let range = *start..*start + chunk.len();
+            if live[range.clone()].iter().all(|&l| l) {
+                for (offset, idx) in range.enumerate() {
+                    remap[idx] = Some(compacted.len + offset);
+                }
+                compacted.push_shared_chunk(chunk);
+                continue;
+            }
+            let mut kept = Vec::new();
+            for (idx, symbol) in range.zip(Arc::unwrap_or_clone(chunk)) {
+                if live[idx] {
+                    remap[idx] = Some(compacted.len + kept.len());
+                    kept.push(symbol);
+                }
+            }
+            compacted.push_chunk(kept);
+        }
+        *self = compacted;
+        remap
+    }
+}
+
+impl std::ops::Index<SymbolIdx> for SymbolStore {
+    type Output = HirSymbol;
+
+    fn index(&self, idx: SymbolIdx) -> &HirSymbol {
+        self.get(idx).expect("symbol index out of bounds")
+    }
+}
+
+impl std::ops::IndexMut<SymbolIdx> for SymbolStore {
+    fn index_mut(&mut self, idx: SymbolIdx) -> &mut HirSymbol {
+        self.get_mut(idx).expect("symbol index out of bounds")
+    }
+}
+
 /// An index of all symbols across multiple files.
 ///
 /// This is the main data structure for workspace-wide name resolution.
 /// It includes pre-computed visibility maps for efficient query-time resolution.
 ///
-/// Symbols are stored in a single vector (`symbols`) and referenced by index
-/// from all other maps. This ensures consistency when symbols are mutated
-/// (e.g., when resolving type references).
+/// Symbols are stored in a single `SymbolStore` (`symbols`) and referenced by
+/// index from all other maps. This ensures consistency when symbols are
+/// mutated (e.g., when resolving type references). The store shares each
+/// file's symbols between clones, so cloning the index for a snapshot is cheap.
 #[derive(Debug, Default)]
 pub struct SymbolIndex {
     /// The single source of truth for all symbols.
-    symbols: Vec<HirSymbol>,
+    symbols: SymbolStore,
     /// Index by qualified name -> symbol index (IndexMap preserves insertion order).
     by_qualified_name: IndexMap<Arc<str>, SymbolIdx>,
     /// Index by simple name -> symbol indices (may have multiple).
@@ -201,7 +681,7 @@ pub struct SymbolIndex {
     definitions: HashMap<Arc<str>, SymbolIdx>,
     /// Lazily-built visibility map for each scope.
     /// Built on-demand when a scope is queried, not upfront.
-    visibility_map: HashMap<Arc<str>, ScopeVisibility>,
+    visibility_map: HashMap<Arc<str>, Arc<ScopeVisibility>>,
     /// Index from parent scope -> child symbol indices (for fast visibility building)
     by_parent_scope: HashMap<Arc<str>, Vec<SymbolIdx>>,
     /// Filters for each scope (e.g., "SafetyGroup" -> ["Safety"])
@@ -213,10 +693,21 @@ pub struct SymbolIndex {
     import_filters: HashMap<Arc<str>, Vec<Arc<str>>>,
     /// Flag to track if parent scope index needs rebuilding.
     parent_index_dirty: bool,
+    /// Scopes whose visibility maps are stale (rebuilt by `ensure_visibility_maps`).
+    dirty_scopes: HashSet<Arc<str>>,
+    /// Reverse dependencies between visibility maps, for incremental rebuilds.
+    visibility_deps: VisibilityDeps,
     /// Cache for SemanticMetadata baseType resolution (with interior mutability for lazy population).
     /// Maps annotation short name (e.g., "systemdd") -> resolved baseType qualified name (e.g., "AHFProfileLib::SysDD").
     /// None value means "already looked up, no baseType found".
     metadata_basetype_cache: RwLock<HashMap<Arc<str>, Option<Arc<str>>>>,
+    /// Maximum number of scopes a name may be re-exported through by public
+    /// wildcard imports (`None` = unlimited).
+    max_reexport_depth: Option<usize>,
+    /// Reverse reference map: effective target qualified name -> reference sites.
+    /// Updated as symbols are added, and refreshed for the files each
+    /// resolution pass touches.
+    references: ReferenceMap,
 }
 
 // Manual Clone implementation because RwLock doesn't implement Clone
@@ -234,10 +725,14 @@ impl Clone for SymbolIndex {
             scope_filters: self.scope_filters.clone(),
             import_filters: self.import_filters.clone(),
             parent_index_dirty: self.parent_index_dirty,
+            dirty_scopes: self.dirty_scopes.clone(),
+            visibility_deps: self.visibility_deps.clone(),
             // Clone the cache contents, not the lock
             metadata_basetype_cache: RwLock::new(
                 self.metadata_basetype_cache.read().unwrap().clone(),
             ),
+            max_reexport_depth: self.max_reexport_depth,
+            references: self.references.clone(),
         }
     }
 }
@@ -248,6 +743,22 @@ impl SymbolIndex {
         Self::default()
     }
 
+    /// Limit how far names travel through chains of public wildcard imports.
+    ///
+    /// A name re-exported by `max` scopes is still visible to scopes importing
+    /// the last one, but is not copied any further; the import that would
+    /// have copied it is reported by
+    /// `ScopeVisibility::truncated_imports`. Unlimited by default.
+    pub fn with_max_reexport_depth(mut self, max: usize) -> Self {
+        self.max_reexport_depth = Some(max);
+        self
+    }
+
+    /// Get the re-export depth limit, if any.
+    pub fn max_reexport_depth(&self) -> Option<usize> {
+        self.max_reexport_depth
+    }
+
     /// Add symbols and filters from an extraction result.
     pub fn add_extraction_result(
         &mut self,
@@ -283,13 +794,11 @@ impl SymbolIndex {
         // Mark parent index as dirty (need to rebuild by_parent_scope)
         self.parent_index_dirty = true;
 
-        // Clear visibility maps for affected scopes (they'll be rebuilt lazily)
-        // We don't clear ALL visibility maps - just mark that parent index needs rebuild
-
         let mut file_indices = Vec::with_capacity(symbols.len());
+        let mut chunk = Vec::with_capacity(symbols.len());
 
         for symbol in symbols {
-            let idx = self.symbols.len();
+            let idx = self.symbols.len() + chunk.len();
 
             // Index by qualified name
             self.by_qualified_name
@@ -317,9 +826,16 @@ impl SymbolIndex {
             // Track for file index
             file_indices.push(idx);
 
+            // Visibility maps of the scopes it joins are rebuilt lazily
+            Self::mark_symbol_scopes_dirty(&mut self.dirty_scopes, &symbol);
+
+            // Index its references (re-keyed once resolved)
+            self.references.record(&symbol);
+
             // Store the symbol
-            self.symbols.push(symbol);
+            chunk.push(symbol);
         }
+        self.symbols.push_chunk(chunk);
 
         // Index by file
         self.by_file.insert(file, file_indices);
@@ -356,6 +872,12 @@ impl SymbolIndex {
             self.definitions.insert(symbol.qualified_name.clone(), idx);
         }
 
+        // Visibility maps of the scopes it joins are rebuilt lazily
+        Self::mark_symbol_scopes_dirty(&mut self.dirty_scopes, &symbol);
+
+        // Index its references (re-keyed once resolved)
+        self.references.record(&symbol);
+
         // Store the symbol
         self.symbols.push(symbol);
     }
@@ -377,17 +899,23 @@ impl SymbolIndex {
     /// Remove all symbols from a file.
     ///
     /// Note: This marks indices as invalid but doesn't compact the symbols vec
-    /// to avoid invalidating other indices. For a full cleanup, rebuild the index.
+    /// to avoid invalidating other indices. Call [`SymbolIndex::compact`] to
+    /// reclaim the memory.
     pub fn remove_file(&mut self, file: FileId) {
         if let Some(indices) = self.by_file.remove(&file) {
             // Mark parent index as dirty
             self.parent_index_dirty = true;
 
+            // Drop references made from this file
+            self.references.remove_file(file);
+
             // Clear metadata baseType cache since definitions might have changed
             self.metadata_basetype_cache.write().unwrap().clear();
 
             for &idx in &indices {
                 if let Some(symbol) = self.symbols.get(idx) {
+                    Self::mark_symbol_scopes_dirty(&mut self.dirty_scopes, symbol);
+
                     let qname = symbol.qualified_name.clone();
                     let sname = symbol.name.clone();
                     let short = symbol.short_name.clone();
@@ -422,6 +950,84 @@ impl SymbolIndex {
         }
     }
 
+    /// Flag, for each entry of the symbols vector, whether it is still live.
+    ///
+    /// A symbol is live if any index still refers to it. Symbols replaced by
+    /// re-adding their file are not, even though their qualified name is.
+    fn live_symbols(&self) -> Vec<bool> {
+        let mut live = vec![false; self.symbols.len()];
+        let referenced = self
+            .by_qualified_name
+            .values()
+            .chain(self.definitions.values())
+            .chain(self.by_simple_name.values().flatten())
+            .chain(self.by_short_name.values().flatten())
+            .chain(self.by_file.values().flatten());
+        for &idx in referenced {
+            live[idx] = true;
+        }
+        live
+    }
+
+    /// Mark the visibility maps of the scopes a file's symbols belong to as stale.
+    ///
+    /// The next [`SymbolIndex::ensure_visibility_maps`] rebuilds only those
+    /// scopes and the scopes whose visibility depends on them. Scopes of symbols
+    /// added by [`SymbolIndex::add_file`] or dropped by [`SymbolIndex::remove_file`]
+    /// are marked automatically.
+    pub fn mark_file_dirty(&mut self, file: FileId) {
+        for &idx in self.by_file.get(&file).into_iter().flatten() {
+            if let Some(symbol) = self.symbols.get(idx) {
+                Self::mark_symbol_scopes_dirty(&mut self.dirty_scopes, symbol);
+            }
+        }
+    }
+
+    /// Mark every scope a symbol contributes visibility to as stale: its own
+    /// scope, its parent, and its grandparent when the parent is anonymous.
+    fn mark_symbol_scopes_dirty(dirty_scopes: &mut HashSet<Arc<str>>, symbol: &HirSymbol) {
+        dirty_scopes.insert(symbol.qualified_name.clone());
+
+        let parent = Self::parent_scope(&symbol.qualified_name).unwrap_or("");
+        if parent.contains('<') {
+            if let Some(grandparent) = Self::parent_scope(parent) {
+                dirty_scopes.insert(Arc::from(grandparent));
+            }
+        }
+        dirty_scopes.insert(Arc::from(parent));
+    }
+
+    /// Drop symbols orphaned by [`SymbolIndex::remove_file`] from the symbols
+    /// vector and remap every index to the new positions.
+    ///
+    /// Safe to call at any time; query results are unchanged. Visibility maps
+    /// are keyed by qualified name and stay valid, but the parent scope index
+    /// is marked dirty so it is rebuilt with the new positions.
+    pub fn compact(&mut self) {
+        let live = self.live_symbols();
+        if live.iter().all(|&l| l) {
+            return;
+        }
+
+        let remap = self.symbols.compact(&live);
+
+        let remap_idx = |idx: &mut SymbolIdx| *idx = remap[*idx].expect("live symbol");
+        self.by_qualified_name.values_mut().for_each(remap_idx);
+        self.definitions.values_mut().for_each(remap_idx);
+        self.by_simple_name
+            .values_mut()
+            .flatten()
+            .for_each(remap_idx);
+        self.by_short_name
+            .values_mut()
+            .flatten()
+            .for_each(remap_idx);
+        self.by_file.values_mut().flatten().for_each(remap_idx);
+
+        self.by_parent_scope.clear();
+        self.parent_index_dirty = true;
+    }
+
     /// Look up a symbol by qualified name.
     pub fn lookup_qualified(&self, name: &str) -> Option<&HirSymbol> {
         self.by_qualified_name
@@ -444,7 +1050,7 @@ impl SymbolIndex {
     where
         F: FnMut(&mut HirSymbol),
     {
-        for symbol in &mut self.symbols {
+        for symbol in self.symbols.iter_mut() {
             f(symbol);
         }
     }
@@ -594,6 +1200,9 @@ impl SymbolIndex {
     }
 
     /// Get all symbols in a file.
+    ///
+    /// Symbols are returned in the order they were extracted, which follows
+    /// the source, so repeated calls see the same order.
     pub fn symbols_in_file(&self, file: FileId) -> Vec<&HirSymbol> {
         self.by_file
             .get(&file)
@@ -606,13 +1215,61 @@ impl SymbolIndex {
             .unwrap_or_default()
     }
 
-    /// Get all definitions in the index.
+    /// Get the innermost symbol whose name is at a position.
+    ///
+    /// A symbol matches when its name or short name contains the position.
+    /// The smallest matching span wins, and ties go to the most deeply
+    /// nested qualified name. Returns `None` if no name is there, such as on
+    /// whitespace.
+    pub fn symbol_at(&self, file: FileId, line: u32, col: u32) -> Option<&HirSymbol> {
+        let pos = LineCol::new(line, col);
+        self.symbols_in_file(file)
+            .into_iter()
+            .filter(|symbol| {
+                symbol.span().contains(pos)
+                    || symbol
+                        .short_name_span()
+                        .is_some_and(|span| span.contains(pos))
+            })
+            .min_by_key(|symbol| {
+                let depth = symbol.qualified_name.matches("::").count();
+                (span_size(symbol.span()), std::cmp::Reverse(depth))
+            })
+    }
+
+    /// Get what the type reference at a position resolves to.
+    ///
+    /// Finds the `TypeRef` in `file` whose span contains the position (for a
+    /// feature chain, the part under the cursor) and returns its
+    /// `resolved_target`. Returns `None` if no reference is there or it is
+    /// unresolved. Reads data filled in by [`SymbolIndex::resolve_all_type_refs`].
+    pub fn resolved_target_at(&self, file: FileId, line: u32, col: u32) -> Option<Arc<str>> {
+        self.by_file
+            .get(&file)?
+            .iter()
+            .filter_map(|&idx| self.symbols.get(idx))
+            .flat_map(|symbol| &symbol.type_refs)
+            .find_map(|trk| trk.part_at(line, col))
+            .and_then(|(_, tr)| tr.resolved_target.clone())
+    }
+
+    /// Get all definitions in the index, in no particular order.
     pub fn all_definitions(&self) -> impl Iterator<Item = &HirSymbol> {
         self.definitions
             .values()
             .filter_map(|&idx| self.symbols.get(idx))
     }
 
+    /// Get all definitions sorted by qualified name.
+    ///
+    /// Use this instead of [`SymbolIndex::all_definitions`] wherever the
+    /// result must not depend on hash order.
+    pub fn definitions_sorted(&self) -> Vec<&HirSymbol> {
+        let mut definitions: Vec<_> = self.all_definitions().collect();
+        definitions.sort_by(|a, b| a.qualified_name.cmp(&b.qualified_name));
+        definitions
+    }
+
     /// Get all symbols in the index.
     pub fn all_symbols(&self) -> impl Iterator<Item = &HirSymbol> {
         self.by_qualified_name
@@ -620,6 +1277,96 @@ impl SymbolIndex {
             .filter_map(|&idx| self.symbols.get(idx))
     }
 
+    /// Convert the index to an interchange model.
+    ///
+    /// Each symbol becomes an element owned by its `::` parent. Relationships
+    /// point at the element their reference resolved to; unresolved targets
+    /// fall back to a lookup next to the source symbol.
+    #[cfg(feature = "interchange")]
+    pub fn to_model(&self) -> crate::interchange::Model {
+        let mut symbols: Vec<HirSymbol> = self.all_symbols().cloned().collect();
+        symbols.sort_by(|a, b| a.qualified_name.cmp(&b.qualified_name));
+
+        for symbol in &mut symbols {
+            let resolved: HashMap<Arc<str>, Arc<str>> = symbol
+                .type_refs
+                .iter()
+                .filter_map(|trk| match trk {
+                    TypeRefKind::Simple(tr) => {
+                        Some((tr.target.clone(), tr.resolved_target.clone()?))
+                    }
+                    TypeRefKind::Chain(_) => None,
+                })
+                .collect();
+            for rel in &mut symbol.relationships {
+                if let Some(target) = rel.resolved_target.as_ref().or(resolved.get(&rel.target)) {
+                    rel.target = target.clone();
+                }
+            }
+        }
+
+        crate::interchange::integrate::model_from_symbols(&symbols)
+    }
+
+    /// Build an index from an interchange model, as if it were one file.
+    ///
+    /// Elements become symbols in `file`; an element without a name is
+    /// named after its id. Typing, specialization, subsetting and
+    /// redefinition relationships become both relationships and type
+    /// references, so the usual resolution pass and IDE features apply.
+    #[cfg(feature = "interchange")]
+    pub fn from_model(model: &crate::interchange::Model, file: FileId) -> Self {
+        use super::symbols::{RelationshipKind, TypeRef};
+
+        let mut symbols = crate::interchange::integrate::symbols_from_model(model);
+        for symbol in &mut symbols {
+            symbol.file = file;
+            for rel in &symbol.relationships {
+                let kind = match rel.kind {
+                    RelationshipKind::TypedBy => RefKind::TypedBy,
+                    RelationshipKind::Specializes => RefKind::Specializes,
+                    RelationshipKind::Subsets => RefKind::Subsets,
+                    RelationshipKind::Redefines => RefKind::Redefines,
+                    _ => continue,
+                };
+                symbol.type_refs.push(TypeRefKind::Simple(TypeRef::new(
+                    rel.target.clone(),
+                    kind,
+                    0,
+                    0,
+                    0,
+                    0,
+                )));
+            }
+            // A usage's type comes first, as it does for parsed usages
+            let types: Vec<Arc<str>> = symbol
+                .relationships
+                .iter()
+                .filter(|rel| rel.kind == RelationshipKind::TypedBy)
+                .map(|rel| rel.target.clone())
+                .collect();
+            symbol.supertypes.splice(0..0, types);
+        }
+
+        let mut index = Self::new();
+        index.add_file(file, symbols);
+        index.resolve_all_type_refs();
+        index
+    }
+
+    /// Get all symbols of the given kind.
+    pub fn symbols_of_kind(&self, kind: SymbolKind) -> impl Iterator<Item = &HirSymbol> {
+        self.all_symbols().filter(move |symbol| symbol.kind == kind)
+    }
+
+    /// Get all definitions of the given kind.
+    ///
+    /// Only scans the definition index, so usage kinds yield nothing.
+    pub fn definitions_of_kind(&self, kind: SymbolKind) -> impl Iterator<Item = &HirSymbol> {
+        self.all_definitions()
+            .filter(move |symbol| symbol.kind == kind)
+    }
+
     /// Update symbols in the index using a closure.
     /// The closure is called for each symbol and can modify it in place.
     pub fn update_symbols<F>(&mut self, mut f: F)
@@ -631,6 +1378,8 @@ impl SymbolIndex {
                 f(symbol);
             }
         }
+        // The closure may have changed type references
+        self.rebuild_references();
     }
 
     /// Get the total number of symbols.
@@ -681,6 +1430,12 @@ impl SymbolIndex {
         // Track for file index
         self.by_file.entry(file).or_default().push(idx);
 
+        // Visibility maps of the scopes it joins are rebuilt lazily
+        Self::mark_symbol_scopes_dirty(&mut self.dirty_scopes, &symbol);
+
+        // Index its references (re-keyed once resolved)
+        self.references.record(&symbol);
+
         // Store the symbol
         self.symbols.push(symbol);
 
@@ -688,8 +1443,45 @@ impl SymbolIndex {
         self.parent_index_dirty = true;
     }
 
+    /// Get the files containing an import from `package`.
+    ///
+    /// Counts wildcard (`import P::*`), recursive (`import P::**`) and member
+    /// (`import P::X`) imports whose target resolves to `package`. Answered from
+    /// data recorded while building visibility maps, so call
+    /// [`SymbolIndex::ensure_visibility_maps`] first.
+    pub fn importers_of(&self, package: &str) -> Vec<FileId> {
+        let mut files: Vec<FileId> = self
+            .visibility_deps
+            .imported_in_file
+            .get(package)
+            .into_iter()
+            .flatten()
+            .map(|&(_, file)| file)
+            .collect();
+        files.sort_unstable();
+        files.dedup();
+        files
+    }
+
+    /// Get the files with a type reference resolved to one of `targets`.
+    ///
+    /// Used to find the files whose resolutions go stale when the symbols in
+    /// `targets` are removed. Answered from the reverse reference map, so it
+    /// reflects the last resolution pass; unresolved references written as
+    /// one of `targets` are included too, which only over-approximates.
+    pub fn files_referencing(&self, targets: &HashSet<Arc<str>>) -> Vec<FileId> {
+        let mut files: Vec<FileId> = targets
+            .iter()
+            .flat_map(|target| self.references.get(target))
+            .map(|site| site.file)
+            .collect();
+        files.sort_unstable();
+        files.dedup();
+        files
+    }
+
     /// Get a reference to the visibility maps.
-    pub fn visibility_maps(&self) -> &HashMap<Arc<str>, ScopeVisibility> {
+    pub fn visibility_maps(&self) -> &HashMap<Arc<str>, Arc<ScopeVisibility>> {
         &self.visibility_map
     }
 
@@ -710,12 +1502,12 @@ impl SymbolIndex {
 
         self.by_parent_scope.clear();
 
-        // Only include symbols that are still valid (in by_qualified_name)
+        // Only include symbols that are still live
         // This handles the case where remove_file marks symbols as invalid
         // but doesn't remove them from the symbols vec
+        let live = self.live_symbols();
         for (idx, symbol) in self.symbols.iter().enumerate() {
-            // Skip symbols that have been removed (not in by_qualified_name lookup)
-            if !self.by_qualified_name.contains_key(&symbol.qualified_name) {
+            if !live[idx] {
                 continue;
             }
 
@@ -768,8 +1560,11 @@ impl SymbolIndex {
     // VISIBILITY MAP CONSTRUCTION
     // ========================================================================
 
-    /// Ensure visibility maps are up-to-date, rebuilding ALL if needed.
-    /// Use this for initial load / full resolution.
+    /// Ensure visibility maps are up-to-date.
+    ///
+    /// Builds ALL maps on initial load (or after [`SymbolIndex::mark_visibility_dirty`]);
+    /// otherwise rebuilds only the scopes affected by files added, removed or
+    /// marked with [`SymbolIndex::mark_file_dirty`] since the last build.
     pub fn ensure_visibility_maps(&mut self) {
         // Build parent index first
         self.ensure_parent_index();
@@ -777,6 +1572,8 @@ impl SymbolIndex {
         // If visibility maps are empty, do a full build
         if self.visibility_map.is_empty() {
             self.build_visibility_maps();
+        } else if !self.dirty_scopes.is_empty() {
+            self.rebuild_dirty_scopes();
         }
     }
 
@@ -911,6 +1708,8 @@ impl SymbolIndex {
                 }
             }
         }
+
+        self.rebuild_references();
     }
 
     /// Resolve type references only for symbols in specific files.
@@ -1029,14 +1828,46 @@ impl SymbolIndex {
                 }
             }
         }
+
+        self.refresh_references(files);
     }
 
-    /// Resolve a single type reference within a symbol's scope (with caching).
+    /// Get every reference site whose effective target is `qualified`.
     ///
-    /// For regular references: uses lexical scoping + imports
-    /// For feature chain members: resolves through type membership
-    fn resolve_type_ref_cached(
-        &self,
+    /// The effective target is the resolved qualified name, or the name as
+    /// written for unresolved references. Sites are kept up to date as files
+    /// are added, removed and resolved, so this is a map lookup, not a scan.
+    pub fn references_to(&self, qualified: &str) -> &[RefSite] {
+        self.references.get(qualified)
+    }
+
+    /// Rebuild the reverse reference map from the current type references.
+    fn rebuild_references(&mut self) {
+        let mut references = ReferenceMap::default();
+        for symbol in self.all_symbols() {
+            references.record(symbol);
+        }
+        self.references = references;
+    }
+
+    /// Re-record the reference sites of `files` only.
+    fn refresh_references(&mut self, files: &[FileId]) {
+        for &file in files {
+            self.references.remove_file(file);
+            if let Some(indices) = self.by_file.get(&file) {
+                for &idx in indices {
+                    self.references.record(&self.symbols[idx]);
+                }
+            }
+        }
+    }
+
+    /// Resolve a single type reference within a symbol's scope (with caching).
+    ///
+    /// For regular references: uses lexical scoping + imports
+    /// For feature chain members: resolves through type membership
+    fn resolve_type_ref_cached(
+        &self,
         containing_symbol: &str,
         target: &str,
         chain_context: &Option<(std::rc::Rc<Vec<Arc<str>>>, usize)>,
@@ -1098,7 +1929,7 @@ impl SymbolIndex {
     ///
     /// IMPORTANT: If the input symbol is already a definition, return it immediately.
     /// We only follow the chain for usages, not for definition inheritance.
-    fn follow_typing_chain(&self, sym: &HirSymbol, scope: &str) -> Arc<str> {
+    pub(crate) fn follow_typing_chain(&self, sym: &HirSymbol, scope: &str) -> Arc<str> {
         // If the input is already a definition, return it - don't follow inheritance
         if sym.kind.is_definition() {
             return sym.qualified_name.clone();
@@ -1723,7 +2554,7 @@ impl SymbolIndex {
         member_name: &str,
     ) -> Option<Arc<str>> {
         // Get the parent scope - be careful with anonymous scopes like `<perform:...>`
-        // For `TestPkg::vehicle_b::<perform:ActionTree::providePower#2@L9>`, parent is `TestPkg::vehicle_b`
+        // For `TestPkg::vehicle_b::<perform:ActionTree::providePower#2>`, parent is `TestPkg::vehicle_b`
         let parent_qname = Self::parent_scope(symbol_qname)?;
 
         // Look up the parent symbol
@@ -1885,9 +2716,54 @@ impl SymbolIndex {
         None
     }
 
+    /// Check whether an import target written in `scope` names a known element.
+    ///
+    /// `target` is the import path without its `::*` or `::**` suffix. Names
+    /// visible in enclosing scopes count, as they do for other references.
+    /// Call [`SymbolIndex::ensure_visibility_maps`] first.
+    pub fn import_target_exists(&self, scope: &str, target: &str) -> bool {
+        if self.resolve_import_target(scope, target).is_some() {
+            return true;
+        }
+        !matches!(
+            self.resolver_for_scope(scope).resolve(target),
+            ResolveResult::NotFound
+        )
+    }
+
     /// Get the visibility map for a scope (if built).
     pub fn visibility_for_scope(&self, scope: &str) -> Option<&ScopeVisibility> {
-        self.visibility_map.get(scope)
+        self.visibility_map.get(scope).map(|vis| &**vis)
+    }
+
+    /// Get every name visible in a scope, with the qualified name it resolves to.
+    ///
+    /// Covers direct definitions and imports, sorted by simple name. A direct
+    /// definition hides an import of the same name.
+    ///
+    /// Read from the built visibility maps: after changing the index, call
+    /// [`SymbolIndex::ensure_visibility_maps`] first. An [`Analysis`] snapshot
+    /// has already done so.
+    ///
+    /// [`Analysis`]: crate::ide::Analysis
+    pub fn visible_names(&self, scope: &str) -> Vec<(Arc<str>, Arc<str>)> {
+        debug_assert!(
+            !self.dirty_scopes.contains(scope),
+            "visibility of `{scope}` is stale; call ensure_visibility_maps first"
+        );
+        let Some(vis) = self.visibility_map.get(scope) else {
+            return Vec::new();
+        };
+        let mut names: Vec<(Arc<str>, Arc<str>)> = vis
+            .direct_defs()
+            .chain(
+                vis.imports()
+                    .filter(|(name, _)| vis.lookup_direct(name).is_none()),
+            )
+            .map(|(name, qualified)| (name.clone(), qualified.clone()))
+            .collect();
+        names.sort();
+        names
     }
 
     /// Build visibility map for a single scope.
@@ -1941,7 +2817,7 @@ impl SymbolIndex {
             }
         }
 
-        self.visibility_map.insert(scope.clone(), vis);
+        self.visibility_map.insert(scope.clone(), Arc::new(vis));
     }
 
     /// Process imports for a single scope (used in lazy building).
@@ -2088,140 +2964,262 @@ impl SymbolIndex {
         // 1. Single pass: collect scopes AND group symbols by parent scope
         // This is O(symbols) instead of O(scopes × symbols)
         self.visibility_map.clear();
+        self.visibility_deps = VisibilityDeps::default();
+        self.dirty_scopes.clear();
 
         // Pre-create root scope
         self.visibility_map
-            .insert(Arc::from(""), ScopeVisibility::new(""));
+            .insert(Arc::from(""), Arc::new(ScopeVisibility::new("")));
 
-        for symbol in &self.symbols {
-            // Skip symbols that have been removed (not in by_qualified_name lookup)
-            if !self.by_qualified_name.contains_key(&symbol.qualified_name) {
+        let live = self.live_symbols();
+        for (idx, symbol) in self.symbols.iter().enumerate() {
+            // Skip symbols that have been removed
+            if !live[idx] {
                 continue;
             }
 
-            // Ensure this symbol's scope exists (for namespace-creating symbols)
-            // Include usages too - they can have nested members and need inherited members from their type
-            if symbol.kind == SymbolKind::Package
-                || symbol.kind.is_definition()
-                || symbol.kind.is_usage()
-            {
-                self.visibility_map
-                    .entry(symbol.qualified_name.clone())
-                    .or_insert_with(|| ScopeVisibility::new(symbol.qualified_name.clone()));
-            }
+            Self::add_symbol_visibility(&mut self.visibility_map, symbol, |_| true);
+        }
 
-            // Skip adding import symbols as direct definitions - they're processed separately
-            // and shouldn't shadow global packages with the same name
-            if symbol.kind == SymbolKind::Import {
-                continue;
-            }
+        // 3. Process all imports FIRST (needed for inheritance to resolve types via imports)
+        let t_imports_start = std::time::Instant::now();
+        let mut visited: HashSet<(Arc<str>, Arc<str>)> = HashSet::new();
+        let scope_keys: Vec<_> = self.visibility_map.keys().cloned().collect();
 
-            // Add symbol to its parent scope's direct definitions
-            let parent_scope: Arc<str> = Self::parent_scope(&symbol.qualified_name)
-                .map(Arc::from)
-                .unwrap_or_else(|| Arc::from(""));
+        for scope in &scope_keys {
+            self.process_imports_recursive(scope, &mut visited, None);
+        }
+        let t_imports = t_imports_start.elapsed();
+
+        // 4. Propagate inherited members from supertypes (can now resolve types via imports)
+        let t_inherit_start = std::time::Instant::now();
+        self.propagate_inherited_members(None);
+        let t_inherit = t_inherit_start.elapsed();
 
+        tracing::info!(
+            "build_visibility_maps: {} scopes, imports={:?}, inheritance={:?}",
+            scope_keys.len(),
+            t_imports,
+            t_inherit
+        );
+    }
+
+    /// Add a symbol to the visibility maps of the scopes it is visible in
+    /// (its parent, or grandparent for anonymous parents), creating its own
+    /// scope if it is a namespace.
+    ///
+    /// Only scopes accepted by `rebuilds` are touched.
+    fn add_symbol_visibility(
+        visibility_map: &mut HashMap<Arc<str>, Arc<ScopeVisibility>>,
+        symbol: &HirSymbol,
+        rebuilds: impl Fn(&str) -> bool,
+    ) {
+        // Ensure this symbol's scope exists (for namespace-creating symbols)
+        // Include usages too - they can have nested members and need inherited members from their type
+        if (symbol.kind == SymbolKind::Package
+            || symbol.kind.is_definition()
+            || symbol.kind.is_usage())
+            && rebuilds(&symbol.qualified_name)
+        {
+            visibility_map
+                .entry(symbol.qualified_name.clone())
+                .or_insert_with(|| Arc::new(ScopeVisibility::new(symbol.qualified_name.clone())));
+        }
+
+        // Skip adding import symbols as direct definitions - they're processed separately
+        // and shouldn't shadow global packages with the same name
+        if symbol.kind == SymbolKind::Import {
+            return;
+        }
+
+        // Add symbol to its parent scope's direct definitions
+        let parent_scope: Arc<str> = Self::parent_scope(&symbol.qualified_name)
+            .map(Arc::from)
+            .unwrap_or_else(|| Arc::from(""));
+
+        if rebuilds(&parent_scope) {
             // Ensure parent scope exists
-            let vis = self
-                .visibility_map
+            let vis = visibility_map
                 .entry(parent_scope.clone())
-                .or_insert_with(|| ScopeVisibility::new(parent_scope.clone()));
-
-            vis.add_direct(symbol.name.clone(), symbol.qualified_name.clone());
-
-            // Also register by short_name if available
-            if let Some(ref short_name) = symbol.short_name {
-                vis.add_direct(short_name.clone(), symbol.qualified_name.clone());
-            }
-
-            // Register anonymous redefining symbols under their base name.
-            // Pattern: `<:>>speedSensor#77@L789>` should be accessible as `speedSensor`
-            // This enables chains like `speedSensor.speedSensorPort.sensedSpeedSent` to resolve
-            // through the local redefining symbol rather than the inherited definition.
-            if symbol.name.starts_with("<:>>") {
-                // Extract base name: `<:>>speedSensor#77@L789>` -> `speedSensor`
-                if let Some(hash_pos) = symbol.name.find('#') {
-                    let base_name: Arc<str> = Arc::from(&symbol.name[4..hash_pos]);
-                    vis.add_direct(base_name, symbol.qualified_name.clone());
-                }
-            }
-
-            // Register ANONYMOUS symbols with explicit `redefines` relationships under the redefined name.
-            // Pattern: `perform ActionTree::providePower redefines providePower` creates a symbol
-            // named `<perform:ActionTree::providePower#24@L568>` that should be accessible as `providePower`.
-            // IMPORTANT: Only do this for anonymous symbols (name starts with '<') to avoid shadowing
-            // legitimate qualified references in named symbols.
-            if symbol.name.starts_with('<') {
-                for type_ref in &symbol.type_refs {
-                    for tr in type_ref.as_refs() {
-                        if tr.kind == RefKind::Redefines {
-                            // The redefines target is the name we should be visible as
-                            vis.add_direct(tr.target.clone(), symbol.qualified_name.clone());
-                        }
-                    }
+                .or_insert_with(|| Arc::new(ScopeVisibility::new(parent_scope.clone())));
+            Self::add_direct_names(Arc::make_mut(vis), symbol);
+        }
 
-                    // For perform actions with chain references like `perform startVehicle.turnVehicleOn`,
-                    // register the LAST part of the chain as the visible name.
-                    // This allows `driver.turnVehicleOn` to find the perform inside driver.
-                    if symbol.name.starts_with("<perform:") {
-                        if let crate::hir::TypeRefKind::Chain(chain) = type_ref {
-                            if let Some(last_part) = chain.parts.last() {
-                                vis.add_direct(
-                                    last_part.target.clone(),
-                                    symbol.qualified_name.clone(),
-                                );
-                            }
-                        }
+        // If the parent scope is anonymous (contains `<` which indicates generated names),
+        // also add this symbol to the grandparent scope so it's accessible from siblings.
+        // This handles cases like `then action foo { ... }` where `foo` needs to be visible
+        // from the enclosing scope, not just from the anonymous succession scope.
+        if parent_scope.contains('<') {
+            if let Some(grandparent) = Self::parent_scope(&parent_scope) {
+                if !rebuilds(grandparent) {
+                    return;
+                }
+                let grandparent_arc: Arc<str> = Arc::from(grandparent);
+                let gp_vis = Arc::make_mut(
+                    visibility_map
+                        .entry(grandparent_arc.clone())
+                        .or_insert_with(|| Arc::new(ScopeVisibility::new(grandparent_arc))),
+                );
+                gp_vis.add_direct(symbol.name.clone(), symbol.qualified_name.clone());
+                if let Some(ref short_name) = symbol.short_name {
+                    gp_vis.add_direct(short_name.clone(), symbol.qualified_name.clone());
+                }
+                // Also register anonymous redefining symbols in grandparent
+                if symbol.name.starts_with("<:>>") {
+                    if let Some(hash_pos) = symbol.name.find('#') {
+                        let base_name: Arc<str> = Arc::from(&symbol.name[4..hash_pos]);
+                        gp_vis.add_direct(base_name, symbol.qualified_name.clone());
                     }
                 }
             }
+        }
+    }
 
-            // If the parent scope is anonymous (contains `<` which indicates generated names),
-            // also add this symbol to the grandparent scope so it's accessible from siblings.
-            // This handles cases like `then action foo { ... }` where `foo` needs to be visible
-            // from the enclosing scope, not just from the anonymous succession scope.
-            if parent_scope.contains('<') {
-                if let Some(grandparent) = Self::parent_scope(&parent_scope) {
-                    let grandparent_arc: Arc<str> = Arc::from(grandparent);
-                    let gp_vis = self
-                        .visibility_map
-                        .entry(grandparent_arc.clone())
-                        .or_insert_with(|| ScopeVisibility::new(grandparent_arc));
-                    gp_vis.add_direct(symbol.name.clone(), symbol.qualified_name.clone());
-                    if let Some(ref short_name) = symbol.short_name {
-                        gp_vis.add_direct(short_name.clone(), symbol.qualified_name.clone());
+    /// Register a symbol's names in its parent scope's direct definitions.
+    fn add_direct_names(vis: &mut ScopeVisibility, symbol: &HirSymbol) {
+        vis.add_direct(symbol.name.clone(), symbol.qualified_name.clone());
+
+        // Also register by short_name if available
+        if let Some(ref short_name) = symbol.short_name {
+            vis.add_direct(short_name.clone(), symbol.qualified_name.clone());
+        }
+
+        // Register anonymous redefining symbols under their base name.
+        // Pattern: `<:>>speedSensor#77>` should be accessible as `speedSensor`
+        // This enables chains like `speedSensor.speedSensorPort.sensedSpeedSent` to resolve
+        // through the local redefining symbol rather than the inherited definition.
+        if symbol.name.starts_with("<:>>") {
+            // Extract base name: `<:>>speedSensor#77>` -> `speedSensor`
+            if let Some(hash_pos) = symbol.name.find('#') {
+                let base_name: Arc<str> = Arc::from(&symbol.name[4..hash_pos]);
+                vis.add_direct(base_name, symbol.qualified_name.clone());
+            }
+        }
+
+        // Register ANONYMOUS symbols with explicit `redefines` relationships under the redefined name.
+        // Pattern: `perform ActionTree::providePower redefines providePower` creates a symbol
+        // named `<perform:ActionTree::providePower#24>` that should be accessible as `providePower`.
+        // IMPORTANT: Only do this for anonymous symbols (name starts with '<') to avoid shadowing
+        // legitimate qualified references in named symbols.
+        if symbol.name.starts_with('<') {
+            for type_ref in &symbol.type_refs {
+                for tr in type_ref.as_refs() {
+                    if tr.kind == RefKind::Redefines {
+                        // The redefines target is the name we should be visible as
+                        vis.add_direct(tr.target.clone(), symbol.qualified_name.clone());
                     }
-                    // Also register anonymous redefining symbols in grandparent
-                    if symbol.name.starts_with("<:>>") {
-                        if let Some(hash_pos) = symbol.name.find('#') {
-                            let base_name: Arc<str> = Arc::from(&symbol.name[4..hash_pos]);
-                            gp_vis.add_direct(base_name, symbol.qualified_name.clone());
+                }
+
+                // For perform actions with chain references like `perform startVehicle.turnVehicleOn`,
+                // register the LAST part of the chain as the visible name.
+                // This allows `driver.turnVehicleOn` to find the perform inside driver.
+                if symbol.name.starts_with("<perform:") {
+                    if let crate::hir::TypeRefKind::Chain(chain) = type_ref {
+                        if let Some(last_part) = chain.parts.last() {
+                            vis.add_direct(last_part.target.clone(), symbol.qualified_name.clone());
                         }
                     }
                 }
             }
         }
+    }
 
-        // 3. Process all imports FIRST (needed for inheritance to resolve types via imports)
-        let t_imports_start = std::time::Instant::now();
-        let mut visited: HashSet<(Arc<str>, Arc<str>)> = HashSet::new();
-        let scope_keys: Vec<_> = self.visibility_map.keys().cloned().collect();
+    /// Rebuild the visibility maps of dirty scopes and every scope depending on them.
+    ///
+    /// Affected scopes are rebuilt with the same passes as
+    /// [`SymbolIndex::build_visibility_maps`], restricted to those scopes. If a
+    /// rebuilt scope now resolves a name differently, scopes that look that name
+    /// up are affected too, and the rebuild repeats with them included.
+    fn rebuild_dirty_scopes(&mut self) {
+        let mut pending: Vec<Arc<str>> = self.dirty_scopes.drain().collect();
+        let mut affected: HashSet<Arc<str>> = HashSet::new();
+        // Maps as they were before this rebuild (None if the scope didn't exist)
+        let mut previous: HashMap<Arc<str>, Option<Arc<ScopeVisibility>>> = HashMap::new();
 
-        for scope in &scope_keys {
-            self.process_imports_recursive(scope, &mut visited);
-        }
-        let t_imports = t_imports_start.elapsed();
+        loop {
+            while let Some(scope) = pending.pop() {
+                if affected.insert(scope.clone()) {
+                    pending.extend(self.visibility_deps.dependents_of(&scope));
+                }
+            }
 
-        // 4. Propagate inherited members from supertypes (can now resolve types via imports)
-        let t_inherit_start = std::time::Instant::now();
-        self.propagate_inherited_members();
-        let t_inherit = t_inherit_start.elapsed();
+            for scope in &affected {
+                let old = self.visibility_map.remove(scope);
+                previous.entry(scope.clone()).or_insert(old);
+            }
+            self.visibility_deps.forget(&affected);
+
+            // 1. Direct definitions (including the root scope, which always exists)
+            if affected.contains("") {
+                self.visibility_map
+                    .insert(Arc::from(""), Arc::new(ScopeVisibility::new("")));
+            }
+            let mut indices: Vec<SymbolIdx> = self
+                .by_parent_scope
+                .iter()
+                .filter(|(parent, _)| {
+                    affected.contains(*parent)
+                        || (parent.contains('<')
+                            && Self::parent_scope(parent).is_some_and(|gp| affected.contains(gp)))
+                })
+                .flat_map(|(_, children)| children.iter().copied())
+                .collect();
+            // Scopes without children still exist if their symbol opens a namespace
+            indices.extend(
+                affected
+                    .iter()
+                    .filter_map(|scope| self.by_qualified_name.get(scope).copied()),
+            );
+            indices.sort_unstable();
+            indices.dedup();
+            for idx in indices {
+                Self::add_symbol_visibility(
+                    &mut self.visibility_map,
+                    &self.symbols[idx],
+                    |scope| affected.contains(scope),
+                );
+            }
+
+            // 2. Imports
+            let mut visited: HashSet<(Arc<str>, Arc<str>)> = HashSet::new();
+            let scope_keys: Vec<_> = affected
+                .iter()
+                .filter(|scope| self.visibility_map.contains_key(*scope))
+                .cloned()
+                .collect();
+            for scope in &scope_keys {
+                self.process_imports_recursive(scope, &mut visited, Some(&affected));
+            }
+
+            // 3. Inheritance
+            self.propagate_inherited_members(Some(&affected));
+
+            // Names that now resolve differently may change lookups elsewhere
+            let empty = ScopeVisibility::default();
+            let changed: HashSet<Arc<str>> = previous
+                .iter()
+                .flat_map(|(scope, old)| {
+                    let old = old.as_deref().unwrap_or(&empty);
+                    let new = self.visibility_map.get(scope).map_or(&empty, |vis| &**vis);
+                    old.changed_names(new)
+                })
+                .collect();
+            pending = changed
+                .iter()
+                .flat_map(|name| self.visibility_deps.dependents_of_name(name))
+                .filter(|scope| !affected.contains(*scope))
+                .cloned()
+                .collect();
+
+            if pending.is_empty() {
+                break;
+            }
+        }
 
         tracing::info!(
-            "build_visibility_maps: {} scopes, imports={:?}, inheritance={:?}",
-            scope_keys.len(),
-            t_imports,
-            t_inherit
+            "rebuild_dirty_scopes: {} of {} scopes",
+            affected.len(),
+            self.visibility_map.len()
         );
     }
 
@@ -2231,17 +3229,37 @@ impl SymbolIndex {
     /// Uses topological ordering by scope depth: shallower scopes are processed first.
     /// This ensures that when processing `Shape::tfe` (which inherits from `edges`),
     /// `Shape` has already inherited `edges` from `Path`.
-    fn propagate_inherited_members(&mut self) {
+    ///
+    /// When `rebuilding` is set, only scopes in it inherit members.
+    fn propagate_inherited_members(&mut self, rebuilding: Option<&HashSet<Arc<str>>>) {
         // Collect symbols with their unresolved supertypes and parent scope for later resolution
         // Format: (qualified_name, parent_scope, unresolved_supertype_name)
         let mut inheritance_edges: Vec<(Arc<str>, Arc<str>, Arc<str>)> = Vec::new();
 
-        for symbol in &self.symbols {
-            // Skip symbols that have been removed
-            if !self.by_qualified_name.contains_key(&symbol.qualified_name) {
-                continue;
+        // Skip symbols that have been removed; keep the rest in index order
+        let candidates: Vec<SymbolIdx> = match rebuilding {
+            None => {
+                let live = self.live_symbols();
+                (0..self.symbols.len()).filter(|&idx| live[idx]).collect()
             }
+            // The parent index only holds live symbols
+            Some(scopes) => {
+                let mut indices: Vec<SymbolIdx> = scopes
+                    .iter()
+                    .filter_map(|scope| Self::parent_scope(scope))
+                    .filter_map(|parent| self.by_parent_scope.get(parent))
+                    .flatten()
+                    .copied()
+                    .filter(|&idx| scopes.contains(&self.symbols[idx].qualified_name))
+                    .collect();
+                indices.sort_unstable();
+                indices.dedup();
+                indices
+            }
+        };
 
+        for idx in candidates {
+            let symbol = &self.symbols[idx];
             if !symbol.supertypes.is_empty() {
                 let scope = &symbol.qualified_name;
                 let parent_scope: Arc<str> = Self::parent_scope(scope)
@@ -2249,6 +3267,7 @@ impl SymbolIndex {
                     .unwrap_or_else(|| Arc::from(""));
 
                 for supertype in &symbol.supertypes {
+                    self.visibility_deps.record_names(supertype, scope);
                     inheritance_edges.push((
                         scope.clone(),
                         parent_scope.clone(),
@@ -2277,6 +3296,8 @@ impl SymbolIndex {
                 if let Some(resolved) =
                     self.resolve_supertype_for_inheritance(supertype, parent_scope, Some(scope))
                 {
+                    self.visibility_deps.record_copy(&resolved, scope);
+
                     // Get members from the resolved supertype's visibility
                     let parent_members: Vec<(Arc<str>, Arc<str>)> = self
                         .visibility_map
@@ -2291,11 +3312,11 @@ impl SymbolIndex {
 
                     // Add to child's visibility if not already present
                     if let Some(child_vis) = self.visibility_map.get_mut(&**scope) {
+                        let child_vis = Arc::make_mut(child_vis);
                         for (name, qname) in parent_members {
-                            child_vis.direct_defs.entry(name).or_insert_with(|| {
+                            if child_vis.add_inherited(name, qname) {
                                 made_progress = true;
-                                qname
-                            });
+                            }
                         }
                     }
                 }
@@ -2414,15 +3435,21 @@ impl SymbolIndex {
     }
 
     /// Process imports for a scope recursively, handling transitive public re-exports.
+    ///
+    /// When `rebuilding` is set, scopes outside it are left untouched.
     fn process_imports_recursive(
         &mut self,
         scope: &str,
         visited: &mut HashSet<(Arc<str>, Arc<str>)>,
+        rebuilding: Option<&HashSet<Arc<str>>>,
     ) {
+        if rebuilding.is_some_and(|scopes| !scopes.contains(scope)) {
+            return;
+        }
         let scope_arc: Arc<str> = Arc::from(scope);
 
         // Find import symbols in this scope using the parent index (much faster than scanning all symbols)
-        let imports_to_process: Vec<(Arc<str>, Arc<str>, bool)> = self
+        let imports_to_process: Vec<(Arc<str>, Arc<str>, bool, FileId)> = self
             .by_parent_scope
             .get(&scope_arc)
             .map(|indices| {
@@ -2430,12 +3457,19 @@ impl SymbolIndex {
                     .iter()
                     .filter_map(|&idx| self.symbols.get(idx))
                     .filter(|s| s.kind == SymbolKind::Import)
-                    .map(|s| (s.name.clone(), s.qualified_name.clone(), s.is_public))
+                    .map(|s| {
+                        (
+                            s.name.clone(),
+                            s.qualified_name.clone(),
+                            s.is_public,
+                            s.file,
+                        )
+                    })
                     .collect()
             })
             .unwrap_or_default();
 
-        for (import_name, import_qname, is_public) in imports_to_process {
+        for (import_name, import_qname, is_public, file) in imports_to_process {
             let is_wildcard = import_name.ends_with("::*") && !import_name.ends_with("::**");
             let is_recursive = import_name.ends_with("::**");
 
@@ -2457,12 +3491,29 @@ impl SymbolIndex {
                     if !visited.contains(&marker) {
                         visited.insert(marker);
                         // Recursively process the parent package first
-                        self.process_imports_recursive(parent_pkg, visited);
+                        self.process_imports_recursive(parent_pkg, visited, rebuilding);
                     }
                 }
             }
 
-            let resolved_target = self.resolve_import_target(scope, import_target);
+            let resolved = self.resolve_import_target(scope, import_target);
+            self.visibility_deps.record_names(import_target, &scope_arc);
+
+            // An unresolved target still records its dependencies, so the
+            // import is retried once the target appears
+            let is_resolved = resolved.is_some();
+            let resolved_target = resolved.unwrap_or_else(|| import_target.to_string());
+
+            // A member import (`import P::X`) is an import from its namespace
+            let imported_namespace = if is_wildcard || is_recursive {
+                Some(resolved_target.as_str())
+            } else {
+                Self::parent_scope(&resolved_target)
+            };
+            if let Some(namespace) = imported_namespace {
+                self.visibility_deps
+                    .record_import_file(namespace, &scope_arc, file);
+            }
 
             if is_wildcard || is_recursive {
                 // Wildcard or recursive import: import symbols from target scope
@@ -2474,8 +3525,15 @@ impl SymbolIndex {
                 }
                 visited.insert(key);
 
+                self.visibility_deps
+                    .record_copy(&resolved_target, &scope_arc);
+                if is_recursive {
+                    self.visibility_deps
+                        .record_recursive(&resolved_target, &scope_arc);
+                }
+
                 // Recursively process the target's imports first (to get transitive symbols)
-                self.process_imports_recursive(&resolved_target, visited);
+                self.process_imports_recursive(&resolved_target, visited, rebuilding);
 
                 // Get filter info - both scope filters and import-specific filters
                 let scope_filters = self.scope_filters.get(scope).cloned();
@@ -2487,46 +3545,27 @@ impl SymbolIndex {
                 // Now copy symbols from target to this scope
                 if let Some(target_vis) = self.visibility_map.get(&resolved_target as &str).cloned()
                 {
-                    // Collect symbols to import (applying filter)
-                    let direct_defs_to_import: Vec<_> = target_vis
-                        .direct_defs()
-                        .filter(|(_, qname)| {
-                            // Apply filter if present
-                            if let Some(ref filters) = active_filters {
-                                if !filters.is_empty() {
-                                    return self.symbol_passes_filters_list(qname, filters);
-                                }
-                            }
-                            true
-                        })
-                        .map(|(n, q)| (n.clone(), q.clone()))
-                        .collect();
+                    let (members, truncated) =
+                        self.namespace_import_members(&target_vis, &active_filters);
 
                     let vis = self
                         .visibility_map
                         .get_mut(scope)
+                        .map(Arc::make_mut)
                         .expect("scope must exist");
 
-                    // Copy direct definitions (filtered)
-                    for (name, qname) in direct_defs_to_import {
-                        vis.add_import(name, qname);
-                    }
-
-                    // Only copy imports that come from publicly re-exported namespaces
-                    // Private imports should NOT be transitively visible
-                    let public_reexports = target_vis.public_reexports();
-                    for (name, qname) in target_vis.imports() {
-                        // Check if this import comes from a publicly re-exported namespace
-                        let is_from_public_reexport = public_reexports.iter().any(|ns| {
-                            qname.starts_with(ns.as_ref())
-                                && (qname.len() == ns.len()
-                                    || qname.as_bytes().get(ns.len()) == Some(&b':'))
-                        });
-                        if is_from_public_reexport {
-                            vis.add_import(name.clone(), qname.clone());
+                    for (name, qname, depth) in members {
+                        if is_public {
+                            vis.add_public_import_at_depth(name, qname, depth);
+                        } else {
+                            vis.add_import(name, qname);
                         }
                     }
+                    if truncated {
+                        vis.truncated_imports.push(import_qname.clone());
+                    }
 
+                    let public_reexports = target_vis.public_reexports();
                     if is_public {
                         vis.add_public_reexport(Arc::from(resolved_target.as_str()));
                         // Also propagate the target's public reexports for transitive chains
@@ -2538,9 +3577,9 @@ impl SymbolIndex {
 
                 // For recursive imports, also import all descendants
                 if is_recursive {
-                    self.import_descendants(scope, &resolved_target, &active_filters);
+                    self.import_descendants(scope, &resolved_target, &active_filters, is_public);
                 }
-            } else {
+            } else if is_resolved {
                 // Specific import: import a single symbol
                 // E.g., `import EngineDefs::Engine;` makes `Engine` visible as `EngineDefs::Engine`
                 // Also handles short name imports: `import Pkg::mop` where mop is a short name
@@ -2555,66 +3594,191 @@ impl SymbolIndex {
                 // Get the import's last segment (may differ if importing via short name)
                 let import_last_seg = import_target.rsplit("::").next().unwrap_or(import_target);
 
+                if let Some(target_scope) = Self::parent_scope(&resolved_target) {
+                    self.visibility_deps.record_copy(target_scope, &scope_arc);
+                }
+
                 // Add to this scope's imports
-                if let Some(vis) = self.visibility_map.get_mut(scope) {
+                if let Some(vis) = self.visibility_map.get_mut(scope).map(Arc::make_mut) {
+                    let mut add = |name: &str| {
+                        let (name, qname) = (Arc::from(name), Arc::from(resolved_target.as_str()));
+                        if is_public {
+                            vis.add_public_import(name, qname);
+                        } else {
+                            vis.add_import(name, qname);
+                        }
+                    };
+
                     // Always add the resolved symbol's name
-                    vis.add_import(Arc::from(simple_name), Arc::from(resolved_target.as_str()));
+                    add(simple_name);
 
                     // If imported via a different name (e.g., short name), add that too
                     if import_last_seg != simple_name {
-                        vis.add_import(
-                            Arc::from(import_last_seg),
-                            Arc::from(resolved_target.as_str()),
-                        );
+                        add(import_last_seg);
                     }
                 }
             }
         }
     }
 
-    /// Import all descendants of a scope (for recursive imports like ::**).
+    /// Names a namespace import copies from the target's visibility: the
+    /// target's own members passing `filters`, then its public imports.
     ///
-    /// This imports all symbols that are nested under the target scope,
-    /// not just direct children.
-    fn import_descendants(
-        &mut self,
-        importing_scope: &str,
+    /// Each name carries the re-export depth it gets in the importing scope.
+    /// Members the target inherits are not imported; inheritance is applied
+    /// after all imports. The flag is set when public imports were cut off
+    /// by the re-export depth limit.
+    fn namespace_import_members(
+        &self,
+        target_vis: &ScopeVisibility,
+        filters: &Option<Vec<Arc<str>>>,
+    ) -> (Vec<ImportedMember>, bool) {
+        let direct_defs = target_vis
+            .direct_defs()
+            .filter(|(name, _)| !target_vis.is_inherited(name))
+            .filter(|(_, qname)| match filters {
+                Some(filters) if !filters.is_empty() => {
+                    self.symbol_passes_filters_list(qname, filters)
+                }
+                _ => true,
+            })
+            .map(|(n, q)| (n.clone(), q.clone(), 1));
+
+        // Private imports are not transitively visible. Names already
+        // re-exported by too many scopes stop here.
+        let max_depth = self.max_reexport_depth;
+        let mut truncated = false;
+        let public_imports: Vec<_> = target_vis
+            .public_imports()
+            .filter(|(n, _)| {
+                let within = max_depth.is_none_or(|max| target_vis.reexport_depth(n) <= max);
+                truncated |= !within;
+                within
+            })
+            .map(|(n, q)| (n.clone(), q.clone(), target_vis.reexport_depth(n) + 1))
+            .collect();
+
+        (direct_defs.chain(public_imports).collect(), truncated)
+    }
+
+    /// Names a recursive import (`import P::**`) adds beyond `P`'s own
+    /// members: the symbols nested anywhere below `P` that pass `filters`.
+    ///
+    /// A recursive import contributes one candidate per name, so nested
+    /// members sharing a name don't make the import ambiguous with itself.
+    /// Direct children (already imported) win; otherwise the last
+    /// descendant seen does.
+    fn descendant_import_names(
+        &self,
         target_scope: &str,
         filters: &Option<Vec<Arc<str>>>,
-    ) {
+    ) -> HashMap<Arc<str>, Arc<str>> {
         let target_prefix = format!("{}::", target_scope);
+        let direct_names: HashSet<&Arc<str>> = self
+            .visibility_map
+            .get(target_scope)
+            .map(|target_vis| {
+                target_vis
+                    .direct_defs()
+                    .map(|(name, _)| name)
+                    .filter(|name| !target_vis.is_inherited(name))
+                    .collect()
+            })
+            .unwrap_or_default();
 
-        // Find all symbols that are descendants of target_scope
-        let descendant_symbols: Vec<(Arc<str>, Arc<str>)> = self
-            .symbols
+        self.symbols
             .iter()
             .filter(|s| {
                 // Skip imports, they're processed separately
                 if s.kind == SymbolKind::Import || !s.qualified_name.starts_with(&target_prefix) {
                     return false;
                 }
-                // Apply filter if present
-                if let Some(filter_list) = filters {
-                    if !filter_list.is_empty() {
-                        return self.symbol_passes_filters_list_static(
-                            &s.metadata_annotations,
-                            filter_list,
-                        );
+                match filters {
+                    Some(filter_list) if !filter_list.is_empty() => {
+                        self.symbol_passes_filters_list_static(&s.metadata_annotations, filter_list)
                     }
+                    _ => true,
                 }
-                true
             })
+            .filter(|s| !direct_names.contains(&s.name))
             .map(|s| (s.name.clone(), s.qualified_name.clone()))
-            .collect();
+            .collect()
+    }
+
+    /// Import all descendants of a scope (for recursive imports like ::**).
+    ///
+    /// This imports all symbols that are nested under the target scope,
+    /// not just direct children.
+    fn import_descendants(
+        &mut self,
+        importing_scope: &str,
+        target_scope: &str,
+        filters: &Option<Vec<Arc<str>>>,
+        is_public: bool,
+    ) {
+        let descendant_symbols = self.descendant_import_names(target_scope, filters);
 
         // Add each descendant to the importing scope
-        if let Some(vis) = self.visibility_map.get_mut(importing_scope) {
+        if let Some(vis) = self
+            .visibility_map
+            .get_mut(importing_scope)
+            .map(Arc::make_mut)
+        {
             for (simple_name, qualified_name) in descendant_symbols {
-                vis.add_import(simple_name, qualified_name);
+                if is_public {
+                    vis.add_public_import(simple_name, qualified_name);
+                } else {
+                    vis.add_import(simple_name, qualified_name);
+                }
             }
         }
     }
 
+    /// Get the names a namespace import (`import P::*` or `import P::**`)
+    /// brings into its scope, as name resolution imports them.
+    ///
+    /// Returns `(simple name, qualified name, re-exported)` sorted by name:
+    /// the members `P` declares (not those it inherits), the names `P`
+    /// publicly re-exports, and for `::**` every member nested below `P`.
+    /// Returns `None` for other imports or an unresolved target. Requires
+    /// current visibility maps (see [`SymbolIndex::ensure_visibility_maps`]).
+    pub fn namespace_import_names(
+        &self,
+        import: &HirSymbol,
+    ) -> Option<(Arc<str>, Vec<ImportedName>)> {
+        let is_recursive = import.name.ends_with("::**");
+        let import_target = if is_recursive {
+            import.name.strip_suffix("::**")
+        } else {
+            import.name.strip_suffix("::*")
+        }?;
+        let scope = Self::parent_scope(&import.qualified_name).unwrap_or("");
+        let target = self.resolve_import_target(scope, import_target)?;
+        let target_vis = self.visibility_map.get(target.as_str())?;
+
+        let filters = self
+            .import_filters
+            .get(import.qualified_name.as_ref())
+            .or_else(|| self.scope_filters.get(scope))
+            .cloned();
+        let (members, _) = self.namespace_import_members(target_vis, &filters);
+        let mut names: Vec<_> = members
+            .into_iter()
+            .map(|(name, qname, depth)| (name, qname, depth > 1))
+            .collect();
+        if is_recursive {
+            names.extend(
+                self.descendant_import_names(&target, &filters)
+                    .into_iter()
+                    .map(|(name, qname)| (name, qname, false)),
+            );
+        }
+        // Stable sort keeps a member ahead of a same-named re-export
+        names.sort_by(|a, b| a.0.cmp(&b.0));
+        names.dedup_by(|a, b| a.0 == b.0);
+        Some((Arc::from(target.as_str()), names))
+    }
+
     /// Check if a symbol passes filters given its metadata annotations directly.
     /// This avoids lookup by qualified name since we already have the symbol.
     fn symbol_passes_filters_list_static(
@@ -2641,35 +3805,24 @@ impl SymbolIndex {
     /// imports like `import C::*` should resolve `C` through prior imports.
     ///
     /// Resolution order:
-    /// 1. Check if target is already fully qualified and exists
-    /// 2. Check current scope's visibility map (direct defs + imports)
+    /// 1. For qualified paths, resolve the parent from `scope` and look up the
+    ///    last segment in it
+    /// 2. For simple names, check current scope's visibility map (direct defs + imports)
     /// 3. Walk up parent scopes
-    /// 4. Fall back to target as-is
-    fn resolve_import_target(&self, scope: &str, target: &str) -> String {
-        // If already qualified with ::, check as-is first
-        if target.contains("::") && self.visibility_map.contains_key(target) {
-            return target.to_string();
-        }
-
-        // For qualified paths like "Pkg::member", check if the last segment is a short name
+    /// 4. Fall back to the target as a global name if that scope exists
+    ///
+    /// Returns `None` when the target names nothing, so an unrelated global
+    /// is never picked up by accident.
+    fn resolve_import_target(&self, scope: &str, target: &str) -> Option<String> {
+        // For qualified paths like "Pkg::member", resolve the parent relative to the
+        // scope, then find the member in it. The last segment may be a short name.
         // E.g., "ParametersOfInterestMetadata::mop" where mop is short for MeasureOfPerformance
-        if target.contains("::") {
-            if let Some(last_sep_idx) = target.rfind("::") {
-                let parent_part = &target[..last_sep_idx];
-                let last_segment = &target[last_sep_idx + 2..];
-
-                // First check if parent resolves directly
-                let parent_qualified = if self.visibility_map.contains_key(parent_part) {
-                    parent_part.to_string()
-                } else {
-                    // Try resolving parent from current scope
-                    self.resolve_import_target(scope, parent_part)
-                };
-
+        if let Some((parent_part, last_segment)) = target.rsplit_once("::") {
+            if let Some(parent_qualified) = self.resolve_import_target(scope, parent_part) {
                 // Check if last_segment is a direct child (by name)
                 let direct_child = format!("{}::{}", parent_qualified, last_segment);
                 if self.visibility_map.contains_key(&direct_child as &str) {
-                    return direct_child;
+                    return Some(direct_child);
                 }
 
                 // Check if last_segment matches a child's short_name
@@ -2680,7 +3833,7 @@ impl SymbolIndex {
                     for &idx in children {
                         if let Some(sym) = self.symbols.get(idx) {
                             if sym.short_name.as_ref().map(|s| s.as_ref()) == Some(last_segment) {
-                                return sym.qualified_name.to_string();
+                                return Some(sym.qualified_name.to_string());
                             }
                         }
                     }
@@ -2692,59 +3845,48 @@ impl SymbolIndex {
                 // to ISQBase::DurationValue via ISQ's visibility map
                 if let Some(vis) = self.visibility_map.get(&parent_qualified as &str) {
                     if let Some(resolved_qname) = vis.lookup(last_segment) {
-                        return resolved_qname.to_string();
+                        return Some(resolved_qname.to_string());
                     }
                 }
             }
-        }
-
-        // For simple names (no ::), first check if it's visible via imports in current scope
-        // This handles the SysML pattern: import P1::*; import C::*;
-        // where C was imported from P1
-        if !target.contains("::") {
+        } else {
+            // For simple names, first check if it's visible via imports in current scope
+            // This handles the SysML pattern: import P1::*; import C::*;
+            // where C was imported from P1
             if let Some(vis) = self.visibility_map.get(scope) {
                 // Check if target is visible (either as direct def or import)
                 if let Some(resolved_qname) = vis.lookup(target) {
                     // Found it - return the qualified name
-                    return resolved_qname.to_string();
+                    return Some(resolved_qname.to_string());
                 }
             }
-        }
-
-        // Try relative to scope and parent scopes (nested namespace lookup)
-        let mut current = scope.to_string();
-        loop {
-            let candidate = if current.is_empty() {
-                target.to_string()
-            } else {
-                format!("{}::{}", current, target)
-            };
 
-            if self.visibility_map.contains_key(&candidate as &str) {
-                return candidate;
-            }
-
-            // Move up
-            if let Some(idx) = current.rfind("::") {
-                current = current[..idx].to_string();
-            } else if !current.is_empty() {
-                current = String::new();
-            } else {
-                break;
+            // Try relative to scope and parent scopes (nested namespace lookup)
+            let mut current = scope;
+            while !current.is_empty() {
+                let candidate = format!("{}::{}", current, target);
+                if self.visibility_map.contains_key(&candidate as &str) {
+                    return Some(candidate);
+                }
+                current = Self::parent_scope(current).unwrap_or("");
             }
         }
 
-        // Fall back to target as-is (might be global)
-        target.to_string()
+        // Fall back to the target as a global name, but only if it exists
+        self.visibility_map
+            .contains_key(target)
+            .then(|| target.to_string())
     }
 
     /// Get the parent scope of a qualified name.
     ///
+    /// ```text
     /// "A::B::C" -> Some("A::B")
     /// "A" -> Some("")
     /// "" -> None
     /// "A::B::<anon>" -> Some("A::B") (anonymous scopes are skipped)
-    fn parent_scope(qualified_name: &str) -> Option<&str> {
+    /// ```
+    pub fn parent_scope(qualified_name: &str) -> Option<&str> {
         if qualified_name.is_empty() {
             return None;
         }
@@ -2850,6 +3992,7 @@ impl SymbolKind {
                 | SymbolKind::ReferenceUsage
                 | SymbolKind::OccurrenceUsage
                 | SymbolKind::FlowConnectionUsage
+                | SymbolKind::MetadataUsage
         )
     }
 
@@ -2868,6 +4011,7 @@ impl SymbolKind {
             SymbolKind::ConstraintUsage => Some(SymbolKind::ConstraintDefinition),
             SymbolKind::StateUsage => Some(SymbolKind::StateDefinition),
             SymbolKind::CalculationUsage => Some(SymbolKind::CalculationDefinition),
+            SymbolKind::MetadataUsage => Some(SymbolKind::MetadataDefinition),
             _ => None,
         }
     }
@@ -2909,6 +4053,39 @@ impl ResolveResult {
     }
 }
 
+/// One scope searched while resolving a name, as recorded by
+/// [`Resolver::resolve_traced`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolveStep {
+    /// The scope searched (empty for the root scope).
+    pub scope: Arc<str>,
+    /// How the name was found in this scope, if at all.
+    pub hit: ResolveHit,
+}
+
+/// How a name was found in a searched scope.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResolveHit {
+    /// The name is not visible in the scope.
+    Miss,
+    /// The name is declared directly in the scope.
+    Direct,
+    /// The name is imported into the scope.
+    Import,
+    /// The name is a member inherited from the scope's supertypes.
+    Inherited,
+}
+
+/// Append a step to the trace, if one is being recorded.
+fn record_step(trace: &mut Option<&mut Vec<ResolveStep>>, scope: &str, hit: ResolveHit) {
+    if let Some(steps) = trace {
+        steps.push(ResolveStep {
+            scope: Arc::from(scope),
+            hit,
+        });
+    }
+}
+
 // ============================================================================
 // RESOLVER
 // ============================================================================
@@ -2923,6 +4100,8 @@ pub struct Resolver<'a> {
     index: &'a SymbolIndex,
     /// Current scope prefix (e.g., "Vehicle::Powertrain").
     current_scope: Arc<str>,
+    /// Aliases being followed by enclosing resolutions (guards alias cycles).
+    followed_aliases: Vec<Arc<str>>,
 }
 
 impl<'a> Resolver<'a> {
@@ -2931,6 +4110,7 @@ impl<'a> Resolver<'a> {
         Self {
             index,
             current_scope: Arc::from(""),
+            followed_aliases: Vec::new(),
         }
     }
 
@@ -2941,22 +4121,60 @@ impl<'a> Resolver<'a> {
     }
 
     /// Resolve a name using pre-computed visibility maps.
+    ///
+    /// A name that resolves to an alias yields the alias's target, following
+    /// chains of aliases as [`Resolver::resolve_alias_target`] does.
     pub fn resolve(&self, name: &str) -> ResolveResult {
+        self.resolve_with_trace(name, None)
+    }
+
+    /// Resolve a name, recording each scope searched along the way.
+    ///
+    /// Intended for diagnosing references that fail to resolve. The steps
+    /// list the scopes of the walk outward from the current scope, and for a
+    /// qualified path also the namespace the last segment was looked up in.
+    /// A name matching a qualified name exactly needs no search and yields
+    /// no steps.
+    pub fn resolve_traced(&self, name: &str) -> (ResolveResult, Vec<ResolveStep>) {
+        let mut steps = Vec::new();
+        let result = self.resolve_with_trace(name, Some(&mut steps));
+        (result, steps)
+    }
+
+    /// Resolve a name, then follow it to the symbol it names if it is an alias.
+    fn resolve_with_trace(
+        &self,
+        name: &str,
+        trace: Option<&mut Vec<ResolveStep>>,
+    ) -> ResolveResult {
+        match self.resolve_name_with_trace(name, trace) {
+            ResolveResult::Found(symbol) if symbol.kind == SymbolKind::Alias => {
+                self.resolve_alias_target(&symbol)
+            }
+            result => result,
+        }
+    }
+
+    fn resolve_name_with_trace(
+        &self,
+        name: &str,
+        mut trace: Option<&mut Vec<ResolveStep>>,
+    ) -> ResolveResult {
         // 1. Handle qualified paths like "ISQ::TorqueValue"
         if name.contains("::") {
             // For qualified paths, try exact match first
             if let Some(symbol) = self.index.lookup_qualified(name) {
                 return ResolveResult::Found(symbol.clone());
             }
-            return self.resolve_qualified_path(name);
+            return self.resolve_qualified_path(name, trace);
         }
 
         // 2. For simple names, try scope walking FIRST (finds local Requirements before global)
-        let mut current = self.current_scope.to_string();
-        let mut scopes_checked = Vec::new();
+        let mut current: &str = &self.current_scope;
+        let mut scopes_checked = 0;
         loop {
-            scopes_checked.push(current.clone());
-            if let Some(vis) = self.index.visibility_for_scope(&current) {
+            scopes_checked += 1;
+            if let Some(vis) = self.index.visibility_for_scope(current) {
                 // Check direct definitions first (higher priority)
                 if let Some(qname) = vis.lookup_direct(name) {
                     tracing::trace!(
@@ -2965,11 +4183,53 @@ impl<'a> Resolver<'a> {
                         current,
                         qname
                     );
+
+                    // Overloaded definitions (e.g., calcs sharing a short name) are
+                    // left to the caller to disambiguate by signature
+                    if let Some(candidates) = vis.lookup_direct_overloads(name) {
+                        let symbols: Vec<HirSymbol> = candidates
+                            .iter()
+                            .filter_map(|qname| self.index.lookup_qualified(qname))
+                            .filter(|sym| sym.kind.is_definition())
+                            .cloned()
+                            .collect();
+                        if symbols.len() > 1 {
+                            tracing::trace!(
+                                "[RESOLVE] '{}' is overloaded in scope '{}': {:?}",
+                                name,
+                                current,
+                                candidates
+                            );
+                            record_step(&mut trace, current, ResolveHit::Direct);
+                            return ResolveResult::Ambiguous(symbols);
+                        }
+                    }
+
                     if let Some(sym) = self.index.lookup_qualified(qname) {
+                        record_step(&mut trace, current, ResolveHit::Direct);
                         return ResolveResult::Found(sym.clone());
                     }
                 }
 
+                // Check for a name brought in by conflicting imports
+                if let Some(candidates) = vis.lookup_ambiguous_import(name) {
+                    let symbols: Vec<HirSymbol> = candidates
+                        .iter()
+                        .filter_map(|qname| self.index.lookup_qualified(qname))
+                        .cloned()
+                        .collect();
+                    if symbols.len() > 1 {
+                        tracing::trace!(
+                            "[RESOLVE] '{}' is ambiguous in scope '{}': {:?}",
+                            name,
+                            current,
+                            candidates
+                        );
+                        record_step(&mut trace, current, ResolveHit::Import);
+                        return ResolveResult::Ambiguous(symbols);
+                    }
+                }
+
                 // Check imports
                 if let Some(qname) = vis.lookup_import(name) {
                     tracing::trace!(
@@ -2979,6 +4239,7 @@ impl<'a> Resolver<'a> {
                         qname
                     );
                     if let Some(sym) = self.index.lookup_qualified(qname) {
+                        record_step(&mut trace, current, ResolveHit::Import);
                         return ResolveResult::Found(sym.clone());
                     }
                 }
@@ -2988,32 +4249,35 @@ impl<'a> Resolver<'a> {
             // E.g., missionContext: MissionContext has spatialCF via inheritance from Context
             // E.g., use case def MyUseCase has start/done via inheritance from Actions::Action
             if !current.is_empty() {
-                if let Some(scope_sym) = self.index.lookup_qualified(&current) {
+                if let Some(scope_sym) = self.index.lookup_qualified(current) {
                     // Check inherited members for both usages and definitions
                     // (both can have supertypes that define members like start/done)
                     if !scope_sym.supertypes.is_empty() {
                         if let Some(result) = self.resolve_inherited_member(scope_sym, name) {
+                            record_step(&mut trace, current, ResolveHit::Inherited);
                             return result;
                         }
                     }
                 }
             }
 
+            record_step(&mut trace, current, ResolveHit::Miss);
+
             // Move up to parent scope
             if let Some(idx) = current.rfind("::") {
-                current = current[..idx].to_string();
+                current = &current[..idx];
             } else if !current.is_empty() {
-                current = String::new(); // Try root scope
+                current = ""; // Try root scope
             } else {
                 break;
             }
         }
 
         tracing::debug!(
-            "[RESOLVE] '{}' not found in any of {} scopes: {:?}",
+            "[RESOLVE] '{}' not found in any of {} scopes starting at '{}'",
             name,
-            scopes_checked.len(),
-            scopes_checked.first()
+            scopes_checked,
+            self.current_scope
         );
 
         // 3. Fall back to exact qualified match for simple names
@@ -3030,32 +4294,34 @@ impl<'a> Resolver<'a> {
     /// This handles cases where:
     /// - ISQ is a package with `public import ISQSpaceTime::*`
     /// - TorqueValue is defined in ISQSpaceTime
-    fn resolve_qualified_path(&self, path: &str) -> ResolveResult {
+    fn resolve_qualified_path(
+        &self,
+        path: &str,
+        mut trace: Option<&mut Vec<ResolveStep>>,
+    ) -> ResolveResult {
         let (first, rest) = match path.find("::") {
             Some(idx) => (&path[..idx], &path[idx + 2..]),
             None => return ResolveResult::NotFound,
         };
 
         // Resolve the first segment (it's a simple name, so resolve() won't recurse here)
-        let first_sym = self.resolve(first);
+        let first_sym = self.resolve_with_trace(first, trace.as_deref_mut());
 
         if let ResolveResult::Found(first_symbol) = first_sym {
-            // Get the target scope (follow alias if needed)
-            let target_scope = if first_symbol.kind == SymbolKind::Alias {
-                if let Some(target) = first_symbol.supertypes.first() {
-                    target.as_ref()
-                } else {
-                    first_symbol.qualified_name.as_ref()
-                }
-            } else {
-                first_symbol.qualified_name.as_ref()
+            // Get the target scope (follow aliases if needed)
+            let ResolveResult::Found(target) = self.resolve_alias_target(&first_symbol) else {
+                return ResolveResult::NotFound;
             };
+            let target_scope = target.qualified_name.as_ref();
 
             // Handle nested qualified paths (e.g., "A::B::C" where rest="B::C")
             if rest.contains("::") {
                 // Recursively resolve with target scope
-                let nested_resolver = Resolver::new(self.index).with_scope(target_scope);
-                return nested_resolver.resolve(rest);
+                let nested_resolver = Resolver {
+                    current_scope: Arc::from(target_scope),
+                    ..self.clone()
+                };
+                return nested_resolver.resolve_with_trace(rest, trace);
             }
 
             // Look up 'rest' in target scope's visibility map
@@ -3063,6 +4329,7 @@ impl<'a> Resolver<'a> {
                 // Check direct definitions first
                 if let Some(qname) = vis.lookup_direct(rest) {
                     if let Some(sym) = self.index.lookup_qualified(qname) {
+                        record_step(&mut trace, target_scope, ResolveHit::Direct);
                         return ResolveResult::Found(sym.clone());
                     }
                 }
@@ -3070,6 +4337,7 @@ impl<'a> Resolver<'a> {
                 // Check imports (handles public import ISQSpaceTime::*)
                 if let Some(qname) = vis.lookup_import(rest) {
                     if let Some(sym) = self.index.lookup_qualified(qname) {
+                        record_step(&mut trace, target_scope, ResolveHit::Import);
                         return ResolveResult::Found(sym.clone());
                     }
                 }
@@ -3078,13 +4346,54 @@ impl<'a> Resolver<'a> {
             // Try direct qualified lookup (might be nested definition)
             let full_path = format!("{}::{}", target_scope, rest);
             if let Some(sym) = self.index.lookup_qualified(&full_path) {
+                record_step(&mut trace, target_scope, ResolveHit::Direct);
                 return ResolveResult::Found(sym.clone());
             }
+            record_step(&mut trace, target_scope, ResolveHit::Miss);
         }
 
         ResolveResult::NotFound
     }
 
+    /// Follow an alias, through any aliases it targets, to the symbol it names.
+    ///
+    /// Each alias target is resolved from the scope owning the alias.
+    /// Non-alias symbols are returned as-is.
+    ///
+    /// # Returns
+    /// `NotFound` if an alias target does not resolve or the aliases form a cycle
+    /// (e.g., `alias A for B; alias B for A;`).
+    pub fn resolve_alias_target(&self, symbol: &HirSymbol) -> ResolveResult {
+        let mut followed = self.followed_aliases.clone();
+        let mut current = symbol.clone();
+
+        while current.kind == SymbolKind::Alias {
+            if followed.contains(&current.qualified_name) {
+                return ResolveResult::NotFound;
+            }
+            followed.push(current.qualified_name.clone());
+
+            let Some(target) = current.supertypes.first() else {
+                return ResolveResult::NotFound;
+            };
+            let scope = current
+                .qualified_name
+                .rsplit_once("::")
+                .map_or("", |(scope, _)| scope);
+            let hop = Resolver {
+                index: self.index,
+                current_scope: Arc::from(scope),
+                followed_aliases: followed.clone(),
+            };
+            match hop.resolve(target) {
+                ResolveResult::Found(next) => current = next,
+                _ => return ResolveResult::NotFound,
+            }
+        }
+
+        ResolveResult::Found(current)
+    }
+
     /// Resolve a member name inherited through a usage's type hierarchy.
     ///
     /// E.g., if `missionContext: MissionContext` and `MissionContext :> Context`
@@ -3291,49 +4600,19 @@ impl<'a> Resolver<'a> {
     }
 }
 
+/// Size of a span for picking the innermost of overlapping spans.
+fn span_size(span: LineColSpan) -> u32 {
+    let line_diff = span.end.line.saturating_sub(span.start.line);
+    let col_diff = span.end.col.saturating_sub(span.start.col);
+    line_diff * 1000 + col_diff
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::new_element_id;
 
     fn make_symbol(name: &str, qualified: &str, kind: SymbolKind, file: u32) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qualified),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(file),
-            start_line: 0,
-            start_col: 0,
-            end_line: 0,
-            end_col: 0,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+        HirSymbol::builder(name, qualified, kind, FileId::new(file)).build()
     }
 
     #[test]
@@ -3355,6 +4634,112 @@ mod tests {
         assert!(index.lookup_definition("Vehicle::Car::engine").is_none()); // Usage, not def
     }
 
+    #[test]
+    fn test_clone_shares_unchanged_files() {
+        let mut index = SymbolIndex::new();
+        index.add_file(
+            FileId::new(0),
+            vec![make_symbol("A", "A", SymbolKind::PartDefinition, 0)],
+        );
+        index.add_file(
+            FileId::new(1),
+            vec![make_symbol("B", "B", SymbolKind::PartDefinition, 1)],
+        );
+        index.ensure_visibility_maps();
+        let snapshot = index.clone();
+
+        index.lookup_qualified_mut("B").unwrap().doc = Some(Arc::from("edited"));
+
+        let shared = |i: usize| Arc::ptr_eq(&index.symbols.chunks[i], &snapshot.symbols.chunks[i]);
+        assert!(shared(0), "unchanged file should stay shared");
+        assert!(!shared(1), "edited file should be copied");
+        assert!(snapshot.lookup_qualified("B").unwrap().doc.is_none());
+        assert!(Arc::ptr_eq(
+            &index.visibility_maps()[""],
+            &snapshot.visibility_maps()[""]
+        ));
+    }
+
+    #[test]
+    fn test_symbols_of_kind() {
+        let mut index = SymbolIndex::new();
+
+        index.add_file(
+            FileId::new(0),
+            vec![
+                make_symbol("Vehicle", "Vehicle", SymbolKind::Package, 0),
+                make_symbol("Car", "Vehicle::Car", SymbolKind::PartDefinition, 0),
+                make_symbol("Truck", "Vehicle::Truck", SymbolKind::PartDefinition, 0),
+                make_symbol("engine", "Vehicle::Car::engine", SymbolKind::PartUsage, 0),
+                make_symbol("Mass", "Vehicle::Mass", SymbolKind::AttributeDefinition, 0),
+                make_symbol(
+                    "Safe",
+                    "Vehicle::Safe",
+                    SymbolKind::RequirementDefinition,
+                    0,
+                ),
+            ],
+        );
+
+        let mut part_defs: Vec<_> = index
+            .symbols_of_kind(SymbolKind::PartDefinition)
+            .map(|s| s.qualified_name.as_ref())
+            .collect();
+        part_defs.sort();
+        assert_eq!(part_defs, vec!["Vehicle::Car", "Vehicle::Truck"]);
+
+        let mut def_kinds: Vec<_> = index
+            .definitions_of_kind(SymbolKind::PartDefinition)
+            .map(|s| s.qualified_name.as_ref())
+            .collect();
+        def_kinds.sort();
+        assert_eq!(def_kinds, part_defs);
+
+        let usages: Vec<_> = index.symbols_of_kind(SymbolKind::PartUsage).collect();
+        assert_eq!(usages.len(), 1);
+        assert_eq!(usages[0].name.as_ref(), "engine");
+        assert_eq!(index.definitions_of_kind(SymbolKind::PartUsage).count(), 0);
+        assert_eq!(
+            index.symbols_of_kind(SymbolKind::ActionDefinition).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_definitions_sorted_ignores_insertion_order() {
+        let definitions = [
+            ("Truck", "Vehicle::Truck"),
+            ("Mass", "Vehicle::Mass"),
+            ("Wheel", "Wheel"),
+            ("Car", "Vehicle::Car"),
+            ("Axle", "Vehicle::Car::Axle"),
+        ];
+        let build = |order: &[usize]| {
+            let mut index = SymbolIndex::new();
+            let symbols = order.iter().map(|&i| {
+                let (name, qualified) = definitions[i];
+                make_symbol(name, qualified, SymbolKind::PartDefinition, 0)
+            });
+            index.add_file(FileId::new(0), symbols.collect());
+            index
+                .definitions_sorted()
+                .iter()
+                .map(|s| s.qualified_name.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let expected = vec![
+            "Vehicle::Car",
+            "Vehicle::Car::Axle",
+            "Vehicle::Mass",
+            "Vehicle::Truck",
+            "Wheel",
+        ];
+        for order in [[0, 1, 2, 3, 4], [4, 3, 2, 1, 0], [2, 0, 4, 1, 3]] {
+            assert_eq!(build(&order), expected);
+        }
+    }
+
     #[test]
     fn test_symbol_index_remove_file() {
         let mut index = SymbolIndex::new();
@@ -3377,6 +4762,63 @@ mod tests {
         assert!(index.lookup_qualified("B").is_some());
     }
 
+    #[test]
+    fn test_symbol_index_compact() {
+        let mut index = SymbolIndex::new();
+
+        for file in 0..4 {
+            let pkg = format!("P{}", file);
+            index.add_file(
+                FileId::new(file),
+                vec![
+                    make_symbol(&pkg, &pkg, SymbolKind::Package, file),
+                    make_symbol(
+                        "Engine",
+                        &format!("{}::Engine", pkg),
+                        SymbolKind::PartDefinition,
+                        file,
+                    ),
+                ],
+            );
+        }
+        index.remove_file(FileId::new(0));
+        index.remove_file(FileId::new(2));
+        assert_eq!(index.symbols.len(), 8);
+
+        index.compact();
+
+        assert_eq!(index.symbols.len(), 4);
+        assert_eq!(index.len(), 4);
+        assert!(index.lookup_qualified("P0::Engine").is_none());
+        assert_eq!(
+            index
+                .lookup_definition("P3::Engine")
+                .unwrap()
+                .qualified_name
+                .as_ref(),
+            "P3::Engine"
+        );
+        let engines: Vec<_> = index
+            .lookup_simple("Engine")
+            .iter()
+            .map(|s| s.qualified_name.to_string())
+            .collect();
+        assert_eq!(engines, ["P1::Engine", "P3::Engine"]);
+        assert_eq!(index.symbols_in_file(FileId::new(3)).len(), 2);
+
+        index.ensure_visibility_maps();
+        let resolver = Resolver::new(&index).with_scope("P1");
+        assert_eq!(
+            resolver
+                .resolve("Engine")
+                .symbol()
+                .unwrap()
+                .qualified_name
+                .as_ref(),
+            "P1::Engine"
+        );
+    }
+
     #[test]
     fn test_resolver_qualified_name() {
         let mut index = SymbolIndex::new();
@@ -3449,6 +4891,74 @@ mod tests {
         );
     }
 
+    fn make_import(path: &str, scope: &str, file: u32) -> HirSymbol {
+        make_symbol(
+            path,
+            &format!("{}::import:{}", scope, path),
+            SymbolKind::Import,
+            file,
+        )
+    }
+
+    #[test]
+    fn test_resolver_ambiguous_wildcard_imports() {
+        let mut index = SymbolIndex::new();
+        index.add_file(
+            FileId::new(0),
+            vec![
+                make_symbol("Gui", "Gui", SymbolKind::Package, 0),
+                make_symbol("Widget", "Gui::Widget", SymbolKind::PartDefinition, 0),
+                make_symbol("Factory", "Factory", SymbolKind::Package, 0),
+                make_symbol("Widget", "Factory::Widget", SymbolKind::PartDefinition, 0),
+            ],
+        );
+        index.add_file(
+            FileId::new(1),
+            vec![
+                make_symbol("App", "App", SymbolKind::Package, 1),
+                make_import("Gui::*", "App", 1),
+                make_import("Factory::*", "App", 1),
+            ],
+        );
+        index.ensure_visibility_maps();
+
+        let result = Resolver::new(&index).with_scope("App").resolve("Widget");
+        let ResolveResult::Ambiguous(candidates) = result else {
+            panic!("expected ambiguous result, got {:?}", result);
+        };
+        let mut names: Vec<_> = candidates
+            .iter()
+            .map(|s| s.qualified_name.to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["Factory::Widget", "Gui::Widget"]);
+    }
+
+    #[test]
+    fn test_resolver_direct_def_shadows_ambiguous_imports() {
+        let mut index = SymbolIndex::new();
+        index.add_file(
+            FileId::new(0),
+            vec![
+                make_symbol("Gui", "Gui", SymbolKind::Package, 0),
+                make_symbol("Widget", "Gui::Widget", SymbolKind::PartDefinition, 0),
+                make_symbol("Factory", "Factory", SymbolKind::Package, 0),
+                make_symbol("Widget", "Factory::Widget", SymbolKind::PartDefinition, 0),
+                make_symbol("App", "App", SymbolKind::Package, 0),
+                make_symbol("Widget", "App::Widget", SymbolKind::PartDefinition, 0),
+                make_import("Gui::*", "App", 0),
+                make_import("Factory::*", "App", 0),
+            ],
+        );
+        index.ensure_visibility_maps();
+
+        let result = Resolver::new(&index).with_scope("App").resolve("Widget");
+        assert_eq!(
+            result.symbol().unwrap().qualified_name.as_ref(),
+            "App::Widget"
+        );
+    }
+
     #[test]
     fn test_symbol_kind_is_definition() {
         assert!(SymbolKind::PartDefinition.is_definition());
@@ -2,6 +2,7 @@
 
 use indexmap::IndexMap;
 use parking_lot::RwLock;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -24,6 +25,8 @@ struct FileSetInner {
     id_to_path: IndexMap<FileId, PathBuf>,
     /// FileId → Contents
     contents: IndexMap<FileId, Arc<str>>,
+    /// FileId → Hash of contents
+    hashes: IndexMap<FileId, u64>,
     /// Next FileId to assign
     next_id: u32,
 }
@@ -69,7 +72,33 @@ impl FileSet {
 
     /// Set the contents of a file.
     pub fn set_contents(&self, file: FileId, contents: impl Into<Arc<str>>) {
-        self.inner.write().contents.insert(file, contents.into());
+        let contents = contents.into();
+        let mut inner = self.inner.write();
+        inner.hashes.insert(file, content_hash(&contents));
+        inner.contents.insert(file, contents);
+    }
+
+    /// Set the contents of a file only if they differ from the stored contents.
+    ///
+    /// Returns `true` if the contents changed (including when the file had no
+    /// contents yet), `false` if they were identical and nothing was updated.
+    pub fn set_contents_if_changed(&self, file: FileId, contents: impl Into<Arc<str>>) -> bool {
+        let contents = contents.into();
+        let hash = content_hash(&contents);
+        let mut inner = self.inner.write();
+
+        let unchanged = inner.hashes.get(&file) == Some(&hash)
+            && inner
+                .contents
+                .get(&file)
+                .is_some_and(|old| **old == *contents);
+        if unchanged {
+            return false;
+        }
+
+        inner.hashes.insert(file, hash);
+        inner.contents.insert(file, contents);
+        true
     }
 
     /// Get the contents of a file.
@@ -84,6 +113,7 @@ impl FileSet {
             inner.path_to_id.swap_remove(&path);
         }
         inner.contents.swap_remove(&file);
+        inner.hashes.swap_remove(&file);
     }
 
     /// Get the number of files.
@@ -102,6 +132,12 @@ impl FileSet {
     }
 }
 
+fn content_hash(contents: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +174,27 @@ mod tests {
 
         assert_eq!(files.path(id).as_deref(), Some(path));
     }
+
+    #[test]
+    fn test_file_set_contents_if_changed() {
+        let files = FileSet::new();
+        let id = files.file_id(Path::new("/test.sysml"));
+
+        // First contents always count as a change
+        assert!(files.set_contents_if_changed(id, "part def Foo;"));
+        assert!(!files.set_contents_if_changed(id, "part def Foo;"));
+
+        assert!(files.set_contents_if_changed(id, "part def Bar;"));
+        assert_eq!(files.contents(id).as_deref(), Some("part def Bar;"));
+        assert!(!files.set_contents_if_changed(id, "part def Bar;"));
+
+        // Plain set_contents keeps the stored hash in sync
+        files.set_contents(id, "part def Baz;");
+        assert!(!files.set_contents_if_changed(id, "part def Baz;"));
+
+        // Removing forgets the hash
+        files.remove(id);
+        let id = files.file_id(Path::new("/test.sysml"));
+        assert!(files.set_contents_if_changed(id, "part def Baz;"));
+    }
 }
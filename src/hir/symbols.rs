@@ -7,11 +7,12 @@
 //! The extraction uses the normalized syntax layer (`crate::syntax::normalized`)
 //! to provide a unified extraction path for both SysML and KerML files.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use uuid::Uuid;
 
-use crate::base::FileId;
+use crate::base::{FileId, LineCol, LineColSpan};
 use crate::parser::Direction;
 use crate::syntax::normalized::{
     Multiplicity, NormalizedAlias, NormalizedComment, NormalizedDefKind, NormalizedDefinition,
@@ -259,12 +260,14 @@ impl TypeRef {
         }
     }
 
+    /// The source span of this type reference.
+    pub fn span(&self) -> LineColSpan {
+        LineColSpan::from_coords(self.start_line, self.start_col, self.end_line, self.end_col)
+    }
+
     /// Check if a position is within this type reference.
     pub fn contains(&self, line: u32, col: u32) -> bool {
-        let after_start =
-            line > self.start_line || (line == self.start_line && col >= self.start_col);
-        let before_end = line < self.end_line || (line == self.end_line && col <= self.end_col);
-        after_start && before_end
+        self.span().contains(LineCol::new(line, col))
     }
 
     /// Check if another TypeRef immediately follows this one (separated by a dot).
@@ -432,6 +435,265 @@ pub struct HirSymbol {
     pub value: Option<crate::syntax::normalized::ValueExpression>,
 }
 
+impl HirSymbol {
+    /// The source span of this symbol.
+    pub fn span(&self) -> LineColSpan {
+        LineColSpan::from_coords(self.start_line, self.start_col, self.end_line, self.end_col)
+    }
+
+    /// The source span of the short name, if the symbol has one.
+    pub fn short_name_span(&self) -> Option<LineColSpan> {
+        Some(LineColSpan::from_coords(
+            self.short_name_start_line?,
+            self.short_name_start_col?,
+            self.short_name_end_line?,
+            self.short_name_end_col?,
+        ))
+    }
+
+    /// Whether this symbol has a synthetic name because it is unnamed in the
+    /// source (e.g., `<:Engine#1>` or `<anonymous-dependency>`).
+    ///
+    /// Anonymous symbols exist so their members have a scope; they are never
+    /// referenced by name.
+    pub fn is_anonymous(&self) -> bool {
+        self.name.starts_with('<') && self.name.ends_with('>')
+    }
+
+    /// Create a builder for a symbol with the given identity.
+    ///
+    /// See [`HirSymbolBuilder`] for the defaults of the remaining fields.
+    pub fn builder(
+        name: impl Into<Arc<str>>,
+        qualified_name: impl Into<Arc<str>>,
+        kind: SymbolKind,
+        file: FileId,
+    ) -> HirSymbolBuilder {
+        HirSymbolBuilder::new(name, qualified_name, kind, file)
+    }
+}
+
+/// Builder for [`HirSymbol`].
+///
+/// Fields that are not set get a fresh element ID, an empty span at the
+/// start of the file, no short name, no doc, no relationships, private
+/// visibility, all flags `false`, and no direction, multiplicity or value.
+#[derive(Clone, Debug)]
+pub struct HirSymbolBuilder {
+    symbol: HirSymbol,
+}
+
+impl HirSymbolBuilder {
+    /// Create a new builder for a symbol with the given identity
+    pub fn new(
+        name: impl Into<Arc<str>>,
+        qualified_name: impl Into<Arc<str>>,
+        kind: SymbolKind,
+        file: FileId,
+    ) -> Self {
+        Self {
+            symbol: HirSymbol {
+                name: name.into(),
+                short_name: None,
+                qualified_name: qualified_name.into(),
+                element_id: new_element_id(),
+                kind,
+                file,
+                start_line: 0,
+                start_col: 0,
+                end_line: 0,
+                end_col: 0,
+                short_name_start_line: None,
+                short_name_start_col: None,
+                short_name_end_line: None,
+                short_name_end_col: None,
+                doc: None,
+                supertypes: Vec::new(),
+                relationships: Vec::new(),
+                type_refs: Vec::new(),
+                is_public: false,
+                view_data: None,
+                metadata_annotations: Vec::new(),
+                is_abstract: false,
+                is_variation: false,
+                is_readonly: false,
+                is_derived: false,
+                is_parallel: false,
+                is_individual: false,
+                is_end: false,
+                is_default: false,
+                is_ordered: false,
+                is_nonunique: false,
+                is_portion: false,
+                direction: None,
+                multiplicity: None,
+                value: None,
+            },
+        }
+    }
+
+    /// Set the short name alias
+    pub fn short_name(mut self, short_name: Option<impl Into<Arc<str>>>) -> Self {
+        self.symbol.short_name = short_name.map(Into::into);
+        self
+    }
+
+    /// Set the element ID (e.g., one preserved from XMI)
+    pub fn element_id(mut self, element_id: impl Into<Arc<str>>) -> Self {
+        self.symbol.element_id = element_id.into();
+        self
+    }
+
+    /// Set the source span
+    pub fn span(mut self, span: LineColSpan) -> Self {
+        self.symbol.start_line = span.start.line;
+        self.symbol.start_col = span.start.col;
+        self.symbol.end_line = span.end.line;
+        self.symbol.end_col = span.end.col;
+        self
+    }
+
+    /// Set the source span of the short name
+    pub fn short_name_span(mut self, span: Option<LineColSpan>) -> Self {
+        self.symbol.short_name_start_line = span.map(|s| s.start.line);
+        self.symbol.short_name_start_col = span.map(|s| s.start.col);
+        self.symbol.short_name_end_line = span.map(|s| s.end.line);
+        self.symbol.short_name_end_col = span.map(|s| s.end.col);
+        self
+    }
+
+    /// Set the documentation comment
+    pub fn doc(mut self, doc: Option<Arc<str>>) -> Self {
+        self.symbol.doc = doc;
+        self
+    }
+
+    /// Set the supertypes
+    pub fn supertypes(mut self, supertypes: Vec<Arc<str>>) -> Self {
+        self.symbol.supertypes = supertypes;
+        self
+    }
+
+    /// Set the relationships
+    pub fn relationships(mut self, relationships: Vec<HirRelationship>) -> Self {
+        self.symbol.relationships = relationships;
+        self
+    }
+
+    /// Set the type references
+    pub fn type_refs(mut self, type_refs: Vec<TypeRefKind>) -> Self {
+        self.symbol.type_refs = type_refs;
+        self
+    }
+
+    /// Set whether the symbol is public
+    pub fn is_public(mut self, is_public: bool) -> Self {
+        self.symbol.is_public = is_public;
+        self
+    }
+
+    /// Set the view-specific data
+    pub fn view_data(mut self, view_data: Option<crate::hir::views::ViewData>) -> Self {
+        self.symbol.view_data = view_data;
+        self
+    }
+
+    /// Set the applied metadata types
+    pub fn metadata_annotations(mut self, metadata_annotations: Vec<Arc<str>>) -> Self {
+        self.symbol.metadata_annotations = metadata_annotations;
+        self
+    }
+
+    /// Set whether the symbol is abstract
+    pub fn is_abstract(mut self, is_abstract: bool) -> Self {
+        self.symbol.is_abstract = is_abstract;
+        self
+    }
+
+    /// Set whether the symbol is a variation
+    pub fn is_variation(mut self, is_variation: bool) -> Self {
+        self.symbol.is_variation = is_variation;
+        self
+    }
+
+    /// Set whether the symbol is readonly
+    pub fn is_readonly(mut self, is_readonly: bool) -> Self {
+        self.symbol.is_readonly = is_readonly;
+        self
+    }
+
+    /// Set whether the symbol is derived
+    pub fn is_derived(mut self, is_derived: bool) -> Self {
+        self.symbol.is_derived = is_derived;
+        self
+    }
+
+    /// Set whether the symbol is parallel
+    pub fn is_parallel(mut self, is_parallel: bool) -> Self {
+        self.symbol.is_parallel = is_parallel;
+        self
+    }
+
+    /// Set whether the symbol is individual
+    pub fn is_individual(mut self, is_individual: bool) -> Self {
+        self.symbol.is_individual = is_individual;
+        self
+    }
+
+    /// Set whether the symbol is an end feature
+    pub fn is_end(mut self, is_end: bool) -> Self {
+        self.symbol.is_end = is_end;
+        self
+    }
+
+    /// Set whether the symbol has a default value
+    pub fn is_default(mut self, is_default: bool) -> Self {
+        self.symbol.is_default = is_default;
+        self
+    }
+
+    /// Set whether the symbol's values are ordered
+    pub fn is_ordered(mut self, is_ordered: bool) -> Self {
+        self.symbol.is_ordered = is_ordered;
+        self
+    }
+
+    /// Set whether the symbol's values are nonunique
+    pub fn is_nonunique(mut self, is_nonunique: bool) -> Self {
+        self.symbol.is_nonunique = is_nonunique;
+        self
+    }
+
+    /// Set whether the symbol is a portion
+    pub fn is_portion(mut self, is_portion: bool) -> Self {
+        self.symbol.is_portion = is_portion;
+        self
+    }
+
+    /// Set the direction
+    pub fn direction(mut self, direction: Option<Direction>) -> Self {
+        self.symbol.direction = direction;
+        self
+    }
+
+    /// Set the multiplicity bounds
+    pub fn multiplicity(mut self, multiplicity: Option<Multiplicity>) -> Self {
+        self.symbol.multiplicity = multiplicity;
+        self
+    }
+
+    /// Set the value expression
+    pub fn value(mut self, value: Option<crate::syntax::normalized::ValueExpression>) -> Self {
+        self.symbol.value = value;
+        self
+    }
+
+    /// Build the symbol
+    pub fn build(self) -> HirSymbol {
+        self.symbol
+    }
+}
+
 /// The kind of a symbol.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SymbolKind {
@@ -485,6 +747,7 @@ pub enum SymbolKind {
     ReferenceUsage,
     OccurrenceUsage,
     FlowConnectionUsage,
+    MetadataUsage,
     // Relationships
     ExposeRelationship,
     // Other
@@ -526,6 +789,8 @@ impl SymbolKind {
             NormalizedDefKind::Behavior => Self::Behavior,
             NormalizedDefKind::Function => Self::Function,
             NormalizedDefKind::Association => Self::Association,
+            NormalizedDefKind::Classifier => Self::PartDefinition,
+            NormalizedDefKind::Predicate => Self::ConstraintDefinition,
             NormalizedDefKind::Metaclass => Self::MetadataDefinition,
             NormalizedDefKind::Interaction => Self::Interaction,
             NormalizedDefKind::Other => Self::Other,
@@ -561,6 +826,7 @@ impl SymbolKind {
             NormalizedUsageKind::View => Self::ViewUsage,
             NormalizedUsageKind::Viewpoint => Self::ViewpointUsage,
             NormalizedUsageKind::Rendering => Self::RenderingUsage,
+            NormalizedUsageKind::Metadata => Self::MetadataUsage,
             NormalizedUsageKind::Other => Self::Other,
         }
     }
@@ -616,6 +882,7 @@ impl SymbolKind {
             Self::ReferenceUsage => "Ref",
             Self::OccurrenceUsage => "Occurrence",
             Self::FlowConnectionUsage => "Flow",
+            Self::MetadataUsage => "Metadata",
             Self::ExposeRelationship => "Expose",
             Self::Import => "Import",
             Self::Alias => "Alias",
@@ -654,6 +921,8 @@ impl SymbolKind {
             NormalizedDefKind::Behavior => Self::Behavior,
             NormalizedDefKind::Function => Self::Function,
             NormalizedDefKind::Association => Self::Association,
+            NormalizedDefKind::Classifier => Self::PartDefinition,
+            NormalizedDefKind::Predicate => Self::ConstraintDefinition,
             NormalizedDefKind::Metaclass => Self::MetadataDefinition,
             NormalizedDefKind::Interaction => Self::Interaction,
             NormalizedDefKind::Other => Self::Other,
@@ -688,6 +957,7 @@ impl SymbolKind {
             NormalizedUsageKind::View => Self::ViewUsage,
             NormalizedUsageKind::Viewpoint => Self::ViewpointUsage,
             NormalizedUsageKind::Rendering => Self::RenderingUsage,
+            NormalizedUsageKind::Metadata => Self::MetadataUsage,
             // KerML features are treated as attribute usages
             NormalizedUsageKind::Feature => Self::AttributeUsage,
             NormalizedUsageKind::Other => Self::Other,
@@ -702,10 +972,12 @@ impl SymbolKind {
 struct ExtractionContext {
     file: FileId,
     prefix: String,
-    /// Counter for generating unique anonymous scope names
-    anon_counter: u32,
+    /// Anonymous usages seen so far in each scope, for unique anonymous names
+    anon_counters: HashMap<String, u32>,
     /// Stack of scope segments for proper push/pop
     scope_stack: Vec<String>,
+    /// Kind of the symbol owning each scope in `scope_stack`
+    scope_kinds: Vec<SymbolKind>,
     /// Line index for converting byte offsets to line/column
     line_index: crate::base::LineIndex,
 }
@@ -724,8 +996,14 @@ impl ExtractionContext {
         self.prefix.clone()
     }
 
-    fn push_scope(&mut self, name: &str) {
+    /// Get the kind of the symbol owning the current scope
+    fn scope_kind(&self) -> Option<SymbolKind> {
+        self.scope_kinds.last().copied()
+    }
+
+    fn push_scope(&mut self, name: &str, kind: SymbolKind) {
         self.scope_stack.push(name.to_string());
+        self.scope_kinds.push(kind);
         if self.prefix.is_empty() {
             self.prefix = name.to_string();
         } else {
@@ -735,6 +1013,7 @@ impl ExtractionContext {
 
     fn pop_scope(&mut self) {
         if let Some(popped) = self.scope_stack.pop() {
+            self.scope_kinds.pop();
             // Remove the last segment (which may contain ::) plus the joining ::
             let suffix_len = if self.scope_stack.is_empty() {
                 popped.len()
@@ -746,10 +1025,17 @@ impl ExtractionContext {
         }
     }
 
-    /// Generate a unique anonymous scope name
-    fn next_anon_scope(&mut self, rel_prefix: &str, target: &str, line: u32) -> String {
-        self.anon_counter += 1;
-        format!("<{}{}#{}@L{}>", rel_prefix, target, self.anon_counter, line)
+    /// Generate a unique anonymous scope name.
+    ///
+    /// Names have the form `<{rel_prefix}{target}#{n}>`, where `n` counts
+    /// anonymous usages in the current scope in source order. The same text
+    /// therefore always extracts to the same names, edits in one scope don't
+    /// renumber another, edits that only shift lines rename nothing, and the
+    /// angle brackets keep them apart from any name a user can write.
+    fn next_anon_scope(&mut self, rel_prefix: &str, target: &str) -> String {
+        let counter = self.anon_counters.entry(self.prefix.clone()).or_default();
+        *counter += 1;
+        format!("<{}{}#{}>", rel_prefix, target, counter)
     }
 
     /// Convert a TextRange to SpanInfo using the line index
@@ -769,24 +1055,13 @@ impl ExtractionContext {
         }
     }
 
-    /// Convert a TextRange to optional line/col values (for short_name fields)
-    fn range_to_optional(
-        &self,
-        range: Option<rowan::TextRange>,
-    ) -> (Option<u32>, Option<u32>, Option<u32>, Option<u32>) {
-        match range {
-            Some(r) => {
-                let start = self.line_index.line_col(r.start());
-                let end = self.line_index.line_col(r.end());
-                (
-                    Some(start.line),
-                    Some(start.col),
-                    Some(end.line),
-                    Some(end.col),
-                )
-            }
-            None => (None, None, None, None),
-        }
+    /// Convert an optional TextRange to a line/col span (for short_name fields)
+    fn range_to_span(&self, range: Option<rowan::TextRange>) -> Option<LineColSpan> {
+        let r = range?;
+        Some(LineColSpan::new(
+            self.line_index.line_col(r.start()),
+            self.line_index.line_col(r.end()),
+        ))
     }
 }
 
@@ -825,8 +1100,9 @@ pub fn extract_with_filters(file: FileId, syntax: &crate::syntax::SyntaxFile) ->
     let mut context = ExtractionContext {
         file,
         prefix: String::new(),
-        anon_counter: 0,
+        anon_counters: HashMap::new(),
         scope_stack: Vec::new(),
+        scope_kinds: Vec::new(),
         line_index,
     };
 
@@ -909,43 +1185,17 @@ fn extract_from_normalized(
                 // Create an anonymous symbol to hold the type_refs
                 let span = ctx.range_to_info(filter.range);
                 let filter_qname = ctx.qualified_name(&format!("<filter@L{}>", span.start_line));
-                result.symbols.push(HirSymbol {
-                    name: Arc::from("<filter>"),
-                    short_name: None,
-                    qualified_name: Arc::from(filter_qname.as_str()),
-                    element_id: new_element_id(),
-                    kind: SymbolKind::Other,
-                    file: ctx.file,
-                    start_line: span.start_line,
-                    start_col: span.start_col,
-                    end_line: span.end_line,
-                    end_col: span.end_col,
-                    short_name_start_line: None,
-                    short_name_start_col: None,
-                    short_name_end_line: None,
-                    short_name_end_col: None,
-                    doc: None,
-                    supertypes: Vec::new(),
-                    relationships: Vec::new(),
-                    type_refs,
-                    is_public: false,
-                    view_data: None,
-                    metadata_annotations: Vec::new(),
-                    is_abstract: false,
-                    is_variation: false,
-                    is_readonly: false,
-                    is_derived: false,
-                    is_parallel: false,
-                    is_individual: false,
-                    is_end: false,
-                    is_default: false,
-                    is_ordered: false,
-                    is_nonunique: false,
-                    is_portion: false,
-                    direction: None,
-                    multiplicity: None,
-                    value: None,
-                });
+                result.symbols.push(
+                    HirSymbol::builder(
+                        "<filter>",
+                        filter_qname.as_str(),
+                        SymbolKind::Other,
+                        ctx.file,
+                    )
+                    .span(span.into())
+                    .type_refs(type_refs)
+                    .build(),
+                );
             }
         }
         NormalizedElement::Expose(_expose) => {
@@ -1004,51 +1254,30 @@ fn extract_from_normalized_package(
     // Extract doc comment
     let doc = pkg.doc.as_ref().map(|s| Arc::from(s.trim()));
 
-    result.symbols.push(HirSymbol {
-        name: Arc::from(name.as_str()),
-        short_name: pkg.short_name.as_ref().map(|s| Arc::from(s.as_str())),
-        qualified_name: Arc::from(qualified_name.as_str()),
-        element_id: new_element_id(),
-        kind: SymbolKind::Package,
-        file: ctx.file,
-        start_line: span.start_line,
-        start_col: span.start_col,
-        end_line: span.end_line,
-        end_col: span.end_col,
-        short_name_start_line: None,
-        short_name_start_col: None,
-        short_name_end_line: None,
-        short_name_end_col: None,
-        doc,
-        supertypes: Vec::new(),
-        relationships: Vec::new(),
-        type_refs: Vec::new(),
-        is_public: false,
-        view_data: None,
-        metadata_annotations: Vec::new(),
-        is_abstract: false,
-        is_variation: false,
-        is_readonly: false,
-        is_derived: false,
-        is_parallel: false,
-        is_individual: false,
-        is_end: false,
-        is_default: false,
-        is_ordered: false,
-        is_nonunique: false,
-        is_portion: false,
-        direction: None,
-        multiplicity: None,
-        value: None,
-    });
-
-    ctx.push_scope(&name);
+    result.symbols.push(
+        HirSymbol::builder(
+            name.as_str(),
+            qualified_name.as_str(),
+            SymbolKind::Package,
+            ctx.file,
+        )
+        .short_name(pkg.short_name.as_deref())
+        .span(span.into())
+        .doc(doc)
+        .build(),
+    );
+
+    ctx.push_scope(&name, SymbolKind::Package);
     for child in &pkg.children {
         extract_from_normalized(result, ctx, child);
     }
     ctx.pop_scope();
 }
 
+/// The root classifier of the kernel library, which every type implicitly
+/// specializes.
+pub(crate) const ROOT_CLASSIFIER: &str = "Base::Anything";
+
 /// Get the implicit supertype for a definition kind based on SysML kernel library.
 /// In SysML, all definitions implicitly specialize their kernel metaclass:
 /// - `part def X` implicitly specializes `Parts::Part`
@@ -1077,6 +1306,23 @@ fn implicit_supertype_for_def_kind(kind: NormalizedDefKind) -> Option<&'static s
     }
 }
 
+/// Map a KerML definition kind to the SysML kind it is indexed as.
+/// KerML classifiers are treated like their SysML counterparts so they share
+/// symbol kinds and implicit supertypes (e.g., `class` behaves as `part def`).
+fn sysml_def_kind(kind: NormalizedDefKind) -> NormalizedDefKind {
+    match kind {
+        NormalizedDefKind::Class | NormalizedDefKind::Structure | NormalizedDefKind::Classifier => {
+            NormalizedDefKind::Part
+        }
+        NormalizedDefKind::DataType => NormalizedDefKind::Attribute,
+        NormalizedDefKind::Association => NormalizedDefKind::Connection,
+        NormalizedDefKind::Behavior | NormalizedDefKind::Interaction => NormalizedDefKind::Action,
+        NormalizedDefKind::Function => NormalizedDefKind::Calculation,
+        NormalizedDefKind::Predicate => NormalizedDefKind::Constraint,
+        other => other,
+    }
+}
+
 /// Get the implicit supertype for a usage kind based on SysML kernel library.
 /// In SysML, usages implicitly specialize their kernel metaclass base type:
 /// - `part x` implicitly specializes `Parts::Part`
@@ -1098,7 +1344,10 @@ fn implicit_supertype_for_usage_kind(kind: NormalizedUsageKind) -> Option<&'stat
         NormalizedUsageKind::Constraint => Some("Constraints::ConstraintCheck"),
         NormalizedUsageKind::Calculation => Some("Calculations::Calculation"),
         NormalizedUsageKind::Port => Some("Ports::Port"),
-        NormalizedUsageKind::Attribute => Some("Attributes::AttributeValue"),
+        // KerML features are indexed as attribute usages
+        NormalizedUsageKind::Attribute | NormalizedUsageKind::Feature => {
+            Some("Attributes::AttributeValue")
+        }
         _ => None,
     }
 }
@@ -1182,6 +1431,39 @@ fn extract_metadata_annotations(
     annotations
 }
 
+/// Extract references for the feature values assigned in a metadata usage body.
+///
+/// For `@StatusInfo { status = StatusKind::closed; }` this yields a
+/// `Redefines` reference to `status` and the references in its value.
+fn extract_metadata_feature_refs(
+    usage: &NormalizedUsage,
+    line_index: &crate::base::LineIndex,
+) -> Vec<TypeRefKind> {
+    let mut refs = Vec::new();
+    for child in &usage.children {
+        let NormalizedElement::Usage(feature) = child else {
+            continue;
+        };
+        if let (Some(name), Some(range)) = (&feature.name, feature.name_range) {
+            let start = line_index.line_col(range.start());
+            let end = line_index.line_col(range.end());
+            refs.push(TypeRefKind::Simple(TypeRef::new(
+                strip_quotes(name),
+                RefKind::Redefines,
+                start.line,
+                start.col,
+                end.line,
+                end.col,
+            )));
+        }
+        refs.extend(extract_type_refs_from_normalized(
+            &feature.relationships,
+            line_index,
+        ));
+    }
+    refs
+}
+
 fn extract_from_normalized_definition(
     symbols: &mut Vec<HirSymbol>,
     ctx: &mut ExtractionContext,
@@ -1193,11 +1475,11 @@ fn extract_from_normalized_definition(
     };
 
     let qualified_name = ctx.qualified_name(&name);
-    let kind = SymbolKind::from_normalized_def_kind(def.kind);
+    let def_kind = sysml_def_kind(def.kind);
+    let kind = SymbolKind::from_normalized_def_kind(def_kind);
     // Use name_range for precise position, fall back to full range
     let span = ctx.range_to_info(def.name_range.or(def.range));
-    let (sn_start_line, sn_start_col, sn_end_line, sn_end_col) =
-        ctx.range_to_optional(def.short_name_range);
+    let sn_span = ctx.range_to_span(def.short_name_range);
 
     // Extract explicit supertypes from relationships
     let mut supertypes: Vec<Arc<str>> = def
@@ -1210,7 +1492,7 @@ fn extract_from_normalized_definition(
     // Add implicit supertypes from SysML kernel library if no explicit specialization
     // This models the implicit inheritance: part def → Part, item def → Item, etc.
     if supertypes.is_empty() {
-        if let Some(implicit) = implicit_supertype_for_def_kind(def.kind) {
+        if let Some(implicit) = implicit_supertype_for_def_kind(def_kind) {
             supertypes.push(Arc::from(implicit));
         }
     }
@@ -1228,48 +1510,27 @@ fn extract_from_normalized_definition(
     let doc = def.doc.as_ref().map(|s| Arc::from(s.trim()));
 
     // Extract view-specific data if this is a view/viewpoint/rendering
-    let view_data = extract_view_data_from_definition(def, def.kind);
-
-    symbols.push(HirSymbol {
-        name: Arc::from(name.as_str()),
-        short_name: def.short_name.as_ref().map(|s| Arc::from(s.as_str())),
-        qualified_name: Arc::from(qualified_name.as_str()),
-        element_id: new_element_id(),
-        kind,
-        file: ctx.file,
-        start_line: span.start_line,
-        start_col: span.start_col,
-        end_line: span.end_line,
-        end_col: span.end_col,
-        short_name_start_line: sn_start_line,
-        short_name_start_col: sn_start_col,
-        short_name_end_line: sn_end_line,
-        short_name_end_col: sn_end_col,
-        doc,
-        supertypes,
-        relationships,
-        type_refs,
-        is_public: false,
-        view_data,
-        metadata_annotations,
-        is_abstract: def.is_abstract,
-        is_variation: def.is_variation,
-        is_readonly: false,
-        is_derived: false,
-        is_parallel: false,
-        is_individual: def.is_individual,
-        is_end: false,
-        is_default: false,
-        is_ordered: false,
-        is_nonunique: false,
-        is_portion: false,
-        direction: None,
-        multiplicity: None,
-        value: None,
-    });
+    let view_data = extract_view_data_from_definition(def, def_kind);
+
+    symbols.push(
+        HirSymbol::builder(name.as_str(), qualified_name.as_str(), kind, ctx.file)
+            .short_name(def.short_name.as_deref())
+            .span(span.into())
+            .short_name_span(sn_span)
+            .doc(doc)
+            .supertypes(supertypes)
+            .relationships(relationships)
+            .type_refs(type_refs)
+            .view_data(view_data)
+            .metadata_annotations(metadata_annotations)
+            .is_abstract(def.is_abstract)
+            .is_variation(def.is_variation)
+            .is_individual(def.is_individual)
+            .build(),
+    );
 
     // Recurse into children
-    ctx.push_scope(&name);
+    ctx.push_scope(&name, kind);
     for child in &def.children {
         extract_from_normalized_into_symbols(symbols, ctx, child);
     }
@@ -1282,11 +1543,39 @@ fn extract_from_normalized_usage(
     usage: &NormalizedUsage,
 ) {
     // Extract type references even for anonymous usages
-    let type_refs = extract_type_refs_from_normalized(&usage.relationships, &ctx.line_index);
+    let mut type_refs = extract_type_refs_from_normalized(&usage.relationships, &ctx.line_index);
 
-    // Extract all relationships for hover display
-    let relationships =
-        extract_relationships_from_normalized(&usage.relationships, &ctx.line_index);
+    // A metadata usage (`@StatusInfo { status = ...; }`) owns the references in
+    // its body instead of extracting the assigned features as separate symbols
+    let is_metadata = matches!(usage.kind, NormalizedUsageKind::Metadata);
+    if is_metadata {
+        type_refs.extend(extract_metadata_feature_refs(usage, &ctx.line_index));
+    }
+
+    // Extract all relationships for hover display. `:>` on a feature is
+    // subsetting rather than specialization, so record it as such in both the
+    // relationships and the type references.
+    let relationships: Vec<HirRelationship> =
+        extract_relationships_from_normalized(&usage.relationships, &ctx.line_index)
+            .into_iter()
+            .map(|mut rel| {
+                if rel.kind == RelationshipKind::Specializes {
+                    rel.kind = RelationshipKind::Subsets;
+                }
+                rel
+            })
+            .collect();
+    for type_ref in &mut type_refs {
+        let parts = match type_ref {
+            TypeRefKind::Simple(part) => std::slice::from_mut(part),
+            TypeRefKind::Chain(chain) => chain.parts.as_mut_slice(),
+        };
+        for part in parts {
+            if part.kind == RefKind::Specializes {
+                part.kind = RefKind::Subsets;
+            }
+        }
+    }
 
     // Extract metadata annotations for filter imports
     let metadata_annotations = extract_metadata_annotations(&usage.relationships, &usage.children);
@@ -1299,7 +1588,8 @@ fn extract_from_normalized_usage(
             // Feature refs (Redefines, Subsets, Specializes) need inheritance context
             // that the parent doesn't have
             // Also skip packages - they shouldn't have type_refs from their anonymous children
-            if !type_refs.is_empty() {
+            // Metadata usages keep their references on their own symbol
+            if !type_refs.is_empty() && !is_metadata {
                 if let Some(parent) = symbols
                     .iter_mut()
                     .rev()
@@ -1322,11 +1612,6 @@ fn extract_from_normalized_usage(
 
             // Generate unique anonymous scope name for children
             // Try to use relationship target for meaningful names, otherwise use generic anon
-            let line = usage
-                .range
-                .map(|r| ctx.line_index.line_col(r.start()).line)
-                .unwrap_or(0);
-
             let anon_scope = usage
                 .relationships
                 .iter()
@@ -1350,6 +1635,7 @@ fn extract_from_normalized_usage(
                         NormalizedRelKind::Expression => "~",
                         NormalizedRelKind::FeatureChain => "chain:",
                         NormalizedRelKind::Conjugates => "~:",
+                        NormalizedRelKind::TypeOperand => "op:",
                         // State/Transition
                         NormalizedRelKind::TransitionSource => "from:",
                         NormalizedRelKind::TransitionTarget => "then:",
@@ -1385,10 +1671,10 @@ fn extract_from_normalized_usage(
                         NormalizedRelKind::DependencySource => "dep:",
                         NormalizedRelKind::DependencyTarget => "to:",
                     };
-                    ctx.next_anon_scope(prefix, &r.target.as_str(), line)
+                    ctx.next_anon_scope(prefix, &r.target.as_str())
                 })
                 // Fallback: always create a unique scope for anonymous usages with children
-                .unwrap_or_else(|| ctx.next_anon_scope("anon", "", line));
+                .unwrap_or_else(|| ctx.next_anon_scope("anon", ""));
 
             // Create a symbol for the anonymous usage so it can be looked up during resolution
             // This is needed for satisfy/perform/exhibit blocks where children need to resolve
@@ -1447,7 +1733,7 @@ fn extract_from_normalized_usage(
                     | NormalizedUsageKind::Allocation
             );
 
-            if !is_expression_scope && !is_connection_kind {
+            if !is_expression_scope && !is_connection_kind && !is_metadata {
                 if let Some(parent) = symbols
                     .iter()
                     .rev()
@@ -1461,50 +1747,38 @@ fn extract_from_normalized_usage(
                 }
             }
 
-            let anon_symbol = HirSymbol {
-                file: ctx.file,
-                name: Arc::from(anon_scope.as_str()),
-                short_name: None,
-                qualified_name: Arc::from(qualified_name.as_str()),
-                element_id: new_element_id(),
-                kind,
-                start_line: span.start_line,
-                start_col: span.start_col,
-                end_line: span.end_line,
-                end_col: span.end_col,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: anon_supertypes,
-                relationships: relationships.clone(),
-                type_refs,
-                doc: None,
-                is_public: false,
-                view_data: None,
-                metadata_annotations: metadata_annotations.clone(),
-                is_abstract: usage.is_abstract,
-                is_variation: usage.is_variation,
-                is_readonly: usage.is_readonly,
-                is_derived: usage.is_derived,
-                is_parallel: usage.is_parallel,
-                is_individual: usage.is_individual,
-                is_end: usage.is_end,
-                is_default: usage.is_default,
-                is_ordered: usage.is_ordered,
-                is_nonunique: usage.is_nonunique,
-                is_portion: usage.is_portion,
-                direction: usage.direction,
-                multiplicity: usage.multiplicity,
-                value: None,
-            };
+            let anon_symbol =
+                HirSymbol::builder(anon_scope.as_str(), qualified_name.as_str(), kind, ctx.file)
+                    .span(span.into())
+                    .supertypes(anon_supertypes)
+                    .relationships(relationships.clone())
+                    .type_refs(type_refs)
+                    .metadata_annotations(metadata_annotations.clone())
+                    .is_abstract(usage.is_abstract)
+                    .is_variation(usage.is_variation)
+                    .is_readonly(usage.is_readonly)
+                    .is_derived(usage.is_derived)
+                    .is_parallel(usage.is_parallel)
+                    .is_individual(usage.is_individual)
+                    .is_end(usage.is_end)
+                    .is_default(usage.is_default)
+                    .is_ordered(usage.is_ordered)
+                    .is_nonunique(usage.is_nonunique)
+                    .is_portion(usage.is_portion)
+                    .direction(usage.direction)
+                    .multiplicity(usage.multiplicity)
+                    .build();
             symbols.push(anon_symbol);
 
             // Push scope for children of anonymous usages
-            ctx.push_scope(&anon_scope);
+            ctx.push_scope(&anon_scope, kind);
 
-            // Recurse into children for anonymous usages
+            // Recurse into children for anonymous usages. The feature values of
+            // a metadata usage were already taken as references above.
             for child in &usage.children {
+                if is_metadata && matches!(child, NormalizedElement::Usage(_)) {
+                    continue;
+                }
                 extract_from_normalized_into_symbols(symbols, ctx, child);
             }
 
@@ -1514,11 +1788,19 @@ fn extract_from_normalized_usage(
     };
 
     let qualified_name = ctx.qualified_name(&name);
-    let kind = SymbolKind::from_normalized_usage_kind(usage.kind);
+    let mut kind = SymbolKind::from_normalized_usage_kind(usage.kind);
     // Use name_range for precise position, fall back to full range
     let span = ctx.range_to_info(usage.name_range.or(usage.range));
-    let (sn_start_line, sn_start_col, sn_end_line, sn_end_col) =
-        ctx.range_to_optional(usage.short_name_range);
+    let sn_span = ctx.range_to_span(usage.short_name_range);
+
+    // Members of an enumeration definition are its literals
+    // (`enum def Color { enum red; green; }`), which are values of the enumeration
+    // The kind of the enclosing scope was recorded when entering it
+    let enumeration = (ctx.scope_kind() == Some(SymbolKind::EnumerationDefinition))
+        .then(|| Arc::<str>::from(ctx.prefix.as_str()));
+    if enumeration.is_some() {
+        kind = SymbolKind::AttributeUsage;
+    }
 
     // Extract typing and subsetting as supertypes
     // For member resolution, we need to look in:
@@ -1549,6 +1831,10 @@ fn extract_from_normalized_usage(
         .map(|r| Arc::from(r.target.as_str().as_ref()))
         .collect();
 
+    if supertypes.is_empty() {
+        supertypes.extend(enumeration);
+    }
+
     // Detect implicit redefinition: if parent has a type, and that type has a member
     // with the same name as this usage, then this usage implicitly redefines that member.
     // e.g., `action transport : TransportScenario { action trigger { ... } }`
@@ -1602,46 +1888,36 @@ fn extract_from_normalized_usage(
     let typed_by = supertypes.first();
     let view_data = extract_view_data_from_usage(usage, usage.kind, typed_by);
 
-    symbols.push(HirSymbol {
-        name: Arc::from(name.as_str()),
-        short_name: usage.short_name.as_ref().map(|s| Arc::from(s.as_str())),
-        qualified_name: Arc::from(qualified_name.as_str()),
-        element_id: new_element_id(),
-        kind,
-        file: ctx.file,
-        start_line: span.start_line,
-        start_col: span.start_col,
-        end_line: span.end_line,
-        end_col: span.end_col,
-        short_name_start_line: sn_start_line,
-        short_name_start_col: sn_start_col,
-        short_name_end_line: sn_end_line,
-        short_name_end_col: sn_end_col,
-        doc,
-        supertypes,
-        relationships,
-        type_refs,
-        is_public: false,
-        view_data,
-        metadata_annotations,
-        is_abstract: usage.is_abstract,
-        is_variation: usage.is_variation,
-        is_readonly: usage.is_readonly,
-        is_derived: usage.is_derived,
-        is_parallel: usage.is_parallel,
-        is_individual: usage.is_individual,
-        is_end: usage.is_end,
-        is_default: usage.is_default,
-        is_ordered: usage.is_ordered,
-        is_nonunique: usage.is_nonunique,
-        is_portion: usage.is_portion,
-        direction: usage.direction,
-        multiplicity: usage.multiplicity,
-        value: usage.value.clone(),
-    });
+    symbols.push(
+        HirSymbol::builder(name.as_str(), qualified_name.as_str(), kind, ctx.file)
+            .short_name(usage.short_name.as_deref())
+            .span(span.into())
+            .short_name_span(sn_span)
+            .doc(doc)
+            .supertypes(supertypes)
+            .relationships(relationships)
+            .type_refs(type_refs)
+            .view_data(view_data)
+            .metadata_annotations(metadata_annotations)
+            .is_abstract(usage.is_abstract)
+            .is_variation(usage.is_variation)
+            .is_readonly(usage.is_readonly)
+            .is_derived(usage.is_derived)
+            .is_parallel(usage.is_parallel)
+            .is_individual(usage.is_individual)
+            .is_end(usage.is_end)
+            .is_default(usage.is_default)
+            .is_ordered(usage.is_ordered)
+            .is_nonunique(usage.is_nonunique)
+            .is_portion(usage.is_portion)
+            .direction(usage.direction)
+            .multiplicity(usage.multiplicity)
+            .value(usage.value.clone())
+            .build(),
+    );
 
     // Recurse into children
-    ctx.push_scope(&name);
+    ctx.push_scope(&name, kind);
     for child in &usage.children {
         extract_from_normalized_into_symbols(symbols, ctx, child);
     }
@@ -1686,43 +1962,18 @@ fn extract_from_normalized_import(
         Vec::new()
     };
 
-    symbols.push(HirSymbol {
-        name: Arc::from(path.as_str()),
-        short_name: None, // Imports don't have short names
-        qualified_name: Arc::from(qualified_name.as_str()),
-        element_id: new_element_id(),
-        kind: SymbolKind::Import,
-        file: ctx.file,
-        start_line: span.start_line,
-        start_col: span.start_col,
-        end_line: span.end_line,
-        end_col: span.end_col,
-        short_name_start_line: None,
-        short_name_start_col: None,
-        short_name_end_line: None,
-        short_name_end_col: None,
-        doc: None,
-        supertypes: Vec::new(),
-        relationships: Vec::new(),
-        type_refs,
-        is_public: import.is_public,
-        view_data: None,
-        metadata_annotations: Vec::new(), // Imports don't have metadata
-        is_abstract: false,
-        is_variation: false,
-        is_readonly: false,
-        is_derived: false,
-        is_parallel: false,
-        is_individual: false,
-        is_end: false,
-        is_default: false,
-        is_ordered: false,
-        is_nonunique: false,
-        is_portion: false,
-        direction: None,
-        multiplicity: None,
-        value: None,
-    });
+    symbols.push(
+        HirSymbol::builder(
+            path.as_str(),
+            qualified_name.as_str(),
+            SymbolKind::Import,
+            ctx.file,
+        )
+        .span(span.into())
+        .type_refs(type_refs)
+        .is_public(import.is_public)
+        .build(),
+    );
 }
 
 fn extract_from_normalized_alias(
@@ -1756,43 +2007,19 @@ fn extract_from_normalized_alias(
         Vec::new()
     };
 
-    symbols.push(HirSymbol {
-        name: Arc::from(name.as_str()),
-        short_name: alias.short_name.as_ref().map(|s| Arc::from(s.as_str())),
-        qualified_name: Arc::from(qualified_name.as_str()),
-        element_id: new_element_id(),
-        kind: SymbolKind::Alias,
-        file: ctx.file,
-        start_line: span.start_line,
-        start_col: span.start_col,
-        end_line: span.end_line,
-        end_col: span.end_col,
-        short_name_start_line: None, // Aliases don't have tracked short_name_span
-        short_name_start_col: None,
-        short_name_end_line: None,
-        short_name_end_col: None,
-        doc: None,
-        supertypes: vec![Arc::from(alias.target.as_str())],
-        relationships: Vec::new(),
-        type_refs,
-        is_public: false,
-        view_data: None,
-        metadata_annotations: Vec::new(), // Aliases don't have metadata
-        is_abstract: false,
-        is_variation: false,
-        is_readonly: false,
-        is_derived: false,
-        is_parallel: false,
-        is_individual: false,
-        is_end: false,
-        is_default: false,
-        is_ordered: false,
-        is_nonunique: false,
-        is_portion: false,
-        direction: None,
-        multiplicity: None,
-        value: None,
-    });
+    symbols.push(
+        HirSymbol::builder(
+            name.as_str(),
+            qualified_name.as_str(),
+            SymbolKind::Alias,
+            ctx.file,
+        )
+        .short_name(alias.short_name.as_deref())
+        .span(span.into())
+        .supertypes(vec![Arc::from(alias.target.as_str())])
+        .type_refs(type_refs)
+        .build(),
+    );
 }
 
 fn extract_from_normalized_comment(
@@ -1825,47 +2052,23 @@ fn extract_from_normalized_comment(
     let qualified_name = ctx.qualified_name(&name);
     let span = ctx.range_to_info(comment.range);
 
-    symbols.push(HirSymbol {
-        name: Arc::from(name.as_str()),
-        short_name: comment.short_name.as_ref().map(|s| Arc::from(s.as_str())),
-        qualified_name: Arc::from(qualified_name.as_str()),
-        element_id: new_element_id(),
-        kind: SymbolKind::Comment,
-        file: ctx.file,
-        start_line: span.start_line,
-        start_col: span.start_col,
-        end_line: span.end_line,
-        end_col: span.end_col,
-        short_name_start_line: None, // Comments don't have tracked short_name_span
-        short_name_start_col: None,
-        short_name_end_line: None,
-        short_name_end_col: None,
-        doc: if is_anonymous {
+    symbols.push(
+        HirSymbol::builder(
+            name.as_str(),
+            qualified_name.as_str(),
+            SymbolKind::Comment,
+            ctx.file,
+        )
+        .short_name(comment.short_name.as_deref())
+        .span(span.into())
+        .doc(if is_anonymous {
             None
         } else {
             Some(Arc::from(comment.content.as_str()))
-        },
-        supertypes: Vec::new(),
-        relationships: Vec::new(),
-        type_refs,
-        is_public: false,
-        view_data: None,
-        metadata_annotations: Vec::new(), // Comments don't have metadata
-        is_abstract: false,
-        is_variation: false,
-        is_readonly: false,
-        is_derived: false,
-        is_parallel: false,
-        is_individual: false,
-        is_end: false,
-        is_default: false,
-        is_ordered: false,
-        is_nonunique: false,
-        is_portion: false,
-        direction: None,
-        multiplicity: None,
-        value: None,
-    });
+        })
+        .type_refs(type_refs)
+        .build(),
+    );
 }
 
 /// Extract type references from normalized relationships.
@@ -1995,85 +2198,34 @@ fn extract_from_normalized_dependency(
         let qualified_name = ctx.qualified_name(name);
         let span = ctx.range_to_info(dep.range);
 
-        symbols.push(HirSymbol {
-            name: Arc::from(name.as_str()),
-            short_name: dep.short_name.as_ref().map(|s| Arc::from(s.as_str())),
-            qualified_name: Arc::from(qualified_name.as_str()),
-            element_id: new_element_id(),
-            kind: SymbolKind::Dependency,
-            file: ctx.file,
-            start_line: span.start_line,
-            start_col: span.start_col,
-            end_line: span.end_line,
-            end_col: span.end_col,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs,
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        });
+        symbols.push(
+            HirSymbol::builder(
+                name.as_str(),
+                qualified_name.as_str(),
+                SymbolKind::Dependency,
+                ctx.file,
+            )
+            .short_name(dep.short_name.as_deref())
+            .span(span.into())
+            .type_refs(type_refs)
+            .build(),
+        );
     } else if !type_refs.is_empty() {
         // Anonymous dependency - attach type refs to parent or create anonymous symbol
         // For now, create an anonymous symbol so refs are tracked
         let span = ctx.range_to_info(dep.range);
 
-        symbols.push(HirSymbol {
-            name: Arc::from("<anonymous-dependency>"),
-            short_name: None,
-            qualified_name: Arc::from(format!("{}::<anonymous-dependency>", ctx.prefix)),
-            element_id: new_element_id(),
-            kind: SymbolKind::Dependency,
-            file: ctx.file,
-            start_line: span.start_line,
-            start_col: span.start_col,
-            end_line: span.end_line,
-            end_col: span.end_col,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs,
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        });
+        symbols.push(
+            HirSymbol::builder(
+                "<anonymous-dependency>",
+                format!("{}::<anonymous-dependency>", ctx.prefix),
+                SymbolKind::Dependency,
+                ctx.file,
+            )
+            .span(span.into())
+            .type_refs(type_refs)
+            .build(),
+        );
     }
 }
 
@@ -2090,6 +2242,12 @@ struct SpanInfo {
     end_col: u32,
 }
 
+impl From<SpanInfo> for LineColSpan {
+    fn from(span: SpanInfo) -> Self {
+        LineColSpan::from_coords(span.start_line, span.start_col, span.end_line, span.end_col)
+    }
+}
+
 /// Strip single quotes from a string.
 fn strip_quotes(s: &str) -> String {
     if s.starts_with('\'') && s.ends_with('\'') && s.len() >= 2 {
@@ -2121,24 +2279,92 @@ mod tests {
         let mut ctx = ExtractionContext {
             file: FileId::new(0),
             prefix: String::new(),
-            anon_counter: 0,
+            anon_counters: HashMap::new(),
             scope_stack: Vec::new(),
+            scope_kinds: Vec::new(),
             line_index: crate::base::LineIndex::new(""),
         };
 
         assert_eq!(ctx.qualified_name("Foo"), "Foo");
 
-        ctx.push_scope("Outer");
+        ctx.push_scope("Outer", SymbolKind::Package);
         assert_eq!(ctx.qualified_name("Inner"), "Outer::Inner");
 
-        ctx.push_scope("Deep");
+        ctx.push_scope("Deep", SymbolKind::PartDefinition);
         assert_eq!(ctx.qualified_name("Leaf"), "Outer::Deep::Leaf");
+        assert_eq!(ctx.scope_kind(), Some(SymbolKind::PartDefinition));
 
         ctx.pop_scope();
         assert_eq!(ctx.qualified_name("Sibling"), "Outer::Sibling");
+        assert_eq!(ctx.scope_kind(), Some(SymbolKind::Package));
 
         ctx.pop_scope();
         assert_eq!(ctx.qualified_name("Root"), "Root");
+        assert_eq!(ctx.scope_kind(), None);
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let symbol = HirSymbol::builder(
+            "Car",
+            "Vehicles::Car",
+            SymbolKind::PartDefinition,
+            FileId::new(3),
+        )
+        .build();
+
+        assert_eq!(symbol.name.as_ref(), "Car");
+        assert_eq!(symbol.qualified_name.as_ref(), "Vehicles::Car");
+        assert_eq!(symbol.kind, SymbolKind::PartDefinition);
+        assert_eq!(symbol.file, FileId::new(3));
+        assert!(!symbol.element_id.is_empty());
+        assert_eq!(symbol.span(), LineColSpan::default());
+        assert_eq!(symbol.short_name, None);
+        assert_eq!(symbol.short_name_span(), None);
+        assert_eq!(symbol.doc, None);
+        assert!(symbol.supertypes.is_empty());
+        assert!(symbol.relationships.is_empty());
+        assert!(symbol.type_refs.is_empty());
+        assert!(symbol.metadata_annotations.is_empty());
+        assert!(!symbol.is_public);
+        assert!(!symbol.is_abstract && !symbol.is_readonly && !symbol.is_ordered);
+        assert_eq!(symbol.view_data, None);
+        assert_eq!(symbol.direction, None);
+        assert_eq!(symbol.multiplicity, None);
+        assert_eq!(symbol.value, None);
+    }
+
+    #[test]
+    fn test_builder_setters() {
+        let span = LineColSpan::from_coords(2, 4, 2, 20);
+        let short_span = LineColSpan::from_coords(2, 14, 2, 16);
+        let symbol = HirSymbol::builder(
+            "kilogram",
+            "SI::kilogram",
+            SymbolKind::AttributeUsage,
+            FileId::new(0),
+        )
+        .short_name(Some("kg"))
+        .element_id("xmi-1")
+        .span(span)
+        .short_name_span(Some(short_span))
+        .doc(Some(Arc::from("SI unit of mass")))
+        .supertypes(vec![Arc::from("MassUnit")])
+        .is_public(true)
+        .is_readonly(true)
+        .direction(Some(Direction::In))
+        .build();
+
+        assert_eq!(symbol.short_name.as_deref(), Some("kg"));
+        assert_eq!(symbol.element_id.as_ref(), "xmi-1");
+        assert_eq!(symbol.span(), span);
+        assert_eq!(symbol.short_name_span(), Some(short_span));
+        assert_eq!(symbol.doc.as_deref(), Some("SI unit of mass"));
+        assert_eq!(symbol.supertypes, vec![Arc::<str>::from("MassUnit")]);
+        assert!(symbol.is_public);
+        assert!(symbol.is_readonly);
+        assert!(!symbol.is_derived);
+        assert_eq!(symbol.direction, Some(Direction::In));
     }
 
     #[test]
@@ -2209,6 +2435,30 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_kerml_feature_implicit_supertype() {
+        use crate::base::FileId;
+        use crate::syntax::parser::parse_content;
+
+        let source = "classifier Car {\n    feature mass;\n    feature wheels : Wheel;\n}\n";
+        let syntax = parse_content(source, std::path::Path::new("test.kerml")).unwrap();
+        let symbols = super::extract_symbols_unified(FileId::new(0), &syntax);
+
+        let mass = symbols.iter().find(|s| s.name.as_ref() == "mass").unwrap();
+        assert_eq!(mass.kind, SymbolKind::AttributeUsage);
+        assert_eq!(
+            mass.supertypes,
+            vec![Arc::<str>::from("Attributes::AttributeValue")]
+        );
+
+        // An explicit type replaces the implicit supertype
+        let wheels = symbols
+            .iter()
+            .find(|s| s.name.as_ref() == "wheels")
+            .unwrap();
+        assert_eq!(wheels.supertypes, vec![Arc::<str>::from("Wheel")]);
+    }
 }
 
 #[cfg(test)]
@@ -16,20 +16,40 @@
 //! let hover = analysis.hover(file_id, line, col);
 //! let symbols = analysis.document_symbols(file_id);
 //! ```
+//!
+//! An `Analysis` shares the host's state through `Arc`s, so it is cheap to
+//! clone and can be sent to other threads. The host copies state on write
+//! while a snapshot is outstanding, so later edits never change a snapshot.
 
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::base::FileId;
 use crate::hir::{HirSymbol, SymbolIndex, extract_with_filters};
 use crate::syntax::SyntaxFile;
 
+use super::text_utils::is_word_character;
 use super::{
-    CompletionItem, DocumentLink, FoldingRange, GotoResult, HoverResult, InlayHint,
-    ReferenceResult, SelectionRange, SemanticToken, SymbolInfo,
+    CompletionItem, DocumentLink, FoldingOptions, FoldingRange, GotoResult, HoverResult,
+    IncomingCall, InlayHint, InlayHintOptions, MatchOptions, OutgoingCall, ReferenceResult,
+    SelectionRange, SemanticToken, SignatureInfo, SymbolInfo, SymbolNode,
 };
 
+/// Per-file timings and counts, recorded when stats collection is enabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FileStats {
+    /// Time spent parsing the file's text, in microseconds.
+    ///
+    /// Zero for files added pre-parsed through `set_file()`.
+    pub parse_micros: u64,
+    /// Time spent extracting symbols from the parsed file, in microseconds.
+    pub extract_micros: u64,
+    /// Number of symbols extracted from the file.
+    pub symbol_count: usize,
+}
+
 /// Owns all mutable state for the IDE layer.
 ///
 /// Apply changes via `set_file_content()` and `remove_file()`,
@@ -37,13 +57,15 @@ use super::{
 #[derive(Clone)]
 pub struct AnalysisHost {
     /// Parsed files stored directly (no Workspace dependency)
-    files: HashMap<PathBuf, SyntaxFile>,
+    files: Arc<HashMap<PathBuf, Arc<SyntaxFile>>>,
     /// HIR-based symbol index built from parsed files
-    symbol_index: SymbolIndex,
+    symbol_index: Arc<SymbolIndex>,
     /// Map from file path to FileId
-    file_id_map: HashMap<String, FileId>,
+    file_id_map: Arc<HashMap<String, FileId>>,
     /// Reverse map from FileId to file path
-    file_path_map: HashMap<FileId, String>,
+    file_path_map: Arc<HashMap<FileId, String>>,
+    /// Next unused FileId; ids are not reused after a file is removed
+    next_file_id: u32,
     /// Files that have been modified and need re-extraction
     dirty_files: HashSet<PathBuf>,
     /// Files that have been removed (need to be removed from index)
@@ -53,6 +75,13 @@ pub struct AnalysisHost {
     /// Persistent cache: qualified_name → element_id
     /// Preserves IDs even when symbols are temporarily removed
     element_id_cache: HashMap<Arc<str>, Arc<str>>,
+    /// Whether parse and extraction timings are recorded
+    collect_stats: bool,
+    /// Timings and counts per file, populated while `collect_stats` is set
+    file_stats: HashMap<PathBuf, FileStats>,
+    /// Library files (e.g., the standard library), used for resolution but
+    /// left out of diagnostics
+    library_files: Arc<HashSet<PathBuf>>,
 }
 
 impl Default for AnalysisHost {
@@ -65,17 +94,40 @@ impl AnalysisHost {
     /// Create a new empty AnalysisHost.
     pub fn new() -> Self {
         Self {
-            files: HashMap::new(),
-            symbol_index: SymbolIndex::new(),
-            file_id_map: HashMap::new(),
-            file_path_map: HashMap::new(),
+            files: Arc::new(HashMap::new()),
+            symbol_index: Arc::new(SymbolIndex::new()),
+            file_id_map: Arc::new(HashMap::new()),
+            file_path_map: Arc::new(HashMap::new()),
+            next_file_id: 0,
             dirty_files: HashSet::new(),
             removed_files: HashSet::new(),
             needs_full_rebuild: true, // First analysis needs full build
             element_id_cache: HashMap::new(),
+            collect_stats: false,
+            file_stats: HashMap::new(),
+            library_files: Arc::new(HashSet::new()),
         }
     }
 
+    /// Enable or disable recording of per-file parse and extraction stats.
+    ///
+    /// Disabled by default. Disabling drops the stats recorded so far.
+    pub fn set_collect_stats(&mut self, enabled: bool) {
+        self.collect_stats = enabled;
+        if !enabled {
+            self.file_stats.clear();
+        }
+    }
+
+    /// Get the parse and extraction stats of a file.
+    ///
+    /// Returns `None` unless stats collection was enabled when the file was
+    /// last set and the index has been rebuilt since.
+    pub fn file_stats(&self, file_id: FileId) -> Option<FileStats> {
+        let path = self.file_path_map.get(&file_id)?;
+        self.file_stats.get(Path::new(path)).copied()
+    }
+
     /// Set the content of a file, parsing it and storing the result.
     ///
     /// Returns parse errors if any.
@@ -90,10 +142,18 @@ impl AnalysisHost {
         let path_buf = PathBuf::from(path);
 
         // Parse the content
+        let start = self.collect_stats.then(Instant::now);
         let result = parse_with_result(content, Path::new(path));
+        if let Some(start) = start {
+            let stats = FileStats {
+                parse_micros: start.elapsed().as_micros() as u64,
+                ..FileStats::default()
+            };
+            self.file_stats.insert(path_buf.clone(), stats);
+        }
 
         if let Some(syntax_file) = result.content {
-            self.files.insert(path_buf.clone(), syntax_file);
+            Arc::make_mut(&mut self.files).insert(path_buf.clone(), Arc::new(syntax_file));
         }
 
         // Mark this file as dirty (needs re-extraction)
@@ -102,17 +162,24 @@ impl AnalysisHost {
     }
 
     /// Remove a file from storage.
+    ///
+    /// On the next `analysis()` the file's symbols leave the index, its FileId
+    /// is released, and references in other files that resolved into it are
+    /// re-resolved (and reported as undefined if nothing else provides them).
     pub fn remove_file(&mut self, path: &str) {
-        let path_buf = PathBuf::from(path);
-        self.files.remove(&path_buf);
-        self.dirty_files.remove(&path_buf);
-        self.removed_files.insert(path_buf);
+        self.remove_file_path(&PathBuf::from(path));
     }
 
     /// Remove a file from storage using PathBuf.
     pub fn remove_file_path(&mut self, path: &PathBuf) {
-        self.files.remove(path);
+        if self.files.contains_key(path) {
+            Arc::make_mut(&mut self.files).remove(path);
+        }
         self.dirty_files.remove(path);
+        self.file_stats.remove(path);
+        if self.library_files.contains(path) {
+            Arc::make_mut(&mut self.library_files).remove(path);
+        }
         self.removed_files.insert(path.clone());
     }
 
@@ -130,15 +197,56 @@ impl AnalysisHost {
     /// Update or add a file with pre-parsed content.
     /// Used when caller already has parsed SyntaxFile.
     pub fn set_file(&mut self, path: PathBuf, file: SyntaxFile) {
+        self.set_shared_file(path, Arc::new(file));
+    }
+
+    /// Update or add a file with a parsed tree shared with another host.
+    fn set_shared_file(&mut self, path: PathBuf, file: Arc<SyntaxFile>) {
+        if self.collect_stats {
+            self.file_stats.insert(path.clone(), FileStats::default());
+        }
         self.dirty_files.insert(path.clone());
-        self.files.insert(path, file);
+        Arc::make_mut(&mut self.files).insert(path, file);
+    }
+
+    /// Add or update a library file with pre-parsed content.
+    ///
+    /// Library files (such as the standard library) are indexed and used for
+    /// name resolution like any other file, but diagnostic queries skip them.
+    /// The file stays a library file until it is removed.
+    pub fn set_library_file(&mut self, path: PathBuf, file: SyntaxFile) {
+        self.set_shared_library_file(path, Arc::new(file));
+    }
+
+    /// Add or update a library file with a parsed tree shared with another
+    /// host, as when copying the cached standard library.
+    pub(crate) fn set_shared_library_file(&mut self, path: PathBuf, file: Arc<SyntaxFile>) {
+        Arc::make_mut(&mut self.library_files).insert(path.clone());
+        self.set_shared_file(path, file);
+    }
+
+    /// Check whether a file was added with [`AnalysisHost::set_library_file`].
+    pub fn is_library_file(&self, file_id: FileId) -> bool {
+        self.file_path_map
+            .get(&file_id)
+            .is_some_and(|path| self.library_files.contains(Path::new(path)))
     }
 
     /// Get access to the parsed files.
-    pub fn files(&self) -> &HashMap<PathBuf, SyntaxFile> {
+    pub fn files(&self) -> &HashMap<PathBuf, Arc<SyntaxFile>> {
         &self.files
     }
 
+    /// Get the parsed syntax tree of a file.
+    ///
+    /// The tree is shared rather than re-parsed, and is replaced when the
+    /// file's content is set again. The file must have been indexed by a
+    /// previous `analysis()` call to have a `FileId`.
+    pub fn syntax(&self, file_id: FileId) -> Option<Arc<SyntaxFile>> {
+        let path = self.file_path_map.get(&file_id)?;
+        self.files.get(Path::new(path)).cloned()
+    }
+
     /// Get the number of files loaded.
     pub fn file_count(&self) -> usize {
         self.files.len()
@@ -178,24 +286,33 @@ impl AnalysisHost {
         }
 
         // Build file ID map from file paths
-        self.file_id_map.clear();
-        self.file_path_map.clear();
+        let mut file_id_map = HashMap::new();
+        let mut file_path_map = HashMap::new();
 
         for (i, path) in self.files.keys().enumerate() {
             let path_str = path.to_string_lossy().to_string();
             let file_id = FileId::new(i as u32);
-            self.file_id_map.insert(path_str.clone(), file_id);
-            self.file_path_map.insert(file_id, path_str);
+            file_id_map.insert(path_str.clone(), file_id);
+            file_path_map.insert(file_id, path_str);
         }
+        self.file_id_map = Arc::new(file_id_map);
+        self.file_path_map = Arc::new(file_path_map);
+        self.next_file_id = self.files.len() as u32;
 
         // Build symbol index directly from parsed files
         let mut new_index = SymbolIndex::new();
 
-        for (path, syntax_file) in &self.files {
+        for (path, syntax_file) in self.files.iter() {
             let path_str = path.to_string_lossy().to_string();
             if let Some(&file_id) = self.file_id_map.get(&path_str) {
                 // Extract symbols and filters using unified extraction (handles both SysML and KerML)
+                let start = self.collect_stats.then(Instant::now);
                 let mut result = extract_with_filters(file_id, syntax_file);
+                if let Some(start) = start {
+                    let stats = self.file_stats.entry(path.clone()).or_default();
+                    stats.extract_micros = start.elapsed().as_micros() as u64;
+                    stats.symbol_count = result.symbols.len();
+                }
 
                 // Preserve element IDs from cache (survives removal/re-add)
                 for symbol in &mut result.symbols {
@@ -214,7 +331,7 @@ impl AnalysisHost {
         // Resolve all type references (pre-compute resolved_target)
         new_index.resolve_all_type_refs();
 
-        self.symbol_index = new_index;
+        self.symbol_index = Arc::new(new_index);
         self.needs_full_rebuild = false;
         self.dirty_files.clear();
         self.removed_files.clear();
@@ -222,26 +339,34 @@ impl AnalysisHost {
 
     /// Incremental rebuild - only re-extract changed files
     fn incremental_rebuild(&mut self) {
-        use std::time::Instant;
+        // Copy-on-write: outstanding snapshots keep the state they were taken with
+        let symbol_index = Arc::make_mut(&mut self.symbol_index);
+        let file_id_map = Arc::make_mut(&mut self.file_id_map);
+        let file_path_map = Arc::make_mut(&mut self.file_path_map);
 
         // Collect files that need type ref resolution
         let mut files_to_resolve: Vec<FileId> = Vec::new();
 
+        // Qualified names of removed symbols, to find references that go stale
+        let mut removed_names: HashSet<Arc<str>> = HashSet::new();
+
         // Handle removed files first - cache their element IDs before removal
         let t0 = Instant::now();
         for path in self.removed_files.drain() {
             let path_str = path.to_string_lossy().to_string();
-            if let Some(&file_id) = self.file_id_map.get(&path_str) {
+            if let Some(file_id) = file_id_map.remove(&path_str) {
                 // Cache element IDs before removing
-                for symbol in self.symbol_index.symbols_in_file(file_id) {
+                for symbol in symbol_index.symbols_in_file(file_id) {
                     if !symbol.element_id.as_ref().is_empty()
                         && !symbol.element_id.starts_with("00000000-0000-0000-0000")
                     {
                         self.element_id_cache
                             .insert(symbol.qualified_name.clone(), symbol.element_id.clone());
                     }
+                    removed_names.insert(symbol.qualified_name.clone());
                 }
-                self.symbol_index.remove_file(file_id);
+                symbol_index.remove_file(file_id);
+                file_path_map.remove(&file_id);
             }
         }
 
@@ -249,9 +374,9 @@ impl AnalysisHost {
         for path in self.dirty_files.drain() {
             let path_str = path.to_string_lossy().to_string();
 
-            let file_id = if let Some(&id) = self.file_id_map.get(&path_str) {
+            let file_id = if let Some(&id) = file_id_map.get(&path_str) {
                 // Cache element IDs before re-extraction (so modified symbols keep their IDs)
-                for symbol in self.symbol_index.symbols_in_file(id) {
+                for symbol in symbol_index.symbols_in_file(id) {
                     if !symbol.element_id.as_ref().is_empty()
                         && !symbol.element_id.starts_with("00000000-0000-0000-0000")
                     {
@@ -261,14 +386,21 @@ impl AnalysisHost {
                 }
                 id
             } else {
-                let new_id = FileId::new(self.file_id_map.len() as u32);
-                self.file_id_map.insert(path_str.clone(), new_id);
-                self.file_path_map.insert(new_id, path_str.clone());
+                let new_id = FileId::new(self.next_file_id);
+                self.next_file_id += 1;
+                file_id_map.insert(path_str.clone(), new_id);
+                file_path_map.insert(new_id, path_str.clone());
                 new_id
             };
 
             if let Some(syntax_file) = self.files.get(&path) {
+                let start = self.collect_stats.then(Instant::now);
                 let mut result = extract_with_filters(file_id, syntax_file);
+                if let Some(start) = start {
+                    let stats = self.file_stats.entry(path.clone()).or_default();
+                    stats.extract_micros = start.elapsed().as_micros() as u64;
+                    stats.symbol_count = result.symbols.len();
+                }
 
                 // Preserve element IDs from cache (survives removal/re-add)
                 for symbol in &mut result.symbols {
@@ -277,21 +409,27 @@ impl AnalysisHost {
                     }
                 }
 
-                self.symbol_index.add_extraction_result(file_id, result);
+                symbol_index.add_extraction_result(file_id, result);
                 files_to_resolve.push(file_id);
             }
         }
         let t1 = Instant::now();
 
-        // Rebuild visibility maps (needed for correct resolution)
-        self.symbol_index.mark_visibility_dirty();
-        self.symbol_index.ensure_visibility_maps();
+        // Rebuild visibility maps for the scopes the changed files affect
+        for &file_id in &files_to_resolve {
+            symbol_index.mark_file_dirty(file_id);
+        }
+        symbol_index.ensure_visibility_maps();
+
+        // Files that referenced removed symbols must be re-resolved too
+        files_to_resolve.extend(symbol_index.files_referencing(&removed_names));
+        files_to_resolve.sort_unstable();
+        files_to_resolve.dedup();
         let t2 = Instant::now();
 
         // Only resolve type refs for changed files (not the entire workspace)
         if !files_to_resolve.is_empty() {
-            self.symbol_index
-                .resolve_type_refs_for_files(&files_to_resolve);
+            symbol_index.resolve_type_refs_for_files(&files_to_resolve);
         }
         let t3 = Instant::now();
 
@@ -306,15 +444,17 @@ impl AnalysisHost {
     /// Get a consistent snapshot for querying.
     ///
     /// If the index is dirty, it will be rebuilt first.
-    pub fn analysis(&mut self) -> Analysis<'_> {
+    pub fn analysis(&mut self) -> Analysis {
         if self.needs_update() {
             self.rebuild_index();
         }
 
         Analysis {
-            symbol_index: &self.symbol_index,
-            file_id_map: &self.file_id_map,
-            file_path_map: &self.file_path_map,
+            symbol_index: Arc::clone(&self.symbol_index),
+            file_id_map: Arc::clone(&self.file_id_map),
+            file_path_map: Arc::clone(&self.file_path_map),
+            files: Arc::clone(&self.files),
+            library_files: Arc::clone(&self.library_files),
         }
     }
 
@@ -343,7 +483,11 @@ impl AnalysisHost {
     /// Get semantic diagnostics for a specific file.
     ///
     /// Returns a list of diagnostics (errors and warnings) found during semantic analysis.
+    /// Library files have none.
     pub fn diagnostics(&self, file_id: FileId) -> Vec<crate::hir::Diagnostic> {
+        if self.is_library_file(file_id) {
+            return Vec::new();
+        }
         crate::hir::check_file(&self.symbol_index, file_id)
     }
 
@@ -352,7 +496,7 @@ impl AnalysisHost {
     /// Returns a map from file path to diagnostics for that file.
     pub fn all_diagnostics(&self) -> HashMap<String, Vec<crate::hir::Diagnostic>> {
         let mut result = HashMap::new();
-        for (path, &file_id) in &self.file_id_map {
+        for (path, &file_id) in self.file_id_map.iter() {
             let diags = self.diagnostics(file_id);
             if !diags.is_empty() {
                 result.insert(path.clone(), diags);
@@ -366,7 +510,7 @@ impl AnalysisHost {
     /// Returns a vec of (file_path, diagnostic) pairs for errors only.
     pub fn all_errors(&self) -> Vec<(String, crate::hir::Diagnostic)> {
         let mut result = Vec::new();
-        for (path, &file_id) in &self.file_id_map {
+        for (path, &file_id) in self.file_id_map.iter() {
             for diag in self.diagnostics(file_id) {
                 if diag.severity == crate::hir::Severity::Error {
                     result.push((path.clone(), diag));
@@ -393,7 +537,7 @@ impl AnalysisHost {
     where
         F: FnMut(&mut HirSymbol),
     {
-        self.symbol_index.update_symbols(f);
+        Arc::make_mut(&mut self.symbol_index).update_symbols(f);
     }
 
     /// Add a model by decompiling it to SysML and adding as a synthetic file.
@@ -435,37 +579,73 @@ impl AnalysisHost {
 /// An immutable snapshot of the analysis state.
 ///
 /// All IDE queries go through this struct to ensure consistent results.
-pub struct Analysis<'a> {
-    symbol_index: &'a SymbolIndex,
-    file_id_map: &'a HashMap<String, FileId>,
-    file_path_map: &'a HashMap<FileId, String>,
+/// Cloning is cheap, and the snapshot is unaffected by later edits to the
+/// `AnalysisHost` it came from.
+#[derive(Clone)]
+pub struct Analysis {
+    symbol_index: Arc<SymbolIndex>,
+    file_id_map: Arc<HashMap<String, FileId>>,
+    file_path_map: Arc<HashMap<FileId, String>>,
+    files: Arc<HashMap<PathBuf, Arc<SyntaxFile>>>,
+    library_files: Arc<HashSet<PathBuf>>,
 }
 
-impl<'a> Analysis<'a> {
+impl Analysis {
     // ==================== Symbol-based features ====================
 
     /// Get hover information at a position.
     pub fn hover(&self, file_id: FileId, line: u32, col: u32) -> Option<HoverResult> {
-        super::hover(self.symbol_index, file_id, line, col)
+        super::hover(&self.symbol_index, file_id, line, col)
     }
 
     /// Get type information at a position.
     ///
     /// Returns info if cursor is on a type annotation (`:`, `:>`, `::>`, etc.).
     pub fn type_info_at(&self, file_id: FileId, line: u32, col: u32) -> Option<super::TypeInfo> {
-        super::type_info_at(self.symbol_index, file_id, line, col)
+        super::type_info_at(&self.symbol_index, file_id, line, col)
     }
 
     /// Go to definition at a position.
+    ///
+    /// Each target's `full_range` covers its whole declaration.
     pub fn goto_definition(&self, file_id: FileId, line: u32, col: u32) -> GotoResult {
-        super::goto_definition(self.symbol_index, file_id, line, col)
+        self.with_declarations(super::goto_definition(
+            &self.symbol_index,
+            file_id,
+            line,
+            col,
+        ))
     }
 
     /// Go to type definition at a position.
     ///
     /// Navigates from a usage to its type definition (e.g., from `engine : Engine` to `part def Engine`).
     pub fn goto_type_definition(&self, file_id: FileId, line: u32, col: u32) -> GotoResult {
-        super::goto_type_definition(self.symbol_index, file_id, line, col)
+        self.with_declarations(super::goto_type_definition(
+            &self.symbol_index,
+            file_id,
+            line,
+            col,
+        ))
+    }
+
+    /// Fill in the declaration range of each target from its file's syntax tree.
+    fn with_declarations(&self, result: GotoResult) -> GotoResult {
+        let targets = result
+            .targets
+            .into_iter()
+            .map(|target| {
+                match self
+                    .file_path_map
+                    .get(&target.file)
+                    .and_then(|path| self.files.get(Path::new(path)))
+                {
+                    Some(syntax) => target.with_declaration(syntax),
+                    None => target,
+                }
+            })
+            .collect();
+        GotoResult::multiple(targets)
     }
 
     /// Find all references to a symbol at a position.
@@ -476,10 +656,33 @@ impl<'a> Analysis<'a> {
         col: u32,
         include_declaration: bool,
     ) -> ReferenceResult {
-        super::find_references(self.symbol_index, file_id, line, col, include_declaration)
+        super::find_references(&self.symbol_index, file_id, line, col, include_declaration)
+    }
+
+    /// Find references, giving up with `None` once `is_cancelled` reports true.
+    pub fn find_references_with_cancel(
+        &self,
+        file_id: FileId,
+        line: u32,
+        col: u32,
+        include_declaration: bool,
+        is_cancelled: &dyn Fn() -> bool,
+    ) -> Option<ReferenceResult> {
+        super::find_references_with_cancel(
+            &self.symbol_index,
+            file_id,
+            line,
+            col,
+            include_declaration,
+            is_cancelled,
+        )
     }
 
     /// Get completions at a position.
+    ///
+    /// Inside the path of an `import` statement, only namespaces and their
+    /// members are offered. After a `:` type annotation only definitions and
+    /// packages are offered.
     pub fn completions(
         &self,
         file_id: FileId,
@@ -487,34 +690,152 @@ impl<'a> Analysis<'a> {
         col: u32,
         trigger: Option<char>,
     ) -> Vec<CompletionItem> {
-        super::completions(self.symbol_index, file_id, line, col, trigger)
+        let line_prefix = self.line_prefix(file_id, line, col).unwrap_or_default();
+        if let Some(items) = super::import_completions(&self.symbol_index, &line_prefix) {
+            return items;
+        }
+        super::completions_in_line(&self.symbol_index, file_id, trigger, &line_prefix)
+    }
+
+    /// Get completions at a position that match the identifier before the cursor.
+    ///
+    /// The partially typed identifier is compared with each label as set by
+    /// `options`; with no identifier before the cursor every item is kept.
+    pub fn completions_matching(
+        &self,
+        file_id: FileId,
+        line: u32,
+        col: u32,
+        trigger: Option<char>,
+        options: MatchOptions,
+    ) -> Vec<CompletionItem> {
+        let line_prefix = self.line_prefix(file_id, line, col).unwrap_or_default();
+        let start = line_prefix
+            .char_indices()
+            .rev()
+            .take_while(|&(_, c)| is_word_character(c))
+            .last()
+            .map_or(line_prefix.len(), |(i, _)| i);
+        let prefix = &line_prefix[start..];
+
+        let mut items = self.completions(file_id, line, col, trigger);
+        items.retain(|item| options.matches(prefix, &item.label));
+        items
+    }
+
+    /// The text of a line up to a (byte) column.
+    fn line_prefix(&self, file_id: FileId, line: u32, col: u32) -> Option<String> {
+        let path = self.file_path_map.get(&file_id)?;
+        let syntax = self.files.get(Path::new(path))?;
+        let text = syntax.source_text();
+        let range = syntax.line_index().line_text_range(line)?;
+        let line_text = &text[range];
+        let end = (col as usize).min(line_text.len());
+        line_text.get(..end).map(str::to_string)
+    }
+
+    /// Get signature help for the invocation surrounding a position.
+    pub fn signature_help(&self, file_id: FileId, line: u32, col: u32) -> Option<SignatureInfo> {
+        super::signature_help(&self.symbol_index, file_id, line, col)
     }
 
     /// Get all symbols in a document.
     pub fn document_symbols(&self, file_id: FileId) -> Vec<SymbolInfo> {
-        super::document_symbols(self.symbol_index, file_id)
+        super::document_symbols(&self.symbol_index, file_id)
+    }
+
+    /// Get the symbols in a document as an outline tree.
+    pub fn document_symbols_hierarchical(&self, file_id: FileId) -> Vec<SymbolNode> {
+        super::document_symbols_hierarchical(&self.symbol_index, file_id)
     }
 
     /// Search for symbols across the workspace.
     pub fn workspace_symbols(&self, query: Option<&str>) -> Vec<SymbolInfo> {
-        super::workspace_symbols(self.symbol_index, query)
+        super::workspace_symbols(&self.symbol_index, query)
+    }
+
+    /// Search workspace symbols, giving up with `None` once `is_cancelled`
+    /// reports true.
+    pub fn workspace_symbols_with_cancel(
+        &self,
+        query: Option<&str>,
+        is_cancelled: &dyn Fn() -> bool,
+    ) -> Option<Vec<SymbolInfo>> {
+        super::workspace_symbols_with_cancel(&self.symbol_index, query, is_cancelled)
+    }
+
+    /// Find the actions and calculations that invoke the one at a position.
+    pub fn incoming_calls(&self, file_id: FileId, line: u32, col: u32) -> Vec<IncomingCall> {
+        super::incoming_calls(&self.symbol_index, file_id, line, col)
+    }
+
+    /// Find the actions and calculations invoked by the one at a position.
+    pub fn outgoing_calls(&self, file_id: FileId, line: u32, col: u32) -> Vec<OutgoingCall> {
+        super::outgoing_calls(&self.symbol_index, file_id, line, col)
     }
 
     /// Get document links (import paths, etc.).
     pub fn document_links(&self, file_id: FileId) -> Vec<DocumentLink> {
-        super::document_links(self.symbol_index, file_id)
+        super::document_links(&self.symbol_index, file_id)
     }
 
     // ==================== AST-based features ====================
 
     /// Get folding ranges for a file.
     pub fn folding_ranges(&self, file_id: FileId) -> Vec<FoldingRange> {
-        super::folding_ranges(self.symbol_index, file_id)
+        super::folding_ranges(&self.symbol_index, file_id)
+    }
+
+    /// Get folding ranges for a file's declarations, adjusted by `options`.
+    ///
+    /// Unlike [`folding_ranges`](Self::folding_ranges), each range covers a
+    /// whole declaration rather than the symbol's span.
+    pub fn folding_ranges_with_options(
+        &self,
+        file_id: FileId,
+        options: FoldingOptions,
+    ) -> Vec<FoldingRange> {
+        let syntax = self
+            .file_path_map
+            .get(&file_id)
+            .and_then(|path| self.files.get(Path::new(path)));
+        match syntax {
+            Some(syntax) => {
+                super::folding_ranges_with_options(&self.symbol_index, file_id, syntax, options)
+            }
+            None => super::folding_ranges(&self.symbol_index, file_id),
+        }
     }
 
     /// Get selection ranges at positions.
     pub fn selection_ranges(&self, file_id: FileId, line: u32, col: u32) -> Vec<SelectionRange> {
-        super::selection_ranges(self.symbol_index, file_id, line, col)
+        let syntax = self
+            .file_path_map
+            .get(&file_id)
+            .and_then(|path| self.files.get(Path::new(path)));
+        match syntax {
+            Some(syntax) => {
+                super::selection_ranges_with_syntax(&self.symbol_index, file_id, syntax, line, col)
+            }
+            None => super::selection_ranges(&self.symbol_index, file_id, line, col),
+        }
+    }
+
+    /// Get semantic diagnostics for every file except library files.
+    ///
+    /// Diagnostics are grouped by file, in `FileId` order.
+    pub fn diagnostics(&self) -> Vec<crate::hir::Diagnostic> {
+        let mut file_ids: Vec<FileId> = self
+            .file_path_map
+            .iter()
+            .filter(|(_, path)| !self.library_files.contains(Path::new(path.as_str())))
+            .map(|(&file_id, _)| file_id)
+            .collect();
+        file_ids.sort_unstable();
+        file_ids
+            .into_iter()
+            .flat_map(|file_id| crate::hir::check_file(&self.symbol_index, file_id))
+            .collect()
     }
 
     /// Get inlay hints for a file (optionally within a range).
@@ -523,24 +844,39 @@ impl<'a> Analysis<'a> {
         file_id: FileId,
         range: Option<(u32, u32, u32, u32)>,
     ) -> Vec<InlayHint> {
-        super::inlay_hints(self.symbol_index, file_id, range)
+        super::inlay_hints(&self.symbol_index, file_id, range)
+    }
+
+    /// Get inlay hints for a file, with optional hints enabled by `options`.
+    pub fn inlay_hints_with_options(
+        &self,
+        file_id: FileId,
+        range: Option<(u32, u32, u32, u32)>,
+        options: InlayHintOptions,
+    ) -> Vec<InlayHint> {
+        super::inlay_hints_with_options(&self.symbol_index, file_id, range, options)
     }
 
     /// Get semantic tokens for a file.
     pub fn semantic_tokens(&self, file_id: FileId) -> Vec<SemanticToken> {
-        super::semantic_tokens(self.symbol_index, file_id)
+        super::semantic_tokens(&self.symbol_index, file_id)
+    }
+
+    /// Get semantic tokens for a file, delta-encoded for LSP.
+    pub fn semantic_tokens_lsp(&self, file_id: FileId) -> Vec<u32> {
+        super::semantic_tokens_lsp(&self.symbol_index, file_id)
     }
 
     // ==================== Accessors ====================
 
     /// Get the symbol index.
     pub fn symbol_index(&self) -> &SymbolIndex {
-        self.symbol_index
+        &self.symbol_index
     }
 
     /// Get the file ID map.
     pub fn file_id_map(&self) -> &HashMap<String, FileId> {
-        self.file_id_map
+        &self.file_id_map
     }
 
     /// Get the file path for a FileId.
@@ -573,6 +909,37 @@ mod tests {
         assert!(analysis.get_file_id("test.sysml").is_some());
     }
 
+    #[test]
+    fn test_library_files_excluded_from_diagnostics() {
+        use crate::syntax::parser::parse_content;
+
+        let mut host = AnalysisHost::new();
+        // The library has a deliberate error, which must not be reported
+        let library = parse_content(
+            "package Lib { part def Engine :> Missing; }",
+            Path::new("lib.sysml"),
+        )
+        .unwrap();
+        host.set_library_file(PathBuf::from("lib.sysml"), library);
+        host.set_file_content(
+            "user.sysml",
+            "package User { import Lib::*; part engine : Engine; part wheel : Wheel; }",
+        );
+        let analysis = host.analysis();
+
+        let lib_id = analysis.get_file_id("lib.sysml").unwrap();
+        let user_id = analysis.get_file_id("user.sysml").unwrap();
+        assert!(host.is_library_file(lib_id));
+        assert!(!host.is_library_file(user_id));
+        assert!(host.diagnostics(lib_id).is_empty());
+
+        // The user file still resolves against the library
+        let diagnostics = analysis.diagnostics();
+        assert!(diagnostics.iter().all(|d| d.file == user_id));
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_ref()).collect();
+        assert_eq!(messages, vec!["undefined reference: 'Wheel'"]);
+    }
+
     #[test]
     fn test_file_removal() {
         let mut host = AnalysisHost::new();
@@ -584,4 +951,117 @@ mod tests {
         let analysis = host.analysis();
         assert!(analysis.get_file_id("test.sysml").is_none());
     }
+
+    #[test]
+    fn test_file_removal_after_analysis_releases_file_id() {
+        let mut host = AnalysisHost::new();
+        host.set_file_content("a.sysml", "package A {}");
+        host.set_file_content("b.sysml", "package B {}");
+        let a = host.analysis().get_file_id("a.sysml").unwrap();
+
+        host.remove_file("a.sysml");
+        host.set_file_content("c.sysml", "package C {}");
+        let analysis = host.analysis();
+
+        assert!(analysis.get_file_id("a.sysml").is_none());
+        let b = analysis.get_file_id("b.sysml").unwrap();
+        let c = analysis.get_file_id("c.sysml").unwrap();
+        assert_ne!(b, c, "new files must not reuse a live FileId");
+        assert_ne!(a, c, "ids of removed files are not reused");
+        assert!(analysis.symbol_index().lookup_qualified("A").is_none());
+    }
+
+    #[test]
+    fn test_file_stats_recorded_when_enabled() {
+        let mut host = AnalysisHost::new();
+        host.set_collect_stats(true);
+        host.set_file_content("a.sysml", "package A { part def Engine; part def Wheel; }");
+
+        let analysis = host.analysis();
+        let file_id = analysis.get_file_id("a.sysml").unwrap();
+        let stats = host.file_stats(file_id).unwrap();
+        assert_eq!(
+            stats.symbol_count,
+            analysis.symbol_index().symbols_in_file(file_id).len()
+        );
+        assert_eq!(stats.symbol_count, 3);
+
+        // Re-setting the file replaces its stats on the next rebuild
+        host.set_file_content("a.sysml", "package A { part def Engine; }");
+        let _ = host.analysis();
+        assert_eq!(host.file_stats(file_id).unwrap().symbol_count, 2);
+    }
+
+    #[test]
+    fn test_file_stats_absent_when_disabled() {
+        let mut host = AnalysisHost::new();
+        host.set_file_content("a.sysml", "package A {}");
+
+        let analysis = host.analysis();
+        let file_id = analysis.get_file_id("a.sysml").unwrap();
+        assert!(host.file_stats(file_id).is_none());
+    }
+
+    #[test]
+    fn test_analysis_snapshot_is_send_sync_clone() {
+        fn assert_snapshot<T: Send + Sync + Clone + 'static>() {}
+        assert_snapshot::<Analysis>();
+    }
+
+    #[test]
+    fn test_analysis_snapshot_unaffected_by_later_edits() {
+        let mut host = AnalysisHost::new();
+        host.set_file_content("a.sysml", "package A { part def Engine; }");
+
+        let snapshot = host.analysis();
+        let file_id = snapshot.get_file_id("a.sysml").unwrap();
+        let symbols_before = snapshot.document_symbols(file_id).len();
+
+        host.set_file_content("a.sysml", "package A { part def Wheel; part def Axle; }");
+        host.set_file_content("b.sysml", "package B {}");
+        let current = host.analysis();
+        assert!(
+            current
+                .symbol_index()
+                .lookup_qualified("A::Wheel")
+                .is_some()
+        );
+
+        // The snapshot still sees the state it was taken with
+        let index = snapshot.symbol_index();
+        assert!(index.lookup_qualified("A::Engine").is_some());
+        assert!(index.lookup_qualified("A::Wheel").is_none());
+        assert!(snapshot.get_file_id("b.sysml").is_none());
+        assert_eq!(snapshot.document_symbols(file_id).len(), symbols_before);
+
+        // Snapshots can be queried from another thread
+        let handle = std::thread::spawn(move || {
+            snapshot
+                .symbol_index()
+                .lookup_qualified("A::Engine")
+                .is_some()
+        });
+        assert!(handle.join().unwrap());
+    }
+
+    #[test]
+    fn test_syntax_reflects_edits() {
+        let mut host = AnalysisHost::new();
+        host.set_file_content("a.sysml", "package A { part def Engine; }");
+        let file_id = host.analysis().get_file_id("a.sysml").unwrap();
+
+        let syntax = host.syntax(file_id).unwrap();
+        assert!(syntax.source_text().contains("Engine"));
+        // Repeated access shares the same parsed tree
+        assert!(Arc::ptr_eq(&syntax, &host.syntax(file_id).unwrap()));
+
+        host.set_file_content("a.sysml", "package A { part def Wheel; }");
+        let edited = host.syntax(file_id).unwrap();
+        assert!(edited.source_text().contains("Wheel"));
+        assert!(!edited.source_text().contains("Engine"));
+        // Trees handed out earlier are unaffected
+        assert!(syntax.source_text().contains("Engine"));
+
+        assert!(host.syntax(FileId::new(99)).is_none());
+    }
 }
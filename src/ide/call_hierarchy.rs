@@ -0,0 +1,271 @@
+//! Call hierarchy for actions and calculations.
+//!
+//! A call is an invocation site inside the body of an action or calculation:
+//! a `perform`, an action/calc usage typed by another action or calculation,
+//! or an invocation in an expression (e.g., `Square(2)`). The caller is the
+//! closest enclosing action or calculation.
+
+use std::sync::Arc;
+
+use crate::base::FileId;
+use crate::hir::{HirSymbol, RefKind, SymbolIndex, SymbolKind, TypeRef, TypeRefKind};
+
+/// An action or calculation in the call hierarchy.
+#[derive(Clone, Debug)]
+pub struct CallHierarchyItem {
+    /// Symbol name.
+    pub name: Arc<str>,
+    /// Qualified name.
+    pub qualified_name: Arc<str>,
+    /// Symbol kind.
+    pub kind: SymbolKind,
+    /// File containing the symbol.
+    pub file: FileId,
+    /// Start line (0-indexed).
+    pub start_line: u32,
+    /// Start column (0-indexed).
+    pub start_col: u32,
+    /// End line (0-indexed).
+    pub end_line: u32,
+    /// End column (0-indexed).
+    pub end_col: u32,
+}
+
+impl CallHierarchyItem {
+    /// Create from a HirSymbol.
+    pub fn from_hir(symbol: &HirSymbol) -> Self {
+        Self {
+            name: symbol.name.clone(),
+            qualified_name: symbol.qualified_name.clone(),
+            kind: symbol.kind,
+            file: symbol.file,
+            start_line: symbol.start_line,
+            start_col: symbol.start_col,
+            end_line: symbol.end_line,
+            end_col: symbol.end_col,
+        }
+    }
+}
+
+/// The location of an invocation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallSite {
+    /// The file containing the invocation.
+    pub file: FileId,
+    /// Start line (0-indexed).
+    pub start_line: u32,
+    /// Start column (0-indexed).
+    pub start_col: u32,
+    /// End line (0-indexed).
+    pub end_line: u32,
+    /// End column (0-indexed).
+    pub end_col: u32,
+}
+
+impl CallSite {
+    /// Create from a type reference.
+    fn from_type_ref(type_ref: &TypeRef, file: FileId) -> Self {
+        Self {
+            file,
+            start_line: type_ref.start_line,
+            start_col: type_ref.start_col,
+            end_line: type_ref.end_line,
+            end_col: type_ref.end_col,
+        }
+    }
+}
+
+/// A caller of the target, with the sites where it invokes the target.
+#[derive(Clone, Debug)]
+pub struct IncomingCall {
+    /// The calling action or calculation.
+    pub from: CallHierarchyItem,
+    /// Invocation sites within the caller.
+    pub sites: Vec<CallSite>,
+}
+
+/// A callee of the source, with the sites where the source invokes it.
+#[derive(Clone, Debug)]
+pub struct OutgoingCall {
+    /// The called action or calculation.
+    pub to: CallHierarchyItem,
+    /// Invocation sites within the source.
+    pub sites: Vec<CallSite>,
+}
+
+/// Find the actions and calculations that invoke the one at the given position.
+///
+/// # Arguments
+/// * `index` - The symbol index to search
+/// * `file` - The file containing the cursor
+/// * `line` - Cursor line (0-indexed)
+/// * `col` - Cursor column (0-indexed)
+///
+/// # Returns
+/// One entry per caller, in index order, or empty if the cursor is not on an
+/// action or calculation.
+pub fn incoming_calls(index: &SymbolIndex, file: FileId, line: u32, col: u32) -> Vec<IncomingCall> {
+    let Some(target) = find_callable_at_position(index, file, line, col) else {
+        return Vec::new();
+    };
+
+    let mut calls: Vec<IncomingCall> = Vec::new();
+    for symbol in index.all_symbols() {
+        for (callee, site) in invocations(index, symbol) {
+            if callee.qualified_name != target.qualified_name {
+                continue;
+            }
+            let Some(caller) = enclosing_callable(index, symbol) else {
+                continue;
+            };
+            let site = CallSite::from_type_ref(site, symbol.file);
+            match calls
+                .iter_mut()
+                .find(|c| c.from.qualified_name == caller.qualified_name)
+            {
+                Some(call) => call.sites.push(site),
+                None => calls.push(IncomingCall {
+                    from: CallHierarchyItem::from_hir(caller),
+                    sites: vec![site],
+                }),
+            }
+        }
+    }
+
+    calls
+}
+
+/// Find the actions and calculations invoked by the one at the given position.
+///
+/// Feature chains such as `providePower.distributeTorque` resolve to their
+/// final member.
+///
+/// # Arguments
+/// * `index` - The symbol index to search
+/// * `file` - The file containing the cursor
+/// * `line` - Cursor line (0-indexed)
+/// * `col` - Cursor column (0-indexed)
+///
+/// # Returns
+/// One entry per callee, in source order of the first invocation, or empty if
+/// the cursor is not on an action or calculation.
+pub fn outgoing_calls(index: &SymbolIndex, file: FileId, line: u32, col: u32) -> Vec<OutgoingCall> {
+    let Some(source) = find_callable_at_position(index, file, line, col) else {
+        return Vec::new();
+    };
+
+    let body_prefix = format!("{}::", source.qualified_name);
+    let mut calls: Vec<OutgoingCall> = Vec::new();
+    for symbol in index.symbols_in_file(source.file) {
+        if !symbol.qualified_name.starts_with(&body_prefix) {
+            continue;
+        }
+        // Calls from nested actions/calcs belong to them
+        if enclosing_callable(index, symbol)
+            .is_none_or(|caller| caller.qualified_name != source.qualified_name)
+        {
+            continue;
+        }
+        for (callee, site) in invocations(index, symbol) {
+            let site = CallSite::from_type_ref(site, symbol.file);
+            match calls
+                .iter_mut()
+                .find(|c| c.to.qualified_name == callee.qualified_name)
+            {
+                Some(call) => call.sites.push(site),
+                None => calls.push(OutgoingCall {
+                    to: CallHierarchyItem::from_hir(callee),
+                    sites: vec![site],
+                }),
+            }
+        }
+    }
+
+    calls
+}
+
+/// Whether a symbol can take part in the call hierarchy.
+fn is_callable(kind: SymbolKind) -> bool {
+    matches!(
+        kind,
+        SymbolKind::ActionDefinition
+            | SymbolKind::ActionUsage
+            | SymbolKind::CalculationDefinition
+            | SymbolKind::CalculationUsage
+    )
+}
+
+/// The invocations made directly by a symbol, with their resolved callees.
+///
+/// Action and calc usages invoke what they are typed by or perform; any
+/// symbol can invoke through an expression. Chains resolve to their last part.
+fn invocations<'a>(
+    index: &'a SymbolIndex,
+    symbol: &'a HirSymbol,
+) -> impl Iterator<Item = (&'a HirSymbol, &'a TypeRef)> {
+    let is_usage_call = matches!(
+        symbol.kind,
+        SymbolKind::ActionUsage | SymbolKind::CalculationUsage
+    );
+
+    symbol
+        .type_refs
+        .iter()
+        .filter_map(|trk| match trk {
+            TypeRefKind::Simple(tr) => Some(tr),
+            TypeRefKind::Chain(chain) => chain.parts.last(),
+        })
+        .filter(move |tr| match tr.kind {
+            RefKind::TypedBy | RefKind::Other => is_usage_call,
+            RefKind::Expression => true,
+            _ => false,
+        })
+        .filter_map(move |tr| {
+            let callee = index.lookup_qualified(tr.resolved_target.as_deref()?)?;
+            is_callable(callee.kind).then_some((callee, tr))
+        })
+}
+
+/// The closest action or calculation strictly enclosing a symbol.
+fn enclosing_callable<'a>(index: &'a SymbolIndex, symbol: &HirSymbol) -> Option<&'a HirSymbol> {
+    let mut scope = symbol.qualified_name.as_ref();
+    while let Some(pos) = scope.rfind("::") {
+        scope = &scope[..pos];
+        // Anonymous segments may contain `::`, so missing prefixes are skipped
+        if let Some(parent) = index.lookup_qualified(scope) {
+            if is_callable(parent.kind) {
+                return Some(parent);
+            }
+        }
+    }
+    None
+}
+
+/// Find the action or calculation at a position, following a type reference
+/// under the cursor to its target.
+fn find_callable_at_position(
+    index: &SymbolIndex,
+    file: FileId,
+    line: u32,
+    col: u32,
+) -> Option<&HirSymbol> {
+    let symbols = index.symbols_in_file(file);
+
+    for symbol in symbols {
+        for type_ref_kind in &symbol.type_refs {
+            if let Some((_, tr)) = type_ref_kind.part_at(line, col) {
+                let target = tr
+                    .resolved_target
+                    .as_deref()
+                    .and_then(|t| index.lookup_qualified(t));
+                if let Some(target) = target.filter(|t| is_callable(t.kind)) {
+                    return Some(target);
+                }
+            }
+        }
+    }
+
+    index
+        .symbol_at(file, line, col)
+        .filter(|s| is_callable(s.kind))
+}
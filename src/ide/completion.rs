@@ -1,9 +1,15 @@
 //! Completion suggestions implementation.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
 use crate::base::FileId;
-use crate::hir::{HirSymbol, SymbolIndex, SymbolKind};
+use crate::hir::{HirSymbol, RefKind, SymbolIndex, SymbolKind};
+
+use super::text_utils::is_word_character;
 
 /// Kind of completion item.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -94,12 +100,11 @@ impl CompletionItem {
 
         let mut item = Self::new(symbol.name.clone(), kind);
 
-        // Add type info as detail
-        if !symbol.supertypes.is_empty() {
-            item.detail = Some(Arc::from(format!(": {}", symbol.supertypes.join(", "))));
-        } else {
-            item.detail = Some(Arc::from(symbol.kind.display()));
-        }
+        // Add kind and type info as detail, e.g. `Part: Vehicles::Engine`
+        item.detail = Some(Arc::from(match symbol_types(symbol) {
+            Some(types) => format!("{}: {}", symbol.kind.display(), types),
+            None => symbol.kind.display().to_string(),
+        }));
 
         // Add doc if available
         if let Some(ref doc) = symbol.doc {
@@ -110,6 +115,59 @@ impl CompletionItem {
     }
 }
 
+/// The types of a symbol for its completion detail.
+///
+/// Uses the resolved targets of its typings when available, falling back to
+/// its supertypes as written.
+fn symbol_types(symbol: &HirSymbol) -> Option<String> {
+    let typings: Vec<&str> = symbol
+        .type_refs
+        .iter()
+        .flat_map(|trk| trk.as_refs())
+        .filter(|tr| tr.kind == RefKind::TypedBy)
+        .map(|tr| tr.effective_target().as_ref())
+        .collect();
+    if !typings.is_empty() {
+        return Some(typings.join(", "));
+    }
+    (!symbol.supertypes.is_empty()).then(|| symbol.supertypes.join(", "))
+}
+
+/// How a typed prefix is matched against completion labels.
+///
+/// The default matches case-sensitively, comparing characters as written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MatchOptions {
+    /// Fold case before comparing, so `ENG` matches `engine`.
+    pub case_insensitive: bool,
+    /// Strip combining marks before comparing, so `e` matches `é`.
+    pub ignore_diacritics: bool,
+}
+
+impl MatchOptions {
+    /// Check whether `label` starts with `prefix` under these options.
+    pub fn matches(&self, prefix: &str, label: &str) -> bool {
+        if !self.case_insensitive && !self.ignore_diacritics {
+            return label.starts_with(prefix);
+        }
+        self.fold(label).starts_with(&self.fold(prefix))
+    }
+
+    /// Normalize text for comparison.
+    fn fold(&self, text: &str) -> String {
+        let text: String = if self.ignore_diacritics {
+            text.nfd().filter(|&c| !is_combining_mark(c)).collect()
+        } else {
+            text.nfc().collect()
+        };
+        if self.case_insensitive {
+            text.to_lowercase()
+        } else {
+            text
+        }
+    }
+}
+
 /// Get completion suggestions at a position.
 ///
 /// # Arguments
@@ -124,20 +182,37 @@ impl CompletionItem {
 pub fn completions(
     index: &SymbolIndex,
     file: FileId,
-    line: u32,
-    col: u32,
+    _line: u32,
+    _col: u32,
+    trigger: Option<char>,
+) -> Vec<CompletionItem> {
+    // Without the line's text, only the trigger character decides the context
+    completions_in_line(index, file, trigger, "")
+}
+
+/// Get completion suggestions at a position, using the text before the cursor.
+///
+/// Like [`completions`], but without a trigger character the expected kind is
+/// inferred from `line_prefix`, the text of the cursor's line up to the
+/// cursor. After a `:` type annotation, or a `:>` in a definition, only
+/// definitions and packages are offered. Other contexts are ambiguous and
+/// offer all symbols.
+pub fn completions_in_line(
+    index: &SymbolIndex,
+    file: FileId,
     trigger: Option<char>,
+    line_prefix: &str,
 ) -> Vec<CompletionItem> {
     let mut items = Vec::new();
 
     // Determine context
-    let context = determine_context(index, file, line, col, trigger);
+    let context = determine_context(trigger, line_prefix);
 
     match context {
         CompletionContext::TypeReference => {
             // Suggest definitions (types)
-            for symbol in index.all_definitions() {
-                if symbol.kind.is_definition() {
+            for symbol in index.definitions_sorted() {
+                if symbol.kind.is_definition() && !symbol.is_anonymous() {
                     let mut item = CompletionItem::from_symbol(symbol);
                     item.sort_priority = 10;
                     items.push(item);
@@ -151,7 +226,7 @@ pub fn completions(
                     let depth = symbol.qualified_name.matches("::").count();
                     let scope_depth = scope.matches("::").count() + 1;
                     // Only direct children
-                    if depth == scope_depth + 1 {
+                    if depth == scope_depth + 1 && !symbol.is_anonymous() {
                         items.push(CompletionItem::from_symbol(symbol));
                     }
                 }
@@ -161,8 +236,12 @@ pub fn completions(
             // Suggest keywords
             items.extend(keyword_completions());
 
-            // Suggest all visible symbols
-            for symbol in index.all_definitions() {
+            // Suggest all visible symbols. Anonymous symbols have synthetic
+            // names that can't be written in source, so they are skipped.
+            for symbol in index.definitions_sorted() {
+                if symbol.is_anonymous() {
+                    continue;
+                }
                 let mut item = CompletionItem::from_symbol(symbol);
                 item.sort_priority = 50;
                 items.push(item);
@@ -170,6 +249,9 @@ pub fn completions(
 
             // Suggest symbols in the same file with higher priority
             for symbol in index.symbols_in_file(file) {
+                if symbol.is_anonymous() {
+                    continue;
+                }
                 let mut item = CompletionItem::from_symbol(symbol);
                 item.sort_priority = 20;
                 items.push(item);
@@ -180,12 +262,114 @@ pub fn completions(
     // Sort by priority
     items.sort_by_key(|item| item.sort_priority);
 
-    // Deduplicate by label
-    items.dedup_by(|a, b| a.label == b.label);
+    // Deduplicate by label, keeping the highest priority item
+    let mut seen = HashSet::new();
+    items.retain(|item| seen.insert(item.label.clone()));
+
+    items
+}
 
+/// Get completion suggestions at a position whose label starts with `prefix`.
+///
+/// Like [`completions`], keeping only items matched by `options`. An empty
+/// prefix keeps every item.
+pub fn completions_matching(
+    index: &SymbolIndex,
+    file: FileId,
+    line: u32,
+    col: u32,
+    trigger: Option<char>,
+    prefix: &str,
+    options: MatchOptions,
+) -> Vec<CompletionItem> {
+    let mut items = completions(index, file, line, col, trigger);
+    items.retain(|item| options.matches(prefix, &item.label));
     items
 }
 
+/// Get completions for the path of an `import` statement.
+///
+/// `line_prefix` is the text of the cursor's line up to the cursor. Returns
+/// `None` when the cursor is not in an import path, so callers can fall back
+/// to [`completions`]. A partial first segment (`import I`) offers the root
+/// packages; after a qualified prefix (`import ISQ::`) the members of that
+/// namespace are offered. Nothing is offered after a `*` wildcard.
+pub fn import_completions(index: &SymbolIndex, line_prefix: &str) -> Option<Vec<CompletionItem>> {
+    let path = import_path_prefix(line_prefix)?;
+    if path.contains('*') {
+        return Some(Vec::new());
+    }
+
+    let mut items: Vec<CompletionItem> = match path.rsplit_once("::") {
+        None => index
+            .all_symbols()
+            .filter(|symbol| {
+                symbol.kind == SymbolKind::Package && !symbol.qualified_name.contains("::")
+            })
+            .map(import_item)
+            .collect(),
+        Some((namespace, _)) => {
+            let Some(target) = index
+                .lookup_qualified(namespace)
+                .or_else(|| index.lookup_definition(namespace))
+            else {
+                return Some(Vec::new());
+            };
+            let Some(visibility) = index.visibility_for_scope(&target.qualified_name) else {
+                return Some(Vec::new());
+            };
+            visibility
+                .direct_defs()
+                .filter_map(|(name, qualified)| {
+                    let symbol = index.lookup_qualified(qualified)?;
+                    // Skip short-name aliases and anonymous members
+                    (name == &symbol.name && !name.starts_with('<')).then(|| import_item(symbol))
+                })
+                .collect()
+        }
+    };
+
+    items.sort_by(|a, b| a.label.cmp(&b.label));
+    items.dedup_by(|a, b| a.label == b.label);
+    Some(items)
+}
+
+/// Get the import path typed so far, if the line ends inside an import path.
+fn import_path_prefix(line_prefix: &str) -> Option<&str> {
+    let start = line_prefix.rfind("import")?;
+    let before = &line_prefix[..start];
+    if before
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+
+    let rest = &line_prefix[start + "import".len()..];
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = rest.trim_start();
+    let path = match rest.strip_prefix("all") {
+        Some(after) if after.starts_with(char::is_whitespace) => after.trim_start(),
+        _ => rest,
+    };
+
+    let is_path_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | ':' | '*' | '\'');
+    path.chars().all(is_path_char).then_some(path)
+}
+
+/// Completion item for an import path segment; packages are offered as modules.
+fn import_item(symbol: &HirSymbol) -> CompletionItem {
+    let mut item = CompletionItem::from_symbol(symbol);
+    if symbol.kind == SymbolKind::Package {
+        item.kind = CompletionKind::Package;
+    }
+    item.sort_priority = 10;
+    item
+}
+
 /// Completion context.
 #[derive(Debug)]
 enum CompletionContext {
@@ -197,21 +381,30 @@ enum CompletionContext {
     General,
 }
 
-fn determine_context(
-    _index: &SymbolIndex,
-    _file: FileId,
-    _line: u32,
-    _col: u32,
-    trigger: Option<char>,
-) -> CompletionContext {
-    // Simple heuristic based on trigger character
+fn determine_context(trigger: Option<char>, line_prefix: &str) -> CompletionContext {
+    // A trigger character wins; otherwise look at the text before the cursor
     match trigger {
         Some(':') => CompletionContext::TypeReference,
         Some('.') => CompletionContext::MemberAccess(String::new()),
+        _ if expects_type(line_prefix) => CompletionContext::TypeReference,
         _ => CompletionContext::General,
     }
 }
 
+/// Whether the text before the cursor ends in a type position, ignoring a
+/// partially typed name.
+fn expects_type(line_prefix: &str) -> bool {
+    let before = line_prefix.trim_end_matches(is_word_character).trim_end();
+    if before.ends_with("::") || before.ends_with(":>>") {
+        return false;
+    }
+    match before.strip_suffix(":>") {
+        // `:>` specializes a definition but subsets a usage
+        Some(head) => head.split_whitespace().any(|word| word == "def"),
+        None => before.ends_with(':'),
+    }
+}
+
 /// Get keyword completions.
 fn keyword_completions() -> Vec<CompletionItem> {
     let keywords = [
@@ -247,46 +440,12 @@ fn keyword_completions() -> Vec<CompletionItem> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::new_element_id;
+    use crate::base::LineColSpan;
 
     fn make_symbol(name: &str, qualified: &str, kind: SymbolKind) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qualified),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(0),
-            start_line: 0,
-            start_col: 0,
-            end_line: 0,
-            end_col: 10,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+        HirSymbol::builder(name, qualified, kind, FileId::new(0))
+            .span(LineColSpan::from_coords(0, 0, 0, 10))
+            .build()
     }
 
     #[test]
@@ -330,6 +489,19 @@ mod tests {
         assert!(items.iter().any(|i| i.label.as_ref() == "Engine"));
     }
 
+    #[test]
+    fn test_import_path_prefix() {
+        assert_eq!(import_path_prefix("    import "), Some(""));
+        assert_eq!(import_path_prefix("import I"), Some("I"));
+        assert_eq!(import_path_prefix("public import ISQ::"), Some("ISQ::"));
+        assert_eq!(import_path_prefix("import all Pkg::Sub"), Some("Pkg::Sub"));
+        assert_eq!(import_path_prefix("import Pkg::*"), Some("Pkg::*"));
+        assert_eq!(import_path_prefix("import Pkg::*;"), None);
+        assert_eq!(import_path_prefix("part x : "), None);
+        assert_eq!(import_path_prefix("reimport X"), None);
+        assert_eq!(import_path_prefix("import"), None);
+    }
+
     #[test]
     fn test_completion_kind_to_lsp() {
         assert_eq!(CompletionKind::Package.to_lsp(), 9);
@@ -54,9 +54,9 @@ pub fn document_links(index: &SymbolIndex, file: FileId) -> Vec<DocumentLink> {
                 // For imports, the "name" field contains the import path (e.g., "Base::*", "Pkg::Thing")
                 // Strip wildcard suffixes for resolution
                 let import_path = sym.name.as_ref();
-                let resolved_path = if let Some(stripped) = import_path.strip_suffix("::*") {
+                let resolved_path = if let Some(stripped) = import_path.strip_suffix("::**") {
                     stripped
-                } else if let Some(stripped) = import_path.strip_suffix(":::**") {
+                } else if let Some(stripped) = import_path.strip_suffix("::*") {
                     stripped
                 } else {
                     import_path
@@ -3,8 +3,13 @@
 //! This module provides folding range extraction from the HIR SymbolIndex,
 //! finding all symbols that span multiple lines.
 
+use rowan::TextSize;
+
 use crate::base::FileId;
 use crate::hir::{SymbolIndex, SymbolKind};
+use crate::syntax::SyntaxFile;
+
+use super::goto::declaration_range;
 
 /// A folding range with position information.
 #[derive(Debug, Clone)]
@@ -21,6 +26,17 @@ pub struct FoldingRange {
     pub is_comment: bool,
 }
 
+/// Options for [`folding_ranges_with_options`].
+///
+/// The default reports each range as the full span of the symbol's
+/// declaration, keeping only ranges that cover more than one line.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FoldingOptions {
+    /// End each range at its last line with content instead of at a line
+    /// holding only the closing brace.
+    pub end_at_last_content_line: bool,
+}
+
 /// Get folding ranges for a file.
 ///
 /// Returns all collapsible regions (definitions, blocks, comments).
@@ -43,3 +59,60 @@ pub fn folding_ranges(index: &SymbolIndex, file: FileId) -> Vec<FoldingRange> {
 
     ranges
 }
+
+/// Get folding ranges for a file's declarations, adjusted by `options`.
+///
+/// Unlike [`folding_ranges`], each symbol's range covers its whole
+/// declaration in `syntax`, not just its name. With
+/// `end_at_last_content_line`, a range whose last line holds only the
+/// closing brace ends at the last non-blank line before it instead, and
+/// ranges left on a single line are dropped.
+pub fn folding_ranges_with_options(
+    index: &SymbolIndex,
+    file: FileId,
+    syntax: &SyntaxFile,
+    options: FoldingOptions,
+) -> Vec<FoldingRange> {
+    let source = syntax.source_text();
+    let line_index = syntax.line_index();
+    let line_text = |line: u32| {
+        line_index
+            .line_text_range(line)
+            .map_or("", |range| &source[range])
+    };
+
+    let mut ranges: Vec<FoldingRange> = index
+        .symbols_in_file(file)
+        .into_iter()
+        .filter_map(|sym| {
+            let span = declaration_range(syntax, sym.span().start).unwrap_or(sym.span());
+            let mut range = FoldingRange {
+                start_line: span.start.line,
+                start_col: span.start.col,
+                end_line: span.end.line,
+                end_col: span.end.col,
+                is_comment: sym.kind == SymbolKind::Comment,
+            };
+            if options.end_at_last_content_line && line_text(range.end_line).trim() == "}" {
+                let last_content = (range.start_line..range.end_line)
+                    .rev()
+                    .find(|&line| !line_text(line).trim().is_empty());
+                let content_end = last_content.and_then(|line| {
+                    let text_range = line_index.line_text_range(line)?;
+                    let trimmed = source[text_range].trim_end().len() as u32;
+                    Some(text_range.start() + TextSize::from(trimmed))
+                });
+                if let Some(end) = content_end {
+                    let end = line_index.line_col(end);
+                    range.end_line = end.line;
+                    range.end_col = end.col;
+                }
+            }
+            (range.end_line > range.start_line).then_some(range)
+        })
+        .collect();
+
+    ranges.sort_by_key(|r| r.start_line);
+
+    ranges
+}
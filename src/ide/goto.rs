@@ -2,8 +2,10 @@
 
 use std::sync::Arc;
 
-use crate::base::FileId;
+use crate::base::{FileId, LineCol, LineColSpan};
 use crate::hir::{HirSymbol, RefKind, ResolveResult, Resolver, SymbolIndex, SymbolKind, TypeRef};
+use crate::parser::SyntaxKind;
+use crate::syntax::SyntaxFile;
 
 /// Result of a go-to-definition request.
 #[derive(Clone, Debug)]
@@ -39,6 +41,10 @@ impl GotoResult {
 }
 
 /// A target location for go-to-definition.
+///
+/// The point fields mirror `selection_range` for callers that only need a
+/// location; `full_range` covers the whole declaration, like the target
+/// range of an LSP `LocationLink`.
 #[derive(Clone, Debug)]
 pub struct GotoTarget {
     /// The file containing the target.
@@ -55,6 +61,27 @@ pub struct GotoTarget {
     pub kind: SymbolKind,
     /// The symbol name.
     pub name: Arc<str>,
+    /// The span of the declared name.
+    pub selection_range: LineColSpan,
+    /// The span of the whole declaration, including its body.
+    ///
+    /// Equal to `selection_range` unless the declaration was found in the
+    /// syntax tree (see [`GotoTarget::with_declaration`]).
+    pub full_range: LineColSpan,
+}
+
+impl GotoTarget {
+    /// Widen `full_range` to the declaration whose name starts at this
+    /// target's `selection_range`.
+    ///
+    /// `syntax` must be the parsed file containing the target. The range is
+    /// left unchanged if no such declaration is found.
+    pub fn with_declaration(mut self, syntax: &SyntaxFile) -> Self {
+        if let Some(range) = declaration_range(syntax, self.selection_range.start) {
+            self.full_range = range;
+        }
+        self
+    }
 }
 
 impl From<&HirSymbol> for GotoTarget {
@@ -67,12 +94,38 @@ impl From<&HirSymbol> for GotoTarget {
             end_col: symbol.end_col,
             kind: symbol.kind,
             name: symbol.name.clone(),
+            selection_range: symbol.span(),
+            full_range: symbol.span(),
         }
     }
 }
 
+/// Find the span of the syntax node declaring the name that starts at `name_start`.
+pub(crate) fn declaration_range(syntax: &SyntaxFile, name_start: LineCol) -> Option<LineColSpan> {
+    let line_index = syntax.line_index();
+    let offset = line_index.offset(name_start)?;
+    let token = syntax
+        .parse()
+        .syntax()
+        .token_at_offset(offset)
+        .right_biased()?;
+    let node = token.parent_ancestors().find(|node| {
+        node.children()
+            .any(|child| child.kind() == SyntaxKind::NAME && child.text_range().start() == offset)
+    })?;
+    let range = node.text_range();
+    Some(LineColSpan::new(
+        line_index.line_col(range.start()),
+        line_index.line_col(range.end()),
+    ))
+}
+
 /// Find the definition of a symbol at the given position.
 ///
+/// On a qualified reference such as `ISQ::SpaceTime::length`, the cursor's
+/// segment decides the target: `SpaceTime` goes to that package, while the
+/// last segment goes to `length` itself.
+///
 /// # Arguments
 /// * `index` - The symbol index to search
 /// * `file` - The file containing the cursor
@@ -90,6 +143,34 @@ pub fn goto_definition(index: &SymbolIndex, file: FileId, line: u32, col: u32) -
         let scope = extract_scope(&source_symbol.qualified_name);
         let resolver = Resolver::new(index).with_scope(scope);
 
+        // On a leading segment of a qualified path, go to that segment's namespace
+        if let Some(prefix) = qualified_prefix_at(type_ref, line, col) {
+            match resolver.resolve(&prefix) {
+                ResolveResult::Found(def) => {
+                    return GotoResult::single(GotoTarget::from(&def));
+                }
+                ResolveResult::Ambiguous(defs) => {
+                    let targets = defs.iter().map(GotoTarget::from).collect();
+                    return GotoResult::multiple(targets);
+                }
+                ResolveResult::NotFound => {
+                    if let Some(def) = index.lookup_qualified(&prefix) {
+                        return GotoResult::single(GotoTarget::from(def));
+                    }
+                }
+            }
+        }
+
+        // Prefer the target found by the resolution pass, which also covers
+        // references written with a short name (e.g., `:> m` for `<m> mass`)
+        if let Some(def) = type_ref
+            .resolved_target
+            .as_ref()
+            .and_then(|qname| index.lookup_qualified(qname))
+        {
+            return GotoResult::single(GotoTarget::from(def));
+        }
+
         // For Expression refs (like unit bracket [spatialCF]), we want to find the symbol
         // even if it's a usage, not just definitions. For other refs (TypedBy, etc.),
         // we only want definitions.
@@ -117,7 +198,7 @@ pub fn goto_definition(index: &SymbolIndex, file: FileId, line: u32, col: u32) -
     }
 
     // Find the symbol at the cursor position
-    let symbol = match find_symbol_at_position(index, file, line, col) {
+    let symbol = match index.symbol_at(file, line, col) {
         Some(s) => s,
         None => return GotoResult::empty(),
     };
@@ -197,7 +278,7 @@ pub fn goto_type_definition(index: &SymbolIndex, file: FileId, line: u32, col: u
     }
 
     // Find the symbol at the cursor position
-    let symbol = match find_symbol_at_position(index, file, line, col) {
+    let symbol = match index.symbol_at(file, line, col) {
         Some(s) => s,
         None => return GotoResult::empty(),
     };
@@ -278,52 +359,30 @@ fn find_type_ref_at_position(
     None
 }
 
-/// Find the symbol at a specific position in a file.
-fn find_symbol_at_position(
-    index: &SymbolIndex,
-    file: FileId,
-    line: u32,
-    col: u32,
-) -> Option<&HirSymbol> {
-    let symbols = index.symbols_in_file(file);
-
-    // Find smallest symbol containing the position
-    let mut best: Option<&HirSymbol> = None;
+/// Get the path up to the segment under the cursor in a qualified reference.
+///
+/// Returns `None` unless the cursor is on a segment other than the last one,
+/// or when the reference's span doesn't cover exactly the written path.
+fn qualified_prefix_at(type_ref: &TypeRef, line: u32, col: u32) -> Option<String> {
+    let target = type_ref.target.as_ref();
+    if !target.contains("::") || type_ref.start_line != line || type_ref.end_line != line {
+        return None;
+    }
+    if type_ref.end_col.checked_sub(type_ref.start_col)? as usize != target.len() {
+        return None;
+    }
 
-    for symbol in symbols {
-        if contains_position(symbol, line, col) {
-            match best {
-                None => best = Some(symbol),
-                Some(current) => {
-                    // Prefer smaller (more specific) symbols
-                    if symbol_size(symbol) < symbol_size(current) {
-                        best = Some(symbol);
-                    }
-                }
-            }
+    let offset = col.checked_sub(type_ref.start_col)? as usize;
+    let mut end = 0;
+    for segment in target.split("::") {
+        end += segment.len();
+        if offset <= end {
+            return (end < target.len()).then(|| target[..end].to_string());
         }
+        // Skip the `::` separator
+        end += 2;
     }
-
-    best
-}
-
-/// Check if a symbol's range contains a position.
-fn contains_position(symbol: &HirSymbol, line: u32, col: u32) -> bool {
-    // Check if position is after start
-    let after_start =
-        line > symbol.start_line || (line == symbol.start_line && col >= symbol.start_col);
-
-    // Check if position is before end
-    let before_end = line < symbol.end_line || (line == symbol.end_line && col <= symbol.end_col);
-
-    after_start && before_end
-}
-
-/// Calculate approximate size of a symbol's range.
-fn symbol_size(symbol: &HirSymbol) -> u32 {
-    let line_diff = symbol.end_line.saturating_sub(symbol.start_line);
-    let col_diff = symbol.end_col.saturating_sub(symbol.start_col);
-    line_diff * 1000 + col_diff
+    None
 }
 
 /// Extract the scope from a qualified name.
@@ -338,7 +397,6 @@ fn extract_scope(qualified_name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::new_element_id;
 
     fn make_symbol(
         name: &str,
@@ -347,43 +405,9 @@ mod tests {
         file: u32,
         line: u32,
     ) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qualified),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(file),
-            start_line: line,
-            start_col: 0,
-            end_line: line,
-            end_col: 10,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+        HirSymbol::builder(name, qualified, kind, FileId::new(file))
+            .span(LineColSpan::from_coords(line, 0, line, 10))
+            .build()
     }
 
     #[test]
@@ -2,8 +2,8 @@
 
 use std::sync::Arc;
 
-use crate::base::FileId;
-use crate::hir::{HirRelationship, HirSymbol, RelationshipKind, SymbolIndex, SymbolKind};
+use crate::base::{FileId, LineCol};
+use crate::hir::{HirRelationship, HirSymbol, RefKind, RelationshipKind, SymbolIndex, SymbolKind};
 use crate::ide::type_info::{find_type_ref_at_position, resolve_type_ref_with_chain};
 
 /// A resolved relationship with target location info for building links.
@@ -107,7 +107,12 @@ fn resolve_relationships(
 /// # Returns
 /// Hover information, or None if nothing to show.
 pub fn hover(index: &SymbolIndex, file: FileId, line: u32, col: u32) -> Option<HoverResult> {
-    // First, check if cursor is on a type reference (e.g., ::>, :, :>)
+    // Namespace imports summarize what they bring into scope
+    if let Some(result) = hover_namespace_import(index, file, line, col) {
+        return Some(result);
+    }
+
+    // Then check if cursor is on a type reference (e.g., ::>, :, :>)
     if let Some(ctx) = find_type_ref_at_position(index, file, line, col) {
         // Try to resolve and show hover for the target type
         if let Some(target_symbol) = resolve_type_ref_with_chain(index, &ctx) {
@@ -145,7 +150,7 @@ pub fn hover(index: &SymbolIndex, file: FileId, line: u32, col: u32) -> Option<H
     }
 
     // Otherwise, find the symbol at the cursor position
-    let symbol = find_symbol_at_position(index, file, line, col)?;
+    let symbol = index.symbol_at(file, line, col)?;
 
     // Build hover content
     let contents = build_hover_content(symbol, index);
@@ -153,6 +158,61 @@ pub fn hover(index: &SymbolIndex, file: FileId, line: u32, col: u32) -> Option<H
     Some(HoverResult::new(contents, symbol, index))
 }
 
+/// Maximum number of names listed in an import hover.
+const MAX_IMPORT_NAMES: usize = 20;
+
+/// Hover for a namespace import (`import Pkg::*` or `import Pkg::**`) under
+/// the cursor, listing the names the imported namespace makes visible.
+///
+/// Membership imports such as `import Pkg::Engine` fall through to the
+/// regular hover of the imported element.
+fn hover_namespace_import(
+    index: &SymbolIndex,
+    file: FileId,
+    line: u32,
+    col: u32,
+) -> Option<HoverResult> {
+    let pos = LineCol::new(line, col);
+    let import = index.symbols_in_file(file).into_iter().find(|symbol| {
+        symbol.kind == SymbolKind::Import
+            && (symbol.name.ends_with("::*") || symbol.name.ends_with("::**"))
+            && symbol.span().contains(pos)
+    })?;
+    let (target, mut names) = index.namespace_import_names(import)?;
+    names.retain(|(name, _, _)| !name.starts_with('<'));
+
+    let mut contents = format!("```sysml\n{}\n```\n", build_signature(import));
+    if import.is_public {
+        contents.push_str("\n*Public import: these names are re-exported.*\n");
+    }
+    contents.push_str(&format!(
+        "\n**Brings into scope from** `{}` ({}):\n\n",
+        target,
+        names.len()
+    ));
+    for (name, qualified, reexported) in names.iter().take(MAX_IMPORT_NAMES) {
+        if *reexported {
+            contents.push_str(&format!("- `{}` *(re-exported: `{}`)*\n", name, qualified));
+        } else {
+            contents.push_str(&format!("- `{}`\n", name));
+        }
+    }
+    if names.len() > MAX_IMPORT_NAMES {
+        contents.push_str(&format!("- +{} more\n", names.len() - MAX_IMPORT_NAMES));
+    }
+
+    Some(HoverResult {
+        contents,
+        qualified_name: Some(target),
+        is_definition: false,
+        relationships: Vec::new(),
+        start_line: import.start_line,
+        start_col: import.start_col,
+        end_line: import.end_line,
+        end_col: import.end_col,
+    })
+}
+
 /// Build markdown hover content for a symbol.
 fn build_hover_content(symbol: &HirSymbol, _index: &SymbolIndex) -> String {
     let mut content = String::new();
@@ -162,6 +222,13 @@ fn build_hover_content(symbol: &HirSymbol, _index: &SymbolIndex) -> String {
     content.push_str(&build_signature(symbol));
     content.push_str("\n```\n");
 
+    // Relationships, grouped by kind
+    if let Some(summary) = relationship_summary(&symbol.relationships) {
+        content.push('\n');
+        content.push_str(&summary);
+        content.push('\n');
+    }
+
     // Documentation
     if let Some(ref doc) = symbol.doc {
         content.push_str("\n---\n\n");
@@ -169,7 +236,7 @@ fn build_hover_content(symbol: &HirSymbol, _index: &SymbolIndex) -> String {
         content.push('\n');
     }
 
-    // Note: Relationships are formatted at the LSP layer with clickable links.
+    // Note: Clickable relationship links are added at the LSP layer.
 
     // Qualified name for context
     content.push_str("\n**Qualified Name:** `");
@@ -181,7 +248,30 @@ fn build_hover_content(symbol: &HirSymbol, _index: &SymbolIndex) -> String {
     content
 }
 
+/// Summarize relationships by kind, e.g. `specializes A, B; subsets x`.
+///
+/// Kinds are listed in the order they first appear.
+fn relationship_summary(relationships: &[HirRelationship]) -> Option<String> {
+    let mut groups: Vec<(RelationshipKind, Vec<&str>)> = Vec::new();
+    for rel in relationships {
+        match groups.iter_mut().find(|(kind, _)| *kind == rel.kind) {
+            Some((_, targets)) => targets.push(&rel.target),
+            None => groups.push((rel.kind, vec![&rel.target])),
+        }
+    }
+    if groups.is_empty() {
+        return None;
+    }
+    let parts: Vec<String> = groups
+        .iter()
+        .map(|(kind, targets)| format!("{} {}", kind.display().to_lowercase(), targets.join(", ")))
+        .collect();
+    Some(parts.join("; "))
+}
+
 /// Build a signature string for a symbol.
+///
+/// Usage types are shown by qualified name when they resolve.
 fn build_signature(symbol: &HirSymbol) -> String {
     let kind_str = symbol.kind.display();
 
@@ -251,13 +341,14 @@ fn build_signature(symbol: &HirSymbol) -> String {
         | SymbolKind::ReferenceUsage
         | SymbolKind::OccurrenceUsage
         | SymbolKind::FlowConnectionUsage
+        | SymbolKind::MetadataUsage
         | SymbolKind::ViewUsage
         | SymbolKind::ViewpointUsage
         | SymbolKind::RenderingUsage => {
             let mut sig = format!("{} {}", kind_str, name_with_alias);
-            if !symbol.supertypes.is_empty() {
+            if let Some(type_name) = typed_by_name(symbol) {
                 sig.push_str(" : ");
-                sig.push_str(symbol.supertypes[0].as_ref());
+                sig.push_str(type_name);
             }
             sig
         }
@@ -285,107 +376,31 @@ fn build_signature(symbol: &HirSymbol) -> String {
     }
 }
 
-/// Find the symbol at a specific position in a file.
-fn find_symbol_at_position(
-    index: &SymbolIndex,
-    file: FileId,
-    line: u32,
-    col: u32,
-) -> Option<&HirSymbol> {
-    let symbols = index.symbols_in_file(file);
-
-    // Find smallest symbol containing the position
-    let mut best: Option<&HirSymbol> = None;
-
-    for symbol in symbols {
-        if contains_position(symbol, line, col) || contains_short_name_position(symbol, line, col) {
-            match best {
-                None => best = Some(symbol),
-                Some(current) => {
-                    if symbol_size(symbol) < symbol_size(current) {
-                        best = Some(symbol);
-                    }
-                }
-            }
-        }
+/// The type a usage is typed by, by resolved qualified name when the
+/// reference resolved, falling back to the name as written.
+///
+/// Symbols built without type references show their first supertype.
+fn typed_by_name(symbol: &HirSymbol) -> Option<&Arc<str>> {
+    if symbol.type_refs.is_empty() {
+        return symbol.supertypes.first();
     }
-
-    best
-}
-
-fn contains_position(symbol: &HirSymbol, line: u32, col: u32) -> bool {
-    let after_start =
-        line > symbol.start_line || (line == symbol.start_line && col >= symbol.start_col);
-    let before_end = line < symbol.end_line || (line == symbol.end_line && col <= symbol.end_col);
-    after_start && before_end
-}
-
-/// Check if position is within the symbol's short_name span (for hover on short names).
-fn contains_short_name_position(symbol: &HirSymbol, line: u32, col: u32) -> bool {
-    // All four span components must be present
-    let (Some(start_line), Some(start_col), Some(end_line), Some(end_col)) = (
-        symbol.short_name_start_line,
-        symbol.short_name_start_col,
-        symbol.short_name_end_line,
-        symbol.short_name_end_col,
-    ) else {
-        return false;
-    };
-
-    let after_start = line > start_line || (line == start_line && col >= start_col);
-    let before_end = line < end_line || (line == end_line && col <= end_col);
-    after_start && before_end
-}
-
-fn symbol_size(symbol: &HirSymbol) -> u32 {
-    let line_diff = symbol.end_line.saturating_sub(symbol.start_line);
-    let col_diff = symbol.end_col.saturating_sub(symbol.start_col);
-    line_diff * 1000 + col_diff
+    symbol
+        .type_refs
+        .iter()
+        .flat_map(|trk| trk.as_refs())
+        .find(|tr| tr.kind == RefKind::TypedBy)
+        .map(|tr| tr.effective_target())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::new_element_id;
+    use crate::base::LineColSpan;
 
     fn make_symbol(name: &str, qualified: &str, kind: SymbolKind, line: u32) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qualified),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(0),
-            start_line: line,
-            start_col: 0,
-            end_line: line,
-            end_col: 20,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+        HirSymbol::builder(name, qualified, kind, FileId::new(0))
+            .span(LineColSpan::from_coords(line, 0, line, 20))
+            .build()
     }
 
     #[test]
@@ -3,8 +3,14 @@
 //! This module provides inlay hint extraction directly from the HIR layer,
 //! without depending on the legacy semantic layer.
 
-use crate::base::FileId;
-use crate::hir::SymbolIndex;
+use std::collections::HashSet;
+
+use super::signature_help::{
+    ExpressionAnchor, argument_offsets, expression_refs, invoked_definition, parameters,
+};
+use crate::base::{FileId, LineColSpan};
+use crate::hir::{HirSymbol, SymbolIndex};
+use crate::syntax::normalized::ValueExpression;
 
 /// Kind of inlay hint.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,6 +19,8 @@ pub enum InlayHintKind {
     Type,
     /// Parameter name hint (e.g., `width:`)
     Parameter,
+    /// Multiplicity hint (e.g., `[0..*]`)
+    Multiplicity,
 }
 
 /// An inlay hint to display in the editor.
@@ -32,10 +40,21 @@ pub struct InlayHint {
     pub padding_right: bool,
 }
 
+/// Options for [`inlay_hints_with_options`].
+///
+/// The default reports the same hints as [`inlay_hints`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InlayHintOptions {
+    /// Show a usage's declared multiplicity after its name.
+    pub show_multiplicity: bool,
+}
+
 /// Get inlay hints for a file.
 ///
 /// Returns type hints for symbols that have explicit type annotations.
-/// Currently shows the first supertype for usages.
+/// Currently shows the first supertype for usages. Positional arguments of
+/// calculation and action invocations get the name of the parameter they
+/// bind to.
 ///
 /// # Arguments
 ///
@@ -50,29 +69,52 @@ pub fn inlay_hints(
     index: &SymbolIndex,
     file: FileId,
     range: Option<(u32, u32, u32, u32)>,
+) -> Vec<InlayHint> {
+    inlay_hints_with_options(index, file, range, InlayHintOptions::default())
+}
+
+/// Get inlay hints for a file, with optional hints enabled by `options`.
+///
+/// With `show_multiplicity`, usages that declare a multiplicity also get a
+/// multiplicity hint after their name.
+pub fn inlay_hints_with_options(
+    index: &SymbolIndex,
+    file: FileId,
+    range: Option<(u32, u32, u32, u32)>,
+    options: InlayHintOptions,
 ) -> Vec<InlayHint> {
     let mut hints = Vec::new();
 
     for symbol in index.symbols_in_file(file) {
         // Skip if outside the requested range
         if let Some((start_line, start_col, end_line, end_col)) = range {
-            if symbol.start_line < start_line
-                || symbol.end_line > end_line
-                || (symbol.start_line == start_line && symbol.start_col < start_col)
-                || (symbol.end_line == end_line && symbol.end_col > end_col)
-            {
+            let range = LineColSpan::from_coords(start_line, start_col, end_line, end_col);
+            if !range.contains_span(symbol.span()) {
                 continue;
             }
         }
 
+        // Position hints after the symbol name
+        let hint_col = symbol.start_col + symbol.name.len() as u32;
+
+        if options.show_multiplicity && symbol.kind.is_usage() {
+            if let Some(multiplicity) = symbol.multiplicity {
+                hints.push(InlayHint {
+                    line: symbol.start_line,
+                    col: hint_col,
+                    label: multiplicity.to_string(),
+                    kind: InlayHintKind::Multiplicity,
+                    padding_left: false,
+                    padding_right: true,
+                });
+            }
+        }
+
         // Only show type hints for usages with explicit types
         if symbol.kind.is_usage() && !symbol.supertypes.is_empty() {
             // Show the primary type (first supertype, which is typically the typed_by)
             let type_name = &symbol.supertypes[0];
 
-            // Position hint after the symbol name
-            let hint_col = symbol.start_col + symbol.name.len() as u32;
-
             hints.push(InlayHint {
                 line: symbol.start_line,
                 col: hint_col,
@@ -82,7 +124,58 @@ pub fn inlay_hints(
                 padding_right: true,
             });
         }
+
+        parameter_hints(index, symbol, &mut hints);
     }
 
     hints
 }
+
+/// Parameter name hints for the positional arguments of the invocations in a
+/// symbol's value expression.
+fn parameter_hints(index: &SymbolIndex, symbol: &HirSymbol, hints: &mut Vec<InlayHint>) {
+    let Some(ValueExpression::Expression(text)) = &symbol.value else {
+        return;
+    };
+    let refs = expression_refs(symbol);
+    let Some(anchor) = ExpressionAnchor::new(text, &refs) else {
+        return;
+    };
+
+    // Expression references may repeat, so each argument list is hinted once
+    let mut seen = HashSet::new();
+    for tr in refs {
+        let Some(callee) = invoked_definition(index, tr) else {
+            continue;
+        };
+        let Some(paren) = anchor.argument_list(tr).filter(|&p| seen.insert(p)) else {
+            continue;
+        };
+        let params = parameters(index, callee);
+        for (arg, param) in argument_offsets(text, paren).into_iter().zip(&params) {
+            let Some(offset) = arg.filter(|&offset| !is_named_argument(&text[offset..])) else {
+                continue;
+            };
+            let Some((line, col)) = anchor.position(offset) else {
+                continue;
+            };
+            hints.push(InlayHint {
+                line,
+                col,
+                label: format!("{}:", param.name),
+                kind: InlayHintKind::Parameter,
+                padding_left: false,
+                padding_right: true,
+            });
+        }
+    }
+}
+
+/// Whether an argument is written as `name = value`.
+fn is_named_argument(arg: &str) -> bool {
+    let name_len = arg
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(arg.len());
+    let rest = arg[name_len..].trim_start();
+    name_len > 0 && rest.starts_with('=') && !rest.starts_with("==")
+}
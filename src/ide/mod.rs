@@ -24,6 +24,7 @@
 //! ```
 
 mod analysis;
+mod call_hierarchy;
 mod completion;
 mod document_links;
 mod folding;
@@ -33,20 +34,35 @@ mod inlay_hints;
 mod references;
 mod selection;
 mod semantic_tokens;
+mod signature_help;
 mod symbols;
 pub mod text_utils;
 mod type_info;
 
-pub use analysis::{Analysis, AnalysisHost};
-pub use completion::{CompletionItem, CompletionKind, completions};
+pub use analysis::{Analysis, AnalysisHost, FileStats};
+pub use call_hierarchy::{
+    CallHierarchyItem, CallSite, IncomingCall, OutgoingCall, incoming_calls, outgoing_calls,
+};
+pub use completion::{
+    CompletionItem, CompletionKind, MatchOptions, completions, completions_in_line,
+    completions_matching, import_completions,
+};
 pub use document_links::{DocumentLink, document_links};
-pub use folding::{FoldingRange, folding_ranges};
+pub use folding::{FoldingOptions, FoldingRange, folding_ranges, folding_ranges_with_options};
 pub use goto::{GotoResult, GotoTarget, goto_definition, goto_type_definition};
 pub use hover::{HoverResult, ResolvedRelationship, hover};
-pub use inlay_hints::{InlayHint, InlayHintKind, inlay_hints};
-pub use references::{Reference, ReferenceResult, find_references};
-pub use selection::{SelectionRange, selection_ranges};
-pub use semantic_tokens::{SemanticToken, TokenType, semantic_tokens};
-pub use symbols::{SymbolInfo, document_symbols, workspace_symbols};
+pub use inlay_hints::{
+    InlayHint, InlayHintKind, InlayHintOptions, inlay_hints, inlay_hints_with_options,
+};
+pub use references::{Reference, ReferenceResult, find_references, find_references_with_cancel};
+pub use selection::{SelectionRange, selection_ranges, selection_ranges_with_syntax};
+pub use semantic_tokens::{
+    SemanticToken, TokenModifier, TokenType, semantic_tokens, semantic_tokens_lsp,
+};
+pub use signature_help::{ParameterInfo, SignatureInfo, signature_help};
+pub use symbols::{
+    SymbolInfo, SymbolNode, document_symbols, document_symbols_hierarchical, workspace_symbols,
+    workspace_symbols_with_cancel,
+};
 pub use text_utils::{extract_qualified_name_at_cursor, extract_word_at_cursor};
 pub use type_info::{TypeInfo, find_type_ref_at_position, resolve_type_ref, type_info_at};
@@ -3,7 +3,7 @@
 use std::sync::Arc;
 
 use crate::base::FileId;
-use crate::hir::{HirSymbol, SymbolIndex, SymbolKind, TypeRef};
+use crate::hir::{HirSymbol, RefSite, SymbolIndex, SymbolKind, TypeRef};
 
 /// Result of a find-references request.
 #[derive(Clone, Debug)]
@@ -67,6 +67,19 @@ impl Reference {
         }
     }
 
+    /// Create from an indexed reference site.
+    pub fn from_ref_site(site: &RefSite) -> Self {
+        Self {
+            file: site.file,
+            start_line: site.span.start.line,
+            start_col: site.span.start.col,
+            end_line: site.span.end.line,
+            end_col: site.span.end.col,
+            is_definition: false,
+            kind: SymbolKind::Other, // Type references don't have a specific kind
+        }
+    }
+
     /// Create from a type reference.
     pub fn from_type_ref(type_ref: &TypeRef, file: FileId) -> Self {
         Self {
@@ -79,6 +92,14 @@ impl Reference {
             kind: SymbolKind::Other, // Type references don't have a specific kind
         }
     }
+
+    /// Whether this is the declaration of the symbol (vs a reference).
+    ///
+    /// The same flag as `is_definition`, named after the LSP
+    /// `includeDeclaration` option.
+    pub fn is_declaration(&self) -> bool {
+        self.is_definition
+    }
 }
 
 /// Find all references to the symbol at the given position.
@@ -99,15 +120,29 @@ pub fn find_references(
     col: u32,
     include_declaration: bool,
 ) -> ReferenceResult {
+    find_references_with_cancel(index, file, line, col, include_declaration, &|| false)
+        .unwrap_or_else(ReferenceResult::empty)
+}
+
+/// Like [`find_references`], but polls `is_cancelled` while scanning and
+/// returns `None` as soon as it reports true.
+pub fn find_references_with_cancel(
+    index: &SymbolIndex,
+    file: FileId,
+    line: u32,
+    col: u32,
+    include_declaration: bool,
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<ReferenceResult> {
     // First, check if cursor is on a type reference
     if let Some((target_name, _source_symbol)) = find_type_ref_at_position(index, file, line, col) {
-        return find_references_for_target(index, &target_name, include_declaration);
+        return find_references_for_target(index, &target_name, include_declaration, is_cancelled);
     }
 
     // Find the symbol at the cursor position
-    let symbol = match find_symbol_at_position(index, file, line, col) {
+    let symbol = match index.symbol_at(file, line, col) {
         Some(s) => s,
-        None => return ReferenceResult::empty(),
+        None => return Some(ReferenceResult::empty()),
     };
 
     // Determine what we're looking for
@@ -123,7 +158,7 @@ pub fn find_references(
         }
     };
 
-    find_references_for_target(index, &target_name, include_declaration)
+    find_references_for_target(index, &target_name, include_declaration, is_cancelled)
 }
 
 /// Find all references to a named target.
@@ -131,39 +166,35 @@ fn find_references_for_target(
     index: &SymbolIndex,
     target_name: &str,
     include_declaration: bool,
-) -> ReferenceResult {
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<ReferenceResult> {
     let mut references = Vec::new();
 
     // Find the definition
-    if let Some(def) = find_definition(index, target_name) {
+    let def = find_definition(index, target_name, is_cancelled);
+    if is_cancelled() {
+        return None;
+    }
+    if let Some(def) = def {
         if include_declaration {
             references.push(Reference::from_symbol(def, true));
         }
     }
 
-    // Extract simple name from qualified (for matching unqualified references)
-    let _simple_name = target_name.rsplit("::").next().unwrap_or(target_name);
-
-    // Collect all type references to this target (actual textual locations)
-    // Use resolved_target for proper scoped matching
-    let type_refs: Vec<_> = index
-        .all_symbols()
-        .flat_map(|sym| {
-            sym.type_refs
-                .iter()
-                .flat_map(|trk| trk.as_refs()) // Flatten TypeRefKind to &TypeRef
-                .filter(|tr| {
-                    // Use effective_target (resolved if available) for accurate scoped matching
-                    tr.effective_target().as_ref() == target_name
-                })
-                .map(move |tr| Reference::from_type_ref(tr, sym.file))
-        })
-        .collect();
-
-    references.extend(type_refs);
+    // Collect all type references to this target (actual textual locations),
+    // keyed by effective target (resolved if available) for scoped matching
+    references.extend(
+        index
+            .references_to(target_name)
+            .iter()
+            .map(Reference::from_ref_site),
+    );
 
     // Find direct name matches (for things like package references)
-    for sym in index.all_symbols() {
+    for sym in index.lookup_simple(target_name) {
+        if is_cancelled() {
+            return None;
+        }
         if sym.name.as_ref() == target_name && !sym.kind.is_definition() {
             // Avoid duplicates
             if !references.iter().any(|r| {
@@ -174,10 +205,10 @@ fn find_references_for_target(
         }
     }
 
-    ReferenceResult {
+    Some(ReferenceResult {
         references,
         include_declaration,
-    }
+    })
 }
 
 /// Find a type reference at a specific position.
@@ -204,7 +235,13 @@ fn find_type_ref_at_position(
 }
 
 /// Find the definition for a name.
-fn find_definition<'a>(index: &'a SymbolIndex, name: &str) -> Option<&'a HirSymbol> {
+///
+/// Gives up (returning `None`) once `is_cancelled` reports true.
+fn find_definition<'a>(
+    index: &'a SymbolIndex,
+    name: &str,
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<&'a HirSymbol> {
     // Try qualified name first
     if let Some(def) = index.lookup_definition(name) {
         return Some(def);
@@ -227,7 +264,10 @@ fn find_definition<'a>(index: &'a SymbolIndex, name: &str) -> Option<&'a HirSymb
     // If name contains ::, try suffix matching on qualified names
     if name.contains("::") {
         let suffix = format!("::{}", name);
-        for def in index.all_definitions() {
+        for def in index.definitions_sorted() {
+            if is_cancelled() {
+                return None;
+            }
             if def.qualified_name.ends_with(&suffix) || def.qualified_name.as_ref() == name {
                 return Some(def);
             }
@@ -237,50 +277,11 @@ fn find_definition<'a>(index: &'a SymbolIndex, name: &str) -> Option<&'a HirSymb
     None
 }
 
-/// Find the symbol at a specific position.
-fn find_symbol_at_position(
-    index: &SymbolIndex,
-    file: FileId,
-    line: u32,
-    col: u32,
-) -> Option<&HirSymbol> {
-    let symbols = index.symbols_in_file(file);
-
-    let mut best: Option<&HirSymbol> = None;
-
-    for symbol in symbols {
-        if contains_position(symbol, line, col) {
-            match best {
-                None => best = Some(symbol),
-                Some(current) => {
-                    if symbol_size(symbol) < symbol_size(current) {
-                        best = Some(symbol);
-                    }
-                }
-            }
-        }
-    }
-
-    best
-}
-
-fn contains_position(symbol: &HirSymbol, line: u32, col: u32) -> bool {
-    let after_start =
-        line > symbol.start_line || (line == symbol.start_line && col >= symbol.start_col);
-    let before_end = line < symbol.end_line || (line == symbol.end_line && col <= symbol.end_col);
-    after_start && before_end
-}
-
-fn symbol_size(symbol: &HirSymbol) -> u32 {
-    let line_diff = symbol.end_line.saturating_sub(symbol.start_line);
-    let col_diff = symbol.end_col.saturating_sub(symbol.start_col);
-    line_diff * 1000 + col_diff
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::{RefKind, new_element_id};
+    use crate::base::LineColSpan;
+    use crate::hir::RefKind;
 
     fn make_symbol(
         name: &str,
@@ -289,43 +290,9 @@ mod tests {
         file: u32,
         line: u32,
     ) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qualified),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(file),
-            start_line: line,
-            start_col: 0,
-            end_line: line,
-            end_col: 10,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+        HirSymbol::builder(name, qualified, kind, FileId::new(file))
+            .span(LineColSpan::from_coords(line, 0, line, 10))
+            .build()
     }
 
     #[test]
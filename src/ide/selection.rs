@@ -1,13 +1,21 @@
 //! Selection ranges — expanding selection regions.
 //!
-//! This module provides selection range extraction from the HIR SymbolIndex,
-//! finding all symbols whose span contains the given position.
+//! This module provides selection ranges for the position under the cursor:
+//! the identifier itself, then each enclosing element that declares a symbol
+//! in the HIR SymbolIndex. Symbol spans only cover names, so the extent of
+//! each element is taken from the syntax tree.
 
-use crate::base::FileId;
+use std::collections::HashSet;
+
+use crate::base::{FileId, LineCol, LineColSpan, LineIndex, TextRange, TextSize};
 use crate::hir::SymbolIndex;
+use crate::parser::SyntaxKind;
+use crate::syntax::SyntaxFile;
+
+use super::text_utils::find_word_boundaries;
 
 /// A selection range with parent chain.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SelectionRange {
     /// Start line (0-indexed)
     pub start_line: u32,
@@ -19,6 +27,28 @@ pub struct SelectionRange {
     pub end_col: u32,
 }
 
+impl SelectionRange {
+    fn from_text_range(line_index: &LineIndex, range: TextRange) -> Self {
+        let start = line_index.line_col(range.start());
+        let end = line_index.line_col(range.end());
+        Self {
+            start_line: start.line,
+            start_col: start.col,
+            end_line: end.line,
+            end_col: end.col,
+        }
+    }
+
+    /// Whether this range fully contains another.
+    fn contains(&self, other: &SelectionRange) -> bool {
+        self.span().contains_span(other.span())
+    }
+
+    fn span(&self) -> LineColSpan {
+        LineColSpan::from_coords(self.start_line, self.start_col, self.end_line, self.end_col)
+    }
+}
+
 /// Get selection ranges at a position.
 ///
 /// Returns spans from innermost to outermost that contain the position.
@@ -71,3 +101,89 @@ fn range_size(range: &SelectionRange) -> u32 {
     };
     lines * 100 + cols
 }
+
+/// Get selection ranges at a position, using the syntax tree for extents.
+///
+/// Returns ranges from innermost to outermost, each strictly containing the
+/// previous one: the identifier under the cursor, then the enclosing
+/// elements that declare symbols. Used for "Expand Selection" feature.
+///
+/// # Arguments
+/// * `index` - The symbol index containing all symbols
+/// * `file` - The file containing the cursor
+/// * `syntax` - The parsed file
+/// * `line` - Cursor line (0-indexed)
+/// * `col` - Cursor column (0-indexed)
+pub fn selection_ranges_with_syntax(
+    index: &SymbolIndex,
+    file: FileId,
+    syntax: &SyntaxFile,
+    line: u32,
+    col: u32,
+) -> Vec<SelectionRange> {
+    let text = syntax.source_text();
+    let line_index = LineIndex::new(&text);
+    let Some(offset) = line_index
+        .offset(LineCol { line, col })
+        .filter(|&offset| offset <= TextSize::of(&text))
+    else {
+        return Vec::new();
+    };
+
+    let mut candidates = Vec::new();
+    candidates.extend(word_range(&text, &line_index, line, col));
+
+    // Elements whose name is the span of a symbol in the index
+    let declared: HashSet<(u32, u32)> = index
+        .symbols_in_file(file)
+        .into_iter()
+        .map(|sym| (sym.start_line, sym.start_col))
+        .collect();
+    let root = syntax.parse().syntax();
+    if let Some(token) = root.token_at_offset(offset).right_biased() {
+        for node in token.parent_ancestors() {
+            let declares_symbol = node
+                .children()
+                .filter(|child| child.kind() == SyntaxKind::NAME)
+                .any(|name| {
+                    let start = line_index.line_col(name.text_range().start());
+                    declared.contains(&(start.line, start.col))
+                });
+            if declares_symbol {
+                candidates.push(SelectionRange::from_text_range(
+                    &line_index,
+                    node.text_range(),
+                ));
+            }
+        }
+    }
+
+    // Keep only ranges that strictly contain the previous one
+    let mut ranges: Vec<SelectionRange> = Vec::new();
+    for range in candidates {
+        if ranges
+            .last()
+            .is_none_or(|inner| range != *inner && range.contains(inner))
+        {
+            ranges.push(range);
+        }
+    }
+
+    ranges
+}
+
+/// The range of the identifier at a position, if any.
+fn word_range(text: &str, line_index: &LineIndex, line: u32, col: u32) -> Option<SelectionRange> {
+    let line_text = &text[line_index.line_text_range(line)?];
+    let chars: Vec<char> = line_text.chars().collect();
+    let position = line_text.get(..col as usize)?.chars().count();
+    let (start, end) = find_word_boundaries(&chars, position)?;
+
+    let byte_col = |index: usize| chars[..index].iter().map(|c| c.len_utf8()).sum::<usize>() as u32;
+    Some(SelectionRange {
+        start_line: line,
+        start_col: byte_col(start),
+        end_line: line,
+        end_col: byte_col(end),
+    })
+}
@@ -31,6 +31,29 @@ impl TokenType {
     }
 }
 
+/// Token modifier for semantic highlighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenModifier {
+    Declaration,
+    Definition,
+    Readonly,
+    Abstract,
+    Deprecated,
+}
+
+impl TokenModifier {
+    /// Convert to the LSP token modifier bit.
+    pub fn to_lsp_bit(self) -> u32 {
+        match self {
+            TokenModifier::Declaration => 1 << 0,
+            TokenModifier::Definition => 1 << 1,
+            TokenModifier::Readonly => 1 << 2,
+            TokenModifier::Abstract => 1 << 3,
+            TokenModifier::Deprecated => 1 << 4,
+        }
+    }
+}
+
 impl From<SymbolKind> for TokenType {
     fn from(kind: SymbolKind) -> Self {
         match kind {
@@ -81,6 +104,7 @@ impl From<SymbolKind> for TokenType {
             | SymbolKind::ReferenceUsage
             | SymbolKind::OccurrenceUsage
             | SymbolKind::FlowConnectionUsage
+            | SymbolKind::MetadataUsage
             | SymbolKind::ViewUsage
             | SymbolKind::ViewpointUsage
             | SymbolKind::RenderingUsage => TokenType::Property,
@@ -106,6 +130,15 @@ pub struct SemanticToken {
     pub length: u32,
     /// The token type
     pub token_type: TokenType,
+    /// Token modifiers, as a set of `TokenModifier::to_lsp_bit` bits
+    pub modifiers: u32,
+}
+
+impl SemanticToken {
+    /// Whether the token carries a modifier.
+    pub fn has_modifier(&self, modifier: TokenModifier) -> bool {
+        self.modifiers & modifier.to_lsp_bit() != 0
+    }
 }
 
 /// Get semantic tokens for a file.
@@ -125,7 +158,7 @@ pub fn semantic_tokens(index: &SymbolIndex, file: FileId) -> Vec<SemanticToken>
 
     // Add tokens for all symbols in this file
     for symbol in index.symbols_in_file(file) {
-        // Skip anonymous/synthetic symbols (names like `<:>>cyl#8@L7>`)
+        // Skip anonymous/synthetic symbols (names like `<:>>cyl#8>`)
         // These are generated names for anonymous usages and shouldn't be highlighted
         if symbol.name.starts_with('<') {
             // Still process type_refs for anonymous symbols
@@ -147,11 +180,22 @@ pub fn semantic_tokens(index: &SymbolIndex, file: FileId) -> Vec<SemanticToken>
                 || (symbol.start_col == 0 && symbol.start_line == 0 && length > 0);
 
             if is_valid_span {
+                // Symbol names are where the symbol is declared
+                let mut modifiers = TokenModifier::Declaration.to_lsp_bit()
+                    | TokenModifier::Definition.to_lsp_bit();
+                if symbol.is_abstract {
+                    modifiers |= TokenModifier::Abstract.to_lsp_bit();
+                }
+                if symbol.is_readonly {
+                    modifiers |= TokenModifier::Readonly.to_lsp_bit();
+                }
+
                 tokens.push(SemanticToken {
                     line: symbol.start_line,
                     col: symbol.start_col,
                     length,
                     token_type: TokenType::from(symbol.kind),
+                    modifiers,
                 });
             }
         }
@@ -170,6 +214,7 @@ pub fn semantic_tokens(index: &SymbolIndex, file: FileId) -> Vec<SemanticToken>
                         col: type_ref.start_col,
                         length: ref_length,
                         token_type: TokenType::Type,
+                        modifiers: 0,
                     });
                 }
             }
@@ -182,6 +227,47 @@ pub fn semantic_tokens(index: &SymbolIndex, file: FileId) -> Vec<SemanticToken>
     tokens
 }
 
+/// Get semantic tokens for a file in the LSP wire format.
+///
+/// Each token is encoded as the 5-tuple `(deltaLine, deltaStart, length,
+/// tokenType, tokenModifiers)`, relative to the previous token. `deltaStart`
+/// is relative to the previous token's column only when both are on the same
+/// line, so the first token encodes its absolute position.
+///
+/// # Arguments
+///
+/// * `index` - The symbol index containing all symbols
+/// * `file` - The file to get tokens for
+///
+/// # Returns
+///
+/// The flattened array of encoded tokens, five entries per token.
+pub fn semantic_tokens_lsp(index: &SymbolIndex, file: FileId) -> Vec<u32> {
+    let tokens = semantic_tokens(index, file);
+    let mut data = Vec::with_capacity(tokens.len() * 5);
+    let (mut prev_line, mut prev_col) = (0, 0);
+
+    for token in tokens {
+        let delta_line = token.line - prev_line;
+        let delta_start = if delta_line == 0 {
+            token.col - prev_col
+        } else {
+            token.col
+        };
+        data.extend([
+            delta_line,
+            delta_start,
+            token.length,
+            token.token_type.to_lsp_index(),
+            token.modifiers,
+        ]);
+        prev_line = token.line;
+        prev_col = token.col;
+    }
+
+    data
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,7 +453,7 @@ mod tests {
     fn test_redefines_multiplicity_not_highlighted() {
         // Test that multiplicity like [6..8] is NOT highlighted when following a redefines target
         // The issue: `part redefines cyl[6..8]` - only `cyl` should be highlighted, not `6..8`
-        // Root cause: anonymous usages get synthetic names like `<:>>cyl#8@L7>` which have
+        // Root cause: anonymous usages get synthetic names like `<:>>cyl#8>` which have
         // length 13, and the span starts at `cyl`, so the token covered `cyl[6..8]`.
         let source = r#"part def Vehicle {
     part eng {
@@ -397,7 +483,7 @@ part def SportsCar :> Vehicle {
         println!("Line 7 tokens: {:?}", line7_tokens);
 
         // There should NOT be a Property token for the anonymous usage
-        // (anonymous symbols with names like `<:>>cyl#8@L7>` should be skipped)
+        // (anonymous symbols with names like `<:>>cyl#8>` should be skipped)
         let property_tokens: Vec<_> = line7_tokens
             .iter()
             .filter(|t| t.token_type == TokenType::Property)
@@ -0,0 +1,294 @@
+//! Signature help for calculation and action invocations.
+//!
+//! Invocations only exist inside value expressions, which the HIR keeps as raw
+//! source text. The text is anchored to the file through the positions of the
+//! expression's type references, so the cursor can be located within it.
+
+use std::sync::Arc;
+
+use crate::base::FileId;
+use crate::hir::{HirSymbol, RefKind, SymbolIndex, SymbolKind, TypeRef, TypeRefKind};
+use crate::parser::Direction;
+use crate::syntax::normalized::ValueExpression;
+
+/// The signature of an invoked calculation or action.
+#[derive(Clone, Debug)]
+pub struct SignatureInfo {
+    /// Full signature label (e.g., `Area(in width : Real, in height : Real)`).
+    pub label: String,
+    /// Qualified name of the invoked definition.
+    pub qualified_name: Arc<str>,
+    /// Documentation of the invoked definition.
+    pub doc: Option<Arc<str>>,
+    /// Parameters in declaration order.
+    pub parameters: Vec<ParameterInfo>,
+    /// Index of the argument containing the cursor.
+    pub active_parameter: usize,
+}
+
+/// A parameter of an invoked calculation or action.
+#[derive(Clone, Debug)]
+pub struct ParameterInfo {
+    /// Parameter label (e.g., `in width : Real`).
+    pub label: String,
+    /// Parameter name.
+    pub name: Arc<str>,
+    /// Parameter direction.
+    pub direction: Direction,
+}
+
+/// Get signature help for the invocation surrounding a position.
+///
+/// # Arguments
+/// * `index` - The symbol index to search
+/// * `file` - The file containing the cursor
+/// * `line` - Cursor line (0-indexed)
+/// * `col` - Cursor column (0-indexed)
+///
+/// # Returns
+/// The signature of the innermost invocation whose argument list contains
+/// the cursor, or `None` if there is none.
+pub fn signature_help(
+    index: &SymbolIndex,
+    file: FileId,
+    line: u32,
+    col: u32,
+) -> Option<SignatureInfo> {
+    // (offset of the opening parenthesis, invoked definition, active argument)
+    let mut innermost: Option<(usize, &HirSymbol, usize)> = None;
+
+    for symbol in index.symbols_in_file(file) {
+        let Some(ValueExpression::Expression(text)) = &symbol.value else {
+            continue;
+        };
+        let refs = expression_refs(symbol);
+        let Some(anchor) = ExpressionAnchor::new(text, &refs) else {
+            continue;
+        };
+        let Some(cursor) = anchor.offset(line, col) else {
+            continue;
+        };
+
+        for tr in refs {
+            let Some(callee) = invoked_definition(index, tr) else {
+                continue;
+            };
+            let Some(paren) = anchor.argument_list(tr) else {
+                continue;
+            };
+            if paren >= cursor {
+                continue;
+            }
+            let Some(active) = active_argument(&text[paren + 1..cursor]) else {
+                continue;
+            };
+            if innermost.is_none_or(|(best, _, _)| paren > best) {
+                innermost = Some((paren, callee, active));
+            }
+        }
+    }
+
+    let (_, callee, active_parameter) = innermost?;
+    let parameters = parameters(index, callee);
+    let label = format!(
+        "{}({})",
+        callee.name,
+        parameters
+            .iter()
+            .map(|p| p.label.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    Some(SignatureInfo {
+        label,
+        qualified_name: callee.qualified_name.clone(),
+        doc: callee.doc.clone(),
+        parameters,
+        active_parameter,
+    })
+}
+
+/// The invocation-capable references of a symbol's value expression.
+pub(super) fn expression_refs(symbol: &HirSymbol) -> Vec<&TypeRef> {
+    symbol
+        .type_refs
+        .iter()
+        .filter_map(|trk| match trk {
+            TypeRefKind::Simple(tr) => Some(tr),
+            TypeRefKind::Chain(chain) => chain.parts.last(),
+        })
+        .filter(|tr| tr.kind == RefKind::Expression)
+        .collect()
+}
+
+/// Maps file positions into the text of a value expression.
+pub(super) struct ExpressionAnchor<'a> {
+    text: &'a str,
+    /// Byte offset in `text` of the anchoring reference.
+    offset: usize,
+    line: u32,
+    col: u32,
+}
+
+impl<'a> ExpressionAnchor<'a> {
+    /// Anchor on the earliest reference, located by its first occurrence.
+    pub(super) fn new(text: &'a str, refs: &[&TypeRef]) -> Option<Self> {
+        let first = refs.iter().min_by_key(|tr| (tr.start_line, tr.start_col))?;
+        Some(Self {
+            text,
+            offset: text.find(first.target.as_ref())?,
+            line: first.start_line,
+            col: first.start_col,
+        })
+    }
+
+    /// Byte offset in the text of a file position, if it falls within it.
+    fn offset(&self, line: u32, col: u32) -> Option<usize> {
+        let offset = if line == self.line {
+            (self.offset + col as usize).checked_sub(self.col as usize)?
+        } else {
+            let after = &self.text[self.offset..];
+            let newline = after
+                .match_indices('\n')
+                .nth(line.checked_sub(self.line)? as usize - 1)?
+                .0;
+            self.offset + newline + 1 + col as usize
+        };
+        (offset <= self.text.len() && self.text.is_char_boundary(offset)).then_some(offset)
+    }
+
+    /// File position of a byte offset at or after the anchoring reference.
+    pub(super) fn position(&self, offset: usize) -> Option<(u32, u32)> {
+        let between = self.text.get(self.offset..offset)?;
+        match between.rfind('\n') {
+            Some(newline) => Some((
+                self.line + between.matches('\n').count() as u32,
+                (between.len() - newline - 1) as u32,
+            )),
+            None => Some((self.line, self.col + between.len() as u32)),
+        }
+    }
+
+    /// Offset of the opening parenthesis following an invoked reference.
+    pub(super) fn argument_list(&self, type_ref: &TypeRef) -> Option<usize> {
+        let name_end = self.offset(type_ref.end_line, type_ref.end_col)?;
+        let rest = &self.text[name_end..];
+        let paren = name_end + (rest.len() - rest.trim_start().len());
+        self.text[paren..].starts_with('(').then_some(paren)
+    }
+}
+
+/// Byte offsets of the arguments of the argument list opening at `paren`,
+/// each at its first non-whitespace character, or `None` if it is empty.
+pub(super) fn argument_offsets(text: &str, paren: usize) -> Vec<Option<usize>> {
+    let mut offsets = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = None;
+    for (i, c) in text[paren + 1..].char_indices() {
+        let offset = paren + 1 + i;
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, ')' | ']' | '}') if depth == 0 => break,
+            (None, ',') if depth == 0 => {
+                offsets.push(start.take());
+                continue;
+            }
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            _ => {}
+        }
+        if start.is_none() && !c.is_whitespace() {
+            start = Some(offset);
+        }
+    }
+    if start.is_some() || !offsets.is_empty() {
+        offsets.push(start);
+    }
+    offsets
+}
+
+/// Index of the argument at the end of `args`, the text between an opening
+/// parenthesis and the cursor. `None` if the invocation closes before it.
+fn active_argument(args: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut active = 0;
+    let mut quote = None;
+    for c in args.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth = depth.checked_sub(1)?,
+            (None, ',') if depth == 0 => active += 1,
+            _ => {}
+        }
+    }
+    Some(active)
+}
+
+/// The calculation or action definition invoked through a reference.
+///
+/// Invoking a usage resolves to the definition it is typed by.
+pub(super) fn invoked_definition<'a>(
+    index: &'a SymbolIndex,
+    type_ref: &TypeRef,
+) -> Option<&'a HirSymbol> {
+    let target = index.lookup_qualified(type_ref.resolved_target.as_deref()?)?;
+    match target.kind {
+        SymbolKind::CalculationDefinition | SymbolKind::ActionDefinition => Some(target),
+        SymbolKind::CalculationUsage | SymbolKind::ActionUsage => target
+            .type_refs
+            .iter()
+            .flat_map(|trk| trk.as_refs())
+            .filter(|tr| tr.kind == RefKind::TypedBy)
+            .find_map(|tr| invoked_definition(index, tr)),
+        _ => None,
+    }
+}
+
+/// The directed features owned by a definition, in declaration order.
+pub(super) fn parameters(index: &SymbolIndex, definition: &HirSymbol) -> Vec<ParameterInfo> {
+    let prefix = format!("{}::", definition.qualified_name);
+    let mut owned: Vec<&HirSymbol> = index
+        .symbols_in_file(definition.file)
+        .into_iter()
+        .filter(|s| {
+            s.direction.is_some()
+                && s.qualified_name
+                    .strip_prefix(&prefix)
+                    .is_some_and(|rest| !rest.contains("::"))
+        })
+        .collect();
+    owned.sort_by_key(|s| (s.start_line, s.start_col));
+
+    owned
+        .into_iter()
+        .filter_map(|s| {
+            let direction = s.direction?;
+            let keyword = match direction {
+                Direction::In => "in",
+                Direction::Out => "out",
+                Direction::InOut => "inout",
+            };
+            let typed_by = s
+                .type_refs
+                .iter()
+                .flat_map(|trk| trk.as_refs())
+                .find(|tr| tr.kind == RefKind::TypedBy);
+            let label = match typed_by {
+                Some(tr) => format!("{} {} : {}", keyword, s.name, tr.target),
+                None => format!("{} {}", keyword, s.name),
+            };
+            Some(ParameterInfo {
+                label,
+                name: s.name.clone(),
+                direction,
+            })
+        })
+        .collect()
+}
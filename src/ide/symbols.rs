@@ -1,9 +1,10 @@
 //! Symbol listing for workspace and document views.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::base::FileId;
-use crate::hir::{HirSymbol, SymbolIndex, SymbolKind};
+use crate::hir::{HirSymbol, RefKind, SymbolIndex, SymbolKind};
 
 /// A symbol for the workspace symbol list or document outline.
 #[derive(Clone, Debug)]
@@ -24,6 +25,10 @@ pub struct SymbolInfo {
     pub end_line: u32,
     /// End column (0-indexed).
     pub end_col: u32,
+    /// Short description shown next to the name, e.g. `: Engine [1]` or `part def`.
+    pub detail: Option<String>,
+    /// Qualified name of the enclosing scope, or `None` at the root.
+    pub container_name: Option<String>,
 }
 
 impl SymbolInfo {
@@ -38,16 +43,54 @@ impl SymbolInfo {
             start_col: symbol.start_col,
             end_line: symbol.end_line,
             end_col: symbol.end_col,
+            detail: symbol_detail(symbol),
+            container_name: SymbolIndex::parent_scope(&symbol.qualified_name)
+                .filter(|scope| !scope.is_empty())
+                .map(str::to_string),
         }
     }
 
     /// Get the container name (parent path) for hierarchy building.
     pub fn container_name(&self) -> Option<&str> {
-        let qname = self.qualified_name.as_ref();
-        qname.rfind("::").map(|idx| &qname[..idx])
+        self.container_name.as_deref()
     }
 }
 
+/// Describe a symbol for outline views.
+///
+/// Typed usages show their type (the resolved qualified name when available)
+/// and multiplicity, e.g. `: Engine [1]`. Definitions show their kind keyword.
+/// Anything else, including packages and untyped usages, has no detail.
+fn symbol_detail(symbol: &HirSymbol) -> Option<String> {
+    if symbol.kind.is_definition() && symbol.kind != SymbolKind::Package {
+        return Some(symbol.kind.display().to_lowercase());
+    }
+
+    let type_ref = symbol
+        .type_refs
+        .iter()
+        .flat_map(|trk| trk.as_refs())
+        .find(|tr| tr.kind == RefKind::TypedBy)?;
+    let type_name = type_ref
+        .resolved_target
+        .as_ref()
+        .unwrap_or(&type_ref.target);
+
+    Some(match symbol.multiplicity {
+        Some(multiplicity) => format!(": {type_name} {multiplicity}"),
+        None => format!(": {type_name}"),
+    })
+}
+
+/// A document outline node: a symbol together with the symbols nested in it.
+#[derive(Clone, Debug)]
+pub struct SymbolNode {
+    /// The symbol at this node.
+    pub symbol: SymbolInfo,
+    /// Nested symbols, in source order.
+    pub children: Vec<SymbolNode>,
+}
+
 /// Get all symbols in the workspace, optionally filtered by a query.
 ///
 /// # Arguments
@@ -57,30 +100,43 @@ impl SymbolInfo {
 /// # Returns
 /// List of matching symbols, sorted by name.
 pub fn workspace_symbols(index: &SymbolIndex, query: Option<&str>) -> Vec<SymbolInfo> {
+    workspace_symbols_with_cancel(index, query, &|| false).unwrap_or_default()
+}
+
+/// Like [`workspace_symbols`], but polls `is_cancelled` for each symbol
+/// scanned and returns `None` as soon as it reports true.
+pub fn workspace_symbols_with_cancel(
+    index: &SymbolIndex,
+    query: Option<&str>,
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<Vec<SymbolInfo>> {
     let query_lower = query.map(|q| q.to_lowercase());
 
-    let mut results: Vec<SymbolInfo> = index
-        .all_symbols()
-        .filter(|sym| {
-            // Skip imports
-            if matches!(sym.kind, SymbolKind::Import) {
-                return false;
-            }
+    let mut results: Vec<SymbolInfo> = Vec::new();
+    for sym in index.all_symbols() {
+        if is_cancelled() {
+            return None;
+        }
 
-            // Filter by query if provided
-            if let Some(ref q) = query_lower {
-                let name_lower = sym.name.to_lowercase();
-                let qname_lower = sym.qualified_name.to_lowercase();
-                name_lower.contains(q) || qname_lower.contains(q)
-            } else {
-                true
+        // Skip imports
+        if matches!(sym.kind, SymbolKind::Import) {
+            continue;
+        }
+
+        // Filter by query if provided
+        if let Some(ref q) = query_lower {
+            let name_lower = sym.name.to_lowercase();
+            let qname_lower = sym.qualified_name.to_lowercase();
+            if !name_lower.contains(q) && !qname_lower.contains(q) {
+                continue;
             }
-        })
-        .map(SymbolInfo::from_hir)
-        .collect();
+        }
+
+        results.push(SymbolInfo::from_hir(sym));
+    }
 
     results.sort_by(|a, b| a.name.cmp(&b.name));
-    results
+    Some(results)
 }
 
 /// Get all symbols in a specific file for document outline.
@@ -102,59 +158,85 @@ pub fn document_symbols(index: &SymbolIndex, file: FileId) -> Vec<SymbolInfo> {
         .map(SymbolInfo::from_hir)
         .collect();
 
-    // Sort by position in file
+    // Sort by position in file. Symbols starting at the same position put
+    // the enclosing (longer) one first, then fall back to the qualified name
+    // so the order never depends on how the index stored them.
     results.sort_by(|a, b| {
-        a.start_line
-            .cmp(&b.start_line)
-            .then(a.start_col.cmp(&b.start_col))
+        (a.start_line, a.start_col)
+            .cmp(&(b.start_line, b.start_col))
+            .then_with(|| (b.end_line, b.end_col).cmp(&(a.end_line, a.end_col)))
+            .then_with(|| a.qualified_name.cmp(&b.qualified_name))
     });
 
     results
 }
 
+/// Get the symbols in a file as an outline tree.
+///
+/// Each symbol is nested under its closest ancestor (by qualified name) that
+/// is also in the outline; symbols without one become top-level nodes.
+///
+/// # Arguments
+/// * `index` - The symbol index to search
+/// * `file` - The file to get symbols for
+///
+/// # Returns
+/// Top-level nodes, with every level in source order.
+pub fn document_symbols_hierarchical(index: &SymbolIndex, file: FileId) -> Vec<SymbolNode> {
+    let symbols = document_symbols(index, file);
+
+    // First symbol wins for duplicated qualified names
+    let mut position: HashMap<&str, usize> = HashMap::new();
+    for (idx, sym) in symbols.iter().enumerate() {
+        position.entry(sym.qualified_name.as_ref()).or_insert(idx);
+    }
+
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); symbols.len()];
+    let mut roots = Vec::new();
+    for (idx, sym) in symbols.iter().enumerate() {
+        // Walk up until an ancestor is in the outline (imports, comments and
+        // scopes from other files are skipped)
+        let mut container = sym.container_name();
+        let parent = loop {
+            match container {
+                Some(name) => match position.get(name) {
+                    Some(&parent) if parent != idx => break Some(parent),
+                    _ => container = name.rfind("::").map(|pos| &name[..pos]),
+                },
+                None => break None,
+            }
+        };
+        match parent {
+            Some(parent) => children[parent].push(idx),
+            None => roots.push(idx),
+        }
+    }
+
+    fn build(idx: usize, symbols: &[SymbolInfo], children: &[Vec<usize>]) -> SymbolNode {
+        SymbolNode {
+            symbol: symbols[idx].clone(),
+            children: children[idx]
+                .iter()
+                .map(|&child| build(child, symbols, children))
+                .collect(),
+        }
+    }
+
+    roots
+        .into_iter()
+        .map(|idx| build(idx, &symbols, &children))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::new_element_id;
+    use crate::base::LineColSpan;
 
     fn make_symbol(name: &str, qname: &str, kind: SymbolKind, line: u32) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qname),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(0),
-            start_line: line,
-            start_col: 0,
-            end_line: line,
-            end_col: 10,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            doc: None,
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+        HirSymbol::builder(name, qname, kind, FileId::new(0))
+            .span(LineColSpan::from_coords(line, 0, line, 10))
+            .build()
     }
 
     #[test]
@@ -213,18 +295,60 @@ mod tests {
     }
 
     #[test]
-    fn test_container_name() {
-        let sym = SymbolInfo {
-            name: Arc::from("engine"),
-            qualified_name: Arc::from("Vehicle::Car::engine"),
-            kind: SymbolKind::PartUsage,
-            file: FileId::new(0),
-            start_line: 0,
-            start_col: 0,
-            end_line: 0,
-            end_col: 10,
+    fn test_document_symbols_hierarchical() {
+        let mut index = SymbolIndex::new();
+        index.add_file(
+            FileId::new(0),
+            vec![
+                make_symbol("Vehicle", "Vehicle", SymbolKind::Package, 0),
+                make_symbol("Car", "Vehicle::Car", SymbolKind::PartDefinition, 1),
+                make_symbol("engine", "Vehicle::Car::engine", SymbolKind::PartUsage, 2),
+                make_symbol(
+                    "pistons",
+                    "Vehicle::Car::engine::pistons",
+                    SymbolKind::PartUsage,
+                    3,
+                ),
+                make_symbol("wheels", "Vehicle::Car::wheels", SymbolKind::PartUsage, 4),
+                make_symbol(
+                    "import:Other::*",
+                    "Vehicle::import:Other::*",
+                    SymbolKind::Import,
+                    5,
+                ),
+                make_symbol("Other", "Other", SymbolKind::Package, 6),
+            ],
+        );
+
+        let roots = document_symbols_hierarchical(&index, FileId::new(0));
+        let names = |nodes: &[SymbolNode]| -> Vec<String> {
+            nodes.iter().map(|n| n.symbol.name.to_string()).collect()
         };
 
+        assert_eq!(names(&roots), ["Vehicle", "Other"]);
+        assert_eq!(names(&roots[0].children), ["Car"]);
+        let car = &roots[0].children[0];
+        assert_eq!(names(&car.children), ["engine", "wheels"]);
+        assert_eq!(names(&car.children[0].children), ["pistons"]);
+        assert!(car.children[1].children.is_empty());
+        assert!(roots[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_container_name() {
+        let engine = make_symbol("engine", "Vehicle::Car::engine", SymbolKind::PartUsage, 0);
+        let sym = SymbolInfo::from_hir(&engine);
+        assert_eq!(sym.container_name.as_deref(), Some("Vehicle::Car"));
         assert_eq!(sym.container_name(), Some("Vehicle::Car"));
+
+        let root = make_symbol("Vehicle", "Vehicle", SymbolKind::Package, 0);
+        assert_eq!(SymbolInfo::from_hir(&root).container_name, None);
+
+        // Separators inside anonymous scope names don't split the container
+        let anon = make_symbol("x", "P::<perform:A::B>::x", SymbolKind::PartUsage, 0);
+        assert_eq!(
+            SymbolInfo::from_hir(&anon).container_name.as_deref(),
+            Some("P::<perform:A::B>")
+        );
     }
 }
@@ -380,7 +380,8 @@ pub fn find_type_ref_at_position(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hir::{RefKind, SymbolKind, new_element_id};
+    use crate::base::LineColSpan;
+    use crate::hir::{RefKind, SymbolKind};
 
     fn make_symbol_with_type_ref(
         name: &str,
@@ -389,50 +390,18 @@ mod tests {
         type_ref_target: &str,
         line: u32,
     ) -> HirSymbol {
-        HirSymbol {
-            name: Arc::from(name),
-            short_name: None,
-            qualified_name: Arc::from(qualified),
-            element_id: new_element_id(),
-            kind,
-            file: FileId::new(0),
-            start_line: line,
-            start_col: 0,
-            end_line: line + 1,
-            end_col: 0,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: vec![Arc::from(type_ref_target)],
-            relationships: Vec::new(),
-            type_refs: vec![crate::hir::TypeRefKind::Simple(TypeRef::new(
+        HirSymbol::builder(name, qualified, kind, FileId::new(0))
+            .span(LineColSpan::from_coords(line, 0, line + 1, 0))
+            .supertypes(vec![Arc::from(type_ref_target)])
+            .type_refs(vec![crate::hir::TypeRefKind::Simple(TypeRef::new(
                 type_ref_target,
                 RefKind::TypedBy,
                 line,
                 10,
                 line,
                 20,
-            ))],
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        }
+            ))])
+            .build()
     }
 
     #[test]
@@ -440,43 +409,14 @@ mod tests {
         let mut index = SymbolIndex::new();
 
         // Add a definition
-        let def = HirSymbol {
-            name: Arc::from("Engine"),
-            short_name: None,
-            qualified_name: Arc::from("Engine"),
-            element_id: new_element_id(),
-            kind: SymbolKind::PartDefinition,
-            file: FileId::new(0),
-            start_line: 0,
-            start_col: 0,
-            end_line: 5,
-            end_col: 0,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        };
+        let def = HirSymbol::builder(
+            "Engine",
+            "Engine",
+            SymbolKind::PartDefinition,
+            FileId::new(0),
+        )
+        .span(LineColSpan::from_coords(0, 0, 5, 0))
+        .build();
 
         // Add a usage with type_ref
         let usage =
@@ -501,43 +441,9 @@ mod tests {
     fn test_type_info_not_on_type_ref() {
         let mut index = SymbolIndex::new();
 
-        let symbol = HirSymbol {
-            name: Arc::from("Car"),
-            short_name: None,
-            qualified_name: Arc::from("Car"),
-            element_id: new_element_id(),
-            kind: SymbolKind::PartDefinition,
-            file: FileId::new(0),
-            start_line: 0,
-            start_col: 0,
-            end_line: 10,
-            end_col: 0,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: None,
-            supertypes: Vec::new(),
-            relationships: Vec::new(),
-            type_refs: Vec::new(),
-            is_public: false,
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: false,
-            is_variation: false,
-            is_readonly: false,
-            is_derived: false,
-            is_parallel: false,
-            is_individual: false,
-            is_end: false,
-            is_default: false,
-            is_ordered: false,
-            is_nonunique: false,
-            is_portion: false,
-            direction: None,
-            multiplicity: None,
-            value: None,
-        };
+        let symbol = HirSymbol::builder("Car", "Car", SymbolKind::PartDefinition, FileId::new(0))
+            .span(LineColSpan::from_coords(0, 0, 10, 0))
+            .build();
 
         index.add_file(FileId::new(0), vec![symbol]);
 
@@ -347,8 +347,13 @@ impl<'a> DecompileContext<'a> {
                 self.decompile_body(element);
                 self.write_line("}");
             }
-        } else if !relations.is_empty() || !value.is_empty() {
-            // Anonymous usage with typing/subsetting/value
+        } else if has_non_value_children {
+            // Anonymous usage with a body
+            self.write_line(&format!("{}{}{} {{", keyword, relations, value));
+            self.decompile_body(element);
+            self.write_line("}");
+        } else {
+            // Anonymous usage, possibly with typing/subsetting/value
             self.write_line(&format!("{}{}{};", keyword, relations, value));
         }
     }
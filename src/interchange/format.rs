@@ -1,7 +1,7 @@
 //! Common trait for model interchange formats.
 
 use super::InterchangeError;
-use super::model::Model;
+use super::model::{Element, Model, Relationship};
 
 /// Capabilities supported by a format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -95,3 +95,23 @@ pub trait ModelFormat: Send + Sync {
         Ok(())
     }
 }
+
+/// Callbacks for consuming a model incrementally while it is being read.
+///
+/// Streaming readers such as [`Xmi::read_streaming`](super::Xmi::read_streaming)
+/// report elements and relationships as they are parsed, so large models can
+/// be processed without materializing a full `Model`.
+pub trait ModelVisitor {
+    /// Called once per element, after all of its children have been read.
+    fn on_element(&mut self, element: &Element);
+
+    /// Called once per relationship, as soon as both ends are known.
+    fn on_relationship(&mut self, relationship: &Relationship) {
+        let _ = relationship;
+    }
+
+    /// Called for each XML namespace declared on the document root.
+    fn on_namespace(&mut self, prefix: &str, uri: &str) {
+        let _ = (prefix, uri);
+    }
+}
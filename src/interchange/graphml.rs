@@ -0,0 +1,347 @@
+//! GraphML export.
+//!
+//! Renders a [`Model`] as a GraphML document for graph tools such as yEd,
+//! Gephi or Cytoscape. Each element becomes a `<node>` and each relationship
+//! an `<edge>`; element ownership can optionally be added as containment
+//! edges. Edges whose ends are not written as nodes are dropped, so the
+//! document never refers to a missing node. This format is write-only.
+//!
+//! ## Output Structure
+//!
+//! ```text
+//! <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+//!   <key id="name" for="node" attr.name="name" attr.type="string"/>
+//!   <key id="kind" for="node" attr.name="kind" attr.type="string"/>
+//!   <key id="edge_kind" for="edge" attr.name="kind" attr.type="string"/>
+//!   <graph id="model" edgedefault="directed">
+//!     <node id="pd-1">
+//!       <data key="name">Vehicle</data>
+//!       <data key="kind">PartDefinition</data>
+//!     </node>
+//!     <edge id="rel-1" source="pd-2" target="pd-1">
+//!       <data key="edge_kind">Specialization</data>
+//!     </edge>
+//!   </graph>
+//! </graphml>
+//! ```
+
+use std::collections::HashSet;
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+
+use super::model::{Element, ElementId, Model};
+use super::{FormatCapability, InterchangeError, ModelFormat};
+
+/// Kind recorded on containment edges added for element ownership.
+const OWNERSHIP_KIND: &str = "Ownership";
+
+/// GraphML format handler.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphMl {
+    /// Emit an edge from each element's nearest owning node to the element.
+    pub include_ownership: bool,
+}
+
+impl GraphMl {
+    /// Also emit element ownership as containment edges.
+    pub fn with_ownership(mut self, include: bool) -> Self {
+        self.include_ownership = include;
+        self
+    }
+}
+
+impl ModelFormat for GraphMl {
+    fn name(&self) -> &'static str {
+        "GraphML"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["graphml"]
+    }
+
+    fn mime_type(&self) -> &'static str {
+        "application/graphml+xml"
+    }
+
+    fn capabilities(&self) -> FormatCapability {
+        FormatCapability {
+            lossless: false,
+            ..FormatCapability::WRITE_ONLY
+        }
+    }
+
+    fn read(&self, input: &[u8]) -> Result<Model, InterchangeError> {
+        let _ = input;
+        Err(InterchangeError::Unsupported(
+            "GraphML is an export-only format".to_string(),
+        ))
+    }
+
+    fn write(&self, model: &Model) -> Result<Vec<u8>, InterchangeError> {
+        let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+        let xml_err = |e: std::io::Error| InterchangeError::xml(format!("Write error: {e}"));
+
+        writer
+            .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
+            .map_err(xml_err)?;
+
+        let mut graphml = BytesStart::new("graphml");
+        graphml.push_attribute(("xmlns", "http://graphml.graphdrawing.org/xmlns"));
+        writer.write_event(Event::Start(graphml)).map_err(xml_err)?;
+
+        for (id, target) in [("name", "node"), ("kind", "node"), ("edge_kind", "edge")] {
+            let attr_name = id.trim_start_matches("edge_");
+            let mut key = BytesStart::new("key");
+            key.push_attribute(("id", id));
+            key.push_attribute(("for", target));
+            key.push_attribute(("attr.name", attr_name));
+            key.push_attribute(("attr.type", "string"));
+            writer.write_event(Event::Empty(key)).map_err(xml_err)?;
+        }
+
+        let mut graph = BytesStart::new("graph");
+        graph.push_attribute(("id", "model"));
+        graph.push_attribute(("edgedefault", "directed"));
+        writer.write_event(Event::Start(graph)).map_err(xml_err)?;
+
+        let mut nodes = HashSet::new();
+        for element in model.iter_elements() {
+            if element.kind.is_relationship() {
+                continue;
+            }
+            nodes.insert(&element.id);
+            let mut node = BytesStart::new("node");
+            node.push_attribute(("id", element.id.as_str()));
+            writer.write_event(Event::Start(node)).map_err(xml_err)?;
+            if let Some(name) = &element.name {
+                write_data(&mut writer, "name", name).map_err(xml_err)?;
+            }
+            write_data(&mut writer, "kind", &format!("{:?}", element.kind)).map_err(xml_err)?;
+            writer
+                .write_event(Event::End(BytesEnd::new("node")))
+                .map_err(xml_err)?;
+        }
+
+        for rel in &model.relationships {
+            if !nodes.contains(&rel.source) || !nodes.contains(&rel.target) {
+                continue;
+            }
+            let kind = format!("{:?}", rel.kind);
+            write_edge(
+                &mut writer,
+                rel.id.as_str(),
+                &rel.source,
+                &rel.target,
+                &kind,
+            )
+            .map_err(xml_err)?;
+        }
+
+        if self.include_ownership {
+            for element in model.iter_elements() {
+                if element.kind.is_relationship() {
+                    continue;
+                }
+                if let Some(owner) = owning_node(model, element) {
+                    let id = format!("{}-owner", element.id);
+                    write_edge(&mut writer, &id, owner, &element.id, OWNERSHIP_KIND)
+                        .map_err(xml_err)?;
+                }
+            }
+        }
+
+        writer
+            .write_event(Event::End(BytesEnd::new("graph")))
+            .map_err(xml_err)?;
+        writer
+            .write_event(Event::End(BytesEnd::new("graphml")))
+            .map_err(xml_err)?;
+
+        let mut output = writer.into_inner();
+        output.push(b'\n');
+        Ok(output)
+    }
+}
+
+/// The nearest owner of `element` that is written as a node.
+///
+/// Membership relationships sit between a namespace and its members in the
+/// ownership tree, so they are skipped. Returns `None` if the chain of owners
+/// ends at a missing element or loops back on itself.
+fn owning_node<'a>(model: &'a Model, element: &Element) -> Option<&'a ElementId> {
+    let mut visited = HashSet::new();
+    let mut owner = model.get(element.owner.as_ref()?)?;
+    while owner.kind.is_relationship() {
+        if !visited.insert(&owner.id) {
+            return None;
+        }
+        owner = model.get(owner.owner.as_ref()?)?;
+    }
+    Some(&owner.id)
+}
+
+fn write_edge(
+    writer: &mut Writer<Vec<u8>>,
+    id: &str,
+    source: &ElementId,
+    target: &ElementId,
+    kind: &str,
+) -> std::io::Result<()> {
+    let mut edge = BytesStart::new("edge");
+    edge.push_attribute(("id", id));
+    edge.push_attribute(("source", source.as_str()));
+    edge.push_attribute(("target", target.as_str()));
+    writer.write_event(Event::Start(edge))?;
+    write_data(writer, "edge_kind", kind)?;
+    writer.write_event(Event::End(BytesEnd::new("edge")))
+}
+
+fn write_data(writer: &mut Writer<Vec<u8>>, key: &str, value: &str) -> std::io::Result<()> {
+    let mut data = BytesStart::new("data");
+    data.push_attribute(("key", key));
+    writer.write_event(Event::Start(data))?;
+    writer.write_event(Event::Text(BytesText::new(value)))?;
+    writer.write_event(Event::End(BytesEnd::new("data")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interchange::model::{ElementKind, Relationship, RelationshipKind};
+    use quick_xml::Reader;
+
+    /// Parse the whole document, counting `<node>` and `<edge>` elements.
+    fn count_nodes_and_edges(xml: &str) -> (usize, usize) {
+        let mut reader = Reader::from_str(xml);
+        let (mut nodes, mut edges) = (0, 0);
+        loop {
+            match reader
+                .read_event()
+                .expect("output should be well-formed XML")
+            {
+                Event::Start(e) | Event::Empty(e) => match e.name().as_ref() {
+                    b"node" => nodes += 1,
+                    b"edge" => edges += 1,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        (nodes, edges)
+    }
+
+    fn sample_model() -> Model {
+        let mut model = Model::new();
+        model.add_element(
+            Element::new(ElementId::new("pkg"), ElementKind::Package).with_name("Vehicles"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("m-1"), ElementKind::OwningMembership).with_owner("pkg"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("pd-1"), ElementKind::PartDefinition)
+                .with_name("Vehicle")
+                .with_owner("m-1"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("pd-2"), ElementKind::PartDefinition)
+                .with_name("Car & Co")
+                .with_owner("pkg"),
+        );
+        model.add_relationship(Relationship::new(
+            "rel-1",
+            RelationshipKind::Specialization,
+            "pd-2",
+            "pd-1",
+        ));
+        model
+    }
+
+    #[test]
+    fn test_graphml_format_metadata() {
+        let graphml = GraphMl::default();
+        assert_eq!(graphml.name(), "GraphML");
+        assert!(!graphml.capabilities().read);
+        assert!(graphml.capabilities().write);
+        assert!(graphml.read(b"<graphml/>").is_err());
+    }
+
+    #[test]
+    fn test_graphml_write_nodes_and_edges() {
+        let bytes = GraphMl::default()
+            .write(&sample_model())
+            .expect("write should succeed");
+        let content = String::from_utf8(bytes).expect("should be valid UTF-8");
+
+        // Membership elements are relationships, not nodes
+        assert_eq!(count_nodes_and_edges(&content), (3, 1));
+        assert!(content.contains(r#"<node id="pd-1">"#));
+        assert!(content.contains(r#"<data key="kind">PartDefinition</data>"#));
+        assert!(content.contains(r#"<data key="name">Car &amp; Co</data>"#));
+        assert!(content.contains(r#"<edge id="rel-1" source="pd-2" target="pd-1">"#));
+        assert!(content.contains(r#"<data key="edge_kind">Specialization</data>"#));
+    }
+
+    #[test]
+    fn test_graphml_write_ownership_edges() {
+        let bytes = GraphMl::default()
+            .with_ownership(true)
+            .write(&sample_model())
+            .expect("write should succeed");
+        let content = String::from_utf8(bytes).expect("should be valid UTF-8");
+
+        assert_eq!(count_nodes_and_edges(&content), (3, 3));
+        // Ownership through a membership links the package to its member
+        assert!(content.contains(r#"<edge id="pd-1-owner" source="pkg" target="pd-1">"#));
+        assert!(content.contains(r#"<edge id="pd-2-owner" source="pkg" target="pd-2">"#));
+        assert!(content.contains(r#"<data key="edge_kind">Ownership</data>"#));
+    }
+
+    #[test]
+    fn test_graphml_ownership_cycle_terminates() {
+        let mut model = Model::new();
+        model.add_element(
+            Element::new(ElementId::new("m-1"), ElementKind::OwningMembership).with_owner("m-2"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("m-2"), ElementKind::OwningMembership).with_owner("m-1"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("pd-1"), ElementKind::PartDefinition).with_owner("m-1"),
+        );
+
+        let bytes = GraphMl::default()
+            .with_ownership(true)
+            .write(&model)
+            .expect("write should succeed");
+        let content = String::from_utf8(bytes).expect("should be valid UTF-8");
+        assert_eq!(count_nodes_and_edges(&content), (1, 0));
+    }
+
+    #[test]
+    fn test_graphml_drops_edges_to_missing_nodes() {
+        let mut model = sample_model();
+        model.add_relationship(Relationship::new(
+            "rel-2",
+            RelationshipKind::Specialization,
+            "pd-2",
+            "missing",
+        ));
+        model.add_relationship(Relationship::new(
+            "rel-3",
+            RelationshipKind::Specialization,
+            "m-1",
+            "pd-1",
+        ));
+
+        let bytes = GraphMl::default()
+            .write(&model)
+            .expect("write should succeed");
+        let content = String::from_utf8(bytes).expect("should be valid UTF-8");
+        assert_eq!(count_nodes_and_edges(&content), (3, 1));
+        assert!(!content.contains("rel-2"));
+        assert!(!content.contains("rel-3"));
+    }
+}
@@ -21,7 +21,9 @@
 //! let xmi_bytes = Xmi.write(&model)?;
 //! ```
 
-use super::model::{Element, ElementId, ElementKind, Model, Relationship, RelationshipKind};
+use super::model::{
+    Element, ElementId, ElementKind, Model, PropertyValue, Relationship, RelationshipKind,
+};
 use crate::base::FileId;
 use crate::hir::{
     HirRelationship, HirSymbol, RelationshipKind as HirRelKind, RootDatabase, SymbolKind,
@@ -118,43 +120,27 @@ pub fn symbols_from_model(model: &Model) -> Vec<HirSymbol> {
             .map(|r| r.target.clone())
             .collect();
 
-        let symbol = HirSymbol {
-            name,
-            short_name: None, // XMI may have this in declaredShortName property
-            qualified_name,
-            element_id: element.id.as_str().into(), // Preserve XMI element ID
-            kind,
-            file: FileId::new(0), // Synthetic - no real file
-            start_line: 0,
-            start_col: 0,
-            end_line: 0,
-            end_col: 0,
-            short_name_start_line: None,
-            short_name_start_col: None,
-            short_name_end_line: None,
-            short_name_end_col: None,
-            doc: element.documentation.as_ref().map(|d| d.to_string().into()),
-            supertypes,
-            relationships,
-            type_refs: Vec::new(),
-            is_public: true, // Default to public for imported symbols
-            view_data: None,
-            metadata_annotations: Vec::new(),
-            is_abstract: element.is_abstract,
-            is_variation: element.is_variation,
-            is_readonly: element.is_readonly,
-            is_derived: element.is_derived,
-            is_parallel: element.is_parallel,
-            is_individual: element.is_individual,
-            is_end: element.is_end,
-            is_default: element.is_default,
-            is_ordered: element.is_ordered,
-            is_nonunique: element.is_nonunique,
-            is_portion: element.is_portion,
-            direction: None,    // TODO: Extract from element if available
-            multiplicity: None, // TODO: Extract from element if available
-            value: None,
-        };
+        // File is synthetic - no real file. XMI may have a short name in
+        // declaredShortName.
+        // TODO: Extract direction and multiplicity from element if available
+        let symbol = HirSymbol::builder(name, qualified_name, kind, FileId::new(0))
+            .element_id(element.id.as_str()) // Preserve XMI element ID
+            .doc(element.documentation.as_ref().map(|d| d.to_string().into()))
+            .supertypes(supertypes)
+            .relationships(relationships)
+            .is_public(true) // Default to public for imported symbols
+            .is_abstract(element.is_abstract)
+            .is_variation(element.is_variation)
+            .is_readonly(element.is_readonly)
+            .is_derived(element.is_derived)
+            .is_parallel(element.is_parallel)
+            .is_individual(element.is_individual)
+            .is_end(element.is_end)
+            .is_default(element.is_default)
+            .is_ordered(element.is_ordered)
+            .is_nonunique(element.is_nonunique)
+            .is_portion(element.is_portion)
+            .build();
 
         symbols.push(symbol);
     }
@@ -224,6 +210,7 @@ fn element_kind_to_symbol_kind(kind: ElementKind) -> SymbolKind {
         ElementKind::ReferenceUsage => SymbolKind::ReferenceUsage,
         ElementKind::OccurrenceUsage => SymbolKind::OccurrenceUsage,
         ElementKind::FlowConnectionUsage => SymbolKind::FlowConnectionUsage,
+        ElementKind::MetadataUsage => SymbolKind::MetadataUsage,
         // Other
         ElementKind::Import | ElementKind::NamespaceImport | ElementKind::MembershipImport => {
             SymbolKind::Import
@@ -448,6 +435,7 @@ fn symbol_kind_to_element_kind(kind: crate::hir::SymbolKind) -> ElementKind {
         SymbolKind::ReferenceUsage => ElementKind::ReferenceUsage,
         SymbolKind::OccurrenceUsage => ElementKind::OccurrenceUsage,
         SymbolKind::FlowConnectionUsage => ElementKind::FlowConnectionUsage,
+        SymbolKind::MetadataUsage => ElementKind::MetadataUsage,
         // Other
         SymbolKind::Import => ElementKind::Import,
         SymbolKind::Comment => ElementKind::Comment,
@@ -675,6 +663,134 @@ mod tests {
         );
     }
 
+    // ========== SymbolIndex::to_model() tests ==========
+
+    #[test]
+    fn test_index_to_model_roundtrip_through_yaml() {
+        use super::super::{ModelFormat, Yaml};
+        use crate::ide::AnalysisHost;
+
+        // Given a resolved index where a typing crosses packages via an import
+        let mut host = AnalysisHost::new();
+        let sysml = r#"
+package Parts {
+    part def Engine;
+}
+package Vehicles {
+    import Parts::*;
+    part def Vehicle;
+    part def Car :> Vehicle {
+        part engine : Engine;
+    }
+}
+"#;
+        host.set_file_content("/test.sysml", sysml);
+        let analysis = host.analysis();
+
+        // When we convert the index to a model
+        let model = analysis.symbol_index().to_model();
+
+        let element = |qualified_name: &str| {
+            model
+                .iter_elements()
+                .find(|e| e.qualified_name.as_deref() == Some(qualified_name))
+                .unwrap_or_else(|| panic!("Should have element {}", qualified_name))
+        };
+        let car = element("Vehicles::Car");
+        let engine = element("Vehicles::Car::engine");
+
+        // Then ownership follows the qualified names
+        assert_eq!(car.kind, ElementKind::PartDefinition);
+        assert_eq!(engine.owner.as_ref(), Some(&car.id));
+        assert!(car.owned_elements.contains(&engine.id));
+
+        // And relationships point at the resolved elements
+        let typing = model
+            .relationships
+            .iter()
+            .find(|r| r.kind == RelationshipKind::FeatureTyping && r.source == engine.id)
+            .expect("Should have a feature typing");
+        assert_eq!(typing.target, element("Parts::Engine").id);
+        assert!(model.relationships.iter().any(|r| {
+            r.kind == RelationshipKind::Specialization
+                && r.source == car.id
+                && r.target == element("Vehicles::Vehicle").id
+        }));
+
+        // And the model survives a YAML round-trip
+        let yaml = Yaml.write(&model).expect("Should write YAML");
+        let roundtrip = Yaml.read(&yaml).expect("Should read YAML");
+        assert_eq!(roundtrip.elements.len(), model.elements.len());
+        assert!(roundtrip.relationships.iter().any(|r| {
+            r.kind == RelationshipKind::FeatureTyping
+                && r.source == engine.id
+                && r.target == typing.target
+        }));
+    }
+
+    // ========== SymbolIndex::from_model() tests ==========
+
+    #[test]
+    fn test_index_from_model_resolves_feature_typing() {
+        use crate::hir::{RefKind, SymbolIndex, TypeRefKind};
+
+        // Given a model where `engine` is typed by `Engine`
+        let mut model = Model::new();
+        model.add_element(
+            Element::new(ElementId::new("pkg"), ElementKind::Package)
+                .with_name("Vehicles")
+                .with_qualified_name("Vehicles"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("engine-def"), ElementKind::PartDefinition)
+                .with_name("Engine")
+                .with_qualified_name("Vehicles::Engine")
+                .with_owner(ElementId::new("pkg")),
+        );
+        model.add_element(
+            Element::new(ElementId::new("engine"), ElementKind::PartUsage)
+                .with_name("engine")
+                .with_qualified_name("Vehicles::engine")
+                .with_owner(ElementId::new("pkg")),
+        );
+        model.add_element(
+            Element::new(ElementId::new("unnamed-1"), ElementKind::PartUsage)
+                .with_owner(ElementId::new("pkg")),
+        );
+        model.add_relationship(Relationship::new(
+            ElementId::new("rel_1"),
+            RelationshipKind::FeatureTyping,
+            ElementId::new("engine"),
+            ElementId::new("engine-def"),
+        ));
+
+        // When we import it and resolution runs
+        let file = FileId::new(7);
+        let index = SymbolIndex::from_model(&model, file);
+
+        // Then the typing resolves to the definition
+        let engine = index
+            .lookup_qualified("Vehicles::engine")
+            .expect("Should have engine");
+        assert_eq!(engine.file, file);
+        let typing = engine
+            .type_refs
+            .iter()
+            .find_map(|trk| match trk {
+                TypeRefKind::Simple(tr) if tr.kind == RefKind::TypedBy => Some(tr),
+                _ => None,
+            })
+            .expect("Should have a typing reference");
+        assert_eq!(typing.resolved_target.as_deref(), Some("Vehicles::Engine"));
+        assert_eq!(
+            index.follow_typing_chain(engine, "Vehicles").as_ref(),
+            "Vehicles::Engine"
+        );
+
+        // And the unnamed element is named after its id
+        assert!(index.lookup_qualified("unnamed-1").is_some());
+    }
+
     // ========== symbols_from_model() tests ==========
 
     #[test]
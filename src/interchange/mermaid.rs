@@ -0,0 +1,240 @@
+//! Mermaid class-diagram export.
+//!
+//! Renders a [`Model`] as Mermaid `classDiagram` text so it can be pasted
+//! into Markdown documentation. This format is write-only.
+//!
+//! ## Output Structure
+//!
+//! ```text
+//! classDiagram
+//!     class c0["Vehicle"]
+//!     class c1["Car"]
+//!     c0 <|-- c1
+//! ```
+//!
+//! Classes are numbered in element order, since element IDs may contain
+//! characters Mermaid identifiers can't. Relationships whose source or
+//! target is not an element of the model are dropped.
+
+use std::collections::HashMap;
+
+use super::model::{Element, ElementId, Model, RelationshipKind};
+use super::{FormatCapability, InterchangeError, ModelFormat};
+
+/// Mermaid class-diagram format handler.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mermaid;
+
+impl ModelFormat for Mermaid {
+    fn name(&self) -> &'static str {
+        "Mermaid"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["mmd", "mermaid"]
+    }
+
+    fn mime_type(&self) -> &'static str {
+        "text/vnd.mermaid"
+    }
+
+    fn capabilities(&self) -> FormatCapability {
+        FormatCapability {
+            lossless: false,
+            ..FormatCapability::WRITE_ONLY
+        }
+    }
+
+    fn read(&self, input: &[u8]) -> Result<Model, InterchangeError> {
+        let _ = input;
+        Err(InterchangeError::Unsupported(
+            "Mermaid is an export-only format".to_string(),
+        ))
+    }
+
+    fn write(&self, model: &Model) -> Result<Vec<u8>, InterchangeError> {
+        let mut out = String::from("classDiagram\n");
+
+        let mut class_ids: HashMap<&ElementId, String> = HashMap::new();
+        for element in model.iter_elements() {
+            if element.kind.is_relationship() {
+                continue;
+            }
+            let id = format!("c{}", class_ids.len());
+            out.push_str(&format!(
+                "    class {}[\"{}\"]\n",
+                id,
+                escape_label(&label(element))
+            ));
+            class_ids.insert(&element.id, id);
+        }
+
+        for rel in &model.relationships {
+            let (Some(source), Some(target)) =
+                (class_ids.get(&rel.source), class_ids.get(&rel.target))
+            else {
+                continue;
+            };
+            // Mermaid draws the arrowhead on the left for inheritance and
+            // composition, so those put the general/owning end first.
+            let line = match rel.kind {
+                RelationshipKind::Specialization => format!("{target} <|-- {source}"),
+                RelationshipKind::FeatureTyping => format!("{source} --> {target}"),
+                RelationshipKind::Membership
+                | RelationshipKind::OwningMembership
+                | RelationshipKind::FeatureMembership => format!("{source} *-- {target}"),
+                RelationshipKind::Connection | RelationshipKind::FlowConnection => {
+                    format!("{source} -- {target} : {}", relationship_label(rel.kind))
+                }
+                kind => format!("{source} ..> {target} : {}", relationship_label(kind)),
+            };
+            out.push_str("    ");
+            out.push_str(&line);
+            out.push('\n');
+        }
+
+        Ok(out.into_bytes())
+    }
+}
+
+/// Display label for an element: its name, falling back to its ID.
+fn label(element: &Element) -> String {
+    match &element.name {
+        Some(name) => name.to_string(),
+        None => element.id.to_string(),
+    }
+}
+
+/// Mermaid labels can't contain double quotes.
+fn escape_label(label: &str) -> String {
+    label.replace('"', "#quot;")
+}
+
+/// Edge label for relationships without a dedicated arrow.
+fn relationship_label(kind: RelationshipKind) -> &'static str {
+    match kind {
+        RelationshipKind::Specialization => "specializes",
+        RelationshipKind::FeatureTyping => "typed by",
+        RelationshipKind::Subsetting => "subsets",
+        RelationshipKind::Redefinition => "redefines",
+        RelationshipKind::Conjugation => "conjugates",
+        RelationshipKind::Membership
+        | RelationshipKind::OwningMembership
+        | RelationshipKind::FeatureMembership => "owns",
+        RelationshipKind::NamespaceImport | RelationshipKind::MembershipImport => "imports",
+        RelationshipKind::Dependency => "depends on",
+        RelationshipKind::Satisfaction => "satisfies",
+        RelationshipKind::Verification => "verifies",
+        RelationshipKind::Allocation => "allocates",
+        RelationshipKind::Connection => "connects",
+        RelationshipKind::FlowConnection => "flows to",
+        RelationshipKind::Succession => "then",
+        RelationshipKind::FeatureChaining => "chains",
+        RelationshipKind::Disjoining => "disjoint from",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interchange::model::{Element, ElementKind, Relationship};
+
+    #[test]
+    fn test_mermaid_format_metadata() {
+        let mermaid = Mermaid;
+        assert_eq!(mermaid.name(), "Mermaid");
+        assert!(!mermaid.capabilities().read);
+        assert!(mermaid.capabilities().write);
+        assert!(mermaid.read(b"classDiagram").is_err());
+    }
+
+    #[test]
+    fn test_mermaid_write_class_diagram() {
+        let mut model = Model::new();
+        model.add_element(
+            Element::new(ElementId::new("pd-1"), ElementKind::PartDefinition).with_name("Vehicle"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("pd-2"), ElementKind::PartDefinition).with_name("Car"),
+        );
+        model.add_element(Element::new(ElementId::new("pu-1"), ElementKind::PartUsage));
+        model.add_relationship(Relationship::new(
+            "rel-1",
+            RelationshipKind::Specialization,
+            "pd-2",
+            "pd-1",
+        ));
+        model.add_relationship(Relationship::new(
+            "rel-2",
+            RelationshipKind::FeatureTyping,
+            "pu-1",
+            "pd-2",
+        ));
+        model.add_relationship(Relationship::new(
+            "rel-3",
+            RelationshipKind::Subsetting,
+            "pu-1",
+            "pd-1",
+        ));
+
+        let bytes = Mermaid.write(&model).expect("write should succeed");
+        let content = String::from_utf8(bytes).expect("should be valid UTF-8");
+
+        assert!(content.starts_with("classDiagram\n"));
+        assert!(content.contains("    class c0[\"Vehicle\"]\n"));
+        // Unnamed elements fall back to their ID
+        assert!(content.contains("    class c2[\"pu-1\"]\n"));
+        assert!(content.contains("    c0 <|-- c1\n"));
+        assert!(content.contains("    c2 --> c1\n"));
+        assert!(content.contains("    c2 ..> c0 : subsets\n"));
+    }
+
+    #[test]
+    fn test_mermaid_ids_do_not_collide() {
+        let mut model = Model::new();
+        model.add_element(
+            Element::new(ElementId::new("a-b"), ElementKind::PartDefinition).with_name("Dash"),
+        );
+        model.add_element(
+            Element::new(ElementId::new("a_b"), ElementKind::PartDefinition).with_name("Under"),
+        );
+        model.add_relationship(Relationship::new(
+            "rel-1",
+            RelationshipKind::Specialization,
+            "a_b",
+            "a-b",
+        ));
+
+        let bytes = Mermaid.write(&model).expect("write should succeed");
+        let content = String::from_utf8(bytes).expect("should be valid UTF-8");
+
+        assert!(content.contains("    class c0[\"Dash\"]\n"));
+        assert!(content.contains("    class c1[\"Under\"]\n"));
+        assert!(content.contains("    c0 <|-- c1\n"));
+    }
+
+    #[test]
+    fn test_mermaid_drops_dangling_relationships() {
+        let mut model = Model::new();
+        model.add_element(
+            Element::new(ElementId::new("pd-1"), ElementKind::PartDefinition).with_name("Vehicle"),
+        );
+        model.add_relationship(Relationship::new(
+            "rel-1",
+            RelationshipKind::Specialization,
+            "pd-1",
+            "missing",
+        ));
+        model.add_relationship(Relationship::new(
+            "rel-2",
+            RelationshipKind::FeatureTyping,
+            "missing",
+            "pd-1",
+        ));
+
+        let bytes = Mermaid.write(&model).expect("write should succeed");
+        let content = String::from_utf8(bytes).expect("should be valid UTF-8");
+
+        assert_eq!(content, "classDiagram\n    class c0[\"Vehicle\"]\n");
+    }
+}
@@ -6,6 +6,9 @@
 //! - **XMI** - XML Model Interchange (OMG standard)
 //! - **KPAR** - Kernel Package Archive (ZIP with XMI + metadata)
 //! - **JSON-LD** - JSON Linked Data format
+//! - **Mermaid** - class-diagram text (export only)
+//! - **GraphML** - graph XML for external graph tools (export only)
+//! - **SysML** - textual notation (export only)
 //!
 //! ## Architecture
 //!
@@ -52,30 +55,37 @@
 pub mod decompile;
 mod error;
 mod format;
+mod graphml;
 pub mod integrate;
 mod jsonld;
 mod kpar;
+mod mermaid;
 pub mod metadata;
 pub mod model;
 pub mod recompile;
+mod sysml_text;
 mod xmi;
 mod yaml;
 
 pub use decompile::{DecompileResult, decompile, decompile_with_source};
 pub use error::InterchangeError;
-pub use format::{FormatCapability, ModelFormat};
+pub use format::{FormatCapability, ModelFormat, ModelVisitor};
+pub use graphml::GraphMl;
 pub use integrate::{
     apply_metadata_to_host, model_from_database, model_from_symbols, symbols_from_model,
 };
 pub use jsonld::JsonLd;
 pub use kpar::{Kpar, KparManifest};
+pub use mermaid::Mermaid;
 pub use metadata::{
     Dependency, ElementMeta, ImportMetadata, PackageMetadata, ProjectMetadata, SourceInfo,
 };
 pub use model::{
-    Element, ElementId, ElementKind, Model, ModelMetadata, Relationship, RelationshipKind,
+    Element, ElementId, ElementKind, Model, ModelError, ModelMetadata, Relationship,
+    RelationshipKind,
 };
 pub use recompile::{restore_element_ids, restore_ids_from_symbols};
+pub use sysml_text::SysMLText;
 pub use xmi::Xmi;
 pub use yaml::Yaml;
 
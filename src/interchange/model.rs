@@ -1177,6 +1177,81 @@ impl Model {
     pub fn relationship_count(&self) -> usize {
         self.relationships.len()
     }
+
+    /// Check the model for dangling references.
+    ///
+    /// Reports relationship endpoints that aren't in `elements`,
+    /// `owned_elements` entries that aren't in `elements`, and elements whose
+    /// `owner` is missing or doesn't list them back. An empty result means
+    /// the model is internally consistent.
+    pub fn validate(&self) -> Vec<ModelError> {
+        let mut errors = Vec::new();
+
+        for rel in &self.relationships {
+            for (end, id) in [("source", &rel.source), ("target", &rel.target)] {
+                if !self.elements.contains_key(id) {
+                    errors.push(ModelError::new(
+                        rel.id.clone(),
+                        format!("relationship {end} '{id}' is not in the model"),
+                    ));
+                }
+            }
+        }
+
+        for element in self.elements.values() {
+            for child in &element.owned_elements {
+                if !self.elements.contains_key(child) {
+                    errors.push(ModelError::new(
+                        element.id.clone(),
+                        format!("owned element '{child}' is not in the model"),
+                    ));
+                }
+            }
+
+            if let Some(owner_id) = &element.owner {
+                match self.elements.get(owner_id) {
+                    None => errors.push(ModelError::new(
+                        element.id.clone(),
+                        format!("owner '{owner_id}' is not in the model"),
+                    )),
+                    Some(owner) if !owner.owned_elements.contains(&element.id) => {
+                        errors.push(ModelError::new(
+                            element.id.clone(),
+                            format!("owner '{owner_id}' does not list it as an owned element"),
+                        ))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+/// A consistency problem found by [`Model::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModelError {
+    /// The element or relationship holding the bad reference.
+    pub id: ElementId,
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+impl ModelError {
+    /// Create a new model error.
+    pub fn new(id: impl Into<ElementId>, message: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ModelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.id, self.message)
+    }
 }
 
 /// Metadata about a model.
@@ -1267,6 +1342,55 @@ mod tests {
         assert_eq!(rels[0].target.as_str(), "def1");
     }
 
+    #[test]
+    fn test_model_validate_dangling_relationship_target() {
+        let mut model = Model::new();
+        model.add_element(Element::new("def1", ElementKind::PartDefinition).with_name("Base"));
+        model.add_relationship(Relationship::new(
+            "rel1",
+            RelationshipKind::Specialization,
+            "def1",
+            "missing",
+        ));
+
+        let errors = model.validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].id.as_str(), "rel1");
+        assert!(errors[0].message.contains("target 'missing'"));
+    }
+
+    #[test]
+    fn test_model_validate_asymmetric_ownership() {
+        let mut model = Model::new();
+        // pkg1 claims part1, but part1 says it belongs to nobody
+        model.add_element(Element::new("pkg1", ElementKind::Package).with_owned("part1"));
+        model.add_element(Element::new("part1", ElementKind::PartDefinition));
+        // part2 claims pkg1 as owner, but pkg1 doesn't list it
+        model.add_element(Element::new("part2", ElementKind::PartDefinition).with_owner("pkg1"));
+        assert_eq!(
+            model.validate(),
+            vec![ModelError::new(
+                "part2",
+                "owner 'pkg1' does not list it as an owned element"
+            )]
+        );
+
+        model
+            .get_mut(&ElementId::new("pkg1"))
+            .unwrap()
+            .owned_elements
+            .push(ElementId::new("part2"));
+        model
+            .get_mut(&ElementId::new("pkg1"))
+            .unwrap()
+            .owned_elements
+            .push(ElementId::new("ghost"));
+        let errors = model.validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].id.as_str(), "pkg1");
+        assert!(errors[0].message.contains("'ghost'"));
+    }
+
     #[test]
     fn test_element_kind_xmi_roundtrip() {
         let kinds = [
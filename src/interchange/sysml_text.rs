@@ -0,0 +1,272 @@
+//! Textual SysML export.
+//!
+//! Regenerates readable `.sysml` source from a [`Model`], e.g. after
+//! importing XMI from another tool. This is a [`ModelFormat`] front end for
+//! [`decompile`]; element IDs and other metadata are not written, so use
+//! `decompile` directly when a lossless roundtrip is needed.
+//!
+//! With [`SysMLText::with_provenance`], the output starts with a block of
+//! line comments in YAML front-matter style recording where the model came
+//! from:
+//!
+//! ```text
+//! // ---
+//! // tool: Example Modeler
+//! // elements: 42
+//! // ---
+//! ```
+
+use super::decompile::decompile;
+use super::model::Model;
+use super::{FormatCapability, InterchangeError, ModelFormat};
+
+/// SysML textual notation format handler.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SysMLText {
+    /// Start the output with a comment block of model-level metadata.
+    pub include_provenance: bool,
+}
+
+impl SysMLText {
+    /// Also emit the model's provenance as a leading comment block.
+    pub fn with_provenance(mut self, include: bool) -> Self {
+        self.include_provenance = include;
+        self
+    }
+}
+
+/// Comment block carrying the model's metadata and element count.
+///
+/// Timestamps come from the model itself, so the same model always
+/// produces the same text.
+fn provenance_comment(model: &Model) -> String {
+    let meta = &model.metadata;
+    let element_count = model.element_count().to_string();
+    let fields = [
+        ("model", meta.name.as_deref()),
+        ("tool", meta.tool.as_deref()),
+        ("sysml_version", meta.sysml_version.as_deref()),
+        ("uri", meta.uri.as_deref()),
+        ("elements", Some(element_count.as_str())),
+        ("created", meta.created.as_deref()),
+        ("modified", meta.modified.as_deref()),
+    ];
+
+    let mut text = String::from("// ---\n");
+    for (key, value) in fields {
+        if let Some(value) = value {
+            // Keep each value on its comment line
+            let value = value.lines().collect::<Vec<_>>().join(" ");
+            text.push_str(&format!("// {key}: {value}\n"));
+        }
+    }
+    text.push_str("// ---\n\n");
+    text
+}
+
+impl ModelFormat for SysMLText {
+    fn name(&self) -> &'static str {
+        "SysML"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["sysml"]
+    }
+
+    fn mime_type(&self) -> &'static str {
+        "text/x-sysml"
+    }
+
+    fn capabilities(&self) -> FormatCapability {
+        FormatCapability {
+            lossless: false,
+            ..FormatCapability::WRITE_ONLY
+        }
+    }
+
+    fn read(&self, input: &[u8]) -> Result<Model, InterchangeError> {
+        let _ = input;
+        Err(InterchangeError::Unsupported(
+            "SysML text import goes through the parser, not the interchange layer".to_string(),
+        ))
+    }
+
+    fn write(&self, model: &Model) -> Result<Vec<u8>, InterchangeError> {
+        let text = decompile(model).text;
+        if !self.include_provenance {
+            return Ok(text.into_bytes());
+        }
+        Ok((provenance_comment(model) + &text).into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::FileId;
+    use crate::hir::extract_symbols_unified;
+    use crate::interchange::model::{Element, ElementKind, Relationship, RelationshipKind};
+    use crate::syntax::parser::parse_content;
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    /// Add `element` to `model` and link it into its owner's children.
+    fn add_owned(model: &mut Model, element: Element) {
+        if let Some(owner) = element.owner.clone() {
+            if let Some(parent) = model.get_mut(&owner) {
+                parent.owned_elements.push(element.id.clone());
+            }
+        }
+        model.add_element(element);
+    }
+
+    #[test]
+    fn test_sysml_text_format_metadata() {
+        let sysml = SysMLText::default();
+        assert_eq!(sysml.extensions(), &["sysml"]);
+        assert!(!sysml.capabilities().read);
+        assert!(sysml.capabilities().write);
+        assert!(sysml.read(b"package P;").is_err());
+    }
+
+    #[test]
+    fn test_sysml_text_reparses_to_equivalent_symbols() {
+        let mut model = Model::new();
+        add_owned(
+            &mut model,
+            Element::new("pkg", ElementKind::Package).with_name("Vehicles"),
+        );
+        add_owned(
+            &mut model,
+            Element::new("engine-def", ElementKind::PartDefinition)
+                .with_name("Engine")
+                .with_owner("pkg"),
+        );
+        add_owned(
+            &mut model,
+            Element::new("vehicle-def", ElementKind::PartDefinition)
+                .with_name("Vehicle")
+                .with_owner("pkg"),
+        );
+        add_owned(
+            &mut model,
+            Element::new("engine", ElementKind::PartUsage)
+                .with_name("engine")
+                .with_owner("vehicle-def"),
+        );
+        add_owned(
+            &mut model,
+            Element::new("mass", ElementKind::AttributeUsage)
+                .with_name("mass")
+                .with_owner("vehicle-def"),
+        );
+        // Anonymous part with a named child
+        add_owned(
+            &mut model,
+            Element::new("anon", ElementKind::PartUsage).with_owner("pkg"),
+        );
+        add_owned(
+            &mut model,
+            Element::new("wheel", ElementKind::PartUsage)
+                .with_name("wheel")
+                .with_owner("anon"),
+        );
+        model.add_relationship(Relationship::new(
+            "typing",
+            RelationshipKind::FeatureTyping,
+            "engine",
+            "engine-def",
+        ));
+
+        let text = String::from_utf8(
+            SysMLText::default()
+                .write(&model)
+                .expect("write should succeed"),
+        )
+        .expect("should be valid UTF-8");
+        assert!(text.contains("package Vehicles {\n"), "{text}");
+        assert!(text.contains("\n        part engine : Engine;\n"), "{text}");
+        assert!(
+            text.contains("\n    part {\n        part wheel;\n    }\n"),
+            "{text}"
+        );
+
+        let syntax = parse_content(&text, Path::new("generated.sysml")).expect("should re-parse");
+        let symbols = extract_symbols_unified(FileId(1), &syntax);
+
+        let named: BTreeSet<&str> = symbols
+            .iter()
+            .filter(|s| !s.qualified_name.contains('<'))
+            .map(|s| s.qualified_name.as_ref())
+            .collect();
+        let expected: BTreeSet<&str> = [
+            "Vehicles",
+            "Vehicles::Engine",
+            "Vehicles::Vehicle",
+            "Vehicles::Vehicle::engine",
+            "Vehicles::Vehicle::mass",
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(named, expected);
+
+        // The anonymous part and its child survive too
+        assert!(
+            symbols
+                .iter()
+                .any(|s| s.name.as_ref() == "wheel" && s.qualified_name.contains('<'))
+        );
+
+        let engine = symbols
+            .iter()
+            .find(|s| s.qualified_name.as_ref() == "Vehicles::Vehicle::engine")
+            .unwrap();
+        assert!(engine.supertypes.iter().any(|t| t.as_ref() == "Engine"));
+    }
+
+    #[test]
+    fn test_sysml_text_provenance_block() {
+        let mut model = Model::new();
+        model.metadata.tool = Some("Example Modeler".to_string());
+        model.metadata.modified = Some("2025-01-31T12:00:00Z".to_string());
+        add_owned(
+            &mut model,
+            Element::new("pkg", ElementKind::Package).with_name("Vehicles"),
+        );
+        add_owned(
+            &mut model,
+            Element::new("engine-def", ElementKind::PartDefinition)
+                .with_name("Engine")
+                .with_owner("pkg"),
+        );
+
+        let write = |format: SysMLText| {
+            String::from_utf8(format.write(&model).expect("write should succeed"))
+                .expect("should be valid UTF-8")
+        };
+        let plain = write(SysMLText::default());
+        let with_provenance = write(SysMLText::default().with_provenance(true));
+
+        assert!(!plain.contains("// ---"), "{plain}");
+        assert!(
+            with_provenance.starts_with(
+                "// ---\n// tool: Example Modeler\n// elements: 2\n\
+                 // modified: 2025-01-31T12:00:00Z\n// ---\n\n"
+            ),
+            "{with_provenance}"
+        );
+        assert!(with_provenance.ends_with(&plain));
+
+        for text in [&plain, &with_provenance] {
+            let syntax =
+                parse_content(text, Path::new("generated.sysml")).expect("should re-parse");
+            assert!(syntax.errors().is_empty(), "{text}");
+            let symbols = extract_symbols_unified(FileId(1), &syntax);
+            assert!(
+                symbols
+                    .iter()
+                    .any(|s| s.qualified_name.as_ref() == "Vehicles::Engine")
+            );
+        }
+    }
+}
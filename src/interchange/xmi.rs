@@ -19,7 +19,7 @@
 use std::sync::Arc;
 
 use super::model::{Element, ElementId, ElementKind, Model, Relationship, RelationshipKind};
-use super::{FormatCapability, InterchangeError, ModelFormat};
+use super::{FormatCapability, InterchangeError, ModelFormat, ModelVisitor};
 
 /// XMI namespace URIs - using 2025 spec versions.
 pub mod namespace {
@@ -51,7 +51,10 @@ impl ModelFormat for Xmi {
     }
 
     fn capabilities(&self) -> FormatCapability {
-        FormatCapability::FULL
+        FormatCapability {
+            streaming: true,
+            ..FormatCapability::FULL
+        }
     }
 
     fn read(&self, input: &[u8]) -> Result<Model, InterchangeError> {
@@ -110,6 +113,20 @@ impl Xmi {
     ) -> Result<Model, InterchangeError> {
         XmiReader::new().read_with_path(input, Some(path))
     }
+
+    /// Read XMI incrementally, handing each element and relationship to `visitor`.
+    ///
+    /// Only the elements whose end tag has not been reached yet are kept in
+    /// memory. Each element is reported once it is complete, so children are
+    /// visited before their owner and `owned_elements` is already populated.
+    #[cfg(feature = "interchange")]
+    pub fn read_streaming(
+        &self,
+        reader: impl std::io::Read,
+        visitor: &mut dyn ModelVisitor,
+    ) -> Result<(), InterchangeError> {
+        XmiReader::new().read_events(std::io::BufReader::new(reader), None, visitor)
+    }
 }
 
 // ============================================================================
@@ -126,14 +143,12 @@ mod reader {
 
     /// XMI document reader.
     pub struct XmiReader {
-        /// Elements by ID for lookup (IndexMap preserves insertion order).
-        elements_by_id: IndexMap<String, Element>,
+        /// Elements whose end tag has not been seen yet, by ID.
+        open_elements: std::collections::HashMap<String, Element>,
         /// Parent stack for ownership tracking (element IDs only).
         parent_stack: Vec<String>,
         /// Depth tracking to match start/end tags properly.
         depth_stack: Vec<StackEntry>,
-        /// Relationships collected during parsing.
-        relationships: Vec<Relationship>,
         /// Counter for generating relationship IDs.
         rel_counter: u32,
         /// Tracks children per parent in parse order (parent_id -> [child_ids]).
@@ -145,8 +160,6 @@ mod reader {
         /// Pending relationship sources - when we have source but not target yet.
         /// Maps element_id -> (source_ref, element_kind)
         pending_rel_sources: std::collections::HashMap<String, (String, ElementKind)>,
-        /// Declared XML namespaces from the document (prefix -> URI).
-        declared_namespaces: std::collections::HashMap<String, String>,
     }
 
     /// Stack entry type for tracking nested elements.
@@ -163,16 +176,14 @@ mod reader {
     impl XmiReader {
         pub fn new() -> Self {
             Self {
-                elements_by_id: IndexMap::new(),
+                open_elements: std::collections::HashMap::new(),
                 parent_stack: Vec::new(),
                 depth_stack: Vec::new(),
-                relationships: Vec::new(),
                 rel_counter: 0,
                 children_in_order: IndexMap::new(),
                 base_path: None,
                 href_name_cache: std::collections::HashMap::new(),
                 pending_rel_sources: std::collections::HashMap::new(),
-                declared_namespaces: std::collections::HashMap::new(),
             }
         }
 
@@ -185,6 +196,18 @@ mod reader {
             input: &[u8],
             path: Option<&std::path::Path>,
         ) -> Result<Model, InterchangeError> {
+            let mut collector = ModelCollector::default();
+            self.read_events(input, path, &mut collector)?;
+            Ok(collector.into_model())
+        }
+
+        /// Drive the XML event loop, reporting completed elements to `visitor`.
+        pub fn read_events<R: std::io::BufRead>(
+            &mut self,
+            input: R,
+            path: Option<&std::path::Path>,
+            visitor: &mut dyn ModelVisitor,
+        ) -> Result<(), InterchangeError> {
             self.base_path = path.map(|p| p.parent().unwrap_or(p).to_path_buf());
 
             let mut reader = Reader::from_reader(input);
@@ -195,15 +218,15 @@ mod reader {
             loop {
                 match reader.read_event_into(&mut buf) {
                     Ok(Event::Start(ref e)) => {
-                        self.handle_start_element(e)?;
+                        self.handle_start_element(e, visitor)?;
                     }
                     Ok(Event::Empty(ref e)) => {
                         // Self-closing element - handle as start + end
-                        self.handle_start_element(e)?;
-                        self.handle_end_element();
+                        self.handle_start_element(e, visitor)?;
+                        self.handle_end_element(visitor);
                     }
                     Ok(Event::End(_)) => {
-                        self.handle_end_element();
+                        self.handle_end_element(visitor);
                     }
                     Ok(Event::Eof) => break,
                     Err(e) => {
@@ -217,17 +240,21 @@ mod reader {
                 buf.clear();
             }
 
-            self.build_model()
+            Ok(())
         }
 
-        fn handle_start_element(&mut self, e: &BytesStart<'_>) -> Result<(), InterchangeError> {
+        fn handle_start_element(
+            &mut self,
+            e: &BytesStart<'_>,
+            visitor: &mut dyn ModelVisitor,
+        ) -> Result<(), InterchangeError> {
             let name_bytes = e.name();
             let tag_name = std::str::from_utf8(name_bytes.as_ref())
                 .map_err(|e| InterchangeError::xml(format!("Invalid tag name: {e}")))?;
 
             // Capture namespace declarations from root element (first element we see)
             if self.depth_stack.is_empty() {
-                self.capture_namespace_declarations(e)?;
+                self.capture_namespace_declarations(e, visitor)?;
             }
 
             // Skip only the XMI wrapper element - sysml:Namespace/kerml:Namespace are real elements!
@@ -346,6 +373,29 @@ mod reader {
             let type_str = xmi_type.as_deref().unwrap_or(tag_name);
             let kind = ElementKind::from_xmi_type(type_str);
 
+            // Standalone relationships sit directly under xmi:XMI with explicit
+            // source/target attributes and have no element of their own.
+            if matches!(self.depth_stack.last(), Some(StackEntry::Root)) {
+                let attr = |name: &str| {
+                    extra_attrs
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value.clone())
+                };
+                if let (Some(rel_kind), Some(id), Some(src), Some(tgt)) = (
+                    RelationshipKind::from_xmi_type(type_str),
+                    xmi_id.clone(),
+                    attr("source"),
+                    attr("target"),
+                ) {
+                    let mut relationship = Relationship::new(id, rel_kind, src, tgt);
+                    relationship.owner = attr("owner").map(ElementId::new);
+                    visitor.on_relationship(&relationship);
+                    self.depth_stack.push(StackEntry::Containment);
+                    return Ok(());
+                }
+            }
+
             // Create element if we have an ID
             if let Some(id) = xmi_id {
                 let mut element = Element::new(id.clone(), kind);
@@ -459,14 +509,14 @@ mod reader {
                     ) {
                         let rel_kind = element_kind_to_relationship_kind(kind);
                         let relationship = Relationship::new(id.clone(), rel_kind, src, tgt);
-                        self.relationships.push(relationship);
+                        visitor.on_relationship(&relationship);
                     } else if let Some(src) = source_ref {
                         // Store source_ref for later use when we encounter the target href child
                         self.pending_rel_sources.insert(id.clone(), (src, kind));
                     }
                 }
 
-                self.elements_by_id.insert(id.clone(), element);
+                self.open_elements.insert(id.clone(), element);
                 self.parent_stack.push(id);
                 self.depth_stack.push(StackEntry::Element);
             } else if let Some(h) = href {
@@ -485,7 +535,7 @@ mod reader {
                     };
 
                     // Now do the mutable borrow
-                    if let Some(parent_elem) = self.elements_by_id.get_mut(&parent_id) {
+                    if let Some(parent_elem) = self.open_elements.get_mut(&parent_id) {
                         if let Some(name) = resolved_name {
                             parent_elem.properties.insert(
                                 Arc::from("href_target_name"),
@@ -522,7 +572,7 @@ mod reader {
                             let rel_kind = element_kind_to_relationship_kind(kind);
                             let relationship =
                                 Relationship::new(parent_id.clone(), rel_kind, src, target);
-                            self.relationships.push(relationship);
+                            visitor.on_relationship(&relationship);
                         }
                     }
                 }
@@ -591,11 +641,22 @@ mod reader {
             None
         }
 
-        fn handle_end_element(&mut self) {
+        fn handle_end_element(&mut self, visitor: &mut dyn ModelVisitor) {
             // Pop from depth stack and handle accordingly
             if let Some(StackEntry::Element) = self.depth_stack.pop() {
-                // This was an actual element, pop parent stack too
-                self.parent_stack.pop();
+                // This was an actual element, pop parent stack too and report it
+                let Some(id) = self.parent_stack.pop() else {
+                    return;
+                };
+                if let Some(mut element) = self.open_elements.remove(&id) {
+                    // Update owned_elements using the recorded parse order
+                    if let Some(child_ids) = self.children_in_order.shift_remove(&id) {
+                        element
+                            .owned_elements
+                            .extend(child_ids.into_iter().map(ElementId::new));
+                    }
+                    visitor.on_element(&element);
+                }
             }
         }
 
@@ -603,6 +664,7 @@ mod reader {
         fn capture_namespace_declarations(
             &mut self,
             e: &BytesStart<'_>,
+            visitor: &mut dyn ModelVisitor,
         ) -> Result<(), InterchangeError> {
             for attr_result in e.attributes() {
                 let attr = attr_result
@@ -616,45 +678,74 @@ mod reader {
                         .unescape_value()
                         .map_err(|e| InterchangeError::xml(format!("Attribute value error: {e}")))?
                         .to_string();
-                    self.declared_namespaces.insert(prefix.to_string(), value);
+                    visitor.on_namespace(prefix, &value);
                 }
             }
             Ok(())
         }
 
-        fn build_model(&mut self) -> Result<Model, InterchangeError> {
+        /// Generate a unique relationship ID.
+        #[allow(dead_code)]
+        fn next_rel_id(&mut self) -> ElementId {
+            self.rel_counter += 1;
+            ElementId::new(format!("_rel_{}", self.rel_counter))
+        }
+    }
+
+    /// Visitor that materializes a streamed document into a [`Model`].
+    #[derive(Default)]
+    struct ModelCollector {
+        /// Elements in the order they were completed (children before owners).
+        elements: IndexMap<ElementId, Element>,
+        relationships: Vec<Relationship>,
+        namespaces: std::collections::HashMap<String, String>,
+    }
+
+    impl ModelVisitor for ModelCollector {
+        fn on_element(&mut self, element: &Element) {
+            self.elements.insert(element.id.clone(), element.clone());
+        }
+
+        fn on_relationship(&mut self, relationship: &Relationship) {
+            self.relationships.push(relationship.clone());
+        }
+
+        fn on_namespace(&mut self, prefix: &str, uri: &str) {
+            self.namespaces.insert(prefix.to_string(), uri.to_string());
+        }
+    }
+
+    impl ModelCollector {
+        /// Build the model, restoring document order (owners before children).
+        fn into_model(mut self) -> Model {
             let mut model = Model::new();
 
             // Store declared namespaces in metadata for roundtrip
-            model.metadata.declared_namespaces = std::mem::take(&mut self.declared_namespaces);
-
-            // Add all elements (drain with full range to preserve order)
-            for (_, element) in self.elements_by_id.drain(..) {
+            model.metadata.declared_namespaces = self.namespaces;
+
+            let mut stack: Vec<ElementId> = self
+                .elements
+                .values()
+                .filter(|e| e.owner.is_none())
+                .map(|e| e.id.clone())
+                .rev()
+                .collect();
+            while let Some(id) = stack.pop() {
+                if let Some(element) = self.elements.shift_remove(&id) {
+                    stack.extend(element.owned_elements.iter().rev().cloned());
+                    model.add_element(element);
+                }
+            }
+            // Anything not reachable from a root keeps its completion order
+            for (_, element) in self.elements.drain(..) {
                 model.add_element(element);
             }
 
-            // Add relationships
-            for rel in self.relationships.drain(..) {
+            for rel in self.relationships {
                 model.add_relationship(rel);
             }
 
-            // Update owned_elements using the recorded parse order (children_in_order)
-            for (parent_id, child_ids) in self.children_in_order.drain(..) {
-                if let Some(owner) = model.elements.get_mut(&ElementId::new(parent_id)) {
-                    for child_id in child_ids {
-                        owner.owned_elements.push(ElementId::new(child_id));
-                    }
-                }
-            }
-
-            Ok(model)
-        }
-
-        /// Generate a unique relationship ID.
-        #[allow(dead_code)]
-        fn next_rel_id(&mut self) -> ElementId {
-            self.rel_counter += 1;
-            ElementId::new(format!("_rel_{}", self.rel_counter))
+            model
         }
     }
 
@@ -774,15 +865,23 @@ mod writer {
             // Get roots
             let roots: Vec<_> = model.iter_roots().collect();
 
-            if roots.len() == 1 {
+            // Relationships backed by an element are written with the ownership
+            // tree; the rest have nowhere to live but beside the roots.
+            let standalone: Vec<_> = model
+                .relationships
+                .iter()
+                .filter(|rel| !model.elements.contains_key(&rel.id))
+                .collect();
+
+            if roots.is_empty() {
+                return Err(InterchangeError::xml("Model has no root elements"));
+            } else if roots.len() == 1 && standalone.is_empty() {
                 // Single root - use element as document root (OMG format)
                 let root = roots[0];
                 self.write_root_element(&mut writer, model, root)?;
-            } else if roots.is_empty() {
-                return Err(InterchangeError::xml("Model has no root elements"));
             } else {
-                // Multiple roots - wrap in xmi:XMI
-                self.write_xmi_wrapper(&mut writer, model, &roots)?;
+                // Multiple roots or standalone relationships - wrap in xmi:XMI
+                self.write_xmi_wrapper(&mut writer, model, &roots, &standalone)?;
             }
 
             // Add trailing newline (per OMG format)
@@ -841,12 +940,13 @@ mod writer {
             Ok(())
         }
 
-        /// Write multiple roots wrapped in xmi:XMI.
+        /// Write multiple roots and standalone relationships wrapped in xmi:XMI.
         fn write_xmi_wrapper<W: std::io::Write>(
             &self,
             writer: &mut Writer<W>,
             model: &Model,
             roots: &[&Element],
+            relationships: &[&Relationship],
         ) -> Result<(), InterchangeError> {
             let mut xmi_start = BytesStart::new("xmi:XMI");
             xmi_start.push_attribute(("xmi:version", "2.0"));
@@ -864,6 +964,10 @@ mod writer {
                 self.write_element_nested(writer, model, root)?;
             }
 
+            for rel in relationships {
+                Self::write_standalone_relationship(writer, rel)?;
+            }
+
             writer
                 .write_event(Event::End(BytesEnd::new("xmi:XMI")))
                 .map_err(|e| InterchangeError::xml(format!("Write error: {e}")))?;
@@ -871,6 +975,26 @@ mod writer {
             Ok(())
         }
 
+        /// Write a relationship that has no backing element.
+        ///
+        /// `<kerml:Specialization xmi:id="r1" source="c1" target="v1"/>`
+        fn write_standalone_relationship<W: std::io::Write>(
+            writer: &mut Writer<W>,
+            rel: &Relationship,
+        ) -> Result<(), InterchangeError> {
+            let mut rel_start = BytesStart::new(rel.kind.xmi_type());
+            rel_start.push_attribute(("xmi:id", rel.id.as_str()));
+            rel_start.push_attribute(("source", rel.source.as_str()));
+            rel_start.push_attribute(("target", rel.target.as_str()));
+            if let Some(ref owner) = rel.owner {
+                rel_start.push_attribute(("owner", owner.as_str()));
+            }
+            writer
+                .write_event(Event::Empty(rel_start))
+                .map_err(|e| InterchangeError::xml(format!("Write error: {e}")))?;
+            Ok(())
+        }
+
         /// Write element attributes (id, name, flags, etc.)
         fn write_element_attrs(
             &self,
@@ -1743,5 +1867,135 @@ mod tests {
                 output_str
             );
         }
+
+        #[test]
+        fn test_xmi_roundtrip_standalone_relationships() {
+            use crate::interchange::model::PropertyValue;
+
+            let mut model = Model::new();
+            model.add_element(
+                Element::new("pkg1", ElementKind::Package)
+                    .with_name("Vehicles")
+                    .with_owned("v1")
+                    .with_owned("c1"),
+            );
+            model.add_element(
+                Element::new("v1", ElementKind::PartDefinition)
+                    .with_name("Vehicle")
+                    .with_owner("pkg1"),
+            );
+            model.add_element(
+                Element::new("c1", ElementKind::PartDefinition)
+                    .with_name("Car")
+                    .with_owner("pkg1")
+                    .with_owned("ft1"),
+            );
+            // Element-backed relationship, written with the ownership tree
+            model.add_element(
+                Element::new("ft1", ElementKind::FeatureTyping)
+                    .with_owner("c1")
+                    .with_property("typedFeature", PropertyValue::String("c1".into()))
+                    .with_property("type", PropertyValue::String("v1".into())),
+            );
+            model.add_relationship(Relationship::new(
+                "ft1",
+                RelationshipKind::FeatureTyping,
+                "c1",
+                "v1",
+            ));
+            // Standalone relationships
+            model.add_relationship(Relationship::new(
+                "spec1",
+                RelationshipKind::Specialization,
+                "c1",
+                "v1",
+            ));
+            let mut dep = Relationship::new("dep1", RelationshipKind::Dependency, "v1", "c1");
+            dep.owner = Some(ElementId::new("pkg1"));
+            model.add_relationship(dep);
+
+            let output = Xmi.write(&model).expect("Failed to write XMI");
+            let model2 = Xmi.read(&output).expect("Failed to read XMI");
+
+            // Standalone relationships don't become elements
+            assert_eq!(model2.element_count(), 4);
+            assert_eq!(model2.roots, vec![ElementId::new("pkg1")]);
+            // ...and the element-backed one isn't counted twice
+            assert_eq!(model2.relationship_count(), 3);
+            for rel in &model.relationships {
+                let rel2 = model2
+                    .relationships
+                    .iter()
+                    .find(|r| r.id == rel.id)
+                    .unwrap_or_else(|| panic!("relationship {} was dropped", rel.id));
+                assert_eq!(rel2.kind, rel.kind);
+                assert_eq!(rel2.source, rel.source);
+                assert_eq!(rel2.target, rel.target);
+            }
+            let dep2 = model2
+                .relationships
+                .iter()
+                .find(|r| r.id.as_str() == "dep1")
+                .unwrap();
+            assert_eq!(dep2.owner, Some(ElementId::new("pkg1")));
+        }
+
+        #[test]
+        fn test_xmi_read_streaming_matches_read() {
+            #[derive(Default)]
+            struct Counter {
+                elements: Vec<ElementId>,
+                relationships: usize,
+            }
+
+            impl ModelVisitor for Counter {
+                fn on_element(&mut self, element: &Element) {
+                    self.elements.push(element.id.clone());
+                }
+
+                fn on_relationship(&mut self, _relationship: &Relationship) {
+                    self.relationships += 1;
+                }
+            }
+
+            let xmi_content = br#"<?xml version="1.0" encoding="UTF-8"?>
+<xmi:XMI xmlns:xmi="http://www.omg.org/spec/XMI/20131001"
+         xmlns:sysml="http://www.omg.org/spec/SysML/20230201">
+  <sysml:Package xmi:id="pkg1" name="Vehicles">
+    <ownedMember>
+      <sysml:PartDefinition xmi:id="pd1" name="Vehicle">
+        <ownedMember>
+          <sysml:PartUsage xmi:id="pu1" name="engine"/>
+        </ownedMember>
+      </sysml:PartDefinition>
+    </ownedMember>
+    <ownedMember>
+      <sysml:PartDefinition xmi:id="pd2" name="Engine"/>
+    </ownedMember>
+  </sysml:Package>
+  <sysml:Specialization xmi:id="spec1" source="pd2" target="pd1"/>
+</xmi:XMI>"#;
+
+            let mut counter = Counter::default();
+            Xmi.read_streaming(&xmi_content[..], &mut counter)
+                .expect("Failed to stream XMI");
+            let model = Xmi.read(xmi_content).expect("Failed to read XMI");
+
+            assert_eq!(counter.elements.len(), model.element_count());
+            assert_eq!(counter.relationships, model.relationship_count());
+            // Children are reported before their owner...
+            assert_eq!(
+                counter.elements,
+                ["pu1", "pd1", "pd2", "pkg1"].map(ElementId::new)
+            );
+            // ...but the collected model keeps document order
+            let ids: Vec<_> = model.elements.keys().map(|id| id.as_str()).collect();
+            assert_eq!(ids, ["pkg1", "pd1", "pu1", "pd2"]);
+            let pkg = model.get(&ElementId::new("pkg1")).unwrap();
+            assert_eq!(
+                pkg.owned_elements,
+                vec![ElementId::new("pd1"), ElementId::new("pd2")]
+            );
+        }
     }
 }
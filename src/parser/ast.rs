@@ -386,6 +386,11 @@ pub trait AstNode: Sized {
 /// SysML supports two forms of documentation:
 /// 1. `doc /* content */` - formal SysML documentation (COMMENT_ELEMENT nodes)
 /// 2. Regular comments `/* ... */` or `// ...` - informal doc comments (trivia)
+///
+/// Leading comments must end on the line just above the node (or on the same
+/// line) with no blank line in between, and must start their own line, so a
+/// trailing comment of the previous declaration isn't picked up. A comment
+/// trailing the node on its last line is attached too.
 pub fn extract_doc_comment(node: &SyntaxNode) -> Option<String> {
     let mut comments = Vec::new();
     let mut current = node.prev_sibling_or_token();
@@ -395,31 +400,24 @@ pub fn extract_doc_comment(node: &SyntaxNode) -> Option<String> {
             rowan::NodeOrToken::Token(ref t) => {
                 match t.kind() {
                     SyntaxKind::WHITESPACE => {
-                        // Allow whitespace, continue looking
+                        // A blank line detaches everything above it
+                        if t.text().matches('\n').count() > 1 {
+                            break;
+                        }
                         current = t.prev_sibling_or_token();
                     }
-                    SyntaxKind::BLOCK_COMMENT => {
+                    SyntaxKind::BLOCK_COMMENT if starts_own_line(t) => {
                         // Block comment - use as doc
-                        let text = t.text();
-                        // Strip /* and */ and trim
-                        let content = text
-                            .strip_prefix("/*")
-                            .and_then(|s| s.strip_suffix("*/"))
-                            .map(clean_doc_comment)
-                            .unwrap_or_default();
-                        if !content.is_empty() {
+                        if let Some(content) = block_comment_content(t.text()) {
                             comments.push(content);
                         }
                         // Block comment found, stop looking
                         break;
                     }
-                    SyntaxKind::LINE_COMMENT => {
+                    SyntaxKind::LINE_COMMENT if starts_own_line(t) => {
                         // Line comment - collect consecutive ones
-                        let text = t.text();
-                        // Strip // and trim
-                        let content = text.strip_prefix("//").unwrap_or(text).trim();
-                        if !content.is_empty() {
-                            comments.push(content.to_string());
+                        if let Some(content) = line_comment_content(t.text()) {
+                            comments.push(content);
                         }
                         current = t.prev_sibling_or_token();
                     }
@@ -432,41 +430,76 @@ pub fn extract_doc_comment(node: &SyntaxNode) -> Option<String> {
                     // Extract the content from the COMMENT_ELEMENT
                     // The structure is: doc /* content */
                     // We need to find the BLOCK_COMMENT token inside
-                    for child in n.children_with_tokens() {
-                        if let rowan::NodeOrToken::Token(t) = child {
-                            if t.kind() == SyntaxKind::BLOCK_COMMENT {
-                                let text = t.text();
-                                let content = text
-                                    .strip_prefix("/*")
-                                    .and_then(|s| s.strip_suffix("*/"))
-                                    .map(clean_doc_comment)
-                                    .unwrap_or_default();
-                                if !content.is_empty() {
-                                    comments.push(content);
-                                }
-                                break;
-                            }
-                        }
+                    let content = n
+                        .children_with_tokens()
+                        .filter_map(|child| child.into_token())
+                        .find(|t| t.kind() == SyntaxKind::BLOCK_COMMENT)
+                        .and_then(|t| block_comment_content(t.text()));
+                    if let Some(content) = content {
+                        comments.push(content);
                     }
-                    // Found doc element, stop looking
-                    break;
-                } else {
-                    // Another node that's not a comment stops the search
-                    break;
                 }
+                // Found doc element or another node, stop looking
+                break;
             }
         }
     }
 
+    // Reverse because we collected bottom-up
+    comments.reverse();
+
+    if let Some(trailing) = trailing_comment(node) {
+        comments.push(trailing);
+    }
+
     if comments.is_empty() {
         return None;
     }
 
-    // Reverse because we collected bottom-up
-    comments.reverse();
     Some(comments.join("\n"))
 }
 
+/// Check that nothing but whitespace precedes a comment token on its line.
+fn starts_own_line(comment: &SyntaxToken) -> bool {
+    match comment.prev_sibling_or_token() {
+        None => true,
+        Some(rowan::NodeOrToken::Token(t)) if t.kind() == SyntaxKind::WHITESPACE => {
+            t.text().contains('\n') || t.prev_sibling_or_token().is_none()
+        }
+        Some(_) => false,
+    }
+}
+
+/// Find a comment following a node on the same line, e.g. `part def A; // doc`.
+fn trailing_comment(node: &SyntaxNode) -> Option<String> {
+    let mut next = node.next_sibling_or_token()?.into_token()?;
+    if next.kind() == SyntaxKind::WHITESPACE {
+        if next.text().contains('\n') {
+            return None;
+        }
+        next = next.next_sibling_or_token()?.into_token()?;
+    }
+    match next.kind() {
+        SyntaxKind::LINE_COMMENT => line_comment_content(next.text()),
+        SyntaxKind::BLOCK_COMMENT => block_comment_content(next.text()),
+        _ => None,
+    }
+}
+
+/// Strip `//` from a line comment, returning `None` if nothing is left.
+fn line_comment_content(text: &str) -> Option<String> {
+    let content = text.strip_prefix("//").unwrap_or(text).trim();
+    (!content.is_empty()).then(|| content.to_string())
+}
+
+/// Strip `/*` and `*/` from a block comment, returning `None` if nothing is left.
+fn block_comment_content(text: &str) -> Option<String> {
+    text.strip_prefix("/*")
+        .and_then(|s| s.strip_suffix("*/"))
+        .map(clean_doc_comment)
+        .filter(|content| !content.is_empty())
+}
+
 /// Clean up doc comment content by removing leading asterisks and normalizing whitespace.
 fn clean_doc_comment(s: &str) -> String {
     s.lines()
@@ -1450,6 +1483,9 @@ impl Specialization {
         FROM_KW => FeatureChain,
         TO_KW => FeatureChain,
         CHAINS_KW => FeatureChain,
+        UNIONS_KW => Unions,
+        INTERSECTS_KW => Intersects,
+        DIFFERENCES_KW => Differences,
     ]);
 
     /// Check if this is a shorthand redefines (`:>>`) vs keyword (`redefines`)
@@ -1472,6 +1508,11 @@ pub enum SpecializationKind {
     /// Per SysML v2 Spec §7.3.4.5: indicates a feature chain relationship.
     /// e.g., `feature x ::> a.b` or `feature self subsets things chains things.that`
     FeatureChain,
+    /// KerML type operators (`unions`, `intersects`, `differences`).
+    /// These relate a type to its operands; they are not specializations.
+    Unions,
+    Intersects,
+    Differences,
 }
 
 // ============================================================================
@@ -539,7 +539,7 @@ impl<'a> Parser<'a> {
         let range = self
             .current()
             .map(|t| TextRange::at(t.offset, TextSize::of(t.text)))
-            .unwrap_or_else(|| TextRange::empty(TextSize::new(0)));
+            .unwrap_or_else(|| TextRange::empty(TextSize::of(self.source)));
         self.errors.push(SyntaxError::new(message, range));
     }
 
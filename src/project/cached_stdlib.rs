@@ -50,7 +50,7 @@ impl CachedHost {
 
         // Add all files to host
         for (path, file) in files {
-            host.set_file(path, file);
+            host.set_library_file(path, file);
         }
 
         // Build the index once (this is the expensive part!)
@@ -142,9 +142,9 @@ impl CachedStdLib {
     /// Note: This is less efficient than `analysis_host()` because the index
     /// will need to be rebuilt. Prefer `analysis_host()` for new hosts.
     pub fn load_into(host: &mut AnalysisHost) {
-        // Clone all files from cached host
+        // Share all parsed files with the cached host
         for (path, file) in CACHED_HOST.host.files() {
-            host.set_file(path.clone(), file.clone());
+            host.set_shared_library_file(path.clone(), Arc::clone(file));
         }
         host.mark_dirty();
     }
@@ -207,6 +207,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_load_into_shares_parsed_files() {
+        let mut host = AnalysisHost::new();
+        CachedStdLib::load_into(&mut host);
+
+        let cached = CachedStdLib::analysis_host_arc();
+        assert_eq!(host.file_count(), cached.file_count());
+        for (path, file) in cached.files() {
+            assert!(Arc::ptr_eq(file, &host.files()[path]));
+        }
+    }
+
     #[test]
     fn test_cached_stdlib_file_count() {
         // Check the static count
@@ -1,12 +1,16 @@
 mod collection;
+mod exclude;
 mod parsing;
 
 // Re-export core file loading functions (generic, no language dependencies)
-pub use collection::collect_file_paths;
-pub use parsing::{get_extension, load_file, validate_extension};
+pub use collection::{collect_file_paths, collect_file_paths_excluding};
+pub use exclude::ExcludeFilter;
+pub use parsing::{get_extension, load_and_parse_if_changed, load_file, validate_extension};
 
 // Re-export language-agnostic parsing that dispatches to correct language parser
-pub use crate::syntax::parser::{load_and_parse, parse_content, parse_with_result};
+pub use crate::syntax::parser::{
+    load_and_parse, parse_content, parse_content_with_language, parse_with_result,
+};
 
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,7 @@
+use super::exclude::ExcludeFilter;
 use crate::base::constants::SUPPORTED_EXTENSIONS;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Recursively collects all supported file paths from a directory.
 ///
@@ -10,21 +11,51 @@ use std::path::PathBuf;
 /// - The directory cannot be read
 /// - A directory entry is invalid
 pub fn collect_file_paths(dir: &PathBuf) -> Result<Vec<PathBuf>, String> {
+    collect_file_paths_excluding(dir, &ExcludeFilter::new())
+}
+
+/// Recursively collects supported file paths, skipping paths matched by `filter`.
+///
+/// Patterns are matched against paths relative to `dir`. Excluded directories
+/// are not descended into.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The directory cannot be read
+/// - A directory entry is invalid
+pub fn collect_file_paths_excluding(
+    dir: &PathBuf,
+    filter: &ExcludeFilter,
+) -> Result<Vec<PathBuf>, String> {
     let mut paths = Vec::new();
-    collect_recursive(dir, &mut paths)?;
+    collect_recursive(dir, dir, filter, &mut paths)?;
     Ok(paths)
 }
 
-fn collect_recursive(dir: &PathBuf, paths: &mut Vec<PathBuf>) -> Result<(), String> {
+fn collect_recursive(
+    root: &Path,
+    dir: &PathBuf,
+    filter: &ExcludeFilter,
+    paths: &mut Vec<PathBuf>,
+) -> Result<(), String> {
     let entries = fs::read_dir(dir)
         .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
 
     for entry in entries {
         let entry = entry.map_err(|e| format!("Failed to read directory entry: {e}"))?;
         let path = entry.path();
+        let is_dir = path.is_dir();
+
+        if !filter.is_empty() {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            if filter.is_excluded(relative, is_dir) {
+                continue;
+            }
+        }
 
-        if path.is_dir() {
-            collect_recursive(&path, paths)?;
+        if is_dir {
+            collect_recursive(root, &path, filter, paths)?;
         } else if path.is_file()
             && path
                 .extension()
@@ -0,0 +1,239 @@
+use std::fs;
+use std::path::Path;
+
+/// A set of gitignore-style patterns used to skip paths while collecting files.
+///
+/// Patterns are matched against paths relative to the workspace root, using
+/// `/` as the separator regardless of platform. Supported syntax:
+/// - `*` matches any run of characters within a path segment
+/// - `?` matches a single character within a path segment
+/// - `**` matches zero or more whole segments
+/// - a trailing `/` restricts the pattern to directories
+/// - a pattern without an inner `/` matches at any depth (`build` excludes
+///   every directory or file named `build`); a leading `/` anchors it to the root
+/// - a leading `!` re-includes a path excluded by an earlier pattern
+/// - a `\` makes the next character literal (`\*`, `\?`, `\!`, `\#`)
+///
+/// As with git, the last matching pattern wins, and a file cannot be
+/// re-included once one of its parent directories has been excluded.
+#[derive(Debug, Clone, Default)]
+pub struct ExcludeFilter {
+    patterns: Vec<ExcludePattern>,
+}
+
+#[derive(Debug, Clone)]
+struct ExcludePattern {
+    segments: Vec<Segment>,
+    negated: bool,
+    dir_only: bool,
+}
+
+/// One `/`-separated part of a pattern.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// `**`: zero or more whole path segments
+    AnyDepth,
+    /// A glob matched against a single path segment
+    Glob(Vec<GlobToken>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GlobToken {
+    /// `*`
+    AnyRun,
+    /// `?`
+    AnyChar,
+    Literal(char),
+}
+
+impl ExcludeFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a filter from a list of glob patterns.
+    pub fn from_globs<S: AsRef<str>>(globs: &[S]) -> Self {
+        let mut filter = Self::new();
+        for glob in globs {
+            filter.add_pattern(glob.as_ref());
+        }
+        filter
+    }
+
+    /// Adds a single pattern. Blank lines and `#` comments are ignored.
+    pub fn add_pattern(&mut self, pattern: &str) {
+        if let Some(pattern) = ExcludePattern::parse(pattern) {
+            self.patterns.push(pattern);
+        }
+    }
+
+    /// Adds the patterns from `root/.gitignore`, if that file exists.
+    ///
+    /// Only the root `.gitignore` is read; nested ignore files are not consulted.
+    pub fn add_gitignore(&mut self, root: &Path) -> Result<(), String> {
+        let gitignore = root.join(".gitignore");
+        if !gitignore.is_file() {
+            return Ok(());
+        }
+        let content = fs::read_to_string(&gitignore)
+            .map_err(|e| format!("Failed to read {}: {}", gitignore.display(), e))?;
+        for line in content.lines() {
+            self.add_pattern(line);
+        }
+        Ok(())
+    }
+
+    /// Returns true if the filter contains no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Returns true if `relative` (a path relative to the workspace root) is excluded.
+    pub fn is_excluded(&self, relative: &Path, is_dir: bool) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+        let segments: Vec<Vec<char>> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().chars().collect())
+            .collect();
+
+        let mut excluded = false;
+        for pattern in &self.patterns {
+            if pattern.dir_only && !is_dir {
+                continue;
+            }
+            if match_segments(&pattern.segments, &segments) {
+                excluded = !pattern.negated;
+            }
+        }
+        excluded
+    }
+}
+
+impl ExcludePattern {
+    fn parse(line: &str) -> Option<Self> {
+        let mut pattern = line.trim();
+        if pattern.is_empty() || pattern.starts_with('#') {
+            return None;
+        }
+
+        let negated = pattern.starts_with('!');
+        if negated {
+            pattern = &pattern[1..];
+        }
+        let dir_only = pattern.ends_with('/');
+        pattern = pattern.trim_end_matches('/');
+        let anchored = pattern.starts_with('/') || pattern.contains('/');
+        pattern = pattern.trim_start_matches('/');
+        if pattern.is_empty() {
+            return None;
+        }
+
+        let mut segments = Vec::new();
+        if !anchored {
+            segments.push(Segment::AnyDepth);
+        }
+        segments.extend(
+            pattern
+                .split('/')
+                .filter(|s| !s.is_empty())
+                .map(Segment::parse),
+        );
+
+        Some(Self {
+            segments,
+            negated,
+            dir_only,
+        })
+    }
+}
+
+impl Segment {
+    fn parse(text: &str) -> Self {
+        if text == "**" {
+            return Self::AnyDepth;
+        }
+        let mut tokens = Vec::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '\\' => GlobToken::Literal(chars.next().unwrap_or('\\')),
+                '*' => GlobToken::AnyRun,
+                '?' => GlobToken::AnyChar,
+                c => GlobToken::Literal(c),
+            };
+            // Consecutive `*` match the same as one
+            if !(token == GlobToken::AnyRun && tokens.last() == Some(&GlobToken::AnyRun)) {
+                tokens.push(token);
+            }
+        }
+        Self::Glob(tokens)
+    }
+}
+
+/// Matches pattern segments against path segments, with `**` spanning segments.
+///
+/// Tracks the set of path positions reachable after each pattern segment, so
+/// every path segment is matched against each glob at most once.
+fn match_segments(pattern: &[Segment], path: &[Vec<char>]) -> bool {
+    // reachable[j]: the pattern so far matches the first `j` path segments
+    let mut reachable = vec![false; path.len() + 1];
+    reachable[0] = true;
+    for segment in pattern {
+        match segment {
+            Segment::AnyDepth => {
+                let mut seen = false;
+                for slot in reachable.iter_mut() {
+                    seen |= *slot;
+                    *slot = seen;
+                }
+            }
+            Segment::Glob(tokens) => {
+                for j in (0..path.len()).rev() {
+                    reachable[j + 1] = reachable[j] && match_segment(tokens, &path[j]);
+                }
+                reachable[0] = false;
+            }
+        }
+        if !reachable.contains(&true) {
+            return false;
+        }
+    }
+    reachable[path.len()]
+}
+
+/// Matches a single segment against a glob.
+///
+/// On a mismatch, only the most recent `*` is extended by one character, so
+/// matching takes at most `pattern.len() * text.len()` steps.
+fn match_segment(pattern: &[GlobToken], text: &[char]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and of the text it was tried against
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(GlobToken::AnyRun) => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(GlobToken::AnyChar) => {
+                p += 1;
+                t += 1;
+            }
+            Some(GlobToken::Literal(c)) if *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, t));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|token| *token == GlobToken::AnyRun)
+}
@@ -1,2 +1,39 @@
 // Re-export file I/O utilities from syntax layer
 pub use crate::syntax::parser::{get_extension, load_file, validate_extension};
+
+use crate::hir::FileSet;
+use crate::syntax::SyntaxFile;
+use crate::syntax::file::Language;
+use crate::syntax::parser::parse_content_with_language;
+use std::path::PathBuf;
+
+/// Loads and parses a file, skipping the parse if its contents are unchanged.
+///
+/// The contents are recorded in `files`. Returns `Ok(None)` when they match
+/// what `files` already holds and `already_loaded` is true, i.e. the caller
+/// still has the previous parse of this file. The grammar is chosen as for
+/// [`Language::Auto`].
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The file cannot be read
+/// - The file has an invalid extension
+pub fn load_and_parse_if_changed(
+    path: &PathBuf,
+    files: &FileSet,
+    already_loaded: bool,
+) -> Result<Option<SyntaxFile>, String> {
+    validate_extension(path)?;
+    let content = load_file(path)?;
+    let file_id = files.file_id(path);
+
+    if !files.set_contents_if_changed(file_id, content.as_str()) && already_loaded {
+        return Ok(None);
+    }
+    Ok(Some(parse_content_with_language(
+        &content,
+        path,
+        Language::Auto,
+    )))
+}
@@ -1,8 +1,8 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
-use super::super::collect_file_paths;
+use super::super::{ExcludeFilter, collect_file_paths, collect_file_paths_excluding};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 #[test]
@@ -174,3 +174,95 @@ fn test_collect_file_paths_hidden_files() {
     // Should include both hidden and visible files
     assert_eq!(paths.len(), 2, "Should collect hidden files too");
 }
+
+#[test]
+fn test_collect_file_paths_excluding_build_directory() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let build_dir = temp_dir.path().join("build");
+    let models_dir = temp_dir.path().join("models");
+    fs::create_dir(&build_dir).expect("Failed to create build");
+    fs::create_dir(&models_dir).expect("Failed to create models");
+
+    fs::write(temp_dir.path().join("main.sysml"), "part def Main;").expect("Failed to write");
+    fs::write(build_dir.join("generated.sysml"), "part def Gen;").expect("Failed to write");
+    fs::write(models_dir.join("base.kerml"), "class Base;").expect("Failed to write");
+
+    let filter = ExcludeFilter::from_globs(&["build/**"]);
+    let paths = collect_file_paths_excluding(&temp_dir.path().to_path_buf(), &filter).unwrap();
+
+    assert_eq!(paths.len(), 2, "Should skip files under build/");
+    assert!(paths.iter().all(|p| !p.starts_with(&build_dir)));
+    assert!(paths.iter().any(|p| p.ends_with("main.sysml")));
+    assert!(paths.iter().any(|p| p.ends_with("base.kerml")));
+}
+
+#[test]
+fn test_collect_file_paths_excluding_is_relative_to_root() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    // An anchored pattern only matches at the root, not in nested directories
+    let root_build = temp_dir.path().join("build");
+    let nested_build = temp_dir.path().join("lib").join("build");
+    fs::create_dir(&root_build).expect("Failed to create build");
+    fs::create_dir_all(&nested_build).expect("Failed to create lib/build");
+
+    fs::write(root_build.join("a.sysml"), "part def A;").expect("Failed to write");
+    fs::write(nested_build.join("b.sysml"), "part def B;").expect("Failed to write");
+
+    let root = temp_dir.path().to_path_buf();
+
+    let anchored = ExcludeFilter::from_globs(&["/build/"]);
+    let paths = collect_file_paths_excluding(&root, &anchored).unwrap();
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("b.sysml"));
+
+    // An unanchored name matches at any depth
+    let unanchored = ExcludeFilter::from_globs(&["build"]);
+    let paths = collect_file_paths_excluding(&root, &unanchored).unwrap();
+    assert!(paths.is_empty());
+}
+
+#[test]
+fn test_exclude_filter_patterns() {
+    let filter = ExcludeFilter::from_globs(&[
+        "# comment",
+        "",
+        "*.tmp.sysml",
+        "**/generated/",
+        "docs/?.kerml",
+        "!keep.tmp.sysml",
+    ]);
+
+    assert!(filter.is_excluded(Path::new("a.tmp.sysml"), false));
+    assert!(filter.is_excluded(Path::new("x/y/a.tmp.sysml"), false));
+    assert!(!filter.is_excluded(Path::new("keep.tmp.sysml"), false));
+    assert!(filter.is_excluded(Path::new("src/generated"), true));
+    assert!(
+        !filter.is_excluded(Path::new("src/generated"), false),
+        "Trailing slash should only match directories"
+    );
+    assert!(filter.is_excluded(Path::new("docs/a.kerml"), false));
+    assert!(!filter.is_excluded(Path::new("docs/ab.kerml"), false));
+    assert!(!filter.is_excluded(Path::new("model.sysml"), false));
+}
+
+#[test]
+fn test_exclude_filter_escapes() {
+    let filter = ExcludeFilter::from_globs(&["\\*.sysml", "\\!keep.sysml", "\\#notes.kerml"]);
+
+    assert!(filter.is_excluded(Path::new("*.sysml"), false));
+    assert!(!filter.is_excluded(Path::new("model.sysml"), false));
+    assert!(filter.is_excluded(Path::new("!keep.sysml"), false));
+    assert!(filter.is_excluded(Path::new("#notes.kerml"), false));
+}
+
+#[test]
+fn test_exclude_filter_long_path_does_not_backtrack() {
+    let filter = ExcludeFilter::from_globs(&["**/a*b*c*d"]);
+    let segment = "abc".repeat(200);
+    let long = vec![segment.as_str(); 50].join("/");
+
+    assert!(!filter.is_excluded(Path::new(&long), false));
+    assert!(filter.is_excluded(Path::new(&format!("{long}/{segment}d")), false));
+}
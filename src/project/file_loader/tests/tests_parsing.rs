@@ -1,8 +1,12 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
-use crate::syntax::parser::{load_and_parse, parse_content, parse_with_result};
+use crate::syntax::Language;
+use crate::syntax::file::FileExtension;
+use crate::syntax::parser::{
+    load_and_parse, parse_content, parse_content_with_language, parse_with_result,
+};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 #[test]
@@ -256,3 +260,46 @@ fn test_load_and_parse_symlink() {
     let result = load_and_parse(&symlink);
     assert!(result.is_ok(), "Should follow symlinks");
 }
+
+#[test]
+fn test_parse_content_autodetects_kerml() {
+    let content = "package Shapes {\n    classifier Shape;\n    feature area : Real;\n}";
+    // The extension claims SysML, but the content is KerML
+    let path = PathBuf::from("shapes.sysml");
+
+    let file = parse_content_with_language(content, &path, Language::Auto);
+    assert!(file.is_kerml(), "KerML content should be parsed as KerML");
+    assert!(!file.has_errors(), "errors: {:?}", file.errors());
+
+    let file = parse_content_with_language(content, Path::new("shapes"), Language::Auto);
+    assert_eq!(file.extension(), FileExtension::KerML);
+}
+
+#[test]
+fn test_parse_content_autodetects_sysml() {
+    let path = Path::new("model");
+    let file = parse_content_with_language("package P { part def Vehicle; }", path, Language::Auto);
+    assert!(file.is_sysml());
+
+    // Without distinguishing keywords, the extension decides
+    let file = parse_content_with_language("package P;", Path::new("p.kerml"), Language::Auto);
+    assert!(file.is_kerml());
+    let file = parse_content_with_language("package P;", path, Language::Auto);
+    assert!(file.is_sysml());
+
+    // SysML models may use KerML declarations too, so mixed content also
+    // falls back to the extension
+    let content = "package P { struct Trigger; part t : Trigger; }";
+    let file = parse_content_with_language(content, Path::new("p.sysml"), Language::Auto);
+    assert!(file.is_sysml());
+    assert!(!file.has_errors(), "errors: {:?}", file.errors());
+}
+
+#[test]
+fn test_parse_content_explicit_language_overrides_content() {
+    let path = Path::new("shapes.sysml");
+    let file = parse_content_with_language("classifier Shape;", path, Language::SysML);
+    assert!(file.is_sysml());
+    let file = parse_content_with_language("part def Vehicle;", path, Language::KerML);
+    assert!(file.is_kerml());
+}
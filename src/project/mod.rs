@@ -4,8 +4,8 @@ pub mod stdlib_loader;
 pub mod workspace_loader;
 
 pub use cached_stdlib::CachedStdLib;
-pub use stdlib_loader::StdLibLoader;
-pub use workspace_loader::WorkspaceLoader;
+pub use stdlib_loader::{StdLibLoader, StdlibLoadReport};
+pub use workspace_loader::{LoadOptions, WorkspaceLoader};
 
 // Re-export parse types from syntax layer
 pub use crate::syntax::parser::{ParseError, ParseResult};
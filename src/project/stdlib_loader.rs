@@ -1,6 +1,9 @@
 mod loader;
 
+pub use loader::StdlibLoadReport;
+
 use crate::base::constants::STDLIB_DIR;
+use crate::hir::FileSet;
 use crate::ide::AnalysisHost;
 use std::path::PathBuf;
 
@@ -9,6 +12,8 @@ pub struct StdLibLoader {
     stdlib_path: PathBuf,
     /// Track if stdlib has been loaded (for lazy loading)
     loaded: bool,
+    /// Contents of loaded stdlib files, used to skip reparsing unchanged files
+    files: FileSet,
 }
 
 impl StdLibLoader {
@@ -21,6 +26,7 @@ impl StdLibLoader {
         Self {
             stdlib_path: Self::discover_path(),
             loaded: false,
+            files: FileSet::new(),
         }
     }
 
@@ -29,6 +35,7 @@ impl StdLibLoader {
         Self {
             stdlib_path: path,
             loaded: false,
+            files: FileSet::new(),
         }
     }
 
@@ -64,14 +71,21 @@ impl StdLibLoader {
             return Ok(false);
         }
 
-        self.load_into_host(host)?;
+        let report = self.load_into_host(host)?;
+        for (path, error) in &report.failed {
+            tracing::warn!("stdlib file {} failed to load: {}", path.display(), error);
+        }
         self.loaded = true;
         Ok(true)
     }
 
     /// Loads the SysML standard library into an AnalysisHost.
-    pub fn load_into_host(&self, host: &mut AnalysisHost) -> Result<(), String> {
-        loader::load_into_host(&self.stdlib_path, host)
+    ///
+    /// Files already in `host` whose contents are unchanged since this loader
+    /// last read them are not reparsed. Files that fail to load are listed in
+    /// the returned report rather than aborting the load.
+    pub fn load_into_host(&self, host: &mut AnalysisHost) -> Result<StdlibLoadReport, String> {
+        loader::load_into_host(&self.stdlib_path, host, &self.files)
     }
 }
 
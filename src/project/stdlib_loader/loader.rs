@@ -1,11 +1,32 @@
+use crate::hir::FileSet;
 use crate::ide::AnalysisHost;
 use crate::project::file_loader;
-use crate::syntax::SyntaxFile;
 use rayon::prelude::*;
 use std::path::PathBuf;
 
+/// Outcome of loading the standard library.
+///
+/// Files skipped because they are unchanged since the last load are not
+/// counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StdlibLoadReport {
+    /// Number of files parsed without errors and added to the host.
+    pub loaded: usize,
+    /// Files that could not be read or have syntax errors, with the first
+    /// error. Files with syntax errors are still added to the host, since the
+    /// parser recovers.
+    pub failed: Vec<(PathBuf, String)>,
+}
+
 /// Loads the SysML standard library into an AnalysisHost.
-pub fn load_into_host(stdlib_path: &PathBuf, host: &mut AnalysisHost) -> Result<(), String> {
+///
+/// Files already in `host` whose contents match those recorded in `files`
+/// are skipped.
+pub fn load_into_host(
+    stdlib_path: &PathBuf,
+    host: &mut AnalysisHost,
+    files: &FileSet,
+) -> Result<StdlibLoadReport, String> {
     if !stdlib_path.exists() {
         return Err(format!(
             "stdlib path does not exist: {}",
@@ -22,22 +43,34 @@ pub fn load_into_host(stdlib_path: &PathBuf, host: &mut AnalysisHost) -> Result<
     // Collect all file paths first
     let file_paths = file_loader::collect_file_paths(stdlib_path)?;
 
-    // Parse files in parallel
+    // Parse changed files in parallel
+    let already_loaded: Vec<bool> = file_paths.iter().map(|p| host.has_file_path(p)).collect();
     let results: Vec<_> = file_paths
         .par_iter()
-        .map(|path| (path, parse_file(path)))
+        .zip(already_loaded.par_iter())
+        .map(|(path, &loaded)| {
+            (
+                path,
+                file_loader::load_and_parse_if_changed(path, files, loaded),
+            )
+        })
         .collect();
 
-    // Add successfully parsed files
-    for (_path, result) in results {
-        if let Ok((path, file)) = result {
-            host.set_file(path, file);
+    // Add parsed files, recording the ones with errors
+    let mut report = StdlibLoadReport::default();
+    for (path, result) in results {
+        match result {
+            Ok(Some(file)) => {
+                match file.errors().first() {
+                    Some(error) => report.failed.push((path.clone(), error.message.clone())),
+                    None => report.loaded += 1,
+                }
+                host.set_library_file(path.clone(), file);
+            }
+            Ok(None) => {}
+            Err(e) => report.failed.push((path.clone(), e)),
         }
     }
 
-    Ok(())
-}
-
-fn parse_file(path: &PathBuf) -> Result<(PathBuf, SyntaxFile), String> {
-    file_loader::load_and_parse(path).map(|file| (path.clone(), file))
+    Ok(report)
 }
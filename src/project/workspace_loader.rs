@@ -1,47 +1,132 @@
+use crate::hir::FileSet;
 use crate::ide::AnalysisHost;
 use std::path::PathBuf;
 
 use super::file_loader;
+use super::file_loader::ExcludeFilter;
+use rayon::prelude::*;
 
 #[cfg(feature = "interchange")]
 use crate::interchange::integrate::apply_metadata_to_host;
 #[cfg(feature = "interchange")]
 use crate::interchange::metadata::ImportMetadata;
 
+/// Options for [`WorkspaceLoader::load_directory_into_host_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct LoadOptions {
+    /// Gitignore-style globs, relative to the loaded directory, of paths to
+    /// skip (e.g. `build/` or `**/generated/*.sysml`)
+    pub exclude_patterns: Vec<String>,
+    /// Apply the patterns in the directory's `.gitignore` before
+    /// `exclude_patterns`
+    pub respect_gitignore: bool,
+    /// Parse on a dedicated pool of this many threads (`None` uses the
+    /// global rayon pool)
+    pub max_threads: Option<usize>,
+}
+
 /// Loads workspace files on demand
-pub struct WorkspaceLoader;
+pub struct WorkspaceLoader {
+    /// Contents of previously loaded files, used to skip reparsing unchanged files
+    files: FileSet,
+}
 
 impl WorkspaceLoader {
     pub fn new() -> Self {
-        Self
+        Self {
+            files: FileSet::new(),
+        }
     }
 
     /// Loads all SysML and KerML files from a directory into an AnalysisHost.
+    ///
+    /// Paths matching any of `exclude_patterns` (gitignore-style globs relative
+    /// to `path`, e.g. `build/` or `**/generated/*.sysml`) are skipped. When
+    /// `respect_gitignore` is set, patterns from `path/.gitignore` are applied
+    /// before `exclude_patterns`.
+    ///
+    /// When the same loader re-scans a directory, files whose contents are
+    /// unchanged since the last load (and which are still in `host`) are skipped.
     pub fn load_directory_into_host<P: Into<PathBuf>>(
         &self,
         path: P,
         host: &mut AnalysisHost,
+        exclude_patterns: Vec<String>,
+        respect_gitignore: bool,
+    ) -> Result<(), String> {
+        let options = LoadOptions {
+            exclude_patterns,
+            respect_gitignore,
+            ..LoadOptions::default()
+        };
+        self.load_directory_into_host_with_options(path, host, &options)
+    }
+
+    /// Loads all SysML and KerML files from a directory into an AnalysisHost,
+    /// as configured by `options`.
+    ///
+    /// Files are parsed in parallel, on a dedicated pool when
+    /// `options.max_threads` is set.
+    pub fn load_directory_into_host_with_options<P: Into<PathBuf>>(
+        &self,
+        path: P,
+        host: &mut AnalysisHost,
+        options: &LoadOptions,
     ) -> Result<(), String> {
         let path = path.into();
         if !path.exists() || !path.is_dir() {
             return Err(format!("Directory not found: {}", path.display()));
         }
-        self.load_directory_recursive_into_host(&path, host)
+
+        let mut filter = ExcludeFilter::new();
+        if options.respect_gitignore {
+            filter.add_gitignore(&path)?;
+        }
+        for pattern in &options.exclude_patterns {
+            filter.add_pattern(pattern);
+        }
+
+        self.load_directory_recursive_into_host(&path, &filter, options.max_threads, host)
     }
 
     fn load_directory_recursive_into_host(
         &self,
         dir: &PathBuf,
+        filter: &ExcludeFilter,
+        max_threads: Option<usize>,
         host: &mut AnalysisHost,
     ) -> Result<(), String> {
-        let paths = file_loader::collect_file_paths(dir)?;
-        let mut errors = Vec::new();
+        let mut paths = file_loader::collect_file_paths_excluding(dir, filter)?;
+        // Sort so files are inserted (and errors reported) in a stable order
+        paths.sort();
+
+        let already_loaded: Vec<bool> = paths.iter().map(|p| host.has_file_path(p)).collect();
+        let parse_all = || -> Vec<_> {
+            paths
+                .par_iter()
+                .zip(already_loaded.par_iter())
+                .map(|(path, &loaded)| {
+                    let result = file_loader::load_and_parse_if_changed(path, &self.files, loaded);
+                    (path, result)
+                })
+                .collect()
+        };
+        let results = match max_threads {
+            Some(threads) => rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|e| format!("Failed to build thread pool: {e}"))?
+                .install(parse_all),
+            None => parse_all(),
+        };
 
-        for path in paths {
-            match file_loader::load_and_parse(&path) {
-                Ok(file) => {
-                    host.set_file(path, file);
+        let mut errors = Vec::new();
+        for (path, result) in results {
+            match result {
+                Ok(Some(file)) => {
+                    host.set_file(path.clone(), file);
                 }
+                Ok(None) => {}
                 Err(e) => {
                     errors.push(format!("{}: {}", path.display(), e));
                 }
@@ -4,7 +4,9 @@
 //! from the rowan-based parser.
 
 use crate::base::LineIndex;
-use crate::parser::{AstNode, NamespaceMember, Parse, SourceFile, parse_kerml, parse_sysml};
+use crate::parser::{
+    AstNode, Lexer, NamespaceMember, Parse, SourceFile, SyntaxKind, parse_kerml, parse_sysml,
+};
 
 /// A parsed syntax file that wraps a rowan Parse result.
 ///
@@ -35,6 +37,65 @@ pub enum FileExtension {
     KerML,
 }
 
+/// Language hint for parsing content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    /// Pick the grammar from the content, falling back to the file extension
+    #[default]
+    Auto,
+    SysML,
+    KerML,
+}
+
+/// Guess the language of `source` from its keywords.
+///
+/// Keywords that belong to only one of the languages decide (e.g., `part` or
+/// `def` for SysML, `classifier` or `feature` for KerML). Returns `None` if
+/// no such keyword appears, as in a file holding only packages and imports,
+/// or if keywords of both appear, since SysML models may also use KerML
+/// declarations such as `struct`.
+pub fn detect_language(source: &str) -> Option<FileExtension> {
+    let mut detected = None;
+    for language in Lexer::new(source).filter_map(|token| match token.kind {
+        SyntaxKind::DEF_KW
+        | SyntaxKind::PART_KW
+        | SyntaxKind::ATTRIBUTE_KW
+        | SyntaxKind::PORT_KW
+        | SyntaxKind::ITEM_KW
+        | SyntaxKind::ACTION_KW
+        | SyntaxKind::STATE_KW
+        | SyntaxKind::CALC_KW
+        | SyntaxKind::REQUIREMENT_KW
+        | SyntaxKind::OCCURRENCE_KW
+        | SyntaxKind::CONNECTION_KW
+        | SyntaxKind::INTERFACE_KW
+        | SyntaxKind::VIEW_KW => Some(FileExtension::SysML),
+        SyntaxKind::CLASSIFIER_KW
+        | SyntaxKind::FEATURE_KW
+        | SyntaxKind::CLASS_KW
+        | SyntaxKind::STRUCT_KW
+        | SyntaxKind::DATATYPE_KW
+        | SyntaxKind::ASSOC_KW
+        | SyntaxKind::BEHAVIOR_KW
+        | SyntaxKind::FUNCTION_KW
+        | SyntaxKind::PREDICATE_KW
+        | SyntaxKind::INTERACTION_KW
+        | SyntaxKind::METACLASS_KW
+        | SyntaxKind::CONNECTOR_KW
+        | SyntaxKind::STEP_KW
+        | SyntaxKind::EXPR_KW
+        | SyntaxKind::TYPE_KW => Some(FileExtension::KerML),
+        _ => None,
+    }) {
+        match detected {
+            None => detected = Some(language),
+            Some(seen) if seen != language => return None,
+            Some(_) => {}
+        }
+    }
+    detected
+}
+
 impl SyntaxFile {
     /// Create a new SyntaxFile from source code and extension
     pub fn new(source: &str, extension: FileExtension) -> Self {
@@ -81,6 +142,11 @@ impl SyntaxFile {
         &self.parse.errors
     }
 
+    /// The language this file was parsed as
+    pub fn extension(&self) -> FileExtension {
+        self.extension
+    }
+
     /// Check if this is a SysML file
     pub fn is_sysml(&self) -> bool {
         self.extension == FileExtension::SysML
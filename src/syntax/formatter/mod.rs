@@ -387,23 +387,34 @@ fn render(
         &mut output,
         &mut indent_level,
         &mut at_line_start,
+        None,
         cancel,
     )?;
 
     Some(output)
 }
 
+/// Render a node. `colon_column` is the output column the node's first `:`
+/// should be padded to when aligning type annotations within a block.
 fn render_node(
     node: &SyntaxNode,
     options: &FormatOptions,
     output: &mut String,
     indent_level: &mut usize,
     at_line_start: &mut bool,
+    mut colon_column: Option<usize>,
     cancel: &CancellationToken,
 ) -> Option<()> {
     // Collect children for lookahead
     let children: Vec<_> = node.children_with_tokens().collect();
 
+    // Members of this block get their colons aligned to a shared column
+    let member_colon_column = if options.align_colons && node.kind() == SyntaxKind::Body {
+        aligned_colon_column(node, options, *indent_level + 1)
+    } else {
+        None
+    };
+
     for (i, child) in children.iter().enumerate() {
         if cancel.is_cancelled() {
             return None;
@@ -424,8 +435,11 @@ fn render_node(
 
                 match kind {
                     SyntaxKind::Whitespace => {
-                        // Don't preserve newlines before opening brace - keep it on same line
-                        if next_significant == Some(SyntaxKind::LBrace) {
+                        // Don't preserve newlines before opening brace - keep it on same line,
+                        // unless a line comment would swallow the brace
+                        if next_significant == Some(SyntaxKind::LBrace)
+                            && !follows_line_comment(token)
+                        {
                             // Just add a single space, brace will be on same line
                             if !*at_line_start && !output.ends_with(' ') && !output.is_empty() {
                                 output.push(' ');
@@ -447,7 +461,8 @@ fn render_node(
                             output.push_str(&options.indent(*indent_level));
                             *at_line_start = false;
                         }
-                        output.push_str(text);
+                        // Drop trailing whitespace (including a CR from CRLF line endings)
+                        output.push_str(text.trim_end());
                     }
                     SyntaxKind::BlockComment => {
                         if *at_line_start {
@@ -461,14 +476,18 @@ fn render_node(
                         if !*at_line_start && !output.ends_with(' ') && !output.ends_with('\n') {
                             output.push(' ');
                         }
-                        // If at line start but we want brace on same line, remove trailing newlines
-                        if *at_line_start && !output.is_empty() {
-                            // Remove trailing newlines to put brace on same line
-                            while output.ends_with('\n') {
-                                output.pop();
-                            }
-                            if !output.ends_with(' ') {
-                                output.push(' ');
+                        if *at_line_start {
+                            if !output.is_empty() && !follows_line_comment(token) {
+                                // Remove trailing newlines to put brace on same line
+                                while output.ends_with('\n') {
+                                    output.pop();
+                                }
+                                if !output.ends_with(' ') {
+                                    output.push(' ');
+                                }
+                            } else {
+                                // Brace has to stay on its own line
+                                output.push_str(&options.indent(*indent_level));
                             }
                             *at_line_start = false;
                         }
@@ -488,6 +507,14 @@ fn render_node(
                         *at_line_start = false;
                     }
                     SyntaxKind::Colon | SyntaxKind::ColonColon | SyntaxKind::Dot => {
+                        if kind == SyntaxKind::Colon {
+                            if let Some(column) = colon_column.take() {
+                                let line = output.rsplit('\n').next().unwrap_or_default();
+                                let width = line.chars().count();
+                                output
+                                    .extend(std::iter::repeat_n(' ', column.saturating_sub(width)));
+                            }
+                        }
                         // No space before colons and dots
                         output.push_str(text);
                         *at_line_start = false;
@@ -503,12 +530,15 @@ fn render_node(
                 }
             }
             rowan::NodeOrToken::Node(child_node) => {
+                let child_colon_column =
+                    member_colon_column.filter(|_| member_head_width(child_node).is_some());
                 render_node(
                     child_node,
                     options,
                     output,
                     indent_level,
                     at_line_start,
+                    child_colon_column,
                     cancel,
                 )?;
             }
@@ -516,3 +546,70 @@ fn render_node(
     }
     Some(())
 }
+
+/// Check whether the closest preceding non-whitespace token is a line comment.
+fn follows_line_comment(token: &syntax_kind::SyntaxToken) -> bool {
+    std::iter::successors(token.prev_token(), |t| t.prev_token())
+        .find(|t| t.kind() != SyntaxKind::Whitespace)
+        .is_some_and(|t| t.kind() == SyntaxKind::LineComment)
+}
+
+/// Compute the column that `:` annotations in a block should line up at.
+///
+/// Returns `None` unless at least two members start their own line and have a
+/// `:` on that line; blocks with a single such member are left untouched.
+fn aligned_colon_column(body: &SyntaxNode, options: &FormatOptions, level: usize) -> Option<usize> {
+    let widths: Vec<usize> = body
+        .children()
+        .filter_map(|n| member_head_width(&n))
+        .collect();
+    if widths.len() < 2 {
+        return None;
+    }
+    let max_width = widths.into_iter().max()?;
+    Some(options.indent(level).chars().count() + max_width + 1)
+}
+
+/// Rendered width of a member's text before its first `:`, e.g. `attribute mass`.
+///
+/// Returns `None` if the member doesn't start on its own line, has no `:`
+/// before its body or `;`, or has a line break or comment before the `:`.
+fn member_head_width(member: &SyntaxNode) -> Option<usize> {
+    if !matches!(member.kind(), SyntaxKind::Definition | SyntaxKind::Usage) {
+        return None;
+    }
+    match member.prev_sibling_or_token() {
+        Some(rowan::NodeOrToken::Token(t))
+            if t.kind() == SyntaxKind::Whitespace && t.text().contains('\n') => {}
+        _ => return None,
+    }
+
+    let mut width = 0;
+    let mut pending_space = false;
+    for child in member.children_with_tokens() {
+        let text = match &child {
+            rowan::NodeOrToken::Token(t) => match t.kind() {
+                SyntaxKind::Colon => return Some(width),
+                SyntaxKind::Whitespace if t.text().contains('\n') => return None,
+                SyntaxKind::Whitespace => {
+                    // The renderer collapses inline whitespace to one space
+                    pending_space = width > 0;
+                    continue;
+                }
+                SyntaxKind::LineComment
+                | SyntaxKind::BlockComment
+                | SyntaxKind::LBrace
+                | SyntaxKind::Semicolon => return None,
+                _ => t.text().to_string(),
+            },
+            rowan::NodeOrToken::Node(n) if n.kind() == SyntaxKind::Name => n.text().to_string(),
+            rowan::NodeOrToken::Node(_) => return None,
+        };
+        if pending_space {
+            width += 1;
+            pending_space = false;
+        }
+        width += text.chars().count();
+    }
+    None
+}
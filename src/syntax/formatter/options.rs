@@ -3,20 +3,30 @@
 /// Formatting options for SysML/KerML code
 #[derive(Debug, Clone)]
 pub struct FormatOptions {
-    /// Number of spaces per indentation level (or tab width if using tabs)
-    pub tab_size: usize,
-    /// Use spaces for indentation (false = use tabs)
-    pub insert_spaces: bool,
+    /// Number of spaces per indentation level (ignored when using tabs);
+    /// `tab_size` is used when unset
+    pub indent_width: Option<usize>,
+    /// Indent with one tab per level instead of spaces
+    pub use_tabs: bool,
+    /// Vertically align the `:` type annotations of members within a block
+    pub align_colons: bool,
     /// Maximum line width before breaking
     pub print_width: usize,
+    /// Number of spaces per indentation level when `indent_width` is unset
+    pub tab_size: usize,
+    /// Use spaces for indentation (false = use tabs, as `use_tabs`)
+    pub insert_spaces: bool,
 }
 
 impl Default for FormatOptions {
     fn default() -> Self {
         Self {
+            indent_width: None,
+            use_tabs: false,
+            align_colons: false,
+            print_width: 80,
             tab_size: 4,
             insert_spaces: true,
-            print_width: 80,
         }
     }
 }
@@ -24,10 +34,20 @@ impl Default for FormatOptions {
 impl FormatOptions {
     /// Generate indentation string for the given level
     pub fn indent(&self, level: usize) -> String {
-        if self.insert_spaces {
-            " ".repeat(self.tab_size * level)
-        } else {
+        if self.indents_with_tabs() {
             "\t".repeat(level)
+        } else {
+            " ".repeat(self.effective_indent_width() * level)
         }
     }
+
+    /// Whether to indent with tabs, from `use_tabs` or `insert_spaces`
+    fn indents_with_tabs(&self) -> bool {
+        self.use_tabs || !self.insert_spaces
+    }
+
+    /// Spaces per level, from `indent_width`, falling back to `tab_size`
+    fn effective_indent_width(&self) -> usize {
+        self.indent_width.unwrap_or(self.tab_size)
+    }
 }
@@ -1,2 +1,3 @@
 mod tests_formatter;
+mod tests_idempotency;
 mod tests_syntax_kind;
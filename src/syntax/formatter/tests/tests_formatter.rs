@@ -522,6 +522,14 @@ fn test_format_options_default() {
     assert_eq!(options.print_width, 80);
 }
 
+#[test]
+fn test_format_options_default_layout() {
+    let options = FormatOptions::default();
+    assert_eq!(options.indent_width, None);
+    assert!(!options.use_tabs);
+    assert!(!options.align_colons);
+}
+
 #[test]
 fn test_format_with_tabs_option() {
     let source = "package Test { part x ; }";
@@ -529,11 +537,100 @@ fn test_format_with_tabs_option() {
         tab_size: 4,
         insert_spaces: false,
         print_width: 80,
+        ..FormatOptions::default()
     };
     let result = format(source, &options);
     assert_eq!(result.trim(), "package Test { part x ; }");
 }
 
+#[test]
+fn test_format_nested_members_with_tabs() {
+    let source = "package A {\n  part def B {\n      part x ;\n  }\n}";
+    let options = FormatOptions {
+        use_tabs: true,
+        ..FormatOptions::default()
+    };
+    let result = format(source, &options);
+    assert_eq!(
+        result.trim(),
+        "package A {\n\tpart def B {\n\t\tpart x ;\n\t}\n}"
+    );
+}
+
+#[test]
+fn test_format_indent_width() {
+    let source = "package A {\n    part x ;\n}";
+    let options = FormatOptions {
+        indent_width: Some(2),
+        ..FormatOptions::default()
+    };
+    let result = format(source, &options);
+    assert_eq!(result.trim(), "package A {\n  part x ;\n}");
+}
+
+#[test]
+fn test_format_tab_size_used_when_indent_width_unset() {
+    let source = "package A {\n    part x ;\n}";
+    let options = FormatOptions {
+        tab_size: 2,
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        format(source, &options).trim(),
+        "package A {\n  part x ;\n}"
+    );
+
+    let options = FormatOptions {
+        insert_spaces: false,
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        format(source, &options).trim(),
+        "package A {\n\tpart x ;\n}"
+    );
+}
+
+#[test]
+fn test_format_indent_width_overrides_tab_size() {
+    let source = "package A {\n  part x ;\n}";
+    let options = FormatOptions {
+        indent_width: Some(4),
+        tab_size: 2,
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        format(source, &options).trim(),
+        "package A {\n    part x ;\n}"
+    );
+}
+
+#[test]
+fn test_format_align_colons() {
+    let source = "part def Car {\n    attribute mass : Real ;\n    part e : Engine ;\n    attribute maxSpeed : Real ;\n}";
+    let options = FormatOptions {
+        align_colons: true,
+        ..FormatOptions::default()
+    };
+    let result = format(source, &options);
+    assert_eq!(
+        result.trim(),
+        "part def Car {\n    attribute mass     : Real ;\n    part e             : Engine ;\n    attribute maxSpeed : Real ;\n}"
+    );
+    // Aligned output is stable
+    assert_eq!(format(&result, &options), result);
+}
+
+#[test]
+fn test_format_align_colons_single_member_untouched() {
+    let source = "part def Car {\n    attribute mass : Real ;\n    part wheels [ 4 ] ;\n}";
+    let options = FormatOptions {
+        align_colons: true,
+        ..FormatOptions::default()
+    };
+    let result = format(source, &options);
+    assert_eq!(result.trim(), source);
+}
+
 #[test]
 fn test_format_multiline_uses_spaces() {
     let source = "package Test {\n    part x ;\n}";
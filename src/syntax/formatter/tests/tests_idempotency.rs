@@ -0,0 +1,145 @@
+//! Idempotency tests for the formatter: `format(format(x)) == format(x)`
+
+use super::super::{FormatOptions, format_async};
+use proptest::prelude::*;
+use tokio_util::sync::CancellationToken;
+
+/// Synchronous format helper for tests
+fn format(source: &str, options: &FormatOptions) -> String {
+    format_async(source, options, &CancellationToken::new()).unwrap_or_default()
+}
+
+/// Check that formatting already-formatted output changes nothing
+fn format_twice_equal(source: &str) -> bool {
+    format_twice_equal_with(source, &FormatOptions::default())
+}
+
+fn format_twice_equal_with(source: &str, options: &FormatOptions) -> bool {
+    let first = format(source, options);
+    format(&first, options) == first
+}
+
+// ============================================================================
+// Regression tests
+// ============================================================================
+
+#[test]
+fn test_idempotent_brace_after_line_comment() {
+    let source = "part def A // note\n{\n    part x ;\n}";
+    let result = format(source, &FormatOptions::default());
+    // The brace must not be pulled onto the comment line
+    assert_eq!(result, "part def A // note\n{\n    part x ;\n}");
+    assert!(format_twice_equal(source));
+}
+
+#[test]
+fn test_idempotent_crlf_line_comment() {
+    let source = "package A {\r\n    // note   \r\n    part x ;\r\n}";
+    let result = format(source, &FormatOptions::default());
+    assert_eq!(result, "package A {\n    // note\n    part x ;\n}");
+    assert!(format_twice_equal(source));
+}
+
+#[test]
+fn test_idempotent_leading_brace() {
+    assert!(format_twice_equal("{\npart x ;\n}"));
+}
+
+#[test]
+fn test_idempotent_blank_line_collapsing() {
+    assert!(format_twice_equal(
+        "package A {\n\n\n\n    part x ;\n   \n  \n    part y ;\n}\n\n\n"
+    ));
+}
+
+// ============================================================================
+// Property tests over generated small models
+// ============================================================================
+
+/// Whitespace that may separate tokens, including odd indentation and blank lines
+fn gap() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(" ".to_string()),
+        Just("   ".to_string()),
+        Just("\t".to_string()),
+        Just("\n".to_string()),
+        Just("\n\n\n".to_string()),
+        Just("  \n\t".to_string()),
+        Just("\r\n".to_string()),
+    ]
+}
+
+fn name() -> impl Strategy<Value = String> {
+    "[A-Za-z][A-Za-z0-9_]{0,8}"
+}
+
+/// An optional comment placed before a member
+fn comment() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just(String::new()),
+        Just("// note\n".to_string()),
+        Just("// trailing spaces   \n".to_string()),
+        Just("/* block */ ".to_string()),
+    ]
+}
+
+/// A leaf member such as `attribute mass : Real ;`
+fn member() -> impl Strategy<Value = String> {
+    (
+        comment(),
+        prop_oneof![Just("part"), Just("attribute"), Just("port"), Just("item")],
+        name(),
+        proptest::option::of(name()),
+        gap(),
+        gap(),
+    )
+        .prop_map(|(comment, keyword, name, ty, g1, g2)| match ty {
+            Some(ty) => format!("{comment}{keyword} {name}{g1}:{g2}{ty} ;"),
+            None => format!("{comment}{keyword} {name}{g1};"),
+        })
+}
+
+/// A small model: nested packages and definitions with leaf members
+fn model() -> impl Strategy<Value = String> {
+    let leaf = member();
+    leaf.prop_recursive(3, 24, 4, |inner| {
+        (
+            comment(),
+            prop_oneof![Just("package"), Just("part def"), Just("action def")],
+            name(),
+            gap(),
+            prop::collection::vec((gap(), inner), 0..4),
+            gap(),
+        )
+            .prop_map(|(comment, keyword, name, g1, members, g2)| {
+                let body: String = members.into_iter().map(|(g, m)| g + &m).collect();
+                format!("{comment}{keyword} {name}{g1}{{{body}{g2}}}")
+            })
+    })
+}
+
+proptest! {
+    #[test]
+    fn prop_format_is_idempotent(source in model()) {
+        prop_assert!(format_twice_equal(&source), "not idempotent:\n{source}");
+    }
+
+    #[test]
+    fn prop_format_is_idempotent_with_options(
+        source in model(),
+        use_tabs in any::<bool>(),
+        align_colons in any::<bool>(),
+        indent_width in 1usize..8,
+    ) {
+        let options = FormatOptions {
+            indent_width: Some(indent_width),
+            use_tabs,
+            align_colons,
+            ..FormatOptions::default()
+        };
+        prop_assert!(
+            format_twice_equal_with(&source, &options),
+            "not idempotent with {options:?}:\n{source}"
+        );
+    }
+}
@@ -5,7 +5,7 @@ pub mod normalized;
 pub mod parser;
 pub mod traits;
 
-pub use file::SyntaxFile;
+pub use file::{Language, SyntaxFile};
 pub use formatter::{FormatOptions, format_async};
 pub use normalized::{
     NormalizedAlias, NormalizedComment, NormalizedDefKind, NormalizedDefinition, NormalizedElement,
@@ -14,7 +14,10 @@ pub use normalized::{
 };
 // Legacy type aliases
 pub use normalized::{KerMLNormalizedIter, SysMLNormalizedIter};
-pub use parser::{ParseError, ParseResult, load_and_parse, parse_content, parse_with_result};
+pub use parser::{
+    ParseDiagnostic, ParseError, ParseResult, TopLevelElement, load_and_parse, parse_content,
+    parse_content_with_language, parse_elements, parse_with_recovery, parse_with_result,
+};
 pub use traits::{AstNode, Named, ToSource};
 
 // Re-export Position and Span from base for backwards compatibility
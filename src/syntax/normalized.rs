@@ -142,6 +142,18 @@ pub struct Multiplicity {
     pub upper: Option<u64>,
 }
 
+impl std::fmt::Display for Multiplicity {
+    /// Formats as in source: `[4]`, `[0..*]`, `[1..3]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bound = |b: Option<u64>| b.map_or_else(|| "*".to_string(), |n| n.to_string());
+        if self.lower.is_some() && self.lower == self.upper {
+            write!(f, "[{}]", bound(self.lower))
+        } else {
+            write!(f, "[{}..{}]", bound(self.lower), bound(self.upper))
+        }
+    }
+}
+
 /// A value expression assigned to a feature (e.g., `= 42`, `= "hello"`, `= true`).
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValueExpression {
@@ -325,6 +337,8 @@ pub enum NormalizedDefKind {
     Association,
     Metaclass,
     Interaction,
+    Predicate,
+    Classifier,
     // Fallback
     Other,
 }
@@ -359,6 +373,8 @@ pub enum NormalizedUsageKind {
     View,
     Viewpoint,
     Rendering,
+    // Metadata usage (`@Type { ... }`)
+    Metadata,
     // KerML: features are treated as usages
     Feature,
     // Fallback
@@ -389,6 +405,8 @@ pub enum NormalizedRelKind {
     Conjugates,
     FeatureChain,
     Expression,
+    /// Operand of a KerML type operator (`unions`, `intersects`, `differences`)
+    TypeOperand,
 
     // State/Transition relationships
     TransitionSource,
@@ -644,7 +662,7 @@ impl NormalizedElement {
                 NormalizedElement::Usage(NormalizedUsage {
                     name: None, // Metadata usages are anonymous
                     short_name: None,
-                    kind: NormalizedUsageKind::Attribute, // Use Attribute for metadata
+                    kind: NormalizedUsageKind::Metadata,
                     relationships,
                     range: Some(meta.syntax().text_range()),
                     name_range: None,
@@ -794,27 +812,28 @@ impl NormalizedDefinition {
             Some(RowanDefinitionKind::Flow) => NormalizedDefKind::Other, // Map flow def to Other
             Some(RowanDefinitionKind::Metadata) => NormalizedDefKind::Other,
             Some(RowanDefinitionKind::Occurrence) => NormalizedDefKind::Other,
-            // KerML mappings to SysML equivalents
-            Some(RowanDefinitionKind::Class) => NormalizedDefKind::Part, // class -> part def
-            Some(RowanDefinitionKind::Struct) => NormalizedDefKind::Part, // struct -> part def
-            Some(RowanDefinitionKind::Datatype) => NormalizedDefKind::Attribute, // datatype -> attribute def
-            Some(RowanDefinitionKind::Assoc) => NormalizedDefKind::Connection, // assoc -> connection def
-            Some(RowanDefinitionKind::Behavior) => NormalizedDefKind::Action, // behavior -> action def
-            Some(RowanDefinitionKind::Function) => NormalizedDefKind::Calculation, // function -> calc def
-            Some(RowanDefinitionKind::Predicate) => NormalizedDefKind::Constraint, // predicate -> constraint def
-            Some(RowanDefinitionKind::Interaction) => NormalizedDefKind::Action, // interaction -> action def
-            Some(RowanDefinitionKind::Classifier) => NormalizedDefKind::Part, // classifier -> part def
-            Some(RowanDefinitionKind::Type) => NormalizedDefKind::Other,      // type -> other
-            Some(RowanDefinitionKind::Metaclass) => NormalizedDefKind::Metaclass, // metaclass -> metaclass
+            // KerML kinds
+            Some(RowanDefinitionKind::Class) => NormalizedDefKind::Class,
+            Some(RowanDefinitionKind::Struct) => NormalizedDefKind::Structure,
+            Some(RowanDefinitionKind::Datatype) => NormalizedDefKind::DataType,
+            Some(RowanDefinitionKind::Assoc) => NormalizedDefKind::Association,
+            Some(RowanDefinitionKind::Behavior) => NormalizedDefKind::Behavior,
+            Some(RowanDefinitionKind::Function) => NormalizedDefKind::Function,
+            Some(RowanDefinitionKind::Predicate) => NormalizedDefKind::Predicate,
+            Some(RowanDefinitionKind::Interaction) => NormalizedDefKind::Interaction,
+            Some(RowanDefinitionKind::Classifier) => NormalizedDefKind::Classifier,
+            Some(RowanDefinitionKind::Type) => NormalizedDefKind::Other,
+            Some(RowanDefinitionKind::Metaclass) => NormalizedDefKind::Metaclass,
             None => NormalizedDefKind::Other,
         };
 
         // Extract relationships from specializations
+        // Comma-continuations inherit the kind of the keyword before them,
+        // starting from Specializes since `:> A, B, C` means A, B, C all specialize
+        let mut previous_kind = NormalizedRelKind::Specializes;
         let mut relationships: Vec<NormalizedRelationship> = def
             .specializations()
             .filter_map(|spec| {
-                // If kind is None but target exists, it's a comma-separated continuation
-                // Default to Specializes since `:> A, B, C` means A, B, C all specialize
                 let rel_kind = match spec.kind() {
                     Some(SpecializationKind::Specializes) => NormalizedRelKind::Specializes,
                     Some(SpecializationKind::Subsets) => NormalizedRelKind::Subsets,
@@ -822,8 +841,14 @@ impl NormalizedDefinition {
                     Some(SpecializationKind::References) => NormalizedRelKind::References,
                     Some(SpecializationKind::Conjugates) => NormalizedRelKind::Specializes,
                     Some(SpecializationKind::FeatureChain) => NormalizedRelKind::Specializes,
-                    None => NormalizedRelKind::Specializes, // Comma-continuation inherits Specializes
+                    Some(
+                        SpecializationKind::Unions
+                        | SpecializationKind::Intersects
+                        | SpecializationKind::Differences,
+                    ) => NormalizedRelKind::TypeOperand,
+                    None => previous_kind,
                 };
+                previous_kind = rel_kind;
                 let target_node = spec.target()?;
                 let target = target_node.to_string();
                 Some(NormalizedRelationship {
@@ -949,9 +974,9 @@ impl NormalizedUsage {
                 Some(RowanUsageKind::Occurrence) => NormalizedUsageKind::Occurrence,
                 Some(RowanUsageKind::Ref) => NormalizedUsageKind::Reference,
                 // KerML mappings
-                Some(RowanUsageKind::Feature) => NormalizedUsageKind::Attribute, // feature -> attribute
-                Some(RowanUsageKind::Step) => NormalizedUsageKind::Action,       // step -> action
-                Some(RowanUsageKind::Expr) => NormalizedUsageKind::Calculation,  // expr -> calc
+                Some(RowanUsageKind::Feature) => NormalizedUsageKind::Feature,
+                Some(RowanUsageKind::Step) => NormalizedUsageKind::Action, // step -> action
+                Some(RowanUsageKind::Expr) => NormalizedUsageKind::Calculation, // expr -> calc
                 Some(RowanUsageKind::Connector) => NormalizedUsageKind::Connection, // connector -> connection
                 Some(RowanUsageKind::Case) => NormalizedUsageKind::Other,
                 None => NormalizedUsageKind::Part, // Default to Part for usages without keyword
@@ -998,9 +1023,10 @@ impl NormalizedUsage {
         }
 
         // Extract specializations
+        // Comma-continuations inherit the kind of the keyword before them,
+        // starting from Subsets since `:> A, B, C` in usages means subsetting
+        let mut previous_kind = NormalizedRelKind::Subsets;
         for spec in usage.specializations() {
-            // If kind is None but target exists, it's a comma-separated continuation
-            // Default to Subsets since `:> A, B, C` in usages means subsetting
             let rel_kind = match spec.kind() {
                 Some(SpecializationKind::Specializes) => NormalizedRelKind::Specializes,
                 Some(SpecializationKind::Subsets) => NormalizedRelKind::Subsets,
@@ -1008,8 +1034,14 @@ impl NormalizedUsage {
                 Some(SpecializationKind::References) => NormalizedRelKind::References,
                 Some(SpecializationKind::Conjugates) => NormalizedRelKind::Specializes,
                 Some(SpecializationKind::FeatureChain) => NormalizedRelKind::FeatureChain,
-                None => NormalizedRelKind::Subsets, // Comma-continuation inherits Subsets for usages
+                Some(
+                    SpecializationKind::Unions
+                    | SpecializationKind::Intersects
+                    | SpecializationKind::Differences,
+                ) => NormalizedRelKind::TypeOperand,
+                None => previous_kind,
             };
+            previous_kind = rel_kind;
             if let Some(target) = spec.target() {
                 let target_str = target.to_string();
                 let target_range = target.syntax().text_range();
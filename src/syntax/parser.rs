@@ -3,8 +3,10 @@
 //! This module provides a unified interface for parsing SysML and KerML files
 //! using the rowan-based parser.
 
+use crate::base::Span;
 use crate::base::constants::{KERML_EXT, SYSML_EXT};
-use crate::syntax::file::{FileExtension, SyntaxFile};
+use crate::parser::{Lexer, NamespaceMember, SyntaxKind};
+use crate::syntax::file::{FileExtension, Language, SyntaxFile, detect_language};
 use std::path::{Path, PathBuf};
 
 /// Parse error type for syntax-level errors
@@ -43,6 +45,26 @@ impl std::fmt::Display for ParseError {
 
 impl std::error::Error for ParseError {}
 
+/// A syntax diagnostic covering the full span of the offending tokens.
+///
+/// Unlike [`ParseError`], which only records where an error starts, this keeps
+/// the end position too so editors can underline the whole error span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    pub message: String,
+    pub span: Span,
+}
+
+impl std::fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}",
+            self.span.start.line, self.span.start.column, self.message
+        )
+    }
+}
+
 /// Parse result containing content and any errors
 #[derive(Debug)]
 pub struct ParseResult<T> {
@@ -111,7 +133,10 @@ pub fn load_file(path: &PathBuf) -> Result<String, String> {
     std::fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))
 }
 
-/// Loads and parses a language file (SysML or KerML) based on file extension.
+/// Loads and parses a language file (SysML or KerML).
+///
+/// The grammar is chosen as for [`Language::Auto`], so KerML content in a
+/// `.sysml` file is still parsed as KerML.
 ///
 /// # Errors
 ///
@@ -119,16 +144,10 @@ pub fn load_file(path: &PathBuf) -> Result<String, String> {
 /// - The file cannot be read
 /// - The file has an invalid extension
 pub fn load_and_parse(path: &PathBuf) -> Result<SyntaxFile, String> {
-    let ext = validate_extension(path)?;
+    validate_extension(path)?;
     let content = load_file(path)?;
 
-    let extension = match ext {
-        SYSML_EXT => FileExtension::SysML,
-        KERML_EXT => FileExtension::KerML,
-        _ => return Err(format!("Unsupported extension: {}", ext)),
-    };
-
-    Ok(SyntaxFile::new(&content, extension))
+    Ok(parse_content_with_language(&content, path, Language::Auto))
 }
 
 /// Parses language content from a string based on file extension.
@@ -144,27 +163,45 @@ pub fn parse_content(content: &str, path: &Path) -> Result<SyntaxFile, String> {
     Ok(SyntaxFile::new(content, extension))
 }
 
+/// Parses language content from a string using an explicit language hint.
+///
+/// With [`Language::Auto`] the grammar is chosen from the content, falling
+/// back to the extension of `path` and then to SysML. The path may have any
+/// extension, or none.
+pub fn parse_content_with_language(content: &str, path: &Path, language: Language) -> SyntaxFile {
+    let extension = match language {
+        Language::SysML => FileExtension::SysML,
+        Language::KerML => FileExtension::KerML,
+        Language::Auto => detect_language(content)
+            .or(match path.extension().and_then(|e| e.to_str()) {
+                Some(KERML_EXT) => Some(FileExtension::KerML),
+                Some(SYSML_EXT) => Some(FileExtension::SysML),
+                _ => None,
+            })
+            .unwrap_or(FileExtension::SysML),
+    };
+    SyntaxFile::new(content, extension)
+}
+
 /// Parses content and returns a ParseResult with detailed error information.
 /// This is the primary function for LSP usage - errors don't fail, they're captured.
+///
+/// The grammar is chosen as for [`Language::Auto`].
 pub fn parse_with_result(content: &str, path: &Path) -> ParseResult<SyntaxFile> {
     let ext = match get_extension(path) {
         Ok(e) => e,
         Err(e) => return ParseResult::with_errors(vec![e]),
     };
 
-    let extension = match ext {
-        SYSML_EXT => FileExtension::SysML,
-        KERML_EXT => FileExtension::KerML,
-        _ => {
-            return ParseResult::with_errors(vec![ParseError::syntax_error(
-                "Unsupported file extension",
-                0,
-                0,
-            )]);
-        }
-    };
+    if ext != SYSML_EXT && ext != KERML_EXT {
+        return ParseResult::with_errors(vec![ParseError::syntax_error(
+            "Unsupported file extension",
+            0,
+            0,
+        )]);
+    }
 
-    let syntax_file = SyntaxFile::new(content, extension);
+    let syntax_file = parse_content_with_language(content, path, Language::Auto);
 
     // Convert rowan syntax errors to our ParseError type with line/column info
     let line_index = crate::base::LineIndex::new(content);
@@ -184,3 +221,170 @@ pub fn parse_with_result(content: &str, path: &Path) -> ParseResult<SyntaxFile>
         ParseResult::with_content_and_errors(syntax_file, errors)
     }
 }
+
+/// Parses content, keeping a best-effort tree even when there are syntax errors.
+///
+/// The parser resynchronizes at statement (`;`) and brace boundaries, so
+/// declarations around a malformed member still appear in the tree and can be
+/// fed to symbol extraction. The grammar is chosen as for [`Language::Auto`].
+/// The tree is `None` only when the path has an unsupported extension.
+pub fn parse_with_recovery(
+    content: &str,
+    path: &Path,
+) -> (Option<SyntaxFile>, Vec<ParseDiagnostic>) {
+    if let Err(message) = validate_extension(path) {
+        let span = Span::from_coords(0, 0, 0, 0);
+        return (None, vec![ParseDiagnostic { message, span }]);
+    }
+
+    let syntax_file = parse_content_with_language(content, path, Language::Auto);
+
+    let line_index = crate::base::LineIndex::new(content);
+    let diagnostics = syntax_file
+        .errors()
+        .iter()
+        .map(|e| {
+            let start = line_index.line_col(e.range.start());
+            let end = line_index.line_col(e.range.end());
+            ParseDiagnostic {
+                message: e.message.clone(),
+                span: Span::from_coords(
+                    start.line as usize,
+                    start.col as usize,
+                    end.line as usize,
+                    end.col as usize,
+                ),
+            }
+        })
+        .collect();
+
+    (Some(syntax_file), diagnostics)
+}
+
+/// A top-level element of a file, parsed on its own by [`parse_elements`].
+///
+/// The syntax tree covers only the element's text, including the whitespace
+/// and comments before it, so its offsets are relative to `offset`.
+#[derive(Debug, Clone)]
+pub struct TopLevelElement {
+    /// Byte offset of the element's text in the source.
+    pub offset: usize,
+    /// Line where the element's text starts (0-indexed).
+    pub line: usize,
+    /// The element's text parsed as a file of its own.
+    pub syntax: SyntaxFile,
+}
+
+impl TopLevelElement {
+    /// Get the parsed element.
+    pub fn member(&self) -> Option<NamespaceMember> {
+        self.syntax.source_file()?.members().next()
+    }
+}
+
+/// Parses the top-level elements of a file one at a time.
+///
+/// The source is split at the `;` or closing brace that ends each top-level
+/// element, and each element is parsed only when the iterator reaches it, so
+/// callers can process a large file without holding its whole tree. An
+/// element with syntax errors is yielded as the first error, positioned in
+/// the whole source. A path with an unsupported extension yields one error.
+pub fn parse_elements<'a>(
+    source: &'a str,
+    path: &Path,
+) -> impl Iterator<Item = Result<TopLevelElement, ParseError>> + 'a {
+    let (extension, error) = match validate_extension(path) {
+        Ok(SYSML_EXT) => (Some(FileExtension::SysML), None),
+        Ok(_) => (Some(FileExtension::KerML), None),
+        Err(message) => (None, Some(ParseError::syntax_error(&message, 0, 0))),
+    };
+
+    let elements = extension.into_iter().flat_map(move |extension| {
+        let mut lexer = Lexer::new(source);
+        let mut start = 0;
+        let mut line = 0;
+        std::iter::from_fn(move || {
+            let end = top_level_element_end(&mut lexer)?;
+            let text = &source[start..end];
+            let element = parse_element(text, start, line, source, extension);
+            line += text.matches('\n').count();
+            start = end;
+            Some(element)
+        })
+    });
+    error.map(Err).into_iter().chain(elements)
+}
+
+/// Advance past the next top-level element and return the offset just after it.
+///
+/// Returns `None` if only trivia is left. A declaration ends at a `;` or at
+/// the brace closing its body; `doc`, `comment` and `rep` elements end at
+/// their block comment.
+fn top_level_element_end(lexer: &mut Lexer<'_>) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut first_kind = None;
+    let mut end = None;
+
+    for token in lexer.by_ref() {
+        let token_end = usize::from(token.offset) + token.text.len();
+        end = Some(token_end);
+        let kind = token.kind;
+        if first_kind.is_none() {
+            if kind.is_trivia() {
+                continue;
+            }
+            first_kind = Some(kind);
+        }
+
+        match kind {
+            SyntaxKind::L_BRACE => depth += 1,
+            SyntaxKind::R_BRACE => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(token_end);
+                }
+            }
+            SyntaxKind::SEMICOLON if depth == 0 => return Some(token_end),
+            SyntaxKind::BLOCK_COMMENT
+                if depth == 0
+                    && matches!(
+                        first_kind,
+                        Some(SyntaxKind::DOC_KW | SyntaxKind::COMMENT_KW | SyntaxKind::REP_KW)
+                    ) =>
+            {
+                return Some(token_end);
+            }
+            _ => {}
+        }
+    }
+
+    // An unterminated element runs to the end of the source
+    first_kind.and(end)
+}
+
+/// Parse one element's text, reporting its first error in source coordinates.
+fn parse_element(
+    text: &str,
+    offset: usize,
+    line: usize,
+    source: &str,
+    extension: FileExtension,
+) -> Result<TopLevelElement, ParseError> {
+    let syntax = SyntaxFile::new(text, extension);
+    if let Some(error) = syntax.errors().first() {
+        let line_col = crate::base::LineIndex::new(text).line_col(error.range.start());
+        let error_line = line + line_col.line as usize;
+        let mut column = line_col.col as usize;
+        if line_col.line == 0 {
+            // The element may start partway through its first line
+            let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+            column += offset - line_start;
+        }
+        return Err(ParseError::syntax_error(&error.message, error_line, column));
+    }
+    Ok(TopLevelElement {
+        offset,
+        line,
+        syntax,
+    })
+}
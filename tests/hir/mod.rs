@@ -11,6 +11,7 @@
 pub mod tests_chain_member_hover;
 pub mod tests_diagnostics;
 pub mod tests_edge_cases;
+pub mod tests_export;
 pub mod tests_hover_connections;
 pub mod tests_hover_constraint_def;
 pub mod tests_hover_control_flow;
@@ -3,7 +3,12 @@
 //! These tests verify that semantic errors are correctly detected and reported.
 
 use crate::helpers::hir_helpers::*;
-use syster::hir::{Diagnostic, Severity, check_file};
+use crate::helpers::symbol_assertions::*;
+use syster::hir::{
+    Diagnostic, DiagnosticConfig, NamingConventions, NamingStyle, SemanticChecker, Severity,
+    check_file, check_file_with_config, check_naming_conventions, check_shadowed_imports,
+    check_unused_imports,
+};
 
 // =============================================================================
 // HELPERS
@@ -170,6 +175,49 @@ fn test_diagnostic_has_position_info() {
     }
 }
 
+#[test]
+fn test_undefined_type_diagnostic_spans_reference() {
+    let source = "package Test {\n    part car : NonExistent;\n}";
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let car = get_symbol(analysis.symbol_index(), "Test::car");
+    let type_ref = car
+        .type_refs
+        .iter()
+        .flat_map(|trk| trk.as_refs())
+        .find(|tr| tr.target.as_ref() == "NonExistent")
+        .expect("type ref to NonExistent");
+
+    let diagnostics = check_file(analysis.symbol_index(), file_id);
+    let error = diagnostics
+        .iter()
+        .find(|d| d.message.contains("NonExistent"))
+        .expect("undefined reference error");
+
+    // The range is the reference's, not the symbol's
+    let range = (
+        error.start_line,
+        error.start_col,
+        error.end_line,
+        error.end_col,
+    );
+    assert_eq!(
+        range,
+        (
+            type_ref.start_line,
+            type_ref.start_col,
+            type_ref.end_line,
+            type_ref.end_col
+        )
+    );
+    assert_eq!(range, (1, 15, 1, 26));
+    assert_ne!(
+        (error.start_line, error.start_col),
+        (car.start_line, car.start_col)
+    );
+}
+
 #[test]
 fn test_chain_member_diagnostic_span_points_to_undefined_member() {
     // Test that when a chain member is undefined, the diagnostic span
@@ -233,6 +281,62 @@ fn test_chain_member_diagnostic_span_points_to_undefined_member() {
     );
 }
 
+#[test]
+fn test_undefined_chain_member_names_owning_type() {
+    let source = r#"
+        package Test {
+            part def Engine { attribute power; }
+            part def Vehicle { part engine : Engine; }
+            part car : Vehicle {
+                attribute p = engine.nonexistent;
+            }
+        }
+    "#;
+
+    let diagnostics = get_errors_for_source(source);
+    assert_eq!(
+        diagnostics.len(),
+        1,
+        "Should have exactly one error. Got: {:?}",
+        diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>()
+    );
+
+    // `engine` resolves, so only `nonexistent` is reported, with its own span
+    let diag = &diagnostics[0];
+    assert_eq!(diag.code.as_deref(), Some("E0001"));
+    assert!(
+        diag.message.contains("'nonexistent'") && diag.message.contains("'Engine'"),
+        "Diagnostic should name the member and its type. Got: {}",
+        diag.message
+    );
+    let line = source.lines().nth(diag.start_line as usize).unwrap();
+    assert_eq!(
+        &line[diag.start_col as usize..diag.end_col as usize],
+        "nonexistent"
+    );
+}
+
+#[test]
+fn test_chain_reports_only_first_undefined_member() {
+    let source = r#"
+        package Test {
+            part def Engine { attribute power; }
+            part engine : Engine;
+            attribute p = engine.missing.deeper;
+        }
+    "#;
+
+    // `deeper` can't be checked once `missing` fails to resolve
+    let diagnostics = get_errors_for_source(source);
+    assert_eq!(
+        diagnostics.len(),
+        1,
+        "Should have exactly one error. Got: {:?}",
+        diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>()
+    );
+    assert!(diagnostics[0].message.contains("'missing'"));
+}
+
 // =============================================================================
 // SEVERITY LEVEL TESTS
 // =============================================================================
@@ -362,6 +466,293 @@ fn test_cross_file_undefined_detected() {
     );
 }
 
+// =============================================================================
+// DUPLICATE DEFINITIONS
+// =============================================================================
+
+fn duplicate_errors(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics
+        .iter()
+        .filter(|d| d.code.as_deref() == Some("E0004"))
+        .collect()
+}
+
+#[test]
+fn test_duplicate_definition_detected() {
+    let source = r#"
+package Test {
+    part def Engine;
+    part def Engine;
+}
+"#;
+
+    let diagnostics = get_diagnostics_for_source(source);
+    let duplicates = duplicate_errors(&diagnostics);
+
+    assert_eq!(duplicates.len(), 1, "Got: {:?}", duplicates);
+    // Reported on the later definition, pointing back at the earlier one
+    assert_eq!(duplicates[0].start_line, 3);
+    assert_eq!(duplicates[0].related.len(), 1);
+    assert_eq!(duplicates[0].related[0].line, 2);
+}
+
+#[test]
+fn test_definition_and_usage_same_name_not_duplicate() {
+    let source = r#"
+package Test {
+    part def engine;
+    part engine : engine;
+}
+"#;
+
+    let diagnostics = get_diagnostics_for_source(source);
+    let duplicates = duplicate_errors(&diagnostics);
+
+    assert!(duplicates.is_empty(), "Got: {:?}", duplicates);
+}
+
+#[test]
+fn test_same_name_in_different_scopes_not_duplicate() {
+    let mut host = analysis_from_sources(&[
+        ("a.sysml", "package A { part def Engine; }"),
+        ("b.sysml", "package B { part def Engine; }"),
+    ]);
+    let analysis = host.analysis();
+
+    for path in ["a.sysml", "b.sysml"] {
+        let file = analysis.get_file_id(path).unwrap();
+        let diagnostics = check_file(analysis.symbol_index(), file);
+        let duplicates = duplicate_errors(&diagnostics);
+        assert!(duplicates.is_empty(), "{}: {:?}", path, duplicates);
+    }
+}
+
+#[test]
+fn test_duplicate_definition_across_files_reported_once() {
+    let mut host = analysis_from_sources(&[
+        ("a.sysml", "part def Engine;"),
+        ("b.sysml", "part def Engine;"),
+    ]);
+    let analysis = host.analysis();
+
+    let duplicates: usize = ["a.sysml", "b.sysml"]
+        .iter()
+        .map(|path| {
+            let file = analysis.get_file_id(path).unwrap();
+            duplicate_errors(&check_file(analysis.symbol_index(), file)).len()
+        })
+        .sum();
+
+    assert_eq!(duplicates, 1);
+}
+
+#[test]
+fn test_check_all_reports_duplicate_definitions() {
+    let mut host = analysis_from_sources(&[
+        ("a.sysml", "package A { part def Engine; part def Engine; }"),
+        ("b.sysml", "package B { part def Engine; }"),
+    ]);
+    let analysis = host.analysis();
+    let file = analysis.get_file_id("a.sysml").unwrap();
+
+    let mut checker = SemanticChecker::new(analysis.symbol_index());
+    checker.check_all();
+    let workspace = checker.finish();
+
+    let duplicates = duplicate_errors(&workspace);
+    assert_eq!(duplicates.len(), 1, "Got: {:?}", workspace);
+    assert_eq!(duplicates[0].file, file);
+}
+
+// =============================================================================
+// CIRCULAR SUPERTYPES
+// =============================================================================
+
+fn circular_errors(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+    diagnostics
+        .iter()
+        .filter(|d| d.code.as_deref() == Some("E0007"))
+        .collect()
+}
+
+#[test]
+fn test_circular_supertypes_reported_once() {
+    let source = r#"
+        package Test {
+            part def A :> B;
+            part def B :> A;
+        }
+    "#;
+
+    let diagnostics = get_diagnostics_for_source(source);
+    let cycles = circular_errors(&diagnostics);
+
+    assert_eq!(cycles.len(), 1, "Got: {:?}", cycles);
+    assert_eq!(cycles[0].severity, Severity::Error);
+    assert!(
+        cycles[0].message.contains("Test::A :> Test::B :> Test::A"),
+        "Cycle path missing from message: {}",
+        cycles[0].message
+    );
+}
+
+#[test]
+fn test_circular_supertypes_through_import() {
+    let source = r#"
+        package Test {
+            package Inner {
+                import Test::*;
+                part def A :> B;
+            }
+            import Inner::*;
+            part def B :> C;
+            part def C :> A;
+        }
+    "#;
+
+    let diagnostics = get_diagnostics_for_source(source);
+    let cycles = circular_errors(&diagnostics);
+
+    assert_eq!(cycles.len(), 1, "Got: {:?}", cycles);
+}
+
+#[test]
+fn test_acyclic_supertypes_no_circular_error() {
+    let source = r#"
+        package Test {
+            part def A;
+            part def B :> A;
+            part def C :> A, B;
+        }
+    "#;
+
+    let diagnostics = get_diagnostics_for_source(source);
+
+    assert!(circular_errors(&diagnostics).is_empty());
+}
+
+#[test]
+fn test_type_operands_and_implicit_supertypes_not_circular() {
+    // `unions` relates a type to its operands without specializing them, and
+    // the implicit `Parts::Part` supertype is not followed
+    let source = r#"
+        package Parts {
+            part def Part :> Test::Thing;
+        }
+        package Test {
+            part def Thing;
+            part def Without unions Before, Outside;
+            part def Before :> Without;
+            part def Outside :> Without;
+        }
+    "#;
+
+    let diagnostics = get_diagnostics_for_source(source);
+
+    assert!(
+        circular_errors(&diagnostics).is_empty(),
+        "Got: {:?}",
+        diagnostics
+    );
+}
+
+// =============================================================================
+// UNUSED IMPORTS
+// =============================================================================
+
+fn unused_import_warnings(source: &str) -> Vec<Diagnostic> {
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    check_unused_imports(analysis.symbol_index(), file_id)
+}
+
+#[test]
+fn test_used_imports_no_warning() {
+    let source = r#"
+        package Lib {
+            part def Engine;
+            part def Wheel;
+            package Sub {
+                part def Bolt;
+            }
+        }
+        package Car {
+            import Lib::Engine;
+            import Lib::*;
+            part engine : Engine;
+            part wheel : Wheel;
+            part bolt : Sub::Bolt;
+        }
+    "#;
+
+    let warnings = unused_import_warnings(source);
+
+    assert!(warnings.is_empty(), "Got: {:?}", warnings);
+}
+
+#[test]
+fn test_unused_specific_import_warning() {
+    let source = r#"
+        package Lib {
+            part def Engine;
+            part def Wheel;
+        }
+        package Car {
+            import Lib::Engine;
+            import Lib::Wheel;
+            part engine : Engine;
+        }
+    "#;
+
+    let warnings = unused_import_warnings(source);
+
+    assert_eq!(warnings.len(), 1, "Got: {:?}", warnings);
+    assert_eq!(warnings[0].severity, Severity::Warning);
+    assert_eq!(warnings[0].code.as_deref(), Some("W0004"));
+    assert!(warnings[0].message.contains("Lib::Wheel"));
+    assert_eq!(warnings[0].start_line, 7);
+}
+
+#[test]
+fn test_unused_wildcard_import_warning() {
+    let source = r#"
+        package Lib {
+            part def Engine;
+        }
+        package Other {
+            part def Seat;
+        }
+        package Car {
+            import Lib::*;
+            import Other::*;
+            part seat : Seat;
+        }
+    "#;
+
+    let warnings = unused_import_warnings(source);
+
+    assert_eq!(warnings.len(), 1, "Got: {:?}", warnings);
+    assert!(warnings[0].message.contains("Lib::*"));
+}
+
+#[test]
+fn test_import_shadowed_by_local_definition_is_unused() {
+    let source = r#"
+        package Lib {
+            part def Engine;
+        }
+        package Car {
+            import Lib::Engine;
+            part def Engine;
+            part engine : Engine;
+        }
+    "#;
+
+    let warnings = unused_import_warnings(source);
+
+    assert_eq!(warnings.len(), 1, "Got: {:?}", warnings);
+}
+
 // =============================================================================
 // IMPORT DIAGNOSTICS
 // =============================================================================
@@ -380,6 +771,57 @@ fn test_import_nonexistent_package_no_crash() {
     let _analysis = host.analysis();
 }
 
+#[test]
+fn test_unresolved_wildcard_import_is_error() {
+    let source = "package Consumer {\n    import NoSuchPkg::*;\n}\n";
+    let errors = get_errors_for_source(source);
+
+    assert_eq!(errors.len(), 1, "Expected one error, got {errors:?}");
+    let error = &errors[0];
+    assert_eq!(error.code.as_deref(), Some("E0001"));
+    assert!(error.message.contains("NoSuchPkg"), "{}", error.message);
+    // The span covers the import path
+    assert_eq!((error.start_line, error.start_col), (1, 11));
+    assert_eq!(error.end_line, 1);
+    assert!(error.end_col >= 20, "span should reach the end of the path");
+}
+
+#[test]
+fn test_unresolved_specific_import_is_error() {
+    let source = r#"
+        package Lib {
+            part def Engine;
+        }
+        package Consumer {
+            import Lib::Wheel;
+        }
+    "#;
+    assert!(has_error_containing(
+        &get_errors_for_source(source),
+        "Lib::Wheel"
+    ));
+}
+
+#[test]
+fn test_resolvable_imports_no_error() {
+    let source = r#"
+        package Lib {
+            package Sub {
+                part def Nested;
+            }
+            part def Engine;
+        }
+        package Consumer {
+            import Lib::*;
+            import Lib::**;
+            import Lib::Engine;
+            import Sub::Nested;
+        }
+    "#;
+    let errors = get_errors_for_source(source);
+    assert!(errors.is_empty(), "Unexpected errors: {errors:?}");
+}
+
 // =============================================================================
 // EDGE CASES
 // =============================================================================
@@ -464,3 +906,606 @@ fn test_qualified_reference_no_false_positive() {
             .collect::<Vec<_>>()
     );
 }
+
+// =============================================================================
+// DIAGNOSTIC CONFIG
+// =============================================================================
+
+const UNUSED_IMPORT_SOURCE: &str = r#"
+    package Lib {
+        part def Engine;
+        part def Wheel;
+    }
+    package Car {
+        import Lib::Engine;
+        import Lib::Wheel;
+        part engine : Engine;
+    }
+"#;
+
+fn unused_import_diagnostics_with(config: DiagnosticConfig) -> Vec<Diagnostic> {
+    let (mut host, file_id) = analysis_from_sysml(UNUSED_IMPORT_SOURCE);
+    let analysis = host.analysis();
+    let mut checker = SemanticChecker::new(analysis.symbol_index()).with_config(config);
+    checker.check_unused_imports(file_id);
+    checker.finish()
+}
+
+#[test]
+fn test_config_raises_warning_to_error() {
+    let config = DiagnosticConfig::new().with_severity("W0004", Severity::Error);
+
+    let diagnostics = unused_import_diagnostics_with(config);
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("W0004"));
+}
+
+#[test]
+fn test_config_suppresses_code() {
+    let config = DiagnosticConfig::new().with_disabled("W0004");
+
+    let diagnostics = unused_import_diagnostics_with(config);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_config_severity_enables_opt_in_checks_in_check_file() {
+    let (mut host, file_id) = analysis_from_sysml(UNUSED_IMPORT_SOURCE);
+    let analysis = host.analysis();
+    let has_unused_import = |diagnostics: &[Diagnostic]| {
+        diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("W0004"))
+    };
+
+    assert!(!has_unused_import(&check_file(
+        analysis.symbol_index(),
+        file_id
+    )));
+
+    let config = DiagnosticConfig::new().with_severity("W0004", Severity::Warning);
+    let diagnostics = check_file_with_config(analysis.symbol_index(), file_id, &config);
+    assert!(has_unused_import(&diagnostics), "Got: {:?}", diagnostics);
+
+    let config = config.with_disabled("W0004");
+    let diagnostics = check_file_with_config(analysis.symbol_index(), file_id, &config);
+    assert!(!has_unused_import(&diagnostics), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_config_sets_naming_conventions_for_check_file() {
+    let (mut host, file_id) = analysis_from_sysml("part def my_vehicle;");
+    let analysis = host.analysis();
+    let naming_warnings = |config: &DiagnosticConfig| {
+        check_file_with_config(analysis.symbol_index(), file_id, config)
+            .into_iter()
+            .filter(|d| d.code.as_deref() == Some("W0003"))
+            .count()
+    };
+
+    let config = DiagnosticConfig::new().with_severity("W0003", Severity::Warning);
+    assert_eq!(naming_warnings(&config), 1);
+
+    let config = config.with_naming_conventions(NamingConventions {
+        definitions: Some(NamingStyle::SnakeCase),
+        usages: None,
+    });
+    assert_eq!(naming_warnings(&config), 0);
+}
+
+#[test]
+fn test_config_leaves_other_codes_at_default_severity() {
+    let source = r#"
+        package Test {
+            part car : NonExistentType;
+        }
+    "#;
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let config = DiagnosticConfig::new()
+        .with_severity("W0003", Severity::Error)
+        .with_disabled("W9999");
+
+    let diagnostics = check_file_with_config(analysis.symbol_index(), file_id, &config);
+
+    assert_eq!(
+        diagnostics.len(),
+        check_file(analysis.symbol_index(), file_id).len()
+    );
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("E0001") && d.severity == Severity::Error),
+        "Got: {:?}",
+        diagnostics
+    );
+}
+
+// =============================================================================
+// NAMING CONVENTIONS
+// =============================================================================
+
+fn naming_diagnostics(source: &str, naming: Option<NamingConventions>) -> Vec<Diagnostic> {
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    match naming {
+        Some(naming) => {
+            let mut checker =
+                SemanticChecker::new(analysis.symbol_index()).with_naming_conventions(naming);
+            checker.check_naming_conventions(file_id);
+            checker.finish()
+        }
+        None => check_naming_conventions(analysis.symbol_index(), file_id),
+    }
+}
+
+#[test]
+fn test_naming_convention_flags_lower_case_definition() {
+    let source = r#"
+        package Vehicles {
+            part def engine;
+        }
+    "#;
+
+    let diagnostics = naming_diagnostics(source, None);
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.code.as_deref(), Some("W0003"));
+    assert_eq!(diagnostic.severity, Severity::Warning);
+    assert!(
+        diagnostic.message.contains("'engine'"),
+        "Got: {:?}",
+        diagnostic
+    );
+    assert!(
+        diagnostic.message.contains("UpperCamelCase"),
+        "Got: {:?}",
+        diagnostic
+    );
+    assert_eq!((diagnostic.start_line, diagnostic.start_col), (2, 21));
+}
+
+#[test]
+fn test_naming_convention_accepts_conventional_names() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def Vehicle {
+                part mainEngine : Engine;
+                attribute mass;
+            }
+        }
+    "#;
+
+    let diagnostics = naming_diagnostics(source, None);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_naming_convention_flags_upper_case_usage() {
+    let source = r#"
+        package Vehicles {
+            part def Vehicle {
+                part MainEngine;
+            }
+        }
+    "#;
+
+    let diagnostics = naming_diagnostics(source, None);
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert!(diagnostics[0].message.contains("'MainEngine'"));
+    assert!(diagnostics[0].message.contains("lowerCamelCase"));
+}
+
+#[test]
+fn test_naming_convention_skips_unrestricted_names() {
+    let source = r#"
+        package Vehicles {
+            part def 'vehicle frame';
+        }
+    "#;
+
+    let diagnostics = naming_diagnostics(source, None);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_naming_convention_snake_case_usages() {
+    let source = r#"
+        package Vehicles {
+            part def Vehicle {
+                part main_engine;
+                part spareWheel;
+            }
+        }
+    "#;
+    let naming = NamingConventions {
+        usages: Some(NamingStyle::SnakeCase),
+        ..NamingConventions::default()
+    };
+
+    let diagnostics = naming_diagnostics(source, Some(naming));
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert!(diagnostics[0].message.contains("'spareWheel'"));
+    assert!(diagnostics[0].message.contains("snake_case"));
+}
+
+#[test]
+fn test_naming_convention_disabled() {
+    let source = r#"
+        package vehicles {
+            part def engine;
+        }
+    "#;
+
+    let diagnostics = naming_diagnostics(source, Some(NamingConventions::disabled()));
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+// =============================================================================
+// SHADOWED IMPORTS
+// =============================================================================
+
+fn shadowed_import_diagnostics(source: &str) -> Vec<Diagnostic> {
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    check_shadowed_imports(analysis.symbol_index(), file_id)
+}
+
+#[test]
+fn test_shadowed_import_hint_on_wildcard_import() {
+    let source = r#"
+        package ScalarValues {
+            attribute def Real;
+            attribute def Integer;
+        }
+        package Model {
+            import ScalarValues::*;
+            part def Real;
+        }
+    "#;
+
+    let diagnostics = shadowed_import_diagnostics(source);
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.code.as_deref(), Some("W0005"));
+    assert_eq!(diagnostic.severity, Severity::Hint);
+    assert!(
+        diagnostic.message.contains("'Real'"),
+        "Got: {:?}",
+        diagnostic
+    );
+    assert!(
+        diagnostic.message.contains("'Model::Real'"),
+        "Got: {:?}",
+        diagnostic
+    );
+    // Points at the import statement
+    assert_eq!(diagnostic.start_line, 6);
+}
+
+#[test]
+fn test_shadowed_import_hint_on_membership_import() {
+    let source = r#"
+        package ScalarValues {
+            attribute def Real;
+        }
+        package Model {
+            import ScalarValues::Real;
+            part def Real;
+        }
+    "#;
+
+    let diagnostics = shadowed_import_diagnostics(source);
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert_eq!(diagnostics[0].start_line, 5);
+}
+
+#[test]
+fn test_shadowed_import_hint_on_each_shadowing_import() {
+    let source = r#"
+        package ScalarValues {
+            attribute def Real;
+        }
+        package Reals {
+            attribute def Real;
+        }
+        package Model {
+            import ScalarValues::*;
+            import Reals::Real;
+            part def Real;
+        }
+    "#;
+
+    let diagnostics = shadowed_import_diagnostics(source);
+
+    let lines: Vec<u32> = diagnostics.iter().map(|d| d.start_line).collect();
+    assert_eq!(lines, vec![8, 9], "Got: {:?}", diagnostics);
+    assert!(
+        diagnostics[0].message.contains("'Real'") && diagnostics[1].message.contains("'Real'"),
+        "Got: {:?}",
+        diagnostics
+    );
+}
+
+#[test]
+fn test_no_shadowed_import_hint_without_conflict() {
+    let source = r#"
+        package ScalarValues {
+            attribute def Real;
+        }
+        package Model {
+            import ScalarValues::*;
+            part def Vehicle;
+        }
+    "#;
+
+    let diagnostics = shadowed_import_diagnostics(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+// =============================================================================
+// FEATURE TYPE CONFORMANCE
+// =============================================================================
+
+fn type_mismatches(source: &str) -> Vec<Diagnostic> {
+    get_diagnostics_for_source(source)
+        .into_iter()
+        .filter(|d| d.code.as_deref() == Some("E0003"))
+        .collect()
+}
+
+#[test]
+fn test_conforming_redefinition_has_no_type_mismatch() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def SportEngine :> Engine;
+            part def TurboEngine :> SportEngine;
+            part def Vehicle {
+                part engine : Engine;
+            }
+            part def SportsCar :> Vehicle {
+                part fastEngine : TurboEngine :>> engine;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_non_conforming_redefinition_reports_type_mismatch() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def Wheel;
+            part def Vehicle {
+                part engine : Engine;
+            }
+            part def Cart :> Vehicle {
+                part wheelEngine : Wheel :>> engine;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert!(
+        diagnostics[0]
+            .message
+            .contains("expected 'Engine', found 'Wheel'"),
+        "Got: {:?}",
+        diagnostics[0]
+    );
+    assert_eq!(diagnostics[0].start_line, 8);
+}
+
+#[test]
+fn test_conforming_subsetting_has_no_type_mismatch() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def SportEngine :> Engine;
+            part def Vehicle {
+                part engines : Engine;
+                part mainEngine : SportEngine subsets engines;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_non_conforming_subsetting_reports_type_mismatch() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            attribute def Speed;
+            part def Vehicle {
+                part engines : Engine;
+                attribute topSpeed : Speed subsets engines;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert!(
+        diagnostics[0]
+            .message
+            .contains("expected 'Engine', found 'Speed'"),
+        "Got: {:?}",
+        diagnostics[0]
+    );
+    assert_eq!(diagnostics[0].start_line, 6);
+}
+
+#[test]
+fn test_subsetting_with_more_general_type_has_no_type_mismatch() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def SportEngine :> Engine;
+            part def Vehicle {
+                part sportEngines : SportEngine;
+                part engine : Engine subsets sportEngines;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_multiple_subsetting_has_no_type_mismatch() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def PowerSource;
+            part def Vehicle {
+                part engines : Engine;
+                part powerSources : PowerSource;
+                part hybrid : Engine subsets engines, powerSources;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_subsetting_with_unrelated_occurrence_type_has_no_type_mismatch() {
+    // A value can be both an Engine and a Wheel, as in the spec's
+    // `pwrCmd:PwrCmd subsets driverCmd`
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def Wheel;
+            part def Vehicle {
+                part engines : Engine;
+                part spare : Wheel subsets engines;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_redefining_qualified_feature_ignores_qualifier_type() {
+    let source = r#"
+        package Vehicles {
+            part def Wheel;
+            part def Vehicle {
+                part wheels : Wheel;
+            }
+            part car : Vehicle;
+            part def Garage {
+                part spare : Wheel redefines car::wheels;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+#[test]
+fn test_redefinition_with_unresolved_supertype_is_not_reported() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            part def Hybrid :> MissingBase;
+            part def Vehicle {
+                part engine : Engine;
+            }
+            part def Car :> Vehicle {
+                part hybridEngine : Hybrid :>> engine;
+            }
+        }
+    "#;
+
+    let diagnostics = type_mismatches(source);
+
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
+
+// =============================================================================
+// ABSTRACT TYPING
+// =============================================================================
+
+const ABSTRACT_TYPING_SOURCE: &str = r#"
+    package Vehicles {
+        abstract part def Vehicle;
+        part def Car :> Vehicle;
+        part vehicle : Vehicle;
+        part car : Car;
+        abstract part anyVehicle : Vehicle;
+    }
+"#;
+
+fn abstract_typing_diagnostics_with(config: DiagnosticConfig) -> Vec<Diagnostic> {
+    let (mut host, file_id) = analysis_from_sysml(ABSTRACT_TYPING_SOURCE);
+    let analysis = host.analysis();
+    check_file_with_config(analysis.symbol_index(), file_id, &config)
+        .into_iter()
+        .filter(|d| d.code.as_deref() == Some("W0006"))
+        .collect()
+}
+
+#[test]
+fn test_usage_typed_by_abstract_definition_warns() {
+    let diagnostics = abstract_typing_diagnostics_with(DiagnosticConfig::new());
+
+    // Only `vehicle`: `car` has a concrete type and `anyVehicle` is abstract
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert!(
+        diagnostics[0]
+            .message
+            .contains("'vehicle' is typed by abstract definition 'Vehicle'"),
+        "Got: {:?}",
+        diagnostics[0]
+    );
+    assert_eq!(diagnostics[0].start_line, 4);
+}
+
+#[test]
+fn test_abstract_typing_warning_is_configurable() {
+    let config = DiagnosticConfig::new().with_severity("W0006", Severity::Error);
+    let diagnostics = abstract_typing_diagnostics_with(config);
+    assert_eq!(diagnostics.len(), 1, "Got: {:?}", diagnostics);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+
+    let config = DiagnosticConfig::new().with_disabled("W0006");
+    let diagnostics = abstract_typing_diagnostics_with(config);
+    assert!(diagnostics.is_empty(), "Got: {:?}", diagnostics);
+}
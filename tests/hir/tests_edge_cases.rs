@@ -254,6 +254,52 @@ fn test_many_type_references() {
     );
 }
 
+#[test]
+fn test_type_operands_not_supertypes() {
+    // `unions` relates a type to its operands; it does not specialize them
+    let source = r#"
+        part def A;
+        part def B;
+        part def Either unions A;
+        part def Sub :> B;
+    "#;
+
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let either = analysis.symbol_index().lookup_qualified("Either").unwrap();
+    assert!(
+        !either.supertypes.iter().any(|s| s.as_ref() == "A"),
+        "Type operands should not be supertypes, got {:?}",
+        either.supertypes
+    );
+    let sub = analysis.symbol_index().lookup_qualified("Sub").unwrap();
+    assert!(sub.supertypes.iter().any(|s| s.as_ref() == "B"));
+}
+
+#[test]
+fn test_comma_continuation_inherits_preceding_kind() {
+    // `unions A, B` lists two operands; B is not a supertype either
+    let source = r#"
+        part def A;
+        part def B;
+        part def Either unions A, B;
+    "#;
+
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let either = analysis.symbol_index().lookup_qualified("Either").unwrap();
+    assert!(
+        !either
+            .supertypes
+            .iter()
+            .any(|s| s.as_ref() == "A" || s.as_ref() == "B"),
+        "Type operands should not be supertypes, got {:?}",
+        either.supertypes
+    );
+}
+
 // =============================================================================
 // DUPLICATE HANDLING
 // =============================================================================
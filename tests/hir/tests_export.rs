@@ -0,0 +1,45 @@
+//! Export tests for the HIR layer.
+//!
+//! These tests verify that the resolved symbol graph renders as DOT.
+
+use crate::helpers::hir_helpers::*;
+use syster::hir::to_dot;
+
+const VEHICLE_MODEL: &str = r#"
+package Vehicles {
+    part def Vehicle;
+    part def Car :> Vehicle;
+    part def Engine;
+    part engine : Engine;
+}
+"#;
+
+#[test]
+fn test_dot_export_definitions_and_specializations() {
+    let (mut host, _) = analysis_from_sysml(VEHICLE_MODEL);
+    let analysis = host.analysis();
+    let dot = to_dot(analysis.symbol_index(), false);
+
+    assert!(dot.starts_with("digraph symbols {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("    \"Vehicles::Car\" [label=\"Vehicles::Car\", shape=box];\n"));
+    assert!(dot.contains(
+        "    \"Vehicles::Car\" -> \"Vehicles::Vehicle\" [label=\":>\", style=solid, arrowhead=empty];\n"
+    ));
+    // Usages and their typings are excluded by default
+    assert!(!dot.contains("Vehicles::engine"));
+}
+
+#[test]
+fn test_dot_export_includes_usages_with_typings() {
+    let (mut host, _) = analysis_from_sysml(VEHICLE_MODEL);
+    let analysis = host.analysis();
+    let dot = to_dot(analysis.symbol_index(), true);
+
+    assert!(
+        dot.contains("    \"Vehicles::engine\" [label=\"Vehicles::engine\", shape=ellipse];\n")
+    );
+    assert!(dot.contains(
+        "    \"Vehicles::engine\" -> \"Vehicles::Engine\" [label=\":\", style=dashed, arrowhead=open];\n"
+    ));
+}
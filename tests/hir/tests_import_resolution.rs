@@ -6,7 +6,8 @@
 use crate::helpers::hir_helpers::*;
 use crate::helpers::source_fixtures::*;
 use crate::helpers::symbol_assertions::*;
-use syster::hir::SymbolKind;
+use syster::base::FileId;
+use syster::hir::{ResolveResult, Severity, SymbolIndex, SymbolKind, check_file};
 
 // =============================================================================
 // WILDCARD IMPORTS
@@ -42,6 +43,121 @@ fn test_wildcard_import_preserves_original_qualified_name() {
     assert_eq!(sym.qualified_name.as_ref(), "Base::Vehicle");
 }
 
+#[test]
+fn test_visible_names_lists_direct_and_imported() {
+    let source = r#"
+        package Base {
+            part def Engine;
+        }
+        package Derived {
+            import Base::*;
+            part def Car;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let names: Vec<(String, String)> = analysis
+        .symbol_index()
+        .visible_names("Derived")
+        .into_iter()
+        .map(|(name, qualified)| (name.to_string(), qualified.to_string()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("Car".to_string(), "Derived::Car".to_string()),
+            ("Engine".to_string(), "Base::Engine".to_string()),
+        ]
+    );
+    assert!(analysis.symbol_index().visible_names("Missing").is_empty());
+}
+
+const VISIBLE_NAMES_FILES: [(&str, &str); 2] = [
+    ("base.sysml", "package Base { part def Engine; }"),
+    (
+        "derived.sysml",
+        "package Derived { import Base::*; part def Car; }",
+    ),
+];
+
+#[test]
+fn test_visible_names_after_scope_changed() {
+    let mut host = analysis_from_sources(&VISIBLE_NAMES_FILES);
+    let analysis = host.analysis();
+    let base = analysis.get_file_id("base.sysml").unwrap();
+    let mut index = analysis.symbol_index().clone();
+
+    // Removing Base changes what Derived imports
+    index.remove_file(base);
+    index.ensure_visibility_maps();
+
+    let names: Vec<String> = index
+        .visible_names("Derived")
+        .into_iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+    assert_eq!(names, vec!["Car"]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "stale")]
+fn test_visible_names_requires_built_maps() {
+    let mut host = analysis_from_sources(&VISIBLE_NAMES_FILES);
+    let analysis = host.analysis();
+    let derived = analysis.get_file_id("derived.sysml").unwrap();
+    let mut index = analysis.symbol_index().clone();
+
+    index.remove_file(derived);
+    let _ = index.visible_names("Derived");
+}
+
+#[test]
+fn test_qualified_import_prefers_relative_over_global() {
+    let source = r#"
+        package Outer {
+            package Shared {
+                package Inner {
+                    part def Local;
+                }
+            }
+            package User {
+                import Shared::Inner::*;
+            }
+        }
+        package Shared {
+            package Inner {
+                part def Global;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+
+    let sym = assert_resolves(index, "Outer::User", "Local");
+    assert_eq!(sym.qualified_name.as_ref(), "Outer::Shared::Inner::Local");
+    assert_not_found(index, "Outer::User", "Global");
+}
+
+#[test]
+fn test_import_of_unknown_target_stays_unresolved() {
+    let source = r#"
+        package Consumer {
+            import Nowhere::Thing;
+            import Nowhere::*;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+
+    assert!(index.visible_names("Consumer").is_empty());
+    assert!(!index.import_target_exists("Consumer", "Nowhere::Thing"));
+    assert!(!index.import_target_exists("Consumer", "Nowhere"));
+}
+
 // =============================================================================
 // MEMBER IMPORTS
 // =============================================================================
@@ -113,6 +229,75 @@ fn test_private_import_does_not_re_export() {
     assert_not_found(analysis.symbol_index(), "Consumer", "Original");
 }
 
+#[test]
+fn test_private_import_not_visible_through_wildcard_import() {
+    let source = r#"
+        package A {
+            part def FromA;
+        }
+        package B {
+            private import A::*;
+            part def FromB;
+        }
+        package C {
+            import B::*;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    // B's own definitions are visible in C, its private imports are not
+    let sym = assert_resolves(analysis.symbol_index(), "C", "FromB");
+    assert_eq!(sym.qualified_name.as_ref(), "B::FromB");
+    assert_not_found(analysis.symbol_index(), "C", "FromA");
+}
+
+#[test]
+fn test_private_import_nested_in_public_reexport_not_visible() {
+    let source = r#"
+        package A {
+            part def Shared;
+            package Inner {
+                part def Hidden;
+            }
+        }
+        package B {
+            public import A::*;
+            private import A::Inner::*;
+        }
+        package C {
+            import B::*;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let sym = assert_resolves(analysis.symbol_index(), "C", "Shared");
+    assert_eq!(sym.qualified_name.as_ref(), "A::Shared");
+    // `Hidden` is under A, but B only imports it privately
+    assert_not_found(analysis.symbol_index(), "C", "Hidden");
+}
+
+#[test]
+fn test_public_specific_import_re_exported() {
+    let source = r#"
+        package A {
+            part def Exported;
+        }
+        package B {
+            public import A::Exported;
+        }
+        package C {
+            import B::*;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let sym = assert_resolves(analysis.symbol_index(), "C", "Exported");
+    assert_eq!(sym.qualified_name.as_ref(), "A::Exported");
+}
+
 // =============================================================================
 // NESTED IMPORTS
 // =============================================================================
@@ -375,6 +560,193 @@ fn test_cross_file_incremental_update() {
     }
 }
 
+#[test]
+fn test_incremental_visibility_matches_full_rebuild() {
+    let mut files = vec![
+        (
+            "base.sysml",
+            r#"
+            package Base {
+                part def Vehicle { part wheel; }
+                part def Engine;
+            }
+        "#,
+        ),
+        (
+            "lib.sysml",
+            r#"
+            package Lib {
+                public import Base::*;
+                part def Car :> Vehicle;
+            }
+        "#,
+        ),
+        (
+            "consumer.sysml",
+            r#"
+            package Consumer {
+                import Lib::*;
+                import Base::Engine;
+                part def Truck :> Car;
+            }
+        "#,
+        ),
+        ("other.sysml", "package Other { part def Engine; }"),
+    ];
+    let mut host = analysis_from_sources(&files);
+
+    // Each step updates one file; the affected scopes span the other files
+    let edits = [
+        (
+            "base.sysml",
+            r#"
+            package Base {
+                part def Vehicle { part wheel; part door; }
+                part def Engine;
+                part def Added;
+            }
+        "#,
+        ),
+        (
+            "consumer.sysml",
+            r#"
+            package Consumer {
+                import Lib::*;
+                import Other::Engine;
+                part def Truck :> Car;
+            }
+        "#,
+        ),
+        (
+            "lib.sysml",
+            r#"
+            package Lib {
+                private import Base::*;
+                part def Car :> Vehicle { part trunk; }
+            }
+        "#,
+        ),
+    ];
+
+    for (path, content) in edits {
+        host.set_file_content(path, content);
+        files.retain(|(p, _)| *p != path);
+        files.push((path, content));
+
+        let mut fresh = analysis_from_sources(&files);
+        let incremental = host.analysis();
+        let full = fresh.analysis();
+        assert_eq!(
+            incremental.symbol_index().visibility_maps(),
+            full.symbol_index().visibility_maps(),
+            "visibility maps diverge after editing {}",
+            path
+        );
+    }
+
+    host.remove_file("other.sysml");
+    files.retain(|(p, _)| *p != "other.sysml");
+    let mut fresh = analysis_from_sources(&files);
+    assert_eq!(
+        host.analysis().symbol_index().visibility_maps(),
+        fresh.analysis().symbol_index().visibility_maps(),
+        "visibility maps diverge after removing other.sysml"
+    );
+}
+
+#[test]
+fn test_add_file_marks_new_scopes_dirty() {
+    let file_symbols = |file: FileId, source: &str| {
+        let mut symbols = symbols_from_sysml(source);
+        for symbol in &mut symbols {
+            symbol.file = file;
+        }
+        symbols
+    };
+    let base = "package P { part def A; }";
+    let base_edited = "package P { part def A; part def B { part x; } }";
+    let consumer = "package Q { import P::*; part def C :> B { part y; } }";
+
+    let mut index = SymbolIndex::new();
+    index.add_file(FileId::new(0), file_symbols(FileId::new(0), base));
+    index.ensure_visibility_maps();
+
+    // No mark_file_dirty: adding the files is enough
+    index.add_file(FileId::new(0), file_symbols(FileId::new(0), base_edited));
+    index.add_file(FileId::new(1), file_symbols(FileId::new(1), consumer));
+    index.ensure_visibility_maps();
+
+    let mut fresh = SymbolIndex::new();
+    fresh.add_file(FileId::new(0), file_symbols(FileId::new(0), base_edited));
+    fresh.add_file(FileId::new(1), file_symbols(FileId::new(1), consumer));
+    fresh.ensure_visibility_maps();
+
+    assert!(index.visibility_for_scope("P::B").is_some());
+    assert!(index.visibility_for_scope("Q::C").is_some());
+    assert_eq!(index.visibility_maps(), fresh.visibility_maps());
+}
+
+#[test]
+fn test_removing_file_invalidates_dependent_references() {
+    let mut host = analysis_from_sources(&[
+        ("lib.sysml", "package Lib { part def Engine; }"),
+        (
+            "car.sysml",
+            r#"
+            package Car {
+                import Lib::*;
+                part engine : Engine;
+            }
+        "#,
+        ),
+    ]);
+
+    let undefined = |host: &mut syster::ide::AnalysisHost| {
+        let analysis = host.analysis();
+        let file = analysis.get_file_id("car.sysml").unwrap();
+        syster::hir::check_file(analysis.symbol_index(), file)
+            .into_iter()
+            .filter(|d| d.message.contains("undefined reference: 'Engine'"))
+            .count()
+    };
+
+    assert_eq!(undefined(&mut host), 0, "Engine should resolve via Lib");
+
+    host.remove_file("lib.sysml");
+
+    assert_eq!(undefined(&mut host), 1, "Engine should be undefined");
+    let analysis = host.analysis();
+    assert!(analysis.get_file_id("lib.sysml").is_none());
+    assert!(
+        analysis
+            .symbol_index()
+            .lookup_qualified("Lib::Engine")
+            .is_none()
+    );
+    assert_not_found(analysis.symbol_index(), "Car", "Engine");
+}
+
+#[test]
+fn test_importers_of_package() {
+    let mut host = analysis_from_sources(&[
+        ("shared.sysml", "package Shared { part def Widget; }"),
+        ("a.sysml", "package A { import Shared::*; }"),
+        ("b.sysml", "package B { import Shared::**; }"),
+        ("c.sysml", "package C { import Shared::Widget; }"),
+        ("unrelated.sysml", "package Unrelated { part def Other; }"),
+    ]);
+    let analysis = host.analysis();
+
+    let file = |path| analysis.get_file_id(path).unwrap();
+    let mut expected = vec![file("a.sysml"), file("b.sysml"), file("c.sysml")];
+    expected.sort();
+
+    let importers = analysis.symbol_index().importers_of("Shared");
+    assert_eq!(importers, expected);
+    assert!(!importers.contains(&file("unrelated.sysml")));
+    assert!(analysis.symbol_index().importers_of("Unrelated").is_empty());
+}
+
 // =============================================================================
 // EDGE CASES
 // =============================================================================
@@ -415,32 +787,24 @@ fn test_self_referential_import_safe() {
 
 #[test]
 fn test_recursive_import_double_star() {
-    // `import Pkg::**;` should import all symbols recursively from nested packages
+    // `import Outer::**;` imports the members of nested packages too
     let source = r#"
-        package Lib {
-            package Sub {
+        package Outer {
+            package Inner {
                 part def Nested;
             }
         }
         package Consumer {
-            import Lib::**;
+            import Outer::**;
         }
     "#;
     let (mut host, _) = analysis_from_sysml(source);
     let analysis = host.analysis();
 
-    // With recursive import, Nested should be visible in Consumer
-    // Note: This may resolve to Sub::Nested or just Nested depending on implementation
-    let resolver = analysis.symbol_index().resolver_for_scope("Consumer");
-    let result = resolver.resolve("Nested");
-    // If recursive imports are not fully implemented, this test documents the expected behavior
-    if result.is_found() {
-        let sym = result.symbol().unwrap();
-        assert_eq!(sym.qualified_name.as_ref(), "Lib::Sub::Nested");
-    } else {
-        // Document that recursive imports are not yet implemented
-        eprintln!("Note: Recursive imports (:**) not yet fully implemented");
-    }
+    let sym = assert_resolves(analysis.symbol_index(), "Consumer", "Nested");
+    assert_eq!(sym.qualified_name.as_ref(), "Outer::Inner::Nested");
+    let sym = assert_resolves(analysis.symbol_index(), "Consumer", "Inner");
+    assert_eq!(sym.qualified_name.as_ref(), "Outer::Inner");
 }
 
 // =============================================================================
@@ -462,14 +826,20 @@ fn test_import_alias() {
     let (mut host, _) = analysis_from_sysml(source);
     let analysis = host.analysis();
 
-    // Should be able to resolve the alias
+    // Resolving the alias yields the element it names
     let resolver = analysis.symbol_index().resolver_for_scope("Consumer");
     let result = resolver.resolve("Alias");
     assert!(result.is_found(), "Aliased import should resolve");
-    let sym = result.symbol().unwrap();
+    assert_eq!(
+        result.symbol().map(|s| s.qualified_name.as_ref()),
+        Some("Lib::Original")
+    );
 
     // Alias is its own symbol in the Consumer namespace
-    assert_eq!(sym.qualified_name.as_ref(), "Consumer::Alias");
+    let sym = analysis
+        .symbol_index()
+        .lookup_qualified("Consumer::Alias")
+        .unwrap();
     assert_eq!(sym.kind, SymbolKind::Alias);
 
     // But it points to the original via supertypes
@@ -480,6 +850,78 @@ fn test_import_alias() {
     assert_eq!(sym.supertypes[0].as_ref(), "Original");
 }
 
+#[test]
+fn test_alias_chain_resolves_to_underlying_definition() {
+    let source = r#"
+        package Lib {
+            part def Engine {
+                part piston;
+            }
+        }
+        package Consumer {
+            import Lib::*;
+            alias Motor for Engine;
+            alias Drive for Motor;
+            part car : Drive;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+    let resolver = index.resolver_for_scope("Consumer");
+
+    let alias = index.lookup_qualified("Consumer::Drive").unwrap();
+    assert_eq!(alias.kind, SymbolKind::Alias);
+
+    let target = resolver.resolve_alias_target(alias);
+    assert_eq!(
+        target.symbol().map(|s| s.qualified_name.as_ref()),
+        Some("Lib::Engine")
+    );
+
+    // Simple names resolve through the chain too, and so do type references
+    assert_eq!(
+        resolver
+            .resolve("Drive")
+            .symbol()
+            .map(|s| s.qualified_name.as_ref()),
+        Some("Lib::Engine")
+    );
+    let car = index.lookup_qualified("Consumer::car").unwrap();
+    assert_eq!(
+        car.type_refs[0].as_refs()[0].resolved_target.as_deref(),
+        Some("Lib::Engine")
+    );
+
+    // Members are reached through the whole chain
+    let member = resolver.resolve("Drive::piston");
+    assert_eq!(
+        member.symbol().map(|s| s.qualified_name.as_ref()),
+        Some("Lib::Engine::piston")
+    );
+}
+
+#[test]
+fn test_cyclic_alias_not_found() {
+    let source = r#"
+        package P {
+            alias A for B;
+            alias B for A;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let resolver = analysis.symbol_index().resolver_for_scope("P");
+
+    let alias = analysis.symbol_index().lookup_qualified("P::A").unwrap();
+    assert!(matches!(
+        resolver.resolve_alias_target(alias),
+        ResolveResult::NotFound
+    ));
+    assert!(matches!(resolver.resolve("A"), ResolveResult::NotFound));
+    assert!(matches!(resolver.resolve("A::x"), ResolveResult::NotFound));
+}
+
 // =============================================================================
 // FILTER IMPORTS (SysML v2 §7.5.4)
 // =============================================================================
@@ -1124,3 +1566,75 @@ fn test_redefines_that_chain_resolution() {
         }
     }
 }
+
+// =============================================================================
+// RE-EXPORT DEPTH LIMIT
+// =============================================================================
+
+/// Six packages where each `Pn` defines `An` and publicly re-exports `Pn-1`,
+/// giving a five-deep chain of public wildcard imports.
+const REEXPORT_CHAIN: &str = r#"
+    package P0 { part def A0; }
+    package P1 { public import P0::*; part def A1; }
+    package P2 { public import P1::*; part def A2; }
+    package P3 { public import P2::*; part def A3; }
+    package P4 { public import P3::*; part def A4; }
+    package P5 { public import P4::*; part def A5; }
+"#;
+
+fn reexport_chain_index(mut index: SymbolIndex) -> SymbolIndex {
+    index.add_file(FileId::new(0), symbols_from_sysml(REEXPORT_CHAIN));
+    index.ensure_visibility_maps();
+    index.resolve_all_type_refs();
+    index
+}
+
+#[test]
+fn test_reexport_chain_unlimited_by_default() {
+    let index = reexport_chain_index(SymbolIndex::new());
+
+    for name in ["A0", "A1", "A2", "A3", "A4"] {
+        assert_resolves(&index, "P5", name);
+    }
+    let diagnostics = check_file(&index, FileId::new(0));
+    assert!(
+        !diagnostics
+            .iter()
+            .any(|d| d.code.as_deref() == Some("I0001")),
+        "Got: {:?}",
+        diagnostics
+    );
+}
+
+#[test]
+fn test_reexport_depth_limit_stops_propagation() {
+    let index = reexport_chain_index(SymbolIndex::new().with_max_reexport_depth(2));
+
+    // A0 is re-exported by P1 and P2, so it reaches P3 but no further
+    assert_resolves(&index, "P3", "A0");
+    assert_not_found(&index, "P4", "A0");
+    assert_not_found(&index, "P5", "A0");
+    // Each package still sees names re-exported by at most two scopes
+    assert_resolves(&index, "P4", "A1");
+    assert_resolves(&index, "P5", "A2");
+    assert_resolves(&index, "P5", "A4");
+    assert_not_found(&index, "P5", "A1");
+
+    let p3 = index.visibility_for_scope("P3").unwrap();
+    assert_eq!(p3.reexport_depth("A2"), 1);
+    assert_eq!(p3.reexport_depth("A1"), 2);
+    assert_eq!(p3.reexport_depth("A0"), 3);
+
+    let diagnostics = check_file(&index, FileId::new(0));
+    let mut truncated: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.code.as_deref() == Some("I0001"))
+        .map(|d| {
+            assert_eq!(d.severity, Severity::Info);
+            d.start_line
+        })
+        .collect();
+    truncated.sort();
+    // The imports in P4 and P5
+    assert_eq!(truncated, vec![5, 6]);
+}
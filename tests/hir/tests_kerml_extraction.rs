@@ -317,3 +317,50 @@ fn test_kerml_public_import_reexport() {
     let sym = assert_resolves(analysis.symbol_index(), "C", "ClassA");
     assert_eq!(sym.qualified_name.as_ref(), "A::ClassA");
 }
+
+// =============================================================================
+// KERML FEATURE CHAINING
+// =============================================================================
+
+#[test]
+fn test_kerml_feature_chain_resolves_through_link_types() {
+    let source = r#"
+        package P {
+            class Engine {
+                feature torque;
+            }
+            class Car {
+                feature engine : Engine;
+            }
+            class SportsCar specializes Car;
+            class Garage {
+                feature car : SportsCar;
+                feature carTorque chains car.engine.torque;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_kerml(source);
+    let analysis = host.analysis();
+
+    let sym = get_symbol(analysis.symbol_index(), "P::Garage::carTorque");
+    let chain = sym
+        .type_refs
+        .iter()
+        .find(|trk| trk.is_chain())
+        .expect("carTorque should have a feature chain");
+    let resolved: Vec<_> = chain
+        .as_refs()
+        .iter()
+        .map(|tr| tr.resolved_target.as_deref())
+        .collect();
+
+    // `engine` is inherited by SportsCar, `torque` is reached through Engine
+    assert_eq!(
+        resolved,
+        [
+            Some("P::Garage::car"),
+            Some("P::Car::engine"),
+            Some("P::Engine::torque")
+        ]
+    );
+}
@@ -6,6 +6,7 @@
 use crate::helpers::hir_helpers::*;
 use crate::helpers::source_fixtures::*;
 use crate::helpers::symbol_assertions::*;
+use syster::hir::{ResolveHit, ResolveResult, ResolveStep, Resolver, SymbolKind};
 
 // =============================================================================
 // SIMPLE NAME RESOLUTION
@@ -77,6 +78,38 @@ fn test_resolve_deeply_nested_qualified_name() {
     );
 }
 
+#[test]
+fn test_enum_literal_resolves_through_enumeration() {
+    let source = r#"
+        package Status {
+            enum def StatusKind {
+                enum open;
+                enum closed;
+            }
+            part def Ticket {
+                attribute status : StatusKind = StatusKind::closed;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+
+    let sym = assert_resolves(index, "Status::Ticket", "StatusKind::closed");
+    assert_eq!(sym.qualified_name.as_ref(), "Status::StatusKind::closed");
+    assert_eq!(sym.kind, SymbolKind::AttributeUsage);
+    assert_eq!(sym.supertypes[0].as_ref(), "Status::StatusKind");
+
+    let status = get_symbol(index, "Status::Ticket::status");
+    let value_refs: Vec<_> = status
+        .type_refs
+        .iter()
+        .flat_map(|trk| trk.as_refs())
+        .filter_map(|tr| tr.resolved_target.as_deref())
+        .collect();
+    assert!(value_refs.contains(&"Status::StatusKind::closed"));
+}
+
 // =============================================================================
 // SCOPE WALKING (PARENT SCOPE LOOKUP)
 // =============================================================================
@@ -237,9 +270,9 @@ fn test_resolve_from_definition_scope() {
 // This test documents the current behavior.
 
 #[test]
-fn test_multiple_imports_same_name_last_wins() {
-    // When the same name is imported from multiple packages,
-    // the current implementation picks the last one (HashMap insert overwrites)
+fn test_multiple_imports_same_name_is_ambiguous() {
+    // When the same name is imported from multiple packages, the resolver
+    // reports every candidate instead of silently picking one
     let source = r#"
         package A {
             part def Thing;
@@ -250,16 +283,46 @@ fn test_multiple_imports_same_name_last_wins() {
         package Consumer {
             import A::*;
             import B::*;
-            // Thing is imported from B last, so B::Thing wins
         }
     "#;
     let (mut host, _) = analysis_from_sysml(source);
     let analysis = host.analysis();
 
-    // Current behavior: last import wins, no ambiguity error
-    let sym = assert_resolves(analysis.symbol_index(), "Consumer", "Thing");
-    // B::Thing should be picked since it's imported last
-    assert_eq!(sym.qualified_name.as_ref(), "B::Thing");
+    let resolver = Resolver::new(analysis.symbol_index()).with_scope("Consumer");
+    let ResolveResult::Ambiguous(candidates) = resolver.resolve("Thing") else {
+        panic!("'Thing' should be ambiguous from 'Consumer'");
+    };
+    let mut names: Vec<_> = candidates
+        .iter()
+        .map(|s| s.qualified_name.as_ref())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["A::Thing", "B::Thing"]);
+}
+
+#[test]
+fn test_recursive_import_nested_same_name_not_ambiguous() {
+    // A single recursive import reaching several members with the same name
+    // is not a conflict between imports; the direct child wins
+    let source = r#"
+        package A {
+            part def Thing;
+            package Inner {
+                part def Thing;
+            }
+        }
+        package Consumer {
+            import A::**;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let resolver = Resolver::new(analysis.symbol_index()).with_scope("Consumer");
+    let ResolveResult::Found(symbol) = resolver.resolve("Thing") else {
+        panic!("'Thing' should resolve from 'Consumer'");
+    };
+    assert_eq!(symbol.qualified_name.as_ref(), "A::Thing");
 }
 
 #[test]
@@ -280,3 +343,117 @@ fn test_not_ambiguous_when_shadowed() {
     let sym = assert_resolves(analysis.symbol_index(), "Consumer", "Thing");
     assert_eq!(sym.qualified_name.as_ref(), "Consumer::Thing");
 }
+
+#[test]
+fn test_overloaded_calc_defs_are_ambiguous() {
+    // Calcs sharing a short name are distinct definitions; the resolver reports
+    // both so callers can pick one by signature
+    let source = r#"
+        package Geometry {
+            calc def <area> circleArea { in r; }
+            calc def <area> rectangleArea { in w; in h; }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let resolver = Resolver::new(analysis.symbol_index()).with_scope("Geometry");
+    let ResolveResult::Ambiguous(candidates) = resolver.resolve("area") else {
+        panic!("'area' should be ambiguous in 'Geometry'");
+    };
+    let mut names: Vec<_> = candidates
+        .iter()
+        .map(|s| s.qualified_name.as_ref())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["Geometry::circleArea", "Geometry::rectangleArea"]);
+
+    // Each definition still resolves on its own by its full name
+    let sym = assert_resolves(analysis.symbol_index(), "Geometry", "circleArea");
+    assert_eq!(sym.qualified_name.as_ref(), "Geometry::circleArea");
+}
+
+// =============================================================================
+// RESOLUTION TRACE
+// =============================================================================
+
+const TRACE_SOURCE: &str = r#"
+    package Level1 {
+        part def Level1Def;
+        package Level2 {
+            package Level3 {
+                part usage : Level1Def;
+            }
+        }
+    }
+"#;
+
+/// The scopes and hits of a trace, for compact assertions.
+fn trace_of(steps: &[ResolveStep]) -> Vec<(&str, ResolveHit)> {
+    steps.iter().map(|s| (s.scope.as_ref(), s.hit)).collect()
+}
+
+#[test]
+fn test_resolve_traced_lists_scopes_searched() {
+    let (mut host, _) = analysis_from_sysml(TRACE_SOURCE);
+    let analysis = host.analysis();
+    let resolver = Resolver::new(analysis.symbol_index()).with_scope("Level1::Level2::Level3");
+
+    let (result, steps) = resolver.resolve_traced("Level1Def");
+    assert_eq!(
+        result.symbol().map(|s| s.qualified_name.as_ref()),
+        Some("Level1::Level1Def")
+    );
+    assert_eq!(
+        trace_of(&steps),
+        vec![
+            ("Level1::Level2::Level3", ResolveHit::Miss),
+            ("Level1::Level2", ResolveHit::Miss),
+            ("Level1", ResolveHit::Direct),
+        ]
+    );
+
+    // The traced and untraced lookups agree
+    assert!(resolver.resolve("Level1Def").is_found());
+}
+
+#[test]
+fn test_resolve_traced_unresolved_reaches_root() {
+    let (mut host, _) = analysis_from_sysml(TRACE_SOURCE);
+    let analysis = host.analysis();
+    let resolver = Resolver::new(analysis.symbol_index()).with_scope("Level1::Level2");
+
+    let (result, steps) = resolver.resolve_traced("Missing");
+    assert!(matches!(result, ResolveResult::NotFound));
+    assert_eq!(
+        trace_of(&steps),
+        vec![
+            ("Level1::Level2", ResolveHit::Miss),
+            ("Level1", ResolveHit::Miss),
+            ("", ResolveHit::Miss),
+        ]
+    );
+}
+
+#[test]
+fn test_resolve_traced_qualified_path() {
+    let (mut host, _) = analysis_from_sysml(TRACE_SOURCE);
+    let analysis = host.analysis();
+    let resolver = Resolver::new(analysis.symbol_index()).with_scope("Level1::Level2::Level3");
+
+    // The first segment is found by the scope walk, the rest in its namespace
+    let (result, steps) = resolver.resolve_traced("Level2::Level3");
+    assert_eq!(
+        result.symbol().map(|s| s.qualified_name.as_ref()),
+        Some("Level1::Level2::Level3")
+    );
+    assert_eq!(
+        trace_of(&steps),
+        vec![
+            ("Level1::Level2::Level3", ResolveHit::Miss),
+            ("Level1::Level2", ResolveHit::Miss),
+            ("Level1", ResolveHit::Direct),
+            ("Level1::Level2", ResolveHit::Direct),
+        ]
+    );
+}
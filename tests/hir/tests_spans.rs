@@ -212,6 +212,35 @@ fn test_symbols_ordered_by_position() {
     );
 }
 
+// =============================================================================
+// SYMBOL AT POSITION
+// =============================================================================
+
+#[test]
+fn test_symbol_at_returns_nested_part() {
+    let source = "part def Vehicle {\n    part engine : Engine;\n}\npart def Engine;";
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+
+    let symbol = index.symbol_at(file_id, 1, 10).expect("symbol on 'engine'");
+    assert_eq!(symbol.qualified_name.as_ref(), "Vehicle::engine");
+
+    let symbol = index
+        .symbol_at(file_id, 0, 12)
+        .expect("symbol on 'Vehicle'");
+    assert_eq!(symbol.qualified_name.as_ref(), "Vehicle");
+}
+
+#[test]
+fn test_symbol_at_whitespace_is_none() {
+    let source = "part def Vehicle {\n    part engine;\n}";
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    assert!(analysis.symbol_index().symbol_at(file_id, 1, 1).is_none());
+}
+
 // =============================================================================
 // EDGE CASES
 // =============================================================================
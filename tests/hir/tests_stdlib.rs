@@ -8,6 +8,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use syster::hir::SymbolKind;
 use syster::ide::AnalysisHost;
+use syster::project::StdLibLoader;
 
 // =============================================================================
 // HELPERS
@@ -349,3 +350,62 @@ fn test_full_stdlib_no_duplicate_symbols() {
         duplicates.iter().take(10).collect::<Vec<_>>()
     );
 }
+
+// =============================================================================
+// STDLIB LOADER REPORT
+// =============================================================================
+
+#[test]
+fn test_stdlib_loader_reports_failed_files() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(root.join("Good.sysml"), "package Good { part def Ok; }").unwrap();
+    fs::write(root.join("Broken.sysml"), "package Broken { part def @#$ }").unwrap();
+    // Not valid UTF-8, so the file cannot be read at all
+    fs::write(root.join("Unreadable.sysml"), [0xff, 0xfe, 0x00]).unwrap();
+
+    let loader = StdLibLoader::with_path(root.to_path_buf());
+    let mut host = AnalysisHost::new();
+    let report = loader
+        .load_into_host(&mut host)
+        .expect("Should load stdlib");
+
+    let file_names = |files: &[(std::path::PathBuf, String)]| -> Vec<String> {
+        files
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    };
+    let mut failed = file_names(&report.failed);
+    failed.sort();
+    assert_eq!(report.loaded, 1);
+    assert_eq!(failed, vec!["Broken.sysml", "Unreadable.sysml"]);
+
+    // The parser recovers from syntax errors, so only the unreadable file is missing
+    assert!(host.has_file_path(&root.join("Good.sysml")));
+    assert!(host.has_file_path(&root.join("Broken.sysml")));
+    assert!(!host.has_file_path(&root.join("Unreadable.sysml")));
+    let analysis = host.analysis();
+    assert!(
+        analysis
+            .symbol_index()
+            .lookup_qualified("Good::Ok")
+            .is_some()
+    );
+}
+
+#[test]
+fn test_stdlib_loader_full_stdlib_is_clean() {
+    let loader = StdLibLoader::with_path(stdlib_path());
+    let mut host = AnalysisHost::new();
+    let report = loader
+        .load_into_host(&mut host)
+        .expect("Should load stdlib");
+
+    assert!(
+        report.failed.is_empty(),
+        "Stdlib failures: {:?}",
+        report.failed
+    );
+    assert_eq!(report.loaded, host.file_count());
+}
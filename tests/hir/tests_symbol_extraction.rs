@@ -6,7 +6,14 @@
 use crate::helpers::hir_helpers::*;
 use crate::helpers::source_fixtures::*;
 use crate::helpers::symbol_assertions::*;
-use syster::hir::SymbolKind;
+use syster::base::FileId;
+use syster::hir::{
+    RefKind, SymbolIndex, SymbolKind, check_naming_conventions, extract_symbols_unified,
+};
+use syster::ide::goto_definition;
+use syster::parser::Direction;
+use syster::syntax::SyntaxFile;
+use syster::syntax::normalized::Multiplicity;
 
 // =============================================================================
 // PACKAGE EXTRACTION
@@ -582,6 +589,191 @@ fn test_symbol_has_span() {
     assert_has_span(sym);
 }
 
+// =============================================================================
+// DOC COMMENTS
+// =============================================================================
+
+#[test]
+fn test_line_comment_run_documents_part_def() {
+    let source = r#"
+        package TestPkg {
+            // A road vehicle.
+            // Has four wheels.
+            part def Vehicle;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let sym = get_symbol(analysis.symbol_index(), "TestPkg::Vehicle");
+    assert_eq!(
+        sym.doc.as_deref(),
+        Some("A road vehicle.\nHas four wheels.")
+    );
+}
+
+#[test]
+fn test_comment_separated_by_blank_line_is_not_doc() {
+    let source = r#"
+        package TestPkg {
+            // Section header
+
+            part def Vehicle;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let sym = get_symbol(analysis.symbol_index(), "TestPkg::Vehicle");
+    assert_eq!(sym.doc, None);
+}
+
+#[test]
+fn test_trailing_comment_documents_its_own_line() {
+    let source = r#"
+        package TestPkg {
+            part def Engine; // Internal combustion
+            part def Wheel;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let engine = get_symbol(analysis.symbol_index(), "TestPkg::Engine");
+    assert_eq!(engine.doc.as_deref(), Some("Internal combustion"));
+    // The trailing comment belongs to Engine, not to the next declaration
+    let wheel = get_symbol(analysis.symbol_index(), "TestPkg::Wheel");
+    assert_eq!(wheel.doc, None);
+}
+
+// =============================================================================
+// ABSTRACT AND VARIATION MODIFIERS
+// =============================================================================
+
+#[test]
+fn test_abstract_definition_extraction() {
+    let source = r#"
+        package TestPkg {
+            abstract part def Foo;
+            part def Plain;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let foo = get_symbol(analysis.symbol_index(), "TestPkg::Foo");
+    assert!(foo.is_abstract);
+    assert!(!foo.is_variation);
+
+    let plain = get_symbol(analysis.symbol_index(), "TestPkg::Plain");
+    assert!(!plain.is_abstract);
+}
+
+#[test]
+fn test_variation_definition_extraction() {
+    let source = r#"
+        package TestPkg {
+            variation part def Bar;
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let bar = get_symbol(analysis.symbol_index(), "TestPkg::Bar");
+    assert!(bar.is_variation);
+    assert!(!bar.is_abstract);
+}
+
+#[test]
+fn test_abstract_usage_extraction() {
+    let source = r#"
+        package TestPkg {
+            part def Vehicle {
+                abstract part engine;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let engine = get_symbol(analysis.symbol_index(), "TestPkg::Vehicle::engine");
+    assert!(engine.is_abstract);
+}
+
+// =============================================================================
+// MULTIPLICITY AND DIRECTION
+// =============================================================================
+
+#[test]
+fn test_attribute_multiplicity_extraction() {
+    let source = r#"
+        package TestPkg {
+            attribute def Real;
+            part def Vehicle {
+                attribute mass[1] : Real;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let mass = get_symbol(analysis.symbol_index(), "TestPkg::Vehicle::mass");
+    assert_eq!(
+        mass.multiplicity,
+        Some(Multiplicity {
+            lower: Some(1),
+            upper: Some(1)
+        })
+    );
+    assert_eq!(mass.direction, None);
+}
+
+#[test]
+fn test_in_parameter_direction_extraction() {
+    let source = r#"
+        package TestPkg {
+            attribute def Real;
+            action def Accelerate {
+                in force : Real;
+                out speed : Real;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let force = get_symbol(analysis.symbol_index(), "TestPkg::Accelerate::force");
+    assert_eq!(force.direction, Some(Direction::In));
+    assert_eq!(force.multiplicity, None);
+
+    let speed = get_symbol(analysis.symbol_index(), "TestPkg::Accelerate::speed");
+    assert_eq!(speed.direction, Some(Direction::Out));
+}
+
+#[test]
+fn test_unbounded_multiplicity_extraction() {
+    let source = r#"
+        package TestPkg {
+            part def Wheel;
+            part def Vehicle {
+                part wheels : Wheel[0..*];
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let wheels = get_symbol(analysis.symbol_index(), "TestPkg::Vehicle::wheels");
+    // An unbounded upper bound is recorded as `None`
+    assert_eq!(
+        wheels.multiplicity,
+        Some(Multiplicity {
+            lower: Some(0),
+            upper: None
+        })
+    );
+}
+
 // =============================================================================
 // ANONYMOUS USAGE TESTS
 // =============================================================================
@@ -669,3 +861,146 @@ fn test_deeply_nested_packages_10_levels() {
         "Level1::Level2::Level3::Level4::Level5::Level6::Level7::Level8::Level9::Level10::DeepPart";
     assert_symbol_exists(analysis.symbol_index(), deep_name);
 }
+
+// =============================================================================
+// ANONYMOUS USAGES
+// =============================================================================
+
+const ANONYMOUS_PARTS: &str = r#"
+package P {
+    part def Engine;
+    part def Car {
+        part : Engine;
+        part : Engine;
+    }
+}
+"#;
+
+/// Qualified names of the anonymous symbols directly under `scope`.
+fn anonymous_members(source: &str, scope: &str) -> Vec<String> {
+    let syntax = SyntaxFile::sysml(source);
+    extract_symbols_unified(FileId::new(0), &syntax)
+        .into_iter()
+        .filter(|s| s.is_anonymous())
+        .filter(|s| SymbolIndex::parent_scope(&s.qualified_name) == Some(scope))
+        .map(|s| s.qualified_name.to_string())
+        .collect()
+}
+
+#[test]
+fn test_anonymous_parts_have_distinct_stable_names() {
+    let names = anonymous_members(ANONYMOUS_PARTS, "P::Car");
+    assert_eq!(names.len(), 2, "Expected two anonymous parts: {names:?}");
+    assert_ne!(names[0], names[1]);
+
+    // Re-extracting the same text yields the same names
+    assert_eq!(anonymous_members(ANONYMOUS_PARTS, "P::Car"), names);
+}
+
+#[test]
+fn test_anonymous_names_numbered_per_scope() {
+    let source = r#"
+package P {
+    part def Engine;
+    part def Car {
+        part : Engine;
+    }
+    part def Truck {
+        part : Engine;
+    }
+}
+"#;
+    let car = anonymous_members(source, "P::Car");
+    let truck = anonymous_members(source, "P::Truck");
+
+    assert_eq!(car, vec!["P::Car::<:Engine#1>".to_string()]);
+    assert_eq!(truck, vec!["P::Truck::<:Engine#1>".to_string()]);
+}
+
+#[test]
+fn test_anonymous_names_survive_line_shifts() {
+    let names = anonymous_members(ANONYMOUS_PARTS, "P::Car");
+    let shifted = format!("// header\n\n{ANONYMOUS_PARTS}");
+
+    assert_eq!(anonymous_members(&shifted, "P::Car"), names);
+}
+
+#[test]
+fn test_anonymous_parts_not_offered_or_flagged() {
+    let (mut host, file_id) = analysis_from_sysml(ANONYMOUS_PARTS);
+    let analysis = host.analysis();
+
+    let labels: Vec<_> = analysis
+        .completions(file_id, 4, 8, None)
+        .into_iter()
+        .map(|item| item.label)
+        .collect();
+    assert!(labels.iter().any(|l| l.as_ref() == "Engine"));
+    assert!(
+        !labels.iter().any(|l| l.starts_with('<')),
+        "Anonymous symbols offered: {labels:?}"
+    );
+
+    let naming = check_naming_conventions(analysis.symbol_index(), file_id);
+    assert!(naming.is_empty(), "Unexpected naming hints: {naming:?}");
+}
+
+// =============================================================================
+// METADATA USAGES
+// =============================================================================
+
+const STATUS_INFO_METADATA: &str = r#"
+package P {
+    enum def StatusKind { open; closed; }
+    metadata def StatusInfo {
+        attribute status : StatusKind;
+    }
+    part def Car {
+        @StatusInfo {
+            status = StatusKind::closed;
+        }
+    }
+}
+"#;
+
+#[test]
+fn test_metadata_usage_extracted_with_resolved_refs() {
+    let (mut host, file_id) = analysis_from_sysml(STATUS_INFO_METADATA);
+    let analysis = host.analysis();
+
+    let metadata = analysis
+        .symbol_index()
+        .all_symbols()
+        .find(|s| s.kind == SymbolKind::MetadataUsage)
+        .expect("metadata usage symbol");
+    assert!(metadata.qualified_name.starts_with("P::Car::"));
+
+    let resolved: Vec<_> = metadata
+        .type_refs
+        .iter()
+        .flat_map(|trk| trk.as_refs())
+        .map(|tr| (tr.target.as_ref(), tr.kind, tr.resolved_target.as_deref()))
+        .collect();
+    assert!(resolved.contains(&("StatusInfo", RefKind::TypedBy, Some("P::StatusInfo"))));
+    assert!(resolved.contains(&("status", RefKind::Redefines, Some("P::StatusInfo::status"))));
+    assert!(resolved.contains(&("closed", RefKind::Expression, Some("P::StatusKind::closed"))));
+
+    // Goto on the annotation and on the assigned feature
+    let result = goto_definition(analysis.symbol_index(), file_id, 7, 12);
+    assert_eq!(result.targets[0].name.as_ref(), "StatusInfo");
+    assert_eq!(result.targets[0].start_line, 3);
+    let result = goto_definition(analysis.symbol_index(), file_id, 8, 14);
+    assert_eq!(result.targets[0].name.as_ref(), "status");
+    assert_eq!(result.targets[0].start_line, 4);
+
+    // The assigned feature is a reference, not a separate symbol
+    let car = get_symbol(analysis.symbol_index(), "P::Car");
+    assert!(car.type_refs.is_empty());
+    assert!(
+        analysis
+            .symbol_index()
+            .all_symbols()
+            .all(|s| !s.qualified_name.ends_with("::status")
+                || s.qualified_name.as_ref() == "P::StatusInfo::status")
+    );
+}
@@ -6,7 +6,7 @@
 use crate::helpers::hir_helpers::*;
 use crate::helpers::source_fixtures::*;
 use crate::helpers::symbol_assertions::*;
-use syster::hir::{RefKind, RelationshipKind};
+use syster::hir::{RefKind, RelationshipKind, SymbolKind, TypeRefKind};
 
 // =============================================================================
 // TYPED BY (`:`)
@@ -572,3 +572,236 @@ fn test_deep_specialization_supertypes() {
         "SportsCar should have Car in supertypes"
     );
 }
+
+// =============================================================================
+// RESOLVED TARGET QUERY
+// =============================================================================
+
+const RESOLVED_TARGET_SOURCE: &str = r#"
+package Shop {
+    part def Engine;
+    part engine : Engine;
+    part other : Missing;
+}
+"#;
+
+/// Column of `needle` on a 0-indexed line of `RESOLVED_TARGET_SOURCE`.
+fn col_of(line: usize, needle: &str) -> u32 {
+    let text = RESOLVED_TARGET_SOURCE.lines().nth(line).unwrap();
+    text.find(needle).unwrap() as u32
+}
+
+#[test]
+fn test_resolved_target_at_resolved_ref() {
+    let (mut host, file_id) = analysis_from_sysml(RESOLVED_TARGET_SOURCE);
+    let analysis = host.analysis();
+
+    let col = col_of(3, ": Engine") + 4;
+    let target = analysis.symbol_index().resolved_target_at(file_id, 3, col);
+    assert_eq!(target.as_deref(), Some("Shop::Engine"));
+}
+
+#[test]
+fn test_resolved_target_at_unresolved_ref() {
+    let (mut host, file_id) = analysis_from_sysml(RESOLVED_TARGET_SOURCE);
+    let analysis = host.analysis();
+
+    let col = col_of(4, "Missing") + 2;
+    assert_eq!(
+        analysis.symbol_index().resolved_target_at(file_id, 4, col),
+        None
+    );
+}
+
+#[test]
+fn test_resolved_target_at_no_ref() {
+    let (mut host, file_id) = analysis_from_sysml(RESOLVED_TARGET_SOURCE);
+    let analysis = host.analysis();
+
+    // On the usage name, not its type
+    let col = col_of(3, "engine") + 1;
+    assert_eq!(
+        analysis.symbol_index().resolved_target_at(file_id, 3, col),
+        None
+    );
+    // Past the end of the file
+    assert_eq!(
+        analysis.symbol_index().resolved_target_at(file_id, 99, 0),
+        None
+    );
+}
+
+// =============================================================================
+// FLOW ENDPOINTS
+// =============================================================================
+
+#[test]
+fn test_flow_endpoint_chains_resolve_to_ports() {
+    let source = r#"
+        package Sys {
+            port def DataPort;
+            part def Sender {
+                port outPort : DataPort;
+            }
+            part def Receiver {
+                port inPort : DataPort;
+            }
+            part def Pipeline {
+                part sender : Sender;
+                part receiver : Receiver;
+                flow dataFlow from sender.outPort to receiver.inPort;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let flow = get_symbol(analysis.symbol_index(), "Sys::Pipeline::dataFlow");
+    assert_symbol_kind(flow, SymbolKind::FlowConnectionUsage);
+    let chains: Vec<Vec<(String, Option<String>)>> = flow
+        .type_refs
+        .iter()
+        .filter_map(|trk| match trk {
+            TypeRefKind::Chain(chain) => Some(
+                chain
+                    .parts
+                    .iter()
+                    .map(|p| {
+                        (
+                            p.target.to_string(),
+                            p.resolved_target.as_deref().map(str::to_string),
+                        )
+                    })
+                    .collect(),
+            ),
+            TypeRefKind::Simple(_) => None,
+        })
+        .collect();
+
+    let resolved = |target: &str, qname: &str| (target.to_string(), Some(qname.to_string()));
+    assert_eq!(
+        chains,
+        vec![
+            vec![
+                resolved("sender", "Sys::Pipeline::sender"),
+                resolved("outPort", "Sys::Sender::outPort"),
+            ],
+            vec![
+                resolved("receiver", "Sys::Pipeline::receiver"),
+                resolved("inPort", "Sys::Receiver::inPort"),
+            ],
+        ]
+    );
+}
+
+// =============================================================================
+// RELATIONSHIP KINDS
+// =============================================================================
+
+#[test]
+fn test_usage_subsets_and_redefines_are_distinct() {
+    let source = r#"
+        package P {
+            part def Engine;
+            part def Vehicle {
+                part engines : Engine[*];
+                part mainEngine : Engine;
+            }
+            part def Car :> Vehicle {
+                part frontEngine :> engines :>> mainEngine;
+                part rearEngine subsets engines redefines mainEngine;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    for name in ["P::Car::frontEngine", "P::Car::rearEngine"] {
+        let usage = get_symbol(analysis.symbol_index(), name);
+        let kinds: Vec<(RelationshipKind, &str)> = usage
+            .relationships
+            .iter()
+            .map(|r| (r.kind, r.target.as_ref()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (RelationshipKind::Subsets, "engines"),
+                (RelationshipKind::Redefines, "mainEngine"),
+            ],
+            "{name} relationships"
+        );
+        // The type references agree with the relationships
+        let ref_kinds: Vec<(RefKind, &str)> = usage
+            .type_refs
+            .iter()
+            .flat_map(|trk| trk.as_refs())
+            .map(|tr| (tr.kind, tr.target.as_ref()))
+            .collect();
+        assert_eq!(
+            ref_kinds,
+            vec![
+                (RefKind::Subsets, "engines"),
+                (RefKind::Redefines, "mainEngine"),
+            ],
+            "{name} type refs"
+        );
+        // Both targets still feed member lookup through supertypes
+        assert!(usage.supertypes.iter().any(|s| s.as_ref() == "engines"));
+        assert!(usage.supertypes.iter().any(|s| s.as_ref() == "mainEngine"));
+    }
+
+    // `:>` on a definition remains specialization
+    let car = get_symbol(analysis.symbol_index(), "P::Car");
+    assert!(
+        car.relationships
+            .iter()
+            .any(|r| r.kind == RelationshipKind::Specializes && r.target.as_ref() == "Vehicle")
+    );
+}
+
+#[test]
+fn test_usage_comma_continuation_inherits_preceding_kind() {
+    let source = r#"
+        package P {
+            part def Engine;
+            part def Vehicle {
+                part engines : Engine[*];
+                part mainEngine : Engine;
+                part spareEngine : Engine;
+            }
+            part def Car :> Vehicle {
+                part engine :>> mainEngine, spareEngine;
+            }
+        }
+    "#;
+    let (mut host, _) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let usage = get_symbol(analysis.symbol_index(), "P::Car::engine");
+    let kinds: Vec<(RelationshipKind, &str)> = usage
+        .relationships
+        .iter()
+        .map(|r| (r.kind, r.target.as_ref()))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (RelationshipKind::Redefines, "mainEngine"),
+            (RelationshipKind::Redefines, "spareEngine"),
+        ]
+    );
+    let ref_kinds: Vec<(RefKind, &str)> = usage
+        .type_refs
+        .iter()
+        .flat_map(|trk| trk.as_refs())
+        .map(|tr| (tr.kind, tr.target.as_ref()))
+        .collect();
+    assert_eq!(
+        ref_kinds,
+        vec![
+            (RefKind::Redefines, "mainEngine"),
+            (RefKind::Redefines, "spareEngine"),
+        ]
+    );
+}
@@ -9,13 +9,21 @@
 //! - Code completion
 //! - Semantic tokens
 //! - Folding ranges
+//! - Selection ranges
 //! - Inlay hints
 //! - Document links
+//! - Call hierarchy
+//! - Signature help
 
+pub mod tests_call_hierarchy;
 pub mod tests_completion;
+pub mod tests_document_links;
 pub mod tests_folding;
 pub mod tests_goto;
 pub mod tests_hover;
+pub mod tests_inlay_hints;
 pub mod tests_references;
+pub mod tests_selection;
 pub mod tests_semantic_tokens;
+pub mod tests_signature_help;
 pub mod tests_symbols;
@@ -0,0 +1,85 @@
+//! Call hierarchy tests for the IDE layer.
+
+use crate::helpers::hir_helpers::*;
+use syster::ide::{incoming_calls, outgoing_calls};
+
+// =============================================================================
+// CALL HIERARCHY
+// =============================================================================
+
+const CALLS_SOURCE: &str = r#"
+        package M {
+            action def ProvidePower {
+                action distributeTorque;
+            }
+            calc def Square;
+            action def Drive {
+                perform action providePower : ProvidePower;
+                perform providePower.distributeTorque;
+                calc sq : Square;
+            }
+            action def Park {
+                action holdPower : ProvidePower;
+            }
+        }
+    "#;
+
+#[test]
+fn test_outgoing_calls_from_action() {
+    let (mut host, file_id) = analysis_from_sysml(CALLS_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on `Drive`
+    let calls = outgoing_calls(analysis.symbol_index(), file_id, 6, 24);
+    let callees: Vec<_> = calls.iter().map(|c| c.to.qualified_name.as_ref()).collect();
+
+    assert_eq!(
+        callees,
+        [
+            "M::ProvidePower",
+            "M::ProvidePower::distributeTorque",
+            "M::Square"
+        ]
+    );
+    assert!(calls.iter().all(|c| c.sites.len() == 1));
+    assert_eq!(calls[1].sites[0].start_line, 8);
+}
+
+#[test]
+fn test_incoming_calls_to_action() {
+    let (mut host, file_id) = analysis_from_sysml(CALLS_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on `ProvidePower`
+    let calls = incoming_calls(analysis.symbol_index(), file_id, 2, 24);
+    let mut callers: Vec<_> = calls
+        .iter()
+        .map(|c| c.from.qualified_name.as_ref())
+        .collect();
+    callers.sort();
+
+    assert_eq!(callers, ["M::Drive", "M::Park"]);
+}
+
+#[test]
+fn test_incoming_calls_through_feature_chain() {
+    let (mut host, file_id) = analysis_from_sysml(CALLS_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on `distributeTorque`
+    let calls = incoming_calls(analysis.symbol_index(), file_id, 3, 25);
+
+    assert_eq!(calls.len(), 1, "Got: {:?}", calls);
+    assert_eq!(calls[0].from.qualified_name.as_ref(), "M::Drive");
+    assert_eq!(calls[0].sites[0].start_line, 8);
+}
+
+#[test]
+fn test_call_hierarchy_not_on_callable() {
+    let (mut host, file_id) = analysis_from_sysml(CALLS_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on the package name
+    assert!(outgoing_calls(analysis.symbol_index(), file_id, 1, 17).is_empty());
+    assert!(incoming_calls(analysis.symbol_index(), file_id, 1, 17).is_empty());
+}
@@ -5,7 +5,7 @@
 
 use crate::helpers::hir_helpers::*;
 use syster::ide::AnalysisHost;
-use syster::ide::completions;
+use syster::ide::{CompletionKind, MatchOptions, completions};
 
 // =============================================================================
 // COMPLETION - INCOMPLETE SYNTAX (REAL TYPING SCENARIOS)
@@ -189,6 +189,42 @@ fn test_completion_has_correct_kind() {
     );
 }
 
+// =============================================================================
+// COMPLETION - DETAIL AND DOCUMENTATION
+// =============================================================================
+
+#[test]
+fn test_completion_item_detail_and_documentation() {
+    let source = r#"
+        package Vehicles {
+            part def Engine;
+            // The main power unit
+            part engine : Engine;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let items = completions(analysis.symbol_index(), file_id, 0, 0, None);
+
+    let engine = items
+        .iter()
+        .find(|i| i.label.as_ref() == "engine")
+        .expect("should offer the engine usage");
+    // Detail shows the kind and the resolved type
+    assert_eq!(engine.detail.as_deref(), Some("Part: Vehicles::Engine"));
+    assert_eq!(engine.documentation.as_deref(), Some("The main power unit"));
+
+    // Keywords carry no symbol context
+    let keyword = items
+        .iter()
+        .find(|i| i.kind == CompletionKind::Keyword)
+        .expect("should offer keywords");
+    assert_eq!(keyword.detail, None);
+    assert_eq!(keyword.documentation, None);
+}
+
 // =============================================================================
 // COMPLETION - EDGE CASES
 // =============================================================================
@@ -220,3 +256,211 @@ fn test_completion_at_file_end() {
     // Should not crash
     let _ = items;
 }
+
+// =============================================================================
+// COMPLETION - PREFIX MATCHING
+// =============================================================================
+
+/// Labels of completions matching the identifier typed at the end of `last_line`.
+fn matching_labels(last_line: &str, options: MatchOptions) -> Vec<String> {
+    let source = format!("part def engine;\npart def \u{e9}clair;\npart def Other;\n{last_line}");
+    // The last line is incomplete, so parse errors are expected
+    let mut host = AnalysisHost::new();
+    let _errors = host.set_file_content("test.sysml", &source);
+    let analysis = host.analysis();
+    let file_id = analysis.get_file_id("test.sysml").unwrap();
+
+    analysis
+        .completions_matching(file_id, 3, last_line.len() as u32, None, options)
+        .into_iter()
+        .map(|item| item.label.to_string())
+        .collect()
+}
+
+#[test]
+fn test_completion_prefix_case_sensitive_by_default() {
+    let labels = matching_labels("part p : ENG", MatchOptions::default());
+    assert!(!labels.contains(&"engine".to_string()), "Got: {:?}", labels);
+
+    let labels = matching_labels("part p : eng", MatchOptions::default());
+    assert_eq!(labels, vec!["engine"]);
+}
+
+#[test]
+fn test_completion_prefix_case_insensitive() {
+    let options = MatchOptions {
+        case_insensitive: true,
+        ..MatchOptions::default()
+    };
+    let labels = matching_labels("part p : ENG", options);
+    assert!(labels.contains(&"engine".to_string()), "Got: {:?}", labels);
+    assert!(!labels.contains(&"Other".to_string()), "Got: {:?}", labels);
+}
+
+#[test]
+fn test_completion_prefix_after_non_ascii_text() {
+    // The column counts bytes, so it lands after the multi-byte characters
+    let labels = matching_labels("part \u{e9}\u{e9} : Ot", MatchOptions::default());
+    assert_eq!(labels, vec!["Other"]);
+}
+
+#[test]
+fn test_completion_prefix_ignores_diacritics() {
+    let labels = matching_labels("part p : e", MatchOptions::default());
+    assert!(
+        !labels.contains(&"\u{e9}clair".to_string()),
+        "Got: {:?}",
+        labels
+    );
+
+    let options = MatchOptions {
+        ignore_diacritics: true,
+        ..MatchOptions::default()
+    };
+    let labels = matching_labels("part p : e", options);
+    assert!(
+        labels.contains(&"\u{e9}clair".to_string()),
+        "Got: {:?}",
+        labels
+    );
+    assert!(labels.contains(&"engine".to_string()), "Got: {:?}", labels);
+}
+
+// =============================================================================
+// COMPLETION - IMPORT PATHS
+// =============================================================================
+
+const IMPORT_LIBRARY: &str = r#"
+package ISQ {
+    attribute def LengthValue;
+    attribute def MassValue;
+    package SpaceTime;
+}
+package Interfaces;
+package Vehicles {
+    part def Car;
+}
+"#;
+
+/// Completions in a file whose last line is `typed`, with the cursor at its end.
+fn import_completion_labels(typed: &str) -> Vec<(String, CompletionKind)> {
+    let source = format!("{}package User {{\n{}", IMPORT_LIBRARY, typed);
+    let mut host = AnalysisHost::new();
+    let _errors = host.set_file_content("test.sysml", &source);
+    let analysis = host.analysis();
+    let file_id = analysis.get_file_id("test.sysml").unwrap();
+
+    let line = source.lines().count() as u32 - 1;
+    analysis
+        .completions(file_id, line, typed.len() as u32, None)
+        .into_iter()
+        .map(|item| (item.label.to_string(), item.kind))
+        .collect()
+}
+
+#[test]
+fn test_import_completion_offers_namespace_members() {
+    let items = import_completion_labels("    import ISQ::");
+    let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+
+    assert_eq!(labels, ["LengthValue", "MassValue", "SpaceTime"]);
+    assert!(items.contains(&("SpaceTime".to_string(), CompletionKind::Package)));
+}
+
+#[test]
+fn test_import_completion_offers_root_packages() {
+    let items = import_completion_labels("    import I");
+    let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+
+    assert!(labels.contains(&"ISQ"), "Got: {:?}", labels);
+    assert!(labels.contains(&"Interfaces"), "Got: {:?}", labels);
+    assert!(labels.contains(&"Vehicles"), "Got: {:?}", labels);
+    // Only packages, no keywords or nested members
+    assert!(!labels.contains(&"LengthValue"), "Got: {:?}", labels);
+    assert!(!labels.contains(&"part def"), "Got: {:?}", labels);
+    assert!(
+        items
+            .iter()
+            .all(|(_, kind)| *kind == CompletionKind::Package)
+    );
+}
+
+#[test]
+fn test_import_completion_nothing_after_wildcard() {
+    let items = import_completion_labels("    import ISQ::*");
+
+    assert!(items.is_empty(), "Got: {:?}", items);
+}
+
+// =============================================================================
+// COMPLETION - EXPECTED KIND
+// =============================================================================
+
+const KIND_SOURCE: &str = r#"package Vehicles {
+    part def Engine;
+    attribute def Mass;
+    part engine : Engine;
+    attribute mass : Mass;
+}
+package User {
+"#;
+
+/// Completions in `KIND_SOURCE` followed by a last line `typed`, with the
+/// cursor at its end.
+fn kind_completion_items(typed: &str) -> Vec<(String, CompletionKind)> {
+    let source = format!("{}{}", KIND_SOURCE, typed);
+    let mut host = AnalysisHost::new();
+    let _errors = host.set_file_content("test.sysml", &source);
+    let analysis = host.analysis();
+    let file_id = analysis.get_file_id("test.sysml").unwrap();
+
+    let line = source.lines().count() as u32 - 1;
+    analysis
+        .completions(file_id, line, typed.len() as u32, None)
+        .into_iter()
+        .map(|item| (item.label.to_string(), item.kind))
+        .collect()
+}
+
+#[test]
+fn test_completion_after_colon_offers_only_definitions() {
+    for typed in ["    part x : ", "    part x : Eng", "    attribute m :"] {
+        let items = kind_completion_items(typed);
+        let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+
+        assert!(labels.contains(&"Engine"), "{typed:?} got: {labels:?}");
+        assert!(labels.contains(&"Mass"), "{typed:?} got: {labels:?}");
+        assert!(labels.contains(&"Vehicles"), "{typed:?} got: {labels:?}");
+        assert!(!labels.contains(&"engine"), "{typed:?} got: {labels:?}");
+        assert!(!labels.contains(&"mass"), "{typed:?} got: {labels:?}");
+        assert!(
+            items.iter().all(|(_, kind)| matches!(
+                kind,
+                CompletionKind::Definition | CompletionKind::Package
+            )),
+            "{typed:?} got: {items:?}"
+        );
+    }
+}
+
+#[test]
+fn test_completion_after_specializes_in_definition_offers_only_definitions() {
+    let items = kind_completion_items("    part def SportsEngine :> ");
+    let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+
+    assert!(labels.contains(&"Engine"), "Got: {:?}", labels);
+    assert!(!labels.contains(&"engine"), "Got: {:?}", labels);
+}
+
+#[test]
+fn test_completion_in_ambiguous_context_offers_all_symbols() {
+    // `:>>` redefines a feature, and a bare line could start anything
+    for typed in ["    part x :>> ", "    "] {
+        let items = kind_completion_items(typed);
+        let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+
+        assert!(labels.contains(&"Engine"), "{typed:?} got: {labels:?}");
+        assert!(labels.contains(&"engine"), "{typed:?} got: {labels:?}");
+        assert!(labels.contains(&"part def"), "{typed:?} got: {labels:?}");
+    }
+}
@@ -0,0 +1,40 @@
+//! Document links tests for the IDE layer.
+
+use crate::helpers::hir_helpers::*;
+use syster::ide::document_links;
+
+// =============================================================================
+// DOCUMENT LINKS - IMPORTS
+// =============================================================================
+
+/// Tooltips of the links in a file that defines `Outer` and imports it with `import_path`.
+fn import_link_tooltips(import_path: &str) -> Vec<String> {
+    let source = format!(
+        "package Outer {{\n    package Inner {{\n        part def Nested;\n    }}\n}}\npackage Consumer {{\n    import {import_path};\n}}\n"
+    );
+    let (mut host, file_id) = analysis_from_sysml(&source);
+    let analysis = host.analysis();
+
+    document_links(analysis.symbol_index(), file_id)
+        .into_iter()
+        .map(|link| link.tooltip.into_owned())
+        .collect()
+}
+
+#[test]
+fn test_document_link_for_wildcard_import() {
+    assert_eq!(import_link_tooltips("Outer::*"), vec!["Go to Outer"]);
+}
+
+#[test]
+fn test_document_link_for_recursive_import() {
+    assert_eq!(import_link_tooltips("Outer::**"), vec!["Go to Outer"]);
+}
+
+#[test]
+fn test_document_link_for_member_import() {
+    assert_eq!(
+        import_link_tooltips("Outer::Inner::Nested"),
+        vec!["Go to Outer::Inner::Nested"]
+    );
+}
@@ -1,7 +1,7 @@
 //! Folding ranges tests for the IDE layer.
 
 use crate::helpers::hir_helpers::*;
-use syster::ide::folding_ranges;
+use syster::ide::{FoldingOptions, folding_ranges};
 
 // =============================================================================
 // FOLDING RANGES - BASIC
@@ -130,3 +130,44 @@ fn test_folding_ranges_multiple_definitions() {
         assert!(range.end_line >= range.start_line);
     }
 }
+
+// =============================================================================
+// FOLDING RANGES - OPTIONS
+// =============================================================================
+
+#[test]
+fn test_folding_range_ends_at_last_content_line() {
+    let source = "package Pkg {\n    part def Vehicle;\n\n}\n";
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let full = analysis.folding_ranges_with_options(file_id, FoldingOptions::default());
+    assert_eq!(full.len(), 1, "Got: {:?}", full);
+    assert_eq!(full[0].end_line, 3);
+
+    let options = FoldingOptions {
+        end_at_last_content_line: true,
+    };
+    let trimmed = analysis.folding_ranges_with_options(file_id, options);
+    assert_eq!(trimmed.len(), 1, "Got: {:?}", trimmed);
+    assert_eq!((trimmed[0].start_line, trimmed[0].end_line), (0, 1));
+    assert_eq!(trimmed[0].end_col, 21);
+}
+
+#[test]
+fn test_folding_range_end_col_counts_bytes() {
+    let source = "package Pkg {\n    part def Véhicule; // 🚗\n}\n";
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let options = FoldingOptions {
+        end_at_last_content_line: true,
+    };
+    let ranges = analysis.folding_ranges_with_options(file_id, options);
+    assert_eq!(ranges.len(), 1, "Got: {:?}", ranges);
+    let line = "    part def Véhicule; // 🚗";
+    // Columns are byte offsets, like the symbols' own columns
+    assert_eq!(ranges[0].end_col, line.len() as u32);
+}
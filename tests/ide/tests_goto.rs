@@ -133,3 +133,148 @@ fn test_goto_definition_empty_position() {
     // Should not crash
     let _ = result;
 }
+
+// =============================================================================
+// GOTO DEFINITION - QUALIFIED PATH SEGMENTS
+// =============================================================================
+
+const QUALIFIED_PATH_SOURCE: &str = r#"
+package ISQ {
+    package SpaceTime {
+        attribute def length;
+    }
+}
+part def Box {
+    attribute width : ISQ::SpaceTime::length;
+}
+"#;
+
+/// Goto from a column on line 7 (`    attribute width : ISQ::SpaceTime::length;`).
+fn goto_on_path(col: u32) -> Option<(String, u32)> {
+    let (mut host, file_id) = analysis_from_sysml(QUALIFIED_PATH_SOURCE);
+    let analysis = host.analysis();
+    let result = goto_definition(analysis.symbol_index(), file_id, 7, col);
+    result
+        .targets
+        .first()
+        .map(|t| (t.name.to_string(), t.start_line))
+}
+
+#[test]
+fn test_goto_definition_middle_path_segment() {
+    let line = QUALIFIED_PATH_SOURCE.lines().nth(7).unwrap();
+    let col = line.find("SpaceTime").unwrap() as u32 + 3;
+
+    let (name, start_line) = goto_on_path(col).expect("should find SpaceTime");
+    assert_eq!(name, "SpaceTime");
+    assert_eq!(start_line, 2);
+}
+
+#[test]
+fn test_goto_definition_first_path_segment() {
+    let line = QUALIFIED_PATH_SOURCE.lines().nth(7).unwrap();
+    let col = line.find("ISQ").unwrap() as u32 + 1;
+
+    let (name, start_line) = goto_on_path(col).expect("should find ISQ");
+    assert_eq!(name, "ISQ");
+    assert_eq!(start_line, 1);
+}
+
+#[test]
+fn test_goto_definition_last_path_segment() {
+    let line = QUALIFIED_PATH_SOURCE.lines().nth(7).unwrap();
+    let col = line.find("length").unwrap() as u32 + 2;
+
+    let (name, start_line) = goto_on_path(col).expect("should find length");
+    assert_eq!(name, "length");
+    assert_eq!(start_line, 3);
+}
+
+// =============================================================================
+// GOTO DEFINITION - SHORT NAMES
+// =============================================================================
+
+const SHORT_NAME_SOURCE: &str = r#"
+package Physics {
+    attribute def Real;
+    part def Body {
+        attribute <m> mass : Real;
+        attribute weight = m * 9.81;
+        attribute heavy :> m;
+    }
+    part rock : Body {
+        attribute :>> m;
+    }
+    alias M for Body::m;
+    attribute massRef : Real = Body::m;
+}
+"#;
+
+/// Goto definition on `m` in the given 0-indexed line of `SHORT_NAME_SOURCE`.
+fn goto_short_name(line: u32, needle: &str) -> Option<(String, u32)> {
+    let text = SHORT_NAME_SOURCE.lines().nth(line as usize).unwrap();
+    let col = text.find(needle).unwrap() as u32 + needle.len() as u32 - 1;
+
+    let (mut host, file_id) = analysis_from_sysml(SHORT_NAME_SOURCE);
+    let analysis = host.analysis();
+    let result = goto_definition(analysis.symbol_index(), file_id, line, col);
+    result
+        .targets
+        .first()
+        .map(|t| (t.name.to_string(), t.start_line))
+}
+
+#[test]
+fn test_goto_definition_short_name_in_expression() {
+    assert_eq!(goto_short_name(5, "= m"), Some(("mass".to_string(), 4)));
+}
+
+#[test]
+fn test_goto_definition_short_name_in_subsetting() {
+    assert_eq!(goto_short_name(6, ":> m"), Some(("mass".to_string(), 4)));
+}
+
+#[test]
+fn test_goto_definition_short_name_in_redefinition() {
+    assert_eq!(goto_short_name(9, ":>> m"), Some(("mass".to_string(), 4)));
+}
+
+#[test]
+fn test_goto_definition_short_name_in_qualified_path() {
+    assert_eq!(
+        goto_short_name(11, "Body::m"),
+        Some(("mass".to_string(), 4))
+    );
+    assert_eq!(
+        goto_short_name(12, "Body::m"),
+        Some(("mass".to_string(), 4))
+    );
+}
+
+// =============================================================================
+// GOTO DEFINITION - DECLARATION RANGES
+// =============================================================================
+
+#[test]
+fn test_goto_definition_returns_name_and_declaration_ranges() {
+    let source = "part def Engine {\n    attribute power;\n}\npart engine : Engine;\n";
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let result = analysis.goto_definition(file_id, 3, 15);
+
+    assert_eq!(result.targets.len(), 1);
+    let target = &result.targets[0];
+    assert_eq!(target.name.as_ref(), "Engine");
+
+    // The selection range is the name, and matches the point fields
+    let selection = target.selection_range;
+    assert_eq!((selection.start.line, selection.start.col), (0, 9));
+    assert_eq!((selection.end.line, selection.end.col), (0, 15));
+    assert_eq!((target.start_line, target.start_col), (0, 9));
+
+    // The full range spans the declaration through its closing brace
+    let full = target.full_range;
+    assert_eq!((full.start.line, full.start.col), (0, 0));
+    assert_eq!((full.end.line, full.end.col), (2, 1));
+}
@@ -87,6 +87,31 @@ fn test_hover_on_definition_is_definition_flag() {
     }
 }
 
+#[test]
+fn test_hover_on_documented_part_def_shows_doc() {
+    let source = r#"
+        doc /* A means of transport. */
+        part def Vehicle;
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let hover = hover(analysis.symbol_index(), file_id, 2, 20).unwrap();
+
+    let (signature, body) = hover
+        .contents
+        .split_once("\n---\n")
+        .expect("Doc should follow the signature");
+    assert!(signature.starts_with("```sysml\nPart def Vehicle"));
+    assert!(signature.ends_with("\n```\n"));
+    assert!(
+        body.contains("A means of transport."),
+        "Got: {}",
+        hover.contents
+    );
+}
+
 // =============================================================================
 // HOVER ON USAGES
 // =============================================================================
@@ -113,6 +138,73 @@ fn test_hover_on_usage_shows_type() {
     );
 }
 
+#[test]
+fn test_hover_on_usage_shows_resolved_type() {
+    let source = r#"
+        package Defs {
+            part def Engine;
+        }
+        package Cars {
+            private import Defs::*;
+            part engine : Engine;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    // Hover on "engine"
+    let hover = hover(analysis.symbol_index(), file_id, 6, 18).unwrap();
+
+    assert!(
+        hover
+            .contents
+            .starts_with("```sysml\nPart engine : Defs::Engine\n```"),
+        "Hover should show the qualified type. Got: {}",
+        hover.contents
+    );
+}
+
+#[test]
+fn test_hover_on_untyped_subsetting_usage_shows_no_type() {
+    let source = r#"
+        part def Car {
+            part wheels;
+            part frontWheels :> wheels;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    // Hover on "frontWheels"
+    let hover = hover(analysis.symbol_index(), file_id, 3, 20).unwrap();
+
+    assert!(
+        hover
+            .contents
+            .starts_with("```sysml\nPart frontWheels\n```"),
+        "Subsetted feature is not a type. Got: {}",
+        hover.contents
+    );
+}
+
+#[test]
+fn test_hover_on_usage_unresolved_type_shows_raw_name() {
+    let source = "part engine : Missing;";
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let hover = hover(analysis.symbol_index(), file_id, 0, 7).unwrap();
+
+    assert!(
+        hover.contents.contains("Part engine : Missing\n"),
+        "Got: {}",
+        hover.contents
+    );
+}
+
 // =============================================================================
 // HOVER POSITION TESTS
 // =============================================================================
@@ -183,3 +275,203 @@ fn test_hover_shows_specialization() {
         );
     }
 }
+
+#[test]
+fn test_hover_lists_relationships_with_verbs() {
+    let source = r#"
+        part def Vehicle {
+            part wheels;
+            part engine;
+        }
+        part def Car :> Vehicle {
+            part frontWheels :> wheels :>> engine;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    // Hover on "frontWheels" (line 6)
+    let hover = hover(analysis.symbol_index(), file_id, 6, 20).unwrap();
+
+    assert!(
+        hover.contents.contains("subsets wheels; redefines engine"),
+        "Hover should list each relationship with its verb. Got: {}",
+        hover.contents
+    );
+}
+
+// =============================================================================
+// HOVER ON IMPORTS
+// =============================================================================
+
+#[test]
+fn test_hover_on_wildcard_import_lists_members() {
+    let source = r#"
+        package Lib {
+            part def Engine;
+            part def Wheel;
+            attribute def Mass;
+        }
+        package Car {
+            import Lib::*;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    // Hover on "Lib::*" (line 7)
+    let hover = hover(analysis.symbol_index(), file_id, 7, 21).expect("hover on import");
+
+    for name in ["Engine", "Wheel", "Mass"] {
+        assert!(
+            hover.contents.contains(&format!("- `{}`", name)),
+            "Import hover should list {}. Got: {}",
+            name,
+            hover.contents
+        );
+    }
+    assert!(
+        hover.contents.contains("`Lib` (3)"),
+        "Got: {}",
+        hover.contents
+    );
+    assert_eq!(hover.qualified_name.as_deref(), Some("Lib"));
+}
+
+#[test]
+fn test_hover_on_wildcard_import_marks_reexports() {
+    let source = r#"
+        package Base {
+            part def Frame;
+        }
+        package Lib {
+            public import Base::*;
+            part def Engine;
+        }
+        package Car {
+            import Lib::*;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let hover = hover(analysis.symbol_index(), file_id, 9, 21).expect("hover on import");
+
+    assert!(
+        hover.contents.contains("- `Engine`\n"),
+        "Got: {}",
+        hover.contents
+    );
+    assert!(
+        hover
+            .contents
+            .contains("- `Frame` *(re-exported: `Base::Frame`)*"),
+        "Got: {}",
+        hover.contents
+    );
+}
+
+#[test]
+fn test_hover_on_wildcard_import_skips_inherited_members() {
+    let source = r#"
+        part def Base {
+            part frame;
+        }
+        part def Vehicle :> Base {
+            part engine;
+        }
+        package Car {
+            import Vehicle::*;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let hover = hover(analysis.symbol_index(), file_id, 8, 21).expect("hover on import");
+
+    assert!(
+        hover.contents.contains("- `engine`"),
+        "Got: {}",
+        hover.contents
+    );
+    assert!(
+        !hover.contents.contains("`frame`"),
+        "Inherited members are not imported. Got: {}",
+        hover.contents
+    );
+    assert!(
+        hover.contents.contains("`Vehicle` (1)"),
+        "Got: {}",
+        hover.contents
+    );
+}
+
+#[test]
+fn test_hover_on_recursive_import_lists_nested_members() {
+    let source = r#"
+        package Lib {
+            part def Engine {
+                part piston;
+            }
+            package Parts {
+                part def Wheel;
+            }
+        }
+        package Car {
+            import Lib::**;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let hover = hover(analysis.symbol_index(), file_id, 10, 21).expect("hover on import");
+
+    for name in ["Engine", "Parts", "piston", "Wheel"] {
+        assert!(
+            hover.contents.contains(&format!("- `{}`", name)),
+            "Recursive import hover should list {}. Got: {}",
+            name,
+            hover.contents
+        );
+    }
+    assert!(
+        hover.contents.contains("`Lib` (4)"),
+        "Got: {}",
+        hover.contents
+    );
+}
+
+#[test]
+fn test_hover_on_wildcard_import_caps_long_lists() {
+    let members: String = (0..25).map(|i| format!("part def P{:02};\n", i)).collect();
+    let source = format!(
+        "package Lib {{\n{}}}\npackage Car {{\nimport Lib::*;\n}}\n",
+        members
+    );
+
+    let (mut host, file_id) = analysis_from_sysml(&source);
+    let analysis = host.analysis();
+
+    let hover = hover(analysis.symbol_index(), file_id, 28, 8).expect("hover on import");
+
+    assert!(
+        hover.contents.contains("- `P19`"),
+        "Got: {}",
+        hover.contents
+    );
+    assert!(
+        !hover.contents.contains("- `P20`"),
+        "Got: {}",
+        hover.contents
+    );
+    assert!(
+        hover.contents.contains("- +5 more"),
+        "Got: {}",
+        hover.contents
+    );
+}
@@ -0,0 +1,110 @@
+//! Inlay hint tests for the IDE layer.
+
+use crate::helpers::hir_helpers::*;
+use syster::ide::{InlayHintKind, InlayHintOptions, inlay_hints, inlay_hints_with_options};
+
+// =============================================================================
+// MULTIPLICITY HINTS
+// =============================================================================
+
+#[test]
+fn test_multiplicity_hint_for_usage() {
+    let source = r#"
+        part def Car {
+            part wheels[4];
+            part passengers[0..*];
+            part engine;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let hints: Vec<_> = inlay_hints_with_options(
+        analysis.symbol_index(),
+        file_id,
+        None,
+        InlayHintOptions {
+            show_multiplicity: true,
+        },
+    )
+    .into_iter()
+    .filter(|h| h.kind == InlayHintKind::Multiplicity)
+    .collect();
+    let labels: Vec<_> = hints.iter().map(|h| h.label.as_str()).collect();
+
+    assert_eq!(labels, ["[4]", "[0..*]"]);
+    // Placed right after `wheels`
+    assert_eq!((hints[0].line, hints[0].col), (2, 23));
+}
+
+#[test]
+fn test_multiplicity_hints_off_by_default() {
+    let source = r#"
+        part def Car {
+            part wheels[4];
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let hints = inlay_hints(analysis.symbol_index(), file_id, None);
+
+    assert!(
+        hints.iter().all(|h| h.kind != InlayHintKind::Multiplicity),
+        "Got: {:?}",
+        hints
+    );
+}
+
+// =============================================================================
+// PARAMETER HINTS
+// =============================================================================
+
+const INVOCATION_SOURCE: &str = r#"
+        package M {
+            calc def ComputeBsfc { in rpm : Real; in load : Real; return : Real; }
+            part def Engine {
+                attribute r;
+                attribute l;
+                attribute bsfc = ComputeBsfc(r, l);
+                attribute named = ComputeBsfc(rpm = r, load = l);
+            }
+        }
+    "#;
+
+#[test]
+fn test_parameter_hints_for_positional_arguments() {
+    let (mut host, file_id) = analysis_from_sysml(INVOCATION_SOURCE);
+    let analysis = host.analysis();
+
+    let hints: Vec<_> = inlay_hints(analysis.symbol_index(), file_id, None)
+        .into_iter()
+        .filter(|h| h.kind == InlayHintKind::Parameter)
+        .collect();
+    let labels: Vec<_> = hints.iter().map(|h| h.label.as_str()).collect();
+
+    assert_eq!(labels, ["rpm:", "load:"]);
+    // Placed before `r` and `l`
+    assert_eq!((hints[0].line, hints[0].col), (6, 45));
+    assert_eq!((hints[1].line, hints[1].col), (6, 48));
+    assert!(hints.iter().all(|h| h.padding_right));
+}
+
+#[test]
+fn test_parameter_hints_skip_named_arguments() {
+    let (mut host, file_id) = analysis_from_sysml(INVOCATION_SOURCE);
+    let analysis = host.analysis();
+
+    let hints = inlay_hints(analysis.symbol_index(), file_id, None);
+
+    assert!(
+        hints
+            .iter()
+            .filter(|h| h.kind == InlayHintKind::Parameter)
+            .all(|h| h.line != 7),
+        "Got: {:?}",
+        hints
+    );
+}
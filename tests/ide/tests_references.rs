@@ -1,7 +1,11 @@
 //! Find references tests for the IDE layer.
 
+use std::collections::HashMap;
+
 use crate::helpers::hir_helpers::*;
-use syster::ide::find_references;
+use syster::base::{FileId, LineColSpan};
+use syster::hir::SymbolIndex;
+use syster::ide::{find_references, find_references_with_cancel};
 
 // =============================================================================
 // FIND REFERENCES - BASIC
@@ -50,6 +54,45 @@ fn test_find_references_include_declaration() {
     );
 }
 
+#[test]
+fn test_find_references_declaration_toggle() {
+    let source = r#"
+        part def Vehicle;
+        part car : Vehicle;
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    // Cursor on the `Vehicle` definition
+    let with_decl = find_references(analysis.symbol_index(), file_id, 1, 18, true);
+    let declarations: Vec<_> = with_decl
+        .references
+        .iter()
+        .filter(|r| r.is_declaration())
+        .collect();
+    assert_eq!(declarations.len(), 1, "Got: {:?}", with_decl.references);
+    assert_eq!(
+        (declarations[0].start_line, declarations[0].start_col),
+        (1, 17)
+    );
+
+    let without_decl = find_references(analysis.symbol_index(), file_id, 1, 18, false);
+    assert!(
+        without_decl.references.iter().all(|r| !r.is_declaration()),
+        "Got: {:?}",
+        without_decl.references
+    );
+    // The reference in `part car : Vehicle` remains
+    assert!(
+        without_decl
+            .references
+            .iter()
+            .any(|r| (r.start_line, r.start_col) == (2, 19))
+    );
+    assert_eq!(without_decl.len(), with_decl.len() - 1);
+}
+
 #[test]
 fn test_find_references_from_usage() {
     let source = r#"
@@ -128,3 +171,139 @@ fn test_find_references_cross_file() {
         "Should find references across files"
     );
 }
+
+// =============================================================================
+// REFERENCE INDEX
+// =============================================================================
+
+/// Reference sites per target, found by scanning every symbol's type refs.
+fn scan_references(index: &SymbolIndex) -> HashMap<String, Vec<(FileId, LineColSpan)>> {
+    let mut sites: HashMap<String, Vec<(FileId, LineColSpan)>> = HashMap::new();
+    for sym in index.all_symbols() {
+        for tr in sym.type_refs.iter().flat_map(|trk| trk.as_refs()) {
+            sites
+                .entry(tr.effective_target().to_string())
+                .or_default()
+                .push((sym.file, tr.span()));
+        }
+    }
+    for list in sites.values_mut() {
+        list.sort_by_key(|(file, span)| (file.0, span.start.line, span.start.col));
+    }
+    sites
+}
+
+/// Reference sites per target, looked up in the index's reverse map.
+fn indexed_references(
+    index: &SymbolIndex,
+    targets: impl Iterator<Item = String>,
+) -> HashMap<String, Vec<(FileId, LineColSpan)>> {
+    targets
+        .map(|target| {
+            let mut list: Vec<_> = index
+                .references_to(&target)
+                .iter()
+                .map(|site| (site.file, site.span))
+                .collect();
+            list.sort_by_key(|(file, span)| (file.0, span.start.line, span.start.col));
+            (target, list)
+        })
+        .collect()
+}
+
+const REFERENCE_INDEX_FILES: [(&str, &str); 3] = [
+    (
+        "base.sysml",
+        "package Base {\n    part def Vehicle;\n    part def Engine;\n    attribute def Mass;\n}\n",
+    ),
+    (
+        "car.sysml",
+        "package Cars {\n    import Base::*;\n    part def Car :> Vehicle {\n        part engine : Engine;\n        attribute mass : Mass;\n    }\n}\n",
+    ),
+    (
+        "fleet.sysml",
+        "package Fleet {\n    import Base::*;\n    import Cars::*;\n    part car : Car;\n    part truck : Vehicle {\n        part engine : Engine;\n    }\n}\n",
+    ),
+];
+
+#[test]
+fn test_reference_index_matches_scan() {
+    let mut host = analysis_from_sources(&REFERENCE_INDEX_FILES);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+
+    let scanned = scan_references(index);
+    assert_eq!(scanned["Base::Vehicle"].len(), 2);
+    assert_eq!(scanned["Base::Engine"].len(), 2);
+    assert_eq!(indexed_references(index, scanned.keys().cloned()), scanned);
+    assert!(index.references_to("Base::Missing").is_empty());
+}
+
+#[test]
+fn test_reference_index_updates_on_file_change() {
+    let mut host = analysis_from_sources(&REFERENCE_INDEX_FILES);
+    let fleet = host.analysis().get_file_id("fleet.sysml").unwrap();
+
+    // Drop the truck, which referenced Vehicle and Engine
+    host.set_file_content(
+        "fleet.sysml",
+        "package Fleet {\n    import Cars::*;\n    part car : Car;\n}\n",
+    );
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+
+    let scanned = scan_references(index);
+    assert_eq!(indexed_references(index, scanned.keys().cloned()), scanned);
+    assert_eq!(index.references_to("Base::Vehicle").len(), 1);
+    assert!(
+        index
+            .references_to("Base::Engine")
+            .iter()
+            .all(|site| site.file != fleet)
+    );
+}
+
+#[test]
+fn test_reference_index_refreshes_only_resolved_files() {
+    let mut host = analysis_from_sources(&REFERENCE_INDEX_FILES);
+    let analysis = host.analysis();
+    let car = analysis.get_file_id("car.sysml").unwrap();
+    let fleet = analysis.get_file_id("fleet.sysml").unwrap();
+    let mut index = analysis.symbol_index().clone();
+
+    index.resolve_type_refs_for_files(&[fleet]);
+
+    let scanned = scan_references(&index);
+    assert_eq!(indexed_references(&index, scanned.keys().cloned()), scanned);
+    let mut expected = vec![car, fleet];
+    expected.sort_unstable();
+    let targets = ["Base::Engine".into()].into_iter().collect();
+    assert_eq!(index.files_referencing(&targets), expected);
+}
+
+// =============================================================================
+// CANCELLATION
+// =============================================================================
+
+#[test]
+fn test_find_references_with_cancel() {
+    let source = r#"
+        part def Vehicle;
+        part car : Vehicle;
+        part truck : Vehicle;
+    "#;
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+
+    let cancelled = find_references_with_cancel(index, file_id, 1, 18, true, &|| true);
+    assert!(cancelled.is_none(), "Cancelled query should return None");
+
+    let result = find_references_with_cancel(index, file_id, 1, 18, true, &|| false)
+        .expect("Uncancelled query should complete");
+    assert_eq!(result.len(), 3);
+    assert_eq!(
+        result.len(),
+        find_references(index, file_id, 1, 18, true).len()
+    );
+}
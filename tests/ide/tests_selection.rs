@@ -0,0 +1,87 @@
+//! Selection range tests for the IDE layer.
+
+use crate::helpers::hir_helpers::*;
+
+// =============================================================================
+// SELECTION RANGES
+// =============================================================================
+
+const NESTED_SOURCE: &str = r#"package P {
+    part def Car {
+        part engine : Engine;
+    }
+}"#;
+
+fn span(range: &syster::ide::SelectionRange) -> (u32, u32, u32, u32) {
+    (
+        range.start_line,
+        range.start_col,
+        range.end_line,
+        range.end_col,
+    )
+}
+
+#[test]
+fn test_selection_ranges_chain_to_parents() {
+    let (mut host, file_id) = analysis_from_sysml(NESTED_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor inside `engine`
+    let ranges = analysis.selection_ranges(file_id, 2, 15);
+    let spans: Vec<_> = ranges.iter().map(span).collect();
+
+    assert_eq!(
+        spans,
+        [
+            (2, 13, 2, 19), // engine
+            (2, 8, 2, 29),  // part engine : Engine;
+            (1, 4, 3, 5),   // part def Car { ... }
+            (0, 0, 4, 1),   // package P { ... }
+        ]
+    );
+}
+
+#[test]
+fn test_selection_ranges_strictly_nest() {
+    let (mut host, file_id) = analysis_from_sysml(NESTED_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on the type reference `Engine`
+    let ranges = analysis.selection_ranges(file_id, 2, 24);
+    assert_eq!(span(&ranges[0]), (2, 22, 2, 28));
+
+    for pair in ranges.windows(2) {
+        let (inner, outer) = (span(&pair[0]), span(&pair[1]));
+        assert_ne!(inner, outer, "Ranges should be deduplicated");
+        assert!(
+            (outer.0, outer.1) <= (inner.0, inner.1) && (outer.2, outer.3) >= (inner.2, inner.3),
+            "{:?} should contain {:?}",
+            outer,
+            inner
+        );
+    }
+}
+
+#[test]
+fn test_selection_ranges_outside_identifier() {
+    let (mut host, file_id) = analysis_from_sysml(NESTED_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on the whitespace before `part engine`
+    let ranges = analysis.selection_ranges(file_id, 2, 2);
+    let spans: Vec<_> = ranges.iter().map(span).collect();
+
+    assert_eq!(spans, [(1, 4, 3, 5), (0, 0, 4, 1)]);
+}
+
+#[test]
+fn test_selection_ranges_without_syntax_uses_symbol_spans() {
+    let (mut host, file_id) = analysis_from_sysml(NESTED_SOURCE);
+    let analysis = host.analysis();
+
+    // Without a syntax tree only the symbol name spans are available
+    let ranges = syster::ide::selection_ranges(analysis.symbol_index(), file_id, 2, 15);
+    let spans: Vec<_> = ranges.iter().map(span).collect();
+
+    assert_eq!(spans, [(2, 13, 2, 19)]);
+}
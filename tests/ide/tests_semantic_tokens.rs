@@ -1,7 +1,7 @@
 //! Semantic tokens tests for the IDE layer.
 
 use crate::helpers::hir_helpers::*;
-use syster::ide::{TokenType, semantic_tokens};
+use syster::ide::{TokenModifier, TokenType, semantic_tokens, semantic_tokens_lsp};
 
 // =============================================================================
 // SEMANTIC TOKENS - BASIC
@@ -211,3 +211,106 @@ fn test_debug_vehicle_usages_semantic_tokens() {
 
     println!("\n=== END DEBUG ===");
 }
+
+// =============================================================================
+// SEMANTIC TOKENS - MODIFIERS
+// =============================================================================
+
+#[test]
+fn test_semantic_tokens_definition_and_reference_modifiers() {
+    let source = r#"
+        part def Engine;
+        part engine : Engine;
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let tokens = semantic_tokens(analysis.symbol_index(), file_id);
+
+    // `Engine` in `part def Engine`
+    let def_name = tokens
+        .iter()
+        .find(|t| t.line == 1 && t.col == 17)
+        .expect("Should have a token for the definition name");
+    assert!(def_name.has_modifier(TokenModifier::Declaration));
+    assert!(def_name.has_modifier(TokenModifier::Definition));
+    assert!(!def_name.has_modifier(TokenModifier::Abstract));
+
+    // `Engine` in `part engine : Engine`
+    let type_ref = tokens
+        .iter()
+        .find(|t| t.line == 2 && t.col == 22)
+        .expect("Should have a token for the type reference");
+    assert_eq!(type_ref.token_type, TokenType::Type);
+    assert_eq!(type_ref.modifiers, 0);
+}
+
+#[test]
+fn test_semantic_tokens_abstract_and_readonly_modifiers() {
+    let source = r#"
+        abstract part def Vehicle {
+            readonly attribute mass;
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let tokens = semantic_tokens(analysis.symbol_index(), file_id);
+
+    let vehicle = tokens.iter().find(|t| t.line == 1).unwrap();
+    assert!(vehicle.has_modifier(TokenModifier::Abstract));
+    assert!(!vehicle.has_modifier(TokenModifier::Readonly));
+
+    let mass = tokens.iter().find(|t| t.line == 2).unwrap();
+    assert!(mass.has_modifier(TokenModifier::Readonly));
+    assert!(!mass.has_modifier(TokenModifier::Abstract));
+}
+
+// =============================================================================
+// SEMANTIC TOKENS - LSP ENCODING
+// =============================================================================
+
+#[test]
+fn test_semantic_tokens_lsp_delta_encoding() {
+    let source = r#"package P {
+    part def Engine;
+    part engine : Engine;
+}"#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let tokens = semantic_tokens(analysis.symbol_index(), file_id);
+    let data = semantic_tokens_lsp(analysis.symbol_index(), file_id);
+    assert_eq!(data.len(), tokens.len() * 5);
+
+    // First token is absolute: `P` at (0, 8)
+    assert_eq!(data[..2], [0, 8]);
+
+    // `engine` and `Engine` share line 2, so the second encodes a column delta
+    let line2: Vec<_> = data.chunks(5).skip(2).take(2).collect();
+    assert_eq!(line2[0][..2], [1, 9]);
+    assert_eq!(line2[1][..2], [0, 9]);
+
+    // Decoding the deltas gives back the absolute tokens
+    let (mut line, mut col) = (0, 0);
+    for (chunk, token) in data.chunks(5).zip(&tokens) {
+        if chunk[0] > 0 {
+            col = 0;
+        }
+        line += chunk[0];
+        col += chunk[1];
+        assert_eq!(
+            (line, col, chunk[2], chunk[3], chunk[4]),
+            (
+                token.line,
+                token.col,
+                token.length,
+                token.token_type.to_lsp_index(),
+                token.modifiers
+            )
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Signature help tests for the IDE layer.
+
+use crate::helpers::hir_helpers::*;
+use syster::ide::signature_help;
+
+// =============================================================================
+// SIGNATURE HELP
+// =============================================================================
+
+const CALC_SOURCE: &str = r#"
+        package M {
+            calc def Area { in width : Real; in height : Real; return : Real; }
+            calc def Zero { return : Real; }
+            part def Box {
+                attribute a = Area(1, 2);
+                attribute b = Area(Zero(), 3);
+            }
+        }
+    "#;
+
+#[test]
+fn test_signature_help_active_parameter_advances_after_comma() {
+    let (mut host, file_id) = analysis_from_sysml(CALC_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on `1` in `Area(1, 2)`
+    let first = signature_help(analysis.symbol_index(), file_id, 5, 35)
+        .expect("Should have signature help inside the argument list");
+    assert_eq!(first.label, "Area(in width : Real, in height : Real)");
+    assert_eq!(first.qualified_name.as_ref(), "M::Area");
+    let params: Vec<_> = first.parameters.iter().map(|p| p.name.as_ref()).collect();
+    assert_eq!(params, ["width", "height"]);
+    assert_eq!(first.active_parameter, 0);
+
+    // Cursor on `2`, after the comma
+    let second = signature_help(analysis.symbol_index(), file_id, 5, 38).unwrap();
+    assert_eq!(second.active_parameter, 1);
+}
+
+#[test]
+fn test_signature_help_innermost_invocation() {
+    let (mut host, file_id) = analysis_from_sysml(CALC_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor inside `Zero()`, which has no parameters
+    let inner = signature_help(analysis.symbol_index(), file_id, 6, 40).unwrap();
+    assert_eq!(inner.qualified_name.as_ref(), "M::Zero");
+    assert_eq!(inner.label, "Zero()");
+    assert!(inner.parameters.is_empty());
+    assert_eq!(inner.active_parameter, 0);
+
+    // Cursor on `3`, back in the outer invocation
+    let outer = signature_help(analysis.symbol_index(), file_id, 6, 43).unwrap();
+    assert_eq!(outer.qualified_name.as_ref(), "M::Area");
+    assert_eq!(outer.active_parameter, 1);
+}
+
+#[test]
+fn test_signature_help_outside_invocation() {
+    let (mut host, file_id) = analysis_from_sysml(CALC_SOURCE);
+    let analysis = host.analysis();
+
+    // Cursor on the invoked name, before the argument list
+    assert!(signature_help(analysis.symbol_index(), file_id, 5, 31).is_none());
+    // Cursor on a definition
+    assert!(signature_help(analysis.symbol_index(), file_id, 2, 22).is_none());
+}
@@ -1,7 +1,9 @@
 //! Document and workspace symbols tests for the IDE layer.
 
+use std::cell::Cell;
+
 use crate::helpers::hir_helpers::*;
-use syster::ide::{document_symbols, workspace_symbols};
+use syster::ide::{document_symbols, workspace_symbols, workspace_symbols_with_cancel};
 
 // =============================================================================
 // DOCUMENT SYMBOLS
@@ -29,6 +31,48 @@ fn test_document_symbols_returns_all_symbols() {
     );
 }
 
+#[test]
+fn test_document_symbols_detail() {
+    let source = r#"
+        package Pkg {
+            part def Engine;
+            part def Car {
+                part engine : Engine [1];
+                part spare : Engine;
+                part untyped;
+            }
+        }
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+    let symbols = document_symbols(analysis.symbol_index(), file_id);
+    let detail = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name.as_ref() == name)
+            .unwrap_or_else(|| panic!("missing symbol {name}"))
+            .detail
+            .clone()
+    };
+
+    assert_eq!(detail("engine").as_deref(), Some(": Pkg::Engine [1]"));
+    assert_eq!(detail("spare").as_deref(), Some(": Pkg::Engine"));
+    assert_eq!(detail("Engine").as_deref(), Some("part def"));
+    assert_eq!(detail("untyped"), None);
+    assert_eq!(detail("Pkg"), None);
+}
+
+#[test]
+fn test_document_symbols_detail_unresolved_type() {
+    let (mut host, file_id) = analysis_from_sysml("part wheel : Missing [4];");
+    let analysis = host.analysis();
+    let symbols = document_symbols(analysis.symbol_index(), file_id);
+
+    let wheel = symbols.iter().find(|s| s.name.as_ref() == "wheel").unwrap();
+    assert_eq!(wheel.detail.as_deref(), Some(": Missing [4]"));
+}
+
 #[test]
 fn test_document_symbols_has_correct_names() {
     let source = r#"
@@ -90,6 +134,57 @@ fn test_document_symbols_has_position() {
     }
 }
 
+#[test]
+fn test_document_symbols_in_source_order() {
+    let source = r#"
+        package Fleet {
+            part def Wheel;
+            part def Vehicle {
+                part frontWheel : Wheel;
+                part rearWheel : Wheel;
+            }
+            attribute def Mass;
+            part car : Vehicle;
+        }
+        part def Trailer;
+    "#;
+
+    let (mut host, file_id) = analysis_from_sysml(source);
+    let analysis = host.analysis();
+
+    let symbols = document_symbols(analysis.symbol_index(), file_id);
+    let names: Vec<_> = symbols.iter().map(|s| s.name.as_ref()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "Fleet",
+            "Wheel",
+            "Vehicle",
+            "frontWheel",
+            "rearWheel",
+            "Mass",
+            "car",
+            "Trailer"
+        ]
+    );
+
+    let positions: Vec<_> = symbols
+        .iter()
+        .map(|s| (s.start_line, s.start_col))
+        .collect();
+    let mut sorted = positions.clone();
+    sorted.sort();
+    assert_eq!(positions, sorted);
+
+    // Repeated calls return the same order
+    let again: Vec<_> = document_symbols(analysis.symbol_index(), file_id)
+        .into_iter()
+        .map(|s| s.qualified_name)
+        .collect();
+    let first: Vec<_> = symbols.into_iter().map(|s| s.qualified_name).collect();
+    assert_eq!(again, first);
+}
+
 #[test]
 fn test_document_symbols_empty_file() {
     let source = "";
@@ -169,3 +264,55 @@ fn test_workspace_symbols_no_match() {
         "Should find no symbols for non-matching query"
     );
 }
+
+#[test]
+fn test_workspace_symbols_container_names() {
+    let mut host = analysis_from_sources(&[
+        ("a.sysml", "package Engines { attribute value : Real; }"),
+        ("b.sysml", "package Wheels { attribute value : Real; }"),
+    ]);
+    let analysis = host.analysis();
+
+    let mut containers: Vec<Option<String>> =
+        workspace_symbols(analysis.symbol_index(), Some("value"))
+            .into_iter()
+            .filter(|s| s.name.as_ref() == "value")
+            .map(|s| s.container_name)
+            .collect();
+    containers.sort();
+
+    assert_eq!(
+        containers,
+        vec![Some("Engines".to_string()), Some("Wheels".to_string())]
+    );
+}
+
+// =============================================================================
+// CANCELLATION
+// =============================================================================
+
+#[test]
+fn test_workspace_symbols_cancelled_mid_scan() {
+    let source: String = (0..5_000).map(|i| format!("part def D{i};\n")).collect();
+    let (mut host, _) = analysis_from_sysml(&source);
+    let analysis = host.analysis();
+
+    // Cancel once part of the index has been scanned
+    let polls = Cell::new(0);
+    let is_cancelled = || {
+        polls.set(polls.get() + 1);
+        polls.get() > 100
+    };
+    let result = workspace_symbols_with_cancel(analysis.symbol_index(), None, &is_cancelled);
+
+    assert!(result.is_none(), "Cancelled scan should return None");
+    assert_eq!(polls.get(), 101, "Scan should stop at the first true poll");
+
+    // Without cancellation the full result matches the plain query
+    let all = workspace_symbols_with_cancel(analysis.symbol_index(), None, &|| false).unwrap();
+    assert_eq!(
+        all.len(),
+        workspace_symbols(analysis.symbol_index(), None).len()
+    );
+    assert!(all.len() >= 5_000);
+}
@@ -3,3 +3,6 @@
 // These tests used the old pest parser AST types which have been replaced by rowan.
 // mod tests_kerml_ast;  // pest parser tests
 // mod tests_sysml_ast;  // pest parser tests
+mod tests_normalized;
+mod tests_parse_elements;
+mod tests_recovery;
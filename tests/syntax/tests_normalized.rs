@@ -0,0 +1,110 @@
+//! Normalized element tests
+//!
+//! These tests verify that KerML members normalize to KerML-specific kinds
+//! with their specialization, typing and subsetting relationships.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use syster::parser::{AstNode, SourceFile, parse_kerml};
+use syster::syntax::{
+    KerMLNormalizedIter, NormalizedDefKind, NormalizedDefinition, NormalizedElement,
+    NormalizedRelKind, NormalizedUsage, NormalizedUsageKind,
+};
+
+fn normalize_kerml(source: &str) -> Vec<NormalizedElement> {
+    let parsed = parse_kerml(source);
+    let root = SourceFile::cast(parsed.syntax()).unwrap();
+    KerMLNormalizedIter::new(&root).collect()
+}
+
+fn definition<'a>(elements: &'a [NormalizedElement], name: &str) -> &'a NormalizedDefinition {
+    elements
+        .iter()
+        .find_map(|e| match e {
+            NormalizedElement::Definition(d) if d.name.as_deref() == Some(name) => Some(d),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("definition {name} not found"))
+}
+
+fn usage<'a>(elements: &'a [NormalizedElement], name: &str) -> &'a NormalizedUsage {
+    elements
+        .iter()
+        .find_map(|e| match e {
+            NormalizedElement::Usage(u) if u.name.as_deref() == Some(name) => Some(u),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("usage {name} not found"))
+}
+
+fn relationships(
+    rels: &[syster::syntax::NormalizedRelationship],
+) -> Vec<(NormalizedRelKind, String)> {
+    rels.iter()
+        .map(|r| (r.kind, r.target.as_str().into_owned()))
+        .collect()
+}
+
+#[test]
+fn test_kerml_definition_kinds() {
+    let elements = normalize_kerml(
+        "classifier C;\nclass K;\nstruct S;\ndatatype D;\nassoc A;\n\
+         behavior B;\nfunction F;\npredicate P;\ninteraction I;\nmetaclass M;\n",
+    );
+
+    let expected = [
+        ("C", NormalizedDefKind::Classifier),
+        ("K", NormalizedDefKind::Class),
+        ("S", NormalizedDefKind::Structure),
+        ("D", NormalizedDefKind::DataType),
+        ("A", NormalizedDefKind::Association),
+        ("B", NormalizedDefKind::Behavior),
+        ("F", NormalizedDefKind::Function),
+        ("P", NormalizedDefKind::Predicate),
+        ("I", NormalizedDefKind::Interaction),
+        ("M", NormalizedDefKind::Metaclass),
+    ];
+    for (name, kind) in expected {
+        assert_eq!(definition(&elements, name).kind, kind, "kind of {name}");
+    }
+}
+
+#[test]
+fn test_kerml_classifier_with_features() {
+    let elements = normalize_kerml(
+        r#"classifier Vehicle;
+classifier Car specializes Vehicle {
+    feature wheels : Wheel;
+    feature frontWheels : Wheel subsets wheels;
+}
+classifier Wheel;
+"#,
+    );
+
+    let car = definition(&elements, "Car");
+    assert_eq!(car.kind, NormalizedDefKind::Classifier);
+    assert_eq!(
+        relationships(&car.relationships),
+        vec![(NormalizedRelKind::Specializes, "Vehicle".to_string())]
+    );
+
+    let wheels = usage(&car.children, "wheels");
+    assert_eq!(wheels.kind, NormalizedUsageKind::Feature);
+    assert_eq!(
+        relationships(&wheels.relationships),
+        vec![(NormalizedRelKind::TypedBy, "Wheel".to_string())]
+    );
+
+    let front = usage(&car.children, "frontWheels");
+    assert_eq!(front.kind, NormalizedUsageKind::Feature);
+    let rels = relationships(&front.relationships);
+    assert!(
+        rels.contains(&(NormalizedRelKind::TypedBy, "Wheel".to_string())),
+        "{rels:?}"
+    );
+    assert!(
+        rels.contains(&(NormalizedRelKind::Subsets, "wheels".to_string())),
+        "{rels:?}"
+    );
+}
@@ -0,0 +1,60 @@
+//! Streaming parse tests
+//!
+//! These tests verify that `parse_elements` yields each top-level element in
+//! source order, with errors positioned in the whole file.
+
+use std::path::Path;
+use syster::parser::{NamespaceMember, Package};
+use syster::syntax::parse_elements;
+
+fn package_name(package: &Package) -> Option<String> {
+    package.name().and_then(|name| name.text())
+}
+
+#[test]
+fn test_parse_elements_yields_packages_in_order() {
+    let source =
+        "package A {\n    part def X;\n}\n\n// Second\npackage B;\npackage C { doc /* C */ }\n";
+
+    let elements: Vec<_> = parse_elements(source, Path::new("test.sysml")).collect();
+
+    assert_eq!(elements.len(), 3);
+    let mut names = Vec::new();
+    for element in elements {
+        let element = element.expect("element should parse");
+        let Some(NamespaceMember::Package(package)) = element.member() else {
+            panic!("expected a package, got {:?}", element.member());
+        };
+        names.push((package_name(&package), element.line));
+    }
+    // Each element's text starts right after the previous element
+    assert_eq!(
+        names,
+        [
+            (Some("A".to_string()), 0),
+            (Some("B".to_string()), 2),
+            (Some("C".to_string()), 5)
+        ]
+    );
+}
+
+#[test]
+fn test_parse_elements_positions_errors_in_source() {
+    let source = "package A;\npackage B { part def 123; }\npackage C;\n";
+
+    let elements: Vec<_> = parse_elements(source, Path::new("test.sysml")).collect();
+
+    assert_eq!(elements.len(), 3);
+    assert!(elements[0].is_ok());
+    let error = elements[1].as_ref().expect_err("B has a syntax error");
+    assert_eq!((error.line, error.column), (1, 21));
+    assert!(elements[2].is_ok());
+}
+
+#[test]
+fn test_parse_elements_rejects_unknown_extension() {
+    let elements: Vec<_> = parse_elements("package P;", Path::new("test.txt")).collect();
+
+    assert_eq!(elements.len(), 1);
+    assert!(elements[0].is_err());
+}
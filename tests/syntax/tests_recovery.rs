@@ -0,0 +1,43 @@
+//! Parse error recovery tests
+//!
+//! These tests verify that a malformed member doesn't discard the rest of the file.
+
+use std::path::Path;
+use syster::base::{FileId, Span};
+use syster::hir::extract_symbols_unified;
+use syster::syntax::{ParseDiagnostic, parse_with_recovery};
+
+#[test]
+fn test_parse_with_recovery_keeps_valid_declarations() {
+    let source = "package P {\n    part def A;\n    part def 123 ;;;\n    part def C;\n}\n";
+
+    let (syntax, diagnostics) = parse_with_recovery(source, Path::new("test.sysml"));
+    let syntax = syntax.expect("recovered tree should be returned");
+
+    // The malformed member is on line 2, columns 13..16
+    assert!(!diagnostics.is_empty());
+    assert_eq!(diagnostics[0].span, Span::from_coords(2, 13, 2, 16));
+
+    let symbols = extract_symbols_unified(FileId(1), &syntax);
+    let names: Vec<&str> = symbols.iter().map(|s| s.qualified_name.as_ref()).collect();
+    assert!(names.contains(&"P::A"), "{names:?}");
+    assert!(names.contains(&"P::C"), "{names:?}");
+}
+
+#[test]
+fn test_parse_with_recovery_unclosed_body_points_at_eof() {
+    let source = "package P {\n    part def A;\n";
+
+    let (syntax, diagnostics) = parse_with_recovery(source, Path::new("test.sysml"));
+    assert!(syntax.is_some());
+    let diagnostics: &[ParseDiagnostic] = &diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].span, Span::from_coords(2, 0, 2, 0));
+}
+
+#[test]
+fn test_parse_with_recovery_rejects_unknown_extension() {
+    let (syntax, diagnostics) = parse_with_recovery("package P;", Path::new("test.txt"));
+    assert!(syntax.is_none());
+    assert_eq!(diagnostics.len(), 1);
+}
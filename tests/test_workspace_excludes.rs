@@ -0,0 +1,76 @@
+//! Tests for exclude patterns and `.gitignore` handling when loading a workspace.
+
+use std::fs;
+use std::path::Path;
+use syster::ide::AnalysisHost;
+use syster::project::WorkspaceLoader;
+use tempfile::TempDir;
+
+/// Creates `main.sysml`, `models/base.sysml` and `build/generated.sysml`.
+fn create_workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join("models")).unwrap();
+    fs::create_dir(root.join("build")).unwrap();
+    fs::write(root.join("main.sysml"), "package Main;").unwrap();
+    fs::write(root.join("models/base.sysml"), "package Base;").unwrap();
+    fs::write(root.join("build/generated.sysml"), "package Generated;").unwrap();
+    temp_dir
+}
+
+fn is_loaded(host: &AnalysisHost, path: &Path) -> bool {
+    host.has_file_path(path)
+}
+
+#[test]
+fn test_load_directory_excludes_build_glob() {
+    let workspace = create_workspace();
+    let root = workspace.path();
+
+    let mut host = AnalysisHost::new();
+    WorkspaceLoader::new()
+        .load_directory_into_host(root, &mut host, vec!["build/**".to_string()], false)
+        .expect("Should load workspace");
+
+    assert!(is_loaded(&host, &root.join("main.sysml")));
+    assert!(is_loaded(&host, &root.join("models/base.sysml")));
+    assert!(!is_loaded(&host, &root.join("build/generated.sysml")));
+}
+
+#[test]
+fn test_load_directory_without_excludes_loads_everything() {
+    let workspace = create_workspace();
+    let root = workspace.path();
+
+    let mut host = AnalysisHost::new();
+    WorkspaceLoader::new()
+        .load_directory_into_host(root, &mut host, Vec::new(), false)
+        .expect("Should load workspace");
+
+    assert!(is_loaded(&host, &root.join("build/generated.sysml")));
+    assert_eq!(host.file_count(), 3);
+}
+
+#[test]
+fn test_load_directory_respects_gitignore() {
+    let workspace = create_workspace();
+    let root = workspace.path();
+    fs::write(root.join(".gitignore"), "# build output\nbuild/\n").unwrap();
+
+    let mut host = AnalysisHost::new();
+    WorkspaceLoader::new()
+        .load_directory_into_host(root, &mut host, Vec::new(), false)
+        .expect("Should load workspace");
+    assert!(
+        is_loaded(&host, &root.join("build/generated.sysml")),
+        ".gitignore should be ignored unless requested"
+    );
+
+    let mut host = AnalysisHost::new();
+    WorkspaceLoader::new()
+        .load_directory_into_host(root, &mut host, Vec::new(), true)
+        .expect("Should load workspace");
+    assert!(is_loaded(&host, &root.join("main.sysml")));
+    assert!(is_loaded(&host, &root.join("models/base.sysml")));
+    assert!(!is_loaded(&host, &root.join("build/generated.sysml")));
+}
@@ -45,7 +45,7 @@ package TestWorkspace {
 
     // Load SysML files
     loader
-        .load_directory_into_host(workspace_path, &mut host)
+        .load_directory_into_host(workspace_path, &mut host, Vec::new(), false)
         .expect("Should load SysML files");
 
     // Load metadata files
@@ -111,7 +111,7 @@ package NoMetadata {
     let loader = WorkspaceLoader::new();
 
     loader
-        .load_directory_into_host(workspace_path, &mut host)
+        .load_directory_into_host(workspace_path, &mut host, Vec::new(), false)
         .expect("Should load SysML files");
 
     // This should not fail even without metadata files
@@ -161,7 +161,7 @@ package MyPackage {
     let loader = WorkspaceLoader::new();
 
     loader
-        .load_directory_into_host(workspace_path, &mut host)
+        .load_directory_into_host(workspace_path, &mut host, Vec::new(), false)
         .unwrap();
     loader
         .load_metadata_from_directory(workspace_path, &mut host)
@@ -0,0 +1,95 @@
+//! Tests for parallel workspace loading with a configurable thread budget.
+
+use std::fs;
+use syster::ide::AnalysisHost;
+use syster::project::{LoadOptions, WorkspaceLoader};
+use tempfile::TempDir;
+
+const FILE_COUNT: usize = 12;
+
+/// Creates `FILE_COUNT` SysML files spread over two directories.
+fn create_workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join("nested")).unwrap();
+    for i in 0..FILE_COUNT {
+        let dir = if i % 2 == 0 {
+            root.to_path_buf()
+        } else {
+            root.join("nested")
+        };
+        fs::write(
+            dir.join(format!("model{i}.sysml")),
+            format!("package P{i} {{ part def D{i}; }}"),
+        )
+        .unwrap();
+    }
+    temp_dir
+}
+
+#[test]
+fn test_parallel_load_is_complete_for_any_thread_count() {
+    let workspace = create_workspace();
+    let root = workspace.path();
+
+    for max_threads in [None, Some(1), Some(2), Some(8)] {
+        let mut host = AnalysisHost::new();
+        WorkspaceLoader::new()
+            .load_directory_into_host_with_options(
+                root,
+                &mut host,
+                &LoadOptions {
+                    max_threads,
+                    ..LoadOptions::default()
+                },
+            )
+            .expect("Should load workspace");
+
+        assert_eq!(host.file_count(), FILE_COUNT, "threads: {max_threads:?}");
+        for i in 0..FILE_COUNT {
+            let dir = if i % 2 == 0 {
+                root.to_path_buf()
+            } else {
+                root.join("nested")
+            };
+            assert!(
+                host.has_file_path(&dir.join(format!("model{i}.sysml"))),
+                "model{i}.sysml missing with threads: {max_threads:?}"
+            );
+        }
+
+        let analysis = host.analysis();
+        assert!(analysis.symbol_index().lookup_qualified("P7::D7").is_some());
+    }
+}
+
+#[test]
+fn test_parallel_load_errors_are_sorted_by_path() {
+    let workspace = create_workspace();
+    let root = workspace.path();
+    // Invalid UTF-8 makes these files unreadable as text
+    fs::write(root.join("z_bad.sysml"), [0xff, 0xfe]).unwrap();
+    fs::write(root.join("a_bad.sysml"), [0xff, 0xfe]).unwrap();
+
+    for max_threads in [Some(1), Some(4)] {
+        let mut host = AnalysisHost::new();
+        let err = WorkspaceLoader::new()
+            .load_directory_into_host_with_options(
+                root,
+                &mut host,
+                &LoadOptions {
+                    max_threads,
+                    ..LoadOptions::default()
+                },
+            )
+            .expect_err("Unreadable files should be reported");
+
+        assert!(err.starts_with("Failed to load 2 file(s)"), "{err}");
+        let a = err.find("a_bad.sysml").unwrap();
+        let z = err.find("z_bad.sysml").unwrap();
+        assert!(a < z, "errors should be sorted by path: {err}");
+
+        // Readable files are still loaded
+        assert_eq!(host.file_count(), FILE_COUNT);
+    }
+}
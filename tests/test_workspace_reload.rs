@@ -0,0 +1,112 @@
+//! Tests for re-scanning a workspace with the same loader.
+
+use std::fs;
+use syster::ide::AnalysisHost;
+use syster::project::WorkspaceLoader;
+use tempfile::TempDir;
+
+fn load(loader: &WorkspaceLoader, root: &std::path::Path, host: &mut AnalysisHost) {
+    loader
+        .load_directory_into_host(root, host, Vec::new(), false)
+        .expect("Should load workspace");
+}
+
+#[test]
+fn test_reload_picks_up_edits_and_keeps_unchanged_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(root.join("a.sysml"), "package A { part def Old; }").unwrap();
+    fs::write(root.join("b.sysml"), "package B { part def Stable; }").unwrap();
+
+    let loader = WorkspaceLoader::new();
+    let mut host = AnalysisHost::new();
+    load(&loader, root, &mut host);
+
+    fs::write(root.join("a.sysml"), "package A { part def New; }").unwrap();
+    load(&loader, root, &mut host);
+
+    assert_eq!(host.file_count(), 2);
+    let analysis = host.analysis();
+    let index = analysis.symbol_index();
+    assert!(index.lookup_qualified("A::New").is_some());
+    assert!(index.lookup_qualified("A::Old").is_none());
+    assert!(index.lookup_qualified("B::Stable").is_some());
+}
+
+#[test]
+fn test_reload_into_fresh_host_parses_unchanged_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(root.join("a.sysml"), "package A { part def D; }").unwrap();
+
+    let loader = WorkspaceLoader::new();
+    let mut first = AnalysisHost::new();
+    load(&loader, root, &mut first);
+
+    // Contents are unchanged, but this host has never seen the file
+    let mut second = AnalysisHost::new();
+    load(&loader, root, &mut second);
+
+    assert!(second.has_file_path(&root.join("a.sysml")));
+    assert!(
+        second
+            .analysis()
+            .symbol_index()
+            .lookup_qualified("A::D")
+            .is_some()
+    );
+}
+
+#[test]
+fn test_load_detects_kerml_content_in_sysml_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let path = root.join("shapes.sysml");
+    fs::write(&path, "package Shapes {\n    classifier Shape;\n}").unwrap();
+
+    let loader = WorkspaceLoader::new();
+    let mut host = AnalysisHost::new();
+    load(&loader, root, &mut host);
+
+    let file = &host.files()[&path];
+    assert!(file.is_kerml(), "KerML content should be parsed as KerML");
+    assert!(!file.has_errors(), "errors: {:?}", file.errors());
+    assert!(
+        host.analysis()
+            .symbol_index()
+            .lookup_qualified("Shapes::Shape")
+            .is_some()
+    );
+}
+
+#[test]
+fn test_editing_kerml_content_in_sysml_file_keeps_symbols() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let path = root.join("shapes.sysml");
+    let content = "package Shapes {\n    classifier Shape;\n    feature area;\n}";
+    fs::write(&path, content).unwrap();
+
+    let loader = WorkspaceLoader::new();
+    let mut host = AnalysisHost::new();
+    load(&loader, root, &mut host);
+
+    let symbols = |host: &mut AnalysisHost| -> Vec<String> {
+        let analysis = host.analysis();
+        let file = analysis.get_file_id(path.to_str().unwrap()).unwrap();
+        analysis
+            .symbol_index()
+            .symbols_in_file(file)
+            .iter()
+            .map(|sym| format!("{} {:?}", sym.qualified_name, sym.kind))
+            .collect()
+    };
+    let loaded = symbols(&mut host);
+    assert!(loaded.iter().any(|s| s.starts_with("Shapes::Shape ")));
+
+    // Editing through the host picks the same grammar as loading from disk
+    let errors = host.set_file_content(path.to_str().unwrap(), content);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+    assert!(host.files()[&path].is_kerml());
+    assert_eq!(symbols(&mut host), loaded);
+}
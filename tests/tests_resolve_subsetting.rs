@@ -20,46 +20,12 @@
 use std::sync::Arc;
 use syster::base::FileId;
 use syster::hir::SymbolIndex;
-use syster::hir::{HirSymbol, SymbolKind, TypeRefKind, new_element_id};
+use syster::hir::{HirSymbol, SymbolKind, TypeRefKind};
 
 fn make_symbol(name: &str, qualified: &str, kind: SymbolKind, supertypes: Vec<&str>) -> HirSymbol {
-    HirSymbol {
-        name: Arc::from(name),
-        short_name: None,
-        qualified_name: Arc::from(qualified),
-        element_id: new_element_id(),
-        kind,
-        file: FileId::new(0),
-        start_line: 0,
-        start_col: 0,
-        end_line: 0,
-        end_col: 0,
-        short_name_start_line: None,
-        short_name_start_col: None,
-        short_name_end_line: None,
-        short_name_end_col: None,
-        doc: None,
-        supertypes: supertypes.into_iter().map(Arc::from).collect(),
-        relationships: Vec::new(),
-        type_refs: Vec::new(),
-        is_public: false,
-        view_data: None,
-        metadata_annotations: Vec::new(),
-        is_abstract: false,
-        is_variation: false,
-        is_readonly: false,
-        is_derived: false,
-        is_parallel: false,
-        is_individual: false,
-        is_end: false,
-        is_default: false,
-        is_ordered: false,
-        is_nonunique: false,
-        is_portion: false,
-        direction: None,
-        multiplicity: None,
-        value: None,
-    }
+    HirSymbol::builder(name, qualified, kind, FileId::new(0))
+        .supertypes(supertypes.into_iter().map(Arc::from).collect())
+        .build()
 }
 
 #[allow(dead_code)]
@@ -70,43 +36,10 @@ fn make_symbol_with_type_refs(
     supertypes: Vec<&str>,
     type_refs: Vec<TypeRefKind>,
 ) -> HirSymbol {
-    HirSymbol {
-        name: Arc::from(name),
-        short_name: None,
-        qualified_name: Arc::from(qualified),
-        element_id: new_element_id(),
-        kind,
-        file: FileId::new(0),
-        start_line: 0,
-        start_col: 0,
-        end_line: 0,
-        end_col: 0,
-        short_name_start_line: None,
-        short_name_start_col: None,
-        short_name_end_line: None,
-        short_name_end_col: None,
-        doc: None,
-        supertypes: supertypes.into_iter().map(Arc::from).collect(),
-        relationships: Vec::new(),
-        type_refs,
-        is_public: false,
-        view_data: None,
-        metadata_annotations: Vec::new(),
-        is_abstract: false,
-        is_variation: false,
-        is_readonly: false,
-        is_derived: false,
-        is_parallel: false,
-        is_individual: false,
-        is_end: false,
-        is_default: false,
-        is_ordered: false,
-        is_nonunique: false,
-        is_portion: false,
-        direction: None,
-        multiplicity: None,
-        value: None,
-    }
+    HirSymbol::builder(name, qualified, kind, FileId::new(0))
+        .supertypes(supertypes.into_iter().map(Arc::from).collect())
+        .type_refs(type_refs)
+        .build()
 }
 
 /// Test basic subsetting: when `tfe :> edges`, we can find `edges` members on `tfe`
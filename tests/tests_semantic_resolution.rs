@@ -7,7 +7,7 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
-use syster::hir::{ResolveResult, Resolver, SymbolIndex, check_file, new_element_id};
+use syster::hir::{ResolveResult, Resolver, SymbolIndex, check_file};
 use syster::ide::AnalysisHost;
 
 fn get_examples_dir() -> PathBuf {
@@ -648,7 +648,7 @@ fn test_short_name_visibility_via_import() {
     // package User { import SI::*; attribute mass = 800[kg]; }
     // In User, "kg" should resolve to "SI::kilogram"
 
-    use syster::base::FileId;
+    use syster::base::{FileId, LineColSpan};
     use syster::hir::{HirSymbol, ResolveResult, SymbolKind};
 
     let mut index = SymbolIndex::new();
@@ -657,80 +657,20 @@ fn test_short_name_visibility_via_import() {
     index.add_file(
         FileId::new(0),
         vec![
-            HirSymbol {
-                name: Arc::from("SI"),
-                qualified_name: Arc::from("SI"),
-                element_id: new_element_id(),
-                kind: SymbolKind::Package,
-                file: FileId::new(0),
-                start_line: 0,
-                start_col: 0,
-                end_line: 0,
-                end_col: 0,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![],
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
-            HirSymbol {
-                name: Arc::from("kilogram"),
-                short_name: Some(Arc::from("kg")), // <-- This is the alias!
-                qualified_name: Arc::from("SI::kilogram"),
-                element_id: new_element_id(),
-                kind: SymbolKind::AttributeUsage,
-                file: FileId::new(0),
-                start_line: 1,
-                start_col: 0,
-                end_line: 1,
-                end_col: 10,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![Arc::from("MassUnit")],
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder("SI", "SI", SymbolKind::Package, FileId::new(0))
+                .is_public(true)
+                .build(),
+            HirSymbol::builder(
+                "kilogram",
+                "SI::kilogram",
+                SymbolKind::AttributeUsage,
+                FileId::new(0),
+            )
+            .short_name(Some("kg")) // <-- This is the alias!
+            .span(LineColSpan::from_coords(1, 0, 1, 10))
+            .supertypes(vec![Arc::from("MassUnit")])
+            .is_public(true)
+            .build(),
         ],
     );
 
@@ -738,80 +678,18 @@ fn test_short_name_visibility_via_import() {
     index.add_file(
         FileId::new(1),
         vec![
-            HirSymbol {
-                name: Arc::from("User"),
-                qualified_name: Arc::from("User"),
-                element_id: new_element_id(),
-                kind: SymbolKind::Package,
-                file: FileId::new(1),
-                start_line: 0,
-                start_col: 0,
-                end_line: 0,
-                end_col: 0,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![],
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
-            HirSymbol {
-                name: Arc::from("SI::*"),
-                qualified_name: Arc::from("User::import:SI::*"),
-                element_id: new_element_id(),
-                kind: SymbolKind::Import,
-                file: FileId::new(1),
-                start_line: 1,
-                start_col: 0,
-                end_line: 1,
-                end_col: 10,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![],
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder("User", "User", SymbolKind::Package, FileId::new(1))
+                .is_public(true)
+                .build(),
+            HirSymbol::builder(
+                "SI::*",
+                "User::import:SI::*",
+                SymbolKind::Import,
+                FileId::new(1),
+            )
+            .span(LineColSpan::from_coords(1, 0, 1, 10))
+            .is_public(true)
+            .build(),
         ],
     );
 
@@ -878,7 +756,7 @@ fn test_short_name_visibility_via_import() {
 /// should be visible inside transportPassenger.
 #[test]
 fn test_usage_inherits_type_members() {
-    use syster::base::FileId;
+    use syster::base::{FileId, LineColSpan};
     use syster::hir::{HirSymbol, ResolveResult, SymbolKind};
 
     let mut index = SymbolIndex::new();
@@ -895,271 +773,78 @@ fn test_usage_inherits_type_members() {
         FileId::new(0),
         vec![
             // Package
-            HirSymbol {
-                name: Arc::from("MissionContext"),
-                qualified_name: Arc::from("MissionContext"),
-                element_id: new_element_id(),
-                kind: SymbolKind::Package,
-                file: FileId::new(0),
-                start_line: 0,
-                start_col: 0,
-                end_line: 0,
-                end_col: 0,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![],
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder(
+                "MissionContext",
+                "MissionContext",
+                SymbolKind::Package,
+                FileId::new(0),
+            )
+            .is_public(true)
+            .build(),
             // Definition: TransportPassenger
-            HirSymbol {
-                name: Arc::from("TransportPassenger"),
-                qualified_name: Arc::from("MissionContext::TransportPassenger"),
-                element_id: new_element_id(),
-                kind: SymbolKind::UseCaseDefinition,
-                file: FileId::new(0),
-                start_line: 1,
-                start_col: 0,
-                end_line: 5,
-                end_col: 0,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![],
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder(
+                "TransportPassenger",
+                "MissionContext::TransportPassenger",
+                SymbolKind::UseCaseDefinition,
+                FileId::new(0),
+            )
+            .span(LineColSpan::from_coords(1, 0, 5, 0))
+            .is_public(true)
+            .build(),
             // Member of definition: getInVehicle_a
-            HirSymbol {
-                name: Arc::from("getInVehicle_a"),
-                qualified_name: Arc::from("MissionContext::TransportPassenger::getInVehicle_a"),
-                element_id: new_element_id(),
-                kind: SymbolKind::ActionUsage, // usage inside definition
-                file: FileId::new(0),
-                start_line: 2,
-                start_col: 4,
-                end_line: 2,
-                end_col: 30,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![Arc::from("getInVehicle")],
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder(
+                "getInVehicle_a",
+                "MissionContext::TransportPassenger::getInVehicle_a",
+                SymbolKind::ActionUsage,
+                FileId::new(0),
+            )
+            .span(LineColSpan::from_coords(2, 4, 2, 30))
+            .supertypes(vec![Arc::from("getInVehicle")])
+            .is_public(true)
+            .build(),
             // Usage: transportPassenger : TransportPassenger
-            HirSymbol {
-                name: Arc::from("transportPassenger"),
-                qualified_name: Arc::from("MissionContext::transportPassenger"),
-                element_id: new_element_id(),
-                kind: SymbolKind::ActionUsage, // usage
-                file: FileId::new(0),
-                start_line: 10,
-                start_col: 0,
-                end_line: 20,
-                end_col: 0,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![Arc::from("TransportPassenger")], // typed by TransportPassenger
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder(
+                "transportPassenger",
+                "MissionContext::transportPassenger",
+                SymbolKind::ActionUsage,
+                FileId::new(0),
+            )
+            .span(LineColSpan::from_coords(10, 0, 20, 0))
+            .supertypes(vec![Arc::from("TransportPassenger")]) // typed by TransportPassenger
+            .is_public(true)
+            .build(),
             // Nested member: driverGetInVehicle (references getInVehicle_a)
-            HirSymbol {
-                name: Arc::from("driverGetInVehicle"),
-                qualified_name: Arc::from("MissionContext::transportPassenger::driverGetInVehicle"),
-                element_id: new_element_id(),
-                kind: SymbolKind::ActionUsage,
-                file: FileId::new(0),
-                start_line: 11,
-                start_col: 4,
-                end_line: 11,
-                end_col: 50,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![Arc::from("getInVehicle_a")], // subsets getInVehicle_a
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder(
+                "driverGetInVehicle",
+                "MissionContext::transportPassenger::driverGetInVehicle",
+                SymbolKind::ActionUsage,
+                FileId::new(0),
+            )
+            .span(LineColSpan::from_coords(11, 4, 11, 50))
+            .supertypes(vec![Arc::from("getInVehicle_a")]) // subsets getInVehicle_a
+            .is_public(true)
+            .build(),
             // Nested action 'a' inside transportPassenger (no type annotation)
-            HirSymbol {
-                name: Arc::from("a"),
-                qualified_name: Arc::from("MissionContext::transportPassenger::a"),
-                element_id: new_element_id(),
-                kind: SymbolKind::ActionUsage,
-                file: FileId::new(0),
-                start_line: 12,
-                start_col: 4,
-                end_line: 15,
-                end_col: 4,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![], // no type annotation
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder(
+                "a",
+                "MissionContext::transportPassenger::a",
+                SymbolKind::ActionUsage,
+                FileId::new(0),
+            )
+            .span(LineColSpan::from_coords(12, 4, 15, 4))
+            .is_public(true)
+            .build(),
             // Action inside 'a' that references getInVehicle_a
-            HirSymbol {
-                name: Arc::from("nestedAction"),
-                qualified_name: Arc::from("MissionContext::transportPassenger::a::nestedAction"),
-                element_id: new_element_id(),
-                kind: SymbolKind::ActionUsage,
-                file: FileId::new(0),
-                start_line: 13,
-                start_col: 8,
-                end_line: 13,
-                end_col: 50,
-                short_name: None,
-                short_name_start_line: None,
-                short_name_start_col: None,
-                short_name_end_line: None,
-                short_name_end_col: None,
-                supertypes: vec![Arc::from("getInVehicle_a")], // subsets getInVehicle_a
-                relationships: vec![],
-                type_refs: vec![],
-                doc: None,
-                is_public: true,
-                view_data: None,
-                metadata_annotations: vec![],
-                is_abstract: false,
-                is_variation: false,
-                is_readonly: false,
-                is_derived: false,
-                is_parallel: false,
-                is_individual: false,
-                is_end: false,
-                is_default: false,
-                is_ordered: false,
-                is_nonunique: false,
-                is_portion: false,
-                direction: None,
-                multiplicity: None,
-                value: None,
-            },
+            HirSymbol::builder(
+                "nestedAction",
+                "MissionContext::transportPassenger::a::nestedAction",
+                SymbolKind::ActionUsage,
+                FileId::new(0),
+            )
+            .span(LineColSpan::from_coords(13, 8, 13, 50))
+            .supertypes(vec![Arc::from("getInVehicle_a")]) // subsets getInVehicle_a
+            .is_public(true)
+            .build(),
         ],
     );
 
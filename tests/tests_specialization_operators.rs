@@ -510,6 +510,27 @@ fn test_subsets_keyword() {
     }
 }
 
+/// Test that KerML type operators parse as their own kinds, not Specializes
+#[test]
+fn test_type_operator_keywords() {
+    let input = "part def U unions A;\npart def I intersects A;";
+    let file = parse_source(input);
+    let kinds: Vec<_> = file
+        .members()
+        .map(|member| match member {
+            NamespaceMember::Definition(def) => def.specializations().next().unwrap().kind(),
+            _ => panic!("Expected Definition"),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            Some(SpecializationKind::Unions),
+            Some(SpecializationKind::Intersects),
+        ]
+    );
+}
+
 /// Test `references` keyword
 #[test]
 fn test_references_keyword() {